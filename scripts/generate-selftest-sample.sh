@@ -0,0 +1,40 @@
+#!/usr/bin/env bash
+# Generate the bundled self-test sample (src-tauri/resources/selftest.wav).
+#
+# Usage:
+#   ./scripts/generate-selftest-sample.sh [output.wav]
+#
+# Speaks selftest::SAMPLE_TEXT with the system text-to-speech voice and
+# converts it to 16 kHz mono 16-bit WAV, the format the pipeline records in.
+#
+# Requirements: ffmpeg, and either `say` (macOS) or `espeak-ng` (Linux)
+
+set -euo pipefail
+
+OUTPUT="${1:-src-tauri/resources/selftest.wav}"
+TEXT="The quick brown fox jumps over the lazy dog."
+
+command -v ffmpeg >/dev/null 2>&1 || { echo "Error: ffmpeg required" >&2; exit 1; }
+
+TMP_DIR=$(mktemp -d)
+trap 'rm -rf "$TMP_DIR"' EXIT
+
+if command -v say >/dev/null 2>&1; then
+  RAW="$TMP_DIR/speech.aiff"
+  say -o "$RAW" "$TEXT"
+elif command -v espeak-ng >/dev/null 2>&1; then
+  RAW="$TMP_DIR/speech.wav"
+  espeak-ng -s 150 -w "$RAW" "$TEXT"
+else
+  echo "Error: say or espeak-ng required" >&2
+  exit 1
+fi
+
+mkdir -p "$(dirname "$OUTPUT")"
+
+# Half a second of silence either side, as in a real recording
+ffmpeg -y -loglevel error -i "$RAW" \
+  -af "adelay=500|500,apad=pad_dur=0.5" \
+  -ac 1 -ar 16000 -sample_fmt s16 "$OUTPUT"
+
+echo "Wrote $OUTPUT"
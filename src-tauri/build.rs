@@ -48,5 +48,48 @@ fn main() {
         }
     }
 
+    // Record the commit this binary was built from for `get_app_info`. Absent
+    // (and reported as unknown) when building outside a git checkout.
+    if let Ok(output) = std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+    {
+        let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if output.status.success() && !commit.is_empty() {
+            println!("cargo:rustc-env=THOTH_BUILD_COMMIT={commit}");
+        }
+    }
+
+    // tauri_build registers its own rerun-if-changed paths, which would
+    // otherwise leave the commit stale across checkouts and new commits.
+    let git_path = |path: &str| {
+        std::process::Command::new("git")
+            .args(["rev-parse", "--git-path", path])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            // A missing path would make cargo rerun the script on every build.
+            .filter(|path| std::path::Path::new(path).exists())
+    };
+    if let Some(head) = git_path("HEAD") {
+        println!("cargo:rerun-if-changed={head}");
+    }
+    if let Ok(output) = std::process::Command::new("git")
+        .args(["symbolic-ref", "-q", "HEAD"])
+        .output()
+    {
+        let reference = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if output.status.success() && !reference.is_empty() {
+            if let Some(path) = git_path(&reference) {
+                println!("cargo:rerun-if-changed={path}");
+            }
+            // The ref may only exist in packed-refs after a `git gc`.
+            if let Some(packed) = git_path("packed-refs") {
+                println!("cargo:rerun-if-changed={packed}");
+            }
+        }
+    }
+
     tauri_build::build();
 }
@@ -17,15 +17,30 @@
 //!
 //! The recorder keeps the cpal stream open ("warm") between recordings so that
 //! pressing record is an instant flag flip rather than a ~150ms device open.
+//!
+//! The one loss the hand-off cannot prevent happens upstream of it: the device
+//! or OS skipping input (an xrun) before the callback sees it. While armed the
+//! callback counts those as dropouts — stream errors, and gaps in the capture
+//! timestamps longer than the previous block plus [`DROPOUT_GAP_TOLERANCE`] —
+//! so a damaged recording is flagged instead of silently transcribed.
+//!
+//! For conference capture the recorder can hold several devices warm at once
+//! ([`AudioRecorder::warm_up_mixed`]); their blocks share the channel, tagged
+//! with the device they came from, and the writer mixes them (see
+//! [`super::mix`]).
 
 use super::format::AudioConverter;
+use super::mix::Mixer;
 use super::ring_buffer::AudioRingBuffer;
+use super::vad::{VadConfig, VadStateTracker};
+use crate::config::ChannelMode;
 use anyhow::{Result, anyhow};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use crossbeam_channel::{Receiver, Sender};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
 
 /// Target sample rate for transcription (whisper.cpp / Parakeet expect 16kHz mono).
 const TARGET_SAMPLE_RATE: u32 = 16000;
@@ -34,13 +49,71 @@ const TARGET_SAMPLE_RATE: u32 = 16000;
 /// live capture and file import resample through the same well-tested path.
 const RESAMPLE_CHUNK_SIZE: usize = 1024;
 
+/// Slack allowed between consecutive capture timestamps beyond the previous
+/// block's length before the difference is counted as a dropout. Generous so
+/// timestamp jitter is not mistaken for lost audio.
+const DROPOUT_GAP_TOLERANCE: Duration = Duration::from_millis(50);
+
+/// A capture timestamp [`GapDetector`] can measure between.
+trait CaptureInstant: Copy {
+    /// Time from `earlier` to this instant, `None` if `earlier` is later.
+    fn since(&self, earlier: &Self) -> Option<Duration>;
+}
+
+impl CaptureInstant for cpal::StreamInstant {
+    fn since(&self, earlier: &Self) -> Option<Duration> {
+        self.duration_since(earlier)
+    }
+}
+
+/// Spots input the device skipped between two callbacks of one recording.
+///
+/// Lives in the audio callback, so it only does arithmetic: nothing that
+/// allocates or blocks.
+struct GapDetector<T> {
+    /// Recording the previous block belonged to
+    seen_generation: u64,
+    /// The previous block's capture time and length
+    previous: Option<(T, Duration)>,
+}
+
+impl<T: CaptureInstant> GapDetector<T> {
+    fn new() -> Self {
+        Self {
+            seen_generation: 0,
+            previous: None,
+        }
+    }
+
+    /// Record a block of `length` captured at `capture` during recording
+    /// `generation`, returning whether input was lost before it.
+    ///
+    /// The first block of a new generation never counts, so the pause
+    /// between two recordings is not mistaken for a dropout.
+    fn is_gap(&mut self, generation: u64, capture: T, length: Duration) -> bool {
+        if generation != self.seen_generation {
+            self.seen_generation = generation;
+            self.previous = None;
+        }
+        let gap = self.previous.is_some_and(|(previous, previous_length)| {
+            capture
+                .since(&previous)
+                .is_some_and(|gap| gap > previous_length + DROPOUT_GAP_TOLERANCE)
+        });
+        self.previous = Some((capture, length));
+        gap
+    }
+}
+
 /// Messages from the audio callback to the writer thread.
 ///
-/// `Samples` carries one callback's worth of device-native interleaved f32.
-/// `Stop` is the end-of-stream sentinel pushed by `disarm`; the writer drains
-/// every queued `Samples` ahead of it before finalising, so no tail is lost.
+/// `Samples` carries one callback's worth of device-native interleaved f32;
+/// `Source` is the same from one of several mixed devices, tagged with its
+/// index. `Stop` is the end-of-stream sentinel pushed by `disarm`; the writer
+/// drains every queued block ahead of it before finalising, so no tail is lost.
 enum RecordingMsg {
     Samples(Vec<f32>),
+    Source(usize, Vec<f32>),
     Stop,
 }
 
@@ -58,6 +131,11 @@ enum RecordingMsg {
 pub struct AudioRecorder {
     /// The warm cpal stream (open from `warm_up` to `cool_down`).
     stream: Option<cpal::Stream>,
+    /// Streams of the other devices when mixing (see `warm_up_mixed`).
+    extra_streams: Vec<cpal::Stream>,
+    /// Sample rate and channel count of each mixed device, by index. Empty
+    /// when recording from a single device.
+    mix_formats: Vec<(u32, usize)>,
     /// Writer thread that resamples queued samples to a WAV file.
     writer_handle: Option<std::thread::JoinHandle<Result<()>>>,
     /// Sender end of the capture channel. Created in `warm_up`, held for the
@@ -76,6 +154,14 @@ pub struct AudioRecorder {
     source_channels: Option<usize>,
     /// Whether the callback should forward samples to the writer.
     armed: Arc<AtomicBool>,
+    /// Bumped by `arm` so each callback forgets the previous recording's last
+    /// capture timestamp instead of counting the pause as a gap.
+    recording_generation: Arc<AtomicU64>,
+    /// Dropouts (stream errors and capture gaps) since the last `arm`.
+    dropouts: Arc<AtomicU64>,
+    /// Set when `cool_down` was refused during a recording, so the stream is
+    /// reopened (e.g. with a new device set) once the recording stops.
+    rewarm_pending: bool,
 }
 
 impl Default for AudioRecorder {
@@ -89,6 +175,8 @@ impl AudioRecorder {
     pub fn new() -> Self {
         Self {
             stream: None,
+            extra_streams: Vec::new(),
+            mix_formats: Vec::new(),
             writer_handle: None,
             sender: None,
             receiver: None,
@@ -97,9 +185,19 @@ impl AudioRecorder {
             source_rate: None,
             source_channels: None,
             armed: Arc::new(AtomicBool::new(false)),
+            recording_generation: Arc::new(AtomicU64::new(0)),
+            dropouts: Arc::new(AtomicU64::new(0)),
+            rewarm_pending: false,
         }
     }
 
+    /// Dropouts detected during the current (or most recent) recording.
+    ///
+    /// Reset by `arm`, so after `disarm` this reports the finished recording.
+    pub fn dropouts(&self) -> u64 {
+        self.dropouts.load(Ordering::SeqCst)
+    }
+
     /// Set a dedicated metering ring buffer.
     ///
     /// The metering buffer receives samples continuously while warm (even before
@@ -141,6 +239,98 @@ impl AudioRecorder {
         let device_name = super::device::get_device_display_name(device);
         tracing::info!("AudioRecorder::warm_up: opening device '{}'", device_name);
 
+        // The capture channel lives for the whole warm-stream lifetime so the
+        // callback always holds a valid sender. Unbounded: the producer (audio
+        // thread) never blocks and never drops.
+        let (sender, receiver) = crossbeam_channel::unbounded::<RecordingMsg>();
+        let (stream, source_rate, source_channels) =
+            self.open_stream(device, None, sender.clone())?;
+
+        self.source_rate = Some(source_rate);
+        self.source_channels = Some(source_channels);
+        self.sender = Some(sender);
+        self.receiver = Some(receiver);
+
+        stream.play()?;
+        self.stream = Some(stream);
+        tracing::info!("AudioRecorder::warm_up: stream open and playing");
+        Ok(())
+    }
+
+    /// Open several input devices and mix them into every recording.
+    ///
+    /// Each device gets its own warm stream feeding the shared capture
+    /// channel, tagged with its index; the writer resamples, aligns and sums
+    /// them. A device that fails to open is left out with a warning rather
+    /// than failing the recording, as long as at least one opens. A no-op if a
+    /// stream is already open, like `warm_up`.
+    pub fn warm_up_mixed(&mut self, devices: &[cpal::Device]) -> Result<()> {
+        if self.stream.is_some() {
+            tracing::debug!("AudioRecorder::warm_up_mixed: streams already open, no-op");
+            return Ok(());
+        }
+
+        tracing::info!(
+            "AudioRecorder::warm_up_mixed: opening {} devices",
+            devices.len()
+        );
+
+        let (sender, receiver) = crossbeam_channel::unbounded::<RecordingMsg>();
+        let mut streams = Vec::with_capacity(devices.len());
+        let mut formats = Vec::with_capacity(devices.len());
+        for device in devices {
+            match self.open_stream(device, Some(streams.len()), sender.clone()) {
+                Ok((stream, rate, channels)) => {
+                    streams.push(stream);
+                    formats.push((rate, channels));
+                }
+                Err(e) => tracing::warn!(
+                    "AudioRecorder::warm_up_mixed: leaving '{}' out of the mix: {}",
+                    super::device::get_device_display_name(device),
+                    e
+                ),
+            }
+        }
+        if streams.is_empty() {
+            return Err(anyhow!(
+                "None of the {} mix devices could be opened",
+                devices.len()
+            ));
+        }
+        for stream in &streams {
+            stream.play()?;
+        }
+
+        self.source_rate = Some(formats[0].0);
+        self.source_channels = Some(formats[0].1);
+        self.mix_formats = formats;
+        self.sender = Some(sender);
+        self.receiver = Some(receiver);
+
+        let mut streams = streams.into_iter();
+        self.stream = streams.next();
+        self.extra_streams = streams.collect();
+        tracing::info!(
+            "AudioRecorder::warm_up_mixed: {} streams open and playing",
+            self.mix_formats.len()
+        );
+        Ok(())
+    }
+
+    /// Build (but do not start) an input stream on `device` that forwards its
+    /// blocks to `sender` while armed.
+    ///
+    /// `source` tags the blocks with a mix index; `None` sends them untagged
+    /// for a single-device recording. Returns the stream with the device's
+    /// sample rate and channel count.
+    #[allow(deprecated)] // cpal 0.17 deprecates name() but description() is not yet stable
+    fn open_stream(
+        &self,
+        device: &cpal::Device,
+        source: Option<usize>,
+        sender: Sender<RecordingMsg>,
+    ) -> Result<(cpal::Stream, u32, usize)> {
+        let device_name = super::device::get_device_display_name(device);
         let supported_config = device.default_input_config()?;
         let source_rate = supported_config.sample_rate();
         let source_channels = supported_config.channels() as usize;
@@ -152,23 +342,17 @@ impl AudioRecorder {
             source_channels,
         );
 
-        self.source_rate = Some(source_rate);
-        self.source_channels = Some(source_channels);
-
-        // The capture channel lives for the whole warm-stream lifetime so the
-        // callback always holds a valid sender. Unbounded: the producer (audio
-        // thread) never blocks and never drops.
-        let (sender, receiver) = crossbeam_channel::unbounded::<RecordingMsg>();
-        let callback_sender = sender.clone();
-        self.sender = Some(sender);
-        self.receiver = Some(receiver);
-
         let callback_metering = self.metering_buffer.clone();
         let callback_armed = self.armed.clone();
+        let callback_generation = self.recording_generation.clone();
+        let callback_dropouts = self.dropouts.clone();
+        let error_armed = self.armed.clone();
+        let error_dropouts = self.dropouts.clone();
+        let mut gaps = GapDetector::new();
 
         let stream = device.build_input_stream(
             supported_config.into(),
-            move |data: &[f32], _: &cpal::InputCallbackInfo| {
+            move |data: &[f32], info: &cpal::InputCallbackInfo| {
                 // Metering always runs while warm (regardless of armed state) so
                 // the recording indicator can show levels before the user hits record.
                 if let Some(ref m) = callback_metering {
@@ -182,19 +366,35 @@ impl AudioRecorder {
                 // cannot fire again and no block can be enqueued after the Stop
                 // sentinel — the channel order faithfully reflects capture order.
                 if callback_armed.load(Ordering::SeqCst) {
-                    let _ = callback_sender.send(RecordingMsg::Samples(data.to_vec()));
+                    let block = data.to_vec();
+                    let msg = match source {
+                        Some(index) => RecordingMsg::Source(index, block),
+                        None => RecordingMsg::Samples(block),
+                    };
+                    let _ = sender.send(msg);
+
+                    // Count input the device skipped before it reached us.
+                    let frames = data.len() / source_channels.max(1);
+                    let length = Duration::from_secs_f64(frames as f64 / source_rate as f64);
+                    if gaps.is_gap(
+                        callback_generation.load(Ordering::SeqCst),
+                        info.timestamp().capture,
+                        length,
+                    ) {
+                        callback_dropouts.fetch_add(1, Ordering::SeqCst);
+                    }
                 }
             },
-            |err| {
+            move |err| {
                 tracing::error!("Audio stream error: {}", err);
+                if error_armed.load(Ordering::SeqCst) {
+                    error_dropouts.fetch_add(1, Ordering::SeqCst);
+                }
             },
             None,
         )?;
 
-        stream.play()?;
-        self.stream = Some(stream);
-        tracing::info!("AudioRecorder::warm_up: stream open and playing");
-        Ok(())
+        Ok((stream, source_rate, source_channels))
     }
 
     /// Arm the recorder: prepare the output file and start the writer thread.
@@ -231,11 +431,29 @@ impl AudioRecorder {
 
         self.output_path = Some(output_path.to_path_buf());
 
+        let channel_mode = crate::config::get_config()
+            .map(|c| c.audio.channel_mode)
+            .unwrap_or_default();
+
         let writer_path = output_path.to_path_buf();
+        let mix_formats = self.mix_formats.clone();
         self.writer_handle = Some(std::thread::spawn(move || {
-            write_audio_to_file(receiver, &writer_path, source_rate, source_channels)
+            if mix_formats.is_empty() {
+                write_audio_to_file(
+                    receiver,
+                    &writer_path,
+                    source_rate,
+                    source_channels,
+                    channel_mode,
+                )
+            } else {
+                write_mixed_audio_to_file(receiver, &writer_path, &mix_formats, channel_mode)
+            }
         }));
 
+        self.dropouts.store(0, Ordering::SeqCst);
+        self.recording_generation.fetch_add(1, Ordering::SeqCst);
+
         // Armed flag is set LAST so the callback doesn't send until the writer
         // thread is running.
         self.armed.store(true, Ordering::SeqCst);
@@ -271,7 +489,7 @@ impl AudioRecorder {
         // Stop — never corruption and never a dropped tail. Falling back to a
         // full device close here would only make the next recording slow for no
         // correctness gain, so we keep the stream warm.
-        if let Some(stream) = self.stream.as_ref() {
+        for stream in self.stream.iter().chain(&self.extra_streams) {
             if let Err(e) = stream.pause() {
                 tracing::debug!(
                     "AudioRecorder::disarm: stream.pause() not supported/failed ({}); the armed \
@@ -297,7 +515,7 @@ impl AudioRecorder {
 
         // Resume the stream so metering keeps flowing and the next arm is instant.
         // The device was only paused, never closed, so this is cheap.
-        if let Some(stream) = self.stream.as_ref() {
+        for stream in self.stream.iter().chain(&self.extra_streams) {
             if let Err(e) = stream.play() {
                 tracing::warn!(
                     "AudioRecorder::disarm: stream.play() (re-warm) failed: {}",
@@ -311,6 +529,15 @@ impl AudioRecorder {
             .take()
             .ok_or_else(|| anyhow!("output_path missing after disarm"))?;
 
+        let dropouts = self.dropouts();
+        if dropouts > 0 {
+            tracing::warn!(
+                "AudioRecorder::disarm: {} audio dropout(s) during this recording; \
+                 some input was lost before capture and the transcript may be incomplete",
+                dropouts
+            );
+        }
+
         tracing::info!(
             "AudioRecorder::disarm: recording saved to {}",
             path.display()
@@ -322,18 +549,29 @@ impl AudioRecorder {
     ///
     /// Called on idle timeout, device change, or sleep/wake. Refuses to act
     /// while a recording is armed — tearing the stream down mid-capture would
-    /// silently lose the recording. Callers must stop the recording first (via
-    /// the normal stop-and-process path) if they need to cool down during one.
+    /// silently lose the recording. The refusal is remembered instead, and
+    /// [`take_rewarm_pending`](Self::take_rewarm_pending) reports it once the
+    /// recording has been disarmed.
     pub fn cool_down(&mut self) {
         if self.armed.load(Ordering::Relaxed) {
-            tracing::warn!("AudioRecorder::cool_down skipped — a recording is in progress");
+            tracing::warn!("AudioRecorder::cool_down deferred — a recording is in progress");
+            self.rewarm_pending = true;
             return;
         }
+        self.rewarm_pending = false;
 
         if let Some(stream) = self.stream.take() {
             drop(stream);
             tracing::info!("AudioRecorder::cool_down: stream closed");
         }
+        if !self.extra_streams.is_empty() {
+            tracing::info!(
+                "AudioRecorder::cool_down: {} mixed streams closed",
+                self.extra_streams.len()
+            );
+            self.extra_streams.clear();
+        }
+        self.mix_formats.clear();
 
         // Drop the channel so a future warm_up starts a fresh one matched to the
         // (possibly different) device's rate and channel count.
@@ -343,6 +581,15 @@ impl AudioRecorder {
         self.source_channels = None;
     }
 
+    /// Whether a `cool_down` was deferred by a recording since the stream was
+    /// last closed, clearing the flag.
+    ///
+    /// The caller should close and reopen the stream so a device change made
+    /// during the recording takes effect.
+    pub fn take_rewarm_pending(&mut self) -> bool {
+        std::mem::take(&mut self.rewarm_pending)
+    }
+
     // -------------------------------------------------------------------------
     // Legacy single-call API (used by tests and VAD recorder path)
     // These wrap warm_up + arm or disarm + cool_down for callers that don't
@@ -392,6 +639,7 @@ fn write_audio_to_file(
     path: &Path,
     source_rate: u32,
     source_channels: usize,
+    channel_mode: ChannelMode,
 ) -> Result<()> {
     let spec = hound::WavSpec {
         channels: 1,
@@ -401,9 +649,10 @@ fn write_audio_to_file(
     };
 
     tracing::info!(
-        "Writer thread starting: source_rate={}, channels={}, target={}, output={}",
+        "Writer thread starting: source_rate={}, channels={} ({:?}), target={}, output={}",
         source_rate,
         source_channels,
+        channel_mode,
         TARGET_SAMPLE_RATE,
         path.display()
     );
@@ -414,7 +663,8 @@ fn write_audio_to_file(
         source_channels,
         RESAMPLE_CHUNK_SIZE,
     )
-    .map_err(|e| anyhow!("Failed to create resampler: {}", e))?;
+    .map_err(|e| anyhow!("Failed to create resampler: {}", e))?
+    .with_channel_mode(channel_mode);
 
     // The resampler consumes fixed-size chunks of interleaved frames. Accumulate
     // the variable-sized callback blocks and drain in exact chunks.
@@ -424,9 +674,14 @@ fn write_audio_to_file(
     let mut writer = hound::WavWriter::create(path, spec)?;
     let mut total_samples = 0usize;
 
+    // Live speech-state tracking for the frontend. Dropped (resetting the
+    // published state to Silence) when this thread finishes.
+    let mut vad_tracker = VadStateTracker::new(VadConfig::default());
+
     let drain_full_chunks = |accumulator: &mut Vec<f32>,
                              converter: &mut AudioConverter,
                              writer: &mut hound::WavWriter<std::io::BufWriter<std::fs::File>>,
+                             vad_tracker: &mut VadStateTracker,
                              total: &mut usize|
      -> Result<()> {
         while accumulator.len() >= frames_per_chunk {
@@ -437,6 +692,8 @@ fn write_audio_to_file(
             for sample in &resampled {
                 writer.write_sample(*sample)?;
             }
+            super::live::append(&resampled);
+            vad_tracker.process(&resampled);
             *total += resampled.len();
         }
         Ok(())
@@ -447,12 +704,15 @@ fn write_audio_to_file(
     // sentinel to end the loop.
     for msg in receiver.iter() {
         match msg {
-            RecordingMsg::Samples(block) => {
+            // Only mixed devices tag their blocks, but a tagged block is still
+            // audio and is never dropped.
+            RecordingMsg::Samples(block) | RecordingMsg::Source(_, block) => {
                 accumulator.extend_from_slice(&block);
                 drain_full_chunks(
                     &mut accumulator,
                     &mut converter,
                     &mut writer,
+                    &mut vad_tracker,
                     &mut total_samples,
                 )?;
             }
@@ -470,6 +730,7 @@ fn write_audio_to_file(
     for sample in &tail {
         writer.write_sample(*sample)?;
     }
+    super::live::append(&tail);
     total_samples += tail.len();
 
     writer.finalize()?;
@@ -484,6 +745,78 @@ fn write_audio_to_file(
     Ok(())
 }
 
+/// Mix queued capture blocks from several devices into a 16kHz mono WAV file.
+///
+/// The mixed counterpart of [`write_audio_to_file`]: blocks arrive tagged with
+/// their device's index, and [`Mixer`] resamples each device on its own, aligns
+/// them and sums them. A device that stalls is filled with silence rather than
+/// holding up the others. Runs until the `Stop` sentinel, then flushes every
+/// device's trailing chunk and resampler delay.
+fn write_mixed_audio_to_file(
+    receiver: Receiver<RecordingMsg>,
+    path: &Path,
+    formats: &[(u32, usize)],
+    channel_mode: ChannelMode,
+) -> Result<()> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: TARGET_SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    tracing::info!(
+        "Mixed writer thread starting: devices={:?} ({:?}), target={}, output={}",
+        formats,
+        channel_mode,
+        TARGET_SAMPLE_RATE,
+        path.display()
+    );
+
+    let mut mixer = Mixer::new(
+        formats,
+        TARGET_SAMPLE_RATE,
+        RESAMPLE_CHUNK_SIZE,
+        channel_mode,
+    )?;
+    let mut writer = hound::WavWriter::create(path, spec)?;
+    let mut total_samples = 0usize;
+    let mut vad_tracker = VadStateTracker::new(VadConfig::default());
+
+    for msg in receiver.iter() {
+        let (source, block) = match msg {
+            RecordingMsg::Source(source, block) => (source, block),
+            RecordingMsg::Samples(block) => (0, block),
+            RecordingMsg::Stop => break,
+        };
+        mixer.push(source, &block)?;
+        let mixed = mixer.take_ready();
+        for sample in &mixed {
+            writer.write_sample(*sample)?;
+        }
+        super::live::append(&mixed);
+        vad_tracker.process(&mixed);
+        total_samples += mixed.len();
+    }
+
+    let tail = mixer.finish()?;
+    for sample in &tail {
+        writer.write_sample(*sample)?;
+    }
+    super::live::append(&tail);
+    total_samples += tail.len();
+
+    writer.finalize()?;
+    tracing::info!(
+        "Mixed audio writer finished: {} samples, {:.2}s from {} devices -> {}",
+        total_samples,
+        total_samples as f32 / TARGET_SAMPLE_RATE as f32,
+        formats.len(),
+        path.display()
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -496,7 +829,7 @@ mod tests {
         let (tx, rx) = crossbeam_channel::unbounded::<RecordingMsg>();
         let writer_path = path.to_path_buf();
         let handle = std::thread::spawn(move || {
-            write_audio_to_file(rx, &writer_path, source_rate, channels)
+            write_audio_to_file(rx, &writer_path, source_rate, channels, ChannelMode::Mix)
         });
 
         // Send the audio in small blocks, mimicking cpal callback cadence.
@@ -519,6 +852,47 @@ mod tests {
         reader.into_samples::<i16>().count()
     }
 
+    #[test]
+    fn test_mixed_writer_combines_devices() {
+        // 0.5s from a 48kHz stereo mic and a 16kHz mono mic, interleaved on the
+        // channel the way two callbacks would deliver them.
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("mixed_test.wav");
+        let (tx, rx) = crossbeam_channel::unbounded::<RecordingMsg>();
+        let writer_path = path.clone();
+        let handle = std::thread::spawn(move || {
+            write_mixed_audio_to_file(
+                rx,
+                &writer_path,
+                &[(48000, 2), (16000, 1)],
+                ChannelMode::Mix,
+            )
+        });
+        for _ in 0..50 {
+            tx.send(RecordingMsg::Source(0, vec![0.1; 480 * 2]))
+                .unwrap();
+            tx.send(RecordingMsg::Source(1, vec![0.1; 160])).unwrap();
+        }
+        tx.send(RecordingMsg::Stop).unwrap();
+        handle.join().unwrap().unwrap();
+
+        let reader = hound::WavReader::open(&path).unwrap();
+        assert_eq!(reader.spec().sample_rate, 16000);
+        let samples: Vec<i16> = reader.into_samples::<i16>().map(|s| s.unwrap()).collect();
+        assert!(
+            (7000..=9000).contains(&samples.len()),
+            "expected ~8000 samples for 0.5s at 16kHz, got {}",
+            samples.len()
+        );
+        let middle = samples[samples.len() / 2];
+        assert!(
+            (6000..=7100).contains(&middle),
+            "both devices mixed: {}",
+            middle
+        );
+        fs::remove_file(path).ok();
+    }
+
     #[test]
     fn test_recorder_new() {
         let recorder = AudioRecorder::new();
@@ -610,4 +984,50 @@ mod tests {
         let mut recorder = AudioRecorder::new();
         assert!(recorder.disarm().is_err());
     }
+
+    impl CaptureInstant for Duration {
+        fn since(&self, earlier: &Self) -> Option<Duration> {
+            self.checked_sub(*earlier)
+        }
+    }
+
+    #[test]
+    fn test_gap_detector_counts_skipped_input() {
+        let block = Duration::from_millis(10);
+        let mut gaps = GapDetector::new();
+        // Back-to-back blocks, with a little jitter
+        assert!(!gaps.is_gap(1, Duration::from_millis(0), block));
+        assert!(!gaps.is_gap(1, Duration::from_millis(10), block));
+        assert!(!gaps.is_gap(1, Duration::from_millis(30), block));
+        // 200 ms of input never arrived
+        assert!(gaps.is_gap(1, Duration::from_millis(240), block));
+        assert!(!gaps.is_gap(1, Duration::from_millis(250), block));
+    }
+
+    #[test]
+    fn test_gap_detector_ignores_pause_between_recordings() {
+        let block = Duration::from_millis(10);
+        let mut gaps = GapDetector::new();
+        assert!(!gaps.is_gap(1, Duration::from_millis(0), block));
+        // The next recording starts seconds later
+        assert!(!gaps.is_gap(2, Duration::from_secs(5), block));
+        assert!(!gaps.is_gap(2, Duration::from_millis(5010), block));
+    }
+
+    #[test]
+    fn test_cool_down_while_armed_is_deferred() {
+        let mut recorder = AudioRecorder::new();
+        recorder.armed.store(true, Ordering::SeqCst);
+        recorder.cool_down();
+        recorder.armed.store(false, Ordering::SeqCst);
+        assert!(recorder.take_rewarm_pending());
+        assert!(!recorder.take_rewarm_pending());
+
+        // A cool down that runs clears the deferred one
+        recorder.armed.store(true, Ordering::SeqCst);
+        recorder.cool_down();
+        recorder.armed.store(false, Ordering::SeqCst);
+        recorder.cool_down();
+        assert!(!recorder.take_rewarm_pending());
+    }
 }
@@ -2,9 +2,13 @@
 //!
 //! Uses symphonia for format-agnostic decoding (MP3, M4A, OGG, FLAC, WAV)
 //! and the existing AudioConverter (rubato) for high-quality resampling.
+//! [`convert_audio_to_wav`] exposes the decoder on its own, for converting a
+//! file without transcribing it.
 
 use crate::audio::format::AudioConverter;
-use std::path::Path;
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use symphonia::core::codecs::audio::AudioDecoderOptions;
 use symphonia::core::errors::Error as SymphoniaError;
@@ -25,15 +29,146 @@ const RESAMPLE_CHUNK_SIZE: usize = 1024;
 /// Check cancellation every N packets
 const CANCEL_CHECK_INTERVAL: u32 = 50;
 
+/// Error returned by [`decode_audio_to_wav`] when it is cancelled. Import is
+/// its main caller; [`convert_audio_to_wav`] reports its own message.
+pub(crate) const CANCELLED_ERROR: &str = "Import cancelled";
+
+/// File extensions accepted for import (matched case-insensitively).
+pub const SUPPORTED_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "ogg", "flac"];
+
+/// Whether `path` has one of the [`SUPPORTED_EXTENSIONS`].
+pub fn has_supported_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| {
+            SUPPORTED_EXTENSIONS
+                .iter()
+                .any(|s| s.eq_ignore_ascii_case(ext))
+        })
+}
+
+/// Prefix of every error meaning "this file is not an audio format we can
+/// decode", as opposed to a read/IO failure part-way through a supported file.
+///
+/// Callers that need to tell the user *which* file to fix (batch import) check
+/// via [`is_unsupported_format_error`] rather than matching inline strings.
+pub const UNSUPPORTED_FORMAT_ERROR: &str = "Unsupported audio format";
+
+/// Returns true when a decode error means the input format or codec is not
+/// supported, rather than a genuine failure decoding a supported file.
+pub fn is_unsupported_format_error(e: &str) -> bool {
+    e.starts_with(UNSUPPORTED_FORMAT_ERROR)
+}
+
+/// Container/codec family of an input file, as detected before decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InputFormat {
+    /// RIFF/WAVE (PCM)
+    Wav,
+    /// MPEG-1/2 Layer III
+    Mp3,
+    /// MP4/M4A container (usually AAC, sometimes ALAC)
+    M4a,
+    /// Ogg container (usually Vorbis)
+    Ogg,
+    /// Free Lossless Audio Codec
+    Flac,
+}
+
+impl InputFormat {
+    /// Detect the format from the file's leading bytes, falling back to the
+    /// extension when the signature is not recognised (e.g. headerless MP3).
+    pub fn detect(path: &Path) -> Option<Self> {
+        use std::io::Read;
+
+        let mut magic = [0u8; 12];
+        let read = std::fs::File::open(path)
+            .and_then(|mut f| f.read(&mut magic))
+            .unwrap_or(0);
+        Self::from_magic(&magic[..read]).or_else(|| Self::from_extension(path))
+    }
+
+    fn from_magic(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WAVE" {
+            Some(Self::Wav)
+        } else if bytes.starts_with(b"fLaC") {
+            Some(Self::Flac)
+        } else if bytes.starts_with(b"OggS") {
+            Some(Self::Ogg)
+        } else if bytes.len() >= 8 && &bytes[4..8] == b"ftyp" {
+            Some(Self::M4a)
+        } else if bytes.starts_with(b"ID3") || is_mp3_frame_sync(bytes) {
+            Some(Self::Mp3)
+        } else {
+            None
+        }
+    }
+
+    fn from_extension(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "wav" => Some(Self::Wav),
+            "mp3" => Some(Self::Mp3),
+            "m4a" => Some(Self::M4a),
+            "ogg" => Some(Self::Ogg),
+            "flac" => Some(Self::Flac),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `bytes` start with an MPEG audio Layer III frame header.
+///
+/// The 11-bit sync word alone also matches AAC in ADTS framing, whose layer
+/// bits are `00`, so the version and layer must be valid too.
+fn is_mp3_frame_sync(bytes: &[u8]) -> bool {
+    let [0xFF, header, ..] = *bytes else {
+        return false;
+    };
+    let sync = header & 0xE0 == 0xE0;
+    let version_valid = (header >> 3) & 0x03 != 0x01;
+    let layer_iii = (header >> 1) & 0x03 == 0x01;
+    sync && version_valid && layer_iii
+}
+
+/// Codec name reported for a decoder's short name, with the PCM sample
+/// layouts ("pcm_s16le", "pcm_f32le", ...) reported as "pcm".
+fn codec_name(short_name: &str) -> String {
+    if short_name.starts_with("pcm") {
+        "pcm".to_string()
+    } else {
+        short_name.to_string()
+    }
+}
+
+/// Result of a successful [`decode_audio_to_wav`] call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedAudio {
+    /// Duration of the decoded audio in seconds
+    pub duration_seconds: f64,
+    /// Detected input format
+    pub format: InputFormat,
+    /// Codec of the decoded track (e.g. "aac", "alac", "vorbis")
+    pub codec: String,
+    /// Sample rate of the input before resampling
+    pub source_sample_rate: u32,
+    /// Channel count of the input before downmixing
+    pub source_channels: usize,
+}
+
 /// Decode an audio file to 16kHz mono WAV suitable for transcription.
 ///
 /// Supports WAV, MP3, M4A (AAC), OGG Vorbis, and FLAC formats.
-/// Returns the audio duration in seconds on success.
+/// Returns the duration and the detected input format on success. Inputs that
+/// are not a supported format or codec fail with an error starting with
+/// [`UNSUPPORTED_FORMAT_ERROR`].
 pub fn decode_audio_to_wav(
     input_path: &Path,
     output_path: &Path,
     cancel: &AtomicBool,
-) -> Result<f64, String> {
+) -> Result<DecodedAudio, String> {
     // Validate file exists and check size
     let metadata = std::fs::metadata(input_path).map_err(|e| format!("Cannot read file: {}", e))?;
 
@@ -45,12 +180,27 @@ pub fn decode_audio_to_wav(
         ));
     }
 
+    let input_format = InputFormat::detect(input_path).ok_or_else(|| {
+        format!(
+            "{}: {} is not one of {}",
+            UNSUPPORTED_FORMAT_ERROR,
+            input_path.display(),
+            SUPPORTED_EXTENSIONS.join(", ")
+        )
+    })?;
+
     // Fast path: if already 16kHz mono WAV, copy directly
     if is_target_format_wav(input_path) {
         tracing::info!("Audio file is already 16kHz mono WAV, copying directly");
         std::fs::copy(input_path, output_path)
             .map_err(|e| format!("Failed to copy WAV file: {}", e))?;
-        return get_wav_duration(output_path);
+        return Ok(DecodedAudio {
+            duration_seconds: get_wav_duration(output_path)?,
+            format: InputFormat::Wav,
+            codec: "pcm".to_string(),
+            source_sample_rate: TARGET_SAMPLE_RATE,
+            source_channels: 1,
+        });
     }
 
     // Open the file and create a media source stream
@@ -72,12 +222,15 @@ pub fn decode_audio_to_wav(
             FormatOptions::default(),
             MetadataOptions::default(),
         )
-        .map_err(|e| format!("Unsupported audio format: {}", e))?;
+        .map_err(|e| format!("{}: {}", UNSUPPORTED_FORMAT_ERROR, e))?;
 
     // Find the first audio track (symphonia 0.6: default_track(TrackType::Audio))
-    let track = format
-        .default_track(TrackType::Audio)
-        .ok_or_else(|| "No supported audio track found in file".to_string())?;
+    let track = format.default_track(TrackType::Audio).ok_or_else(|| {
+        format!(
+            "{}: no supported audio track found",
+            UNSUPPORTED_FORMAT_ERROR
+        )
+    })?;
 
     let track_id = track.id;
 
@@ -98,26 +251,35 @@ pub fn decode_audio_to_wav(
         .map(|c| c.count())
         .unwrap_or(1);
 
+    // Create the decoder (symphonia 0.6: make_audio_decoder() replaces make())
+    let mut decoder = symphonia::default::get_codecs()
+        .make_audio_decoder(&audio_params, &AudioDecoderOptions::default())
+        .map_err(|e| format!("{} (codec): {}", UNSUPPORTED_FORMAT_ERROR, e))?;
+    // The track's codec, not a guess from the container: an M4A may hold
+    // ALAC, an Ogg Opus or FLAC
+    let codec = codec_name(decoder.codec_info().short_name);
+
     tracing::info!(
-        "Decoding: {}Hz, {} channels -> {}Hz mono",
+        "Decoding {:?} ({}): {}Hz, {} channels -> {}Hz mono",
+        input_format,
+        codec,
         source_rate,
         source_channels,
         TARGET_SAMPLE_RATE
     );
 
-    // Create the decoder (symphonia 0.6: make_audio_decoder() replaces make())
-    let mut decoder = symphonia::default::get_codecs()
-        .make_audio_decoder(&audio_params, &AudioDecoderOptions::default())
-        .map_err(|e| format!("Unsupported audio codec: {}", e))?;
-
     // Create the resampler (handles arbitrary sample rate ratios)
+    let channel_mode = crate::config::get_config()
+        .map(|c| c.audio.channel_mode)
+        .unwrap_or_default();
     let mut converter = AudioConverter::new(
         source_rate,
         TARGET_SAMPLE_RATE,
         source_channels,
         RESAMPLE_CHUNK_SIZE,
     )
-    .map_err(|e| format!("Failed to create resampler: {}", e))?;
+    .map_err(|e| format!("Failed to create resampler: {}", e))?
+    .with_channel_mode(channel_mode);
 
     // Open the output WAV file
     let wav_spec = hound::WavSpec {
@@ -132,6 +294,9 @@ pub fn decode_audio_to_wav(
     // Decode loop with chunk buffering for rubato
     let mut resample_buffer: Vec<f32> = Vec::new();
     let mut total_source_frames: u64 = 0;
+    let mut written_frames: u64 = 0;
+    // Leading resampler latency still to drop, so output lines up with the source
+    let mut skip_frames = converter.output_delay();
     let mut packet_count: u32 = 0;
     let frames_per_chunk = RESAMPLE_CHUNK_SIZE * source_channels;
 
@@ -141,7 +306,7 @@ pub fn decode_audio_to_wav(
             // Clean up partial output
             drop(wav_writer);
             let _ = std::fs::remove_file(output_path);
-            return Err("Import cancelled".to_string());
+            return Err(CANCELLED_ERROR.to_string());
         }
         packet_count += 1;
 
@@ -183,10 +348,11 @@ pub fn decode_audio_to_wav(
                 .process_to_i16(&chunk)
                 .map_err(|e| format!("Resampling error: {}", e))?;
 
-            for &sample in &resampled {
+            for &sample in skip_leading(&resampled, &mut skip_frames) {
                 wav_writer
                     .write_sample(sample)
                     .map_err(|e| format!("Failed to write WAV sample: {}", e))?;
+                written_frames += 1;
             }
         }
     }
@@ -197,7 +363,13 @@ pub fn decode_audio_to_wav(
     let tail = converter
         .finish_to_i16(&resample_buffer)
         .map_err(|e| format!("Resampling error during finalise: {}", e))?;
-    for &sample in &tail {
+    // Stop at the length the rate ratio implies; anything past it is the
+    // zero padding used to flush the final chunk.
+    let expected_frames =
+        (total_source_frames * TARGET_SAMPLE_RATE as u64).div_ceil(source_rate.max(1) as u64);
+    let tail = skip_leading(&tail, &mut skip_frames);
+    let room = expected_frames.saturating_sub(written_frames) as usize;
+    for &sample in &tail[..tail.len().min(room)] {
         wav_writer
             .write_sample(sample)
             .map_err(|e| format!("Failed to write WAV sample: {}", e))?;
@@ -218,7 +390,20 @@ pub fn decode_audio_to_wav(
         duration,
         output_path.display()
     );
-    Ok(duration)
+    Ok(DecodedAudio {
+        duration_seconds: duration,
+        format: input_format,
+        codec,
+        source_sample_rate: source_rate,
+        source_channels,
+    })
+}
+
+/// Drop up to `skip` frames from the front of `samples`, counting them off.
+fn skip_leading<'a>(samples: &'a [i16], skip: &mut usize) -> &'a [i16] {
+    let n = (*skip).min(samples.len());
+    *skip -= n;
+    &samples[n..]
 }
 
 /// Check if a WAV file is already in 16kHz mono i16 format (fast path).
@@ -250,6 +435,87 @@ fn get_wav_duration(path: &Path) -> Result<f64, String> {
     Ok(duration)
 }
 
+/// Cancellation signal for [`convert_audio_to_wav`]
+static CONVERT_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// Check a conversion's paths before decoding: the input must be an existing
+/// file and the output must be a different file in a writable directory.
+fn validate_conversion_paths(input: &Path, output: &Path) -> Result<(), String> {
+    if !input.is_file() {
+        return Err(format!("Input file does not exist: {}", input.display()));
+    }
+
+    let dir = match output.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    if !dir.is_dir() {
+        return Err(format!(
+            "Output directory does not exist: {}",
+            dir.display()
+        ));
+    }
+
+    let same_file = match (input.canonicalize(), output.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    };
+    if same_file {
+        return Err("Output must be a different file from the input".to_string());
+    }
+
+    // Permission bits do not tell the whole story (ACLs, read-only mounts),
+    // so probe with a real file.
+    let probe = dir.join(format!(".thoth_write_check_{}", uuid::Uuid::new_v4()));
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .map_err(|e| format!("Output directory is not writable: {}: {}", dir.display(), e))?;
+    let _ = std::fs::remove_file(&probe);
+
+    Ok(())
+}
+
+/// Convert an audio file to 16kHz mono WAV without transcribing it.
+///
+/// Accepts the same formats as import. Returns the duration of the converted
+/// audio in seconds. Can be stopped with [`cancel_audio_conversion`], which
+/// removes the partial output.
+#[tauri::command]
+pub async fn convert_audio_to_wav(input: String, output: String) -> Result<f64, Error> {
+    let input = PathBuf::from(input);
+    let output = PathBuf::from(output);
+    validate_conversion_paths(&input, &output)?;
+
+    CONVERT_CANCELLED.store(false, Ordering::SeqCst);
+    tracing::info!(
+        "Converting {} to WAV at {}",
+        input.display(),
+        output.display()
+    );
+    let decoded = tokio::task::spawn_blocking(move || {
+        decode_audio_to_wav(&input, &output, &CONVERT_CANCELLED)
+    })
+    .await
+    .map_err(|e| format!("Conversion task failed: {}", e))?
+    .map_err(|e| {
+        if e == CANCELLED_ERROR {
+            "Conversion cancelled".to_string()
+        } else {
+            e
+        }
+    })?;
+
+    Ok(decoded.duration_seconds)
+}
+
+/// Stop a running [`convert_audio_to_wav`].
+#[tauri::command]
+pub fn cancel_audio_conversion() {
+    CONVERT_CANCELLED.store(true, Ordering::SeqCst);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,6 +534,29 @@ mod tests {
         assert!(result.unwrap_err().contains("Cannot read file"));
     }
 
+    #[test]
+    fn test_validate_conversion_paths() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let input = dir.path().join("in.m4a");
+        std::fs::write(&input, b"not really audio").unwrap();
+        let output = dir.path().join("out.wav");
+
+        assert!(validate_conversion_paths(&input, &output).is_ok());
+        // The write probe leaves nothing behind.
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        let missing = dir.path().join("missing.m4a");
+        let err = validate_conversion_paths(&missing, &output).unwrap_err();
+        assert!(err.contains("Input file does not exist"));
+
+        let no_dir = dir.path().join("nope").join("out.wav");
+        let err = validate_conversion_paths(&input, &no_dir).unwrap_err();
+        assert!(err.contains("Output directory does not exist"));
+
+        let err = validate_conversion_paths(&input, &input).unwrap_err();
+        assert!(err.contains("different file"));
+    }
+
     #[test]
     fn test_cancellation() {
         let cancel = AtomicBool::new(true); // Pre-cancelled
@@ -322,7 +611,9 @@ mod tests {
 
         let result = decode_audio_to_wav(input.path(), output.path(), &cancel);
         assert!(result.is_ok());
-        let duration = result.unwrap();
+        let decoded = result.unwrap();
+        assert_eq!(decoded.format, InputFormat::Wav);
+        let duration = decoded.duration_seconds;
         assert!(
             (duration - 1.0).abs() < 0.01,
             "Expected ~1.0s, got {}",
@@ -363,7 +654,10 @@ mod tests {
 
         let result = decode_audio_to_wav(input.path(), output.path(), &cancel);
         assert!(result.is_ok());
-        let duration = result.unwrap();
+        let decoded = result.unwrap();
+        assert_eq!(decoded.source_sample_rate, 44100);
+        assert_eq!(decoded.source_channels, 2);
+        let duration = decoded.duration_seconds;
         assert!(
             (duration - 1.0).abs() < 0.05,
             "Expected ~1.0s, got {}",
@@ -375,4 +669,92 @@ mod tests {
         assert_eq!(reader.spec().sample_rate, 16000);
         assert_eq!(reader.spec().channels, 1);
     }
+
+    #[test]
+    fn test_wav_upsample_8k_length() {
+        let cancel = AtomicBool::new(false);
+
+        // 1 second of 8kHz mono telephone-rate audio
+        let input = tempfile::NamedTempFile::with_suffix(".wav").unwrap();
+        let output = tempfile::NamedTempFile::with_suffix(".wav").unwrap();
+
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        {
+            let mut writer = hound::WavWriter::create(input.path(), spec).unwrap();
+            for i in 0..8000 {
+                let sample = ((i as f64 * 440.0 * 2.0 * std::f64::consts::PI / 8000.0).sin()
+                    * 16000.0) as i16;
+                writer.write_sample(sample).unwrap();
+            }
+            writer.finalize().unwrap();
+        }
+
+        decode_audio_to_wav(input.path(), output.path(), &cancel).unwrap();
+
+        // Latency trimmed and flush padding dropped: ~16000 samples, never more
+        let reader = hound::WavReader::open(output.path()).unwrap();
+        let len = reader.len();
+        assert!(
+            len <= 16000 && 16000 - len <= 16000 / 50,
+            "expected ~16000 samples, got {}",
+            len
+        );
+    }
+
+    #[test]
+    fn test_unknown_format_is_unsupported_error() {
+        let cancel = AtomicBool::new(false);
+        let mut input = tempfile::NamedTempFile::with_suffix(".xyz").unwrap();
+        std::io::Write::write_all(&mut input, b"definitely not audio").unwrap();
+        let output = tempfile::NamedTempFile::with_suffix(".wav").unwrap();
+
+        let err = decode_audio_to_wav(input.path(), output.path(), &cancel).unwrap_err();
+        assert!(is_unsupported_format_error(&err), "got: {err}");
+    }
+
+    #[test]
+    fn test_input_format_from_magic() {
+        assert_eq!(
+            InputFormat::from_magic(b"RIFF\0\0\0\0WAVE"),
+            Some(InputFormat::Wav)
+        );
+        assert_eq!(InputFormat::from_magic(b"fLaC"), Some(InputFormat::Flac));
+        assert_eq!(InputFormat::from_magic(b"OggS"), Some(InputFormat::Ogg));
+        assert_eq!(
+            InputFormat::from_magic(b"\0\0\0\x20ftypM4A "),
+            Some(InputFormat::M4a)
+        );
+        assert_eq!(InputFormat::from_magic(b"ID3\x04"), Some(InputFormat::Mp3));
+        assert_eq!(
+            InputFormat::from_magic(&[0xFF, 0xFB]),
+            Some(InputFormat::Mp3)
+        );
+        assert_eq!(
+            InputFormat::from_magic(&[0xFF, 0xF3]),
+            Some(InputFormat::Mp3)
+        );
+        assert_eq!(InputFormat::from_magic(b"hello"), None);
+    }
+
+    #[test]
+    fn test_adts_aac_is_not_mp3() {
+        // ADTS sync words: MPEG-4 and MPEG-2 AAC, with and without CRC
+        for header in [0xF1, 0xF0, 0xF9, 0xF8] {
+            assert_eq!(InputFormat::from_magic(&[0xFF, header]), None);
+        }
+        // Reserved MPEG version
+        assert_eq!(InputFormat::from_magic(&[0xFF, 0xEB]), None);
+    }
+
+    #[test]
+    fn test_codec_name() {
+        assert_eq!(codec_name("pcm_s16le"), "pcm");
+        assert_eq!(codec_name("alac"), "alac");
+        assert_eq!(codec_name("opus"), "opus");
+    }
 }
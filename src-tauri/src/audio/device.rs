@@ -173,6 +173,8 @@ pub fn get_recording_device(device_id: Option<&str>) -> Option<cpal::Device> {
         // ALSA/PulseAudio/PipeWire switch) does not silently record from the
         // default device. Deduped so it does not toast on every recording.
         notify_device_fallback_once(id);
+        // The device list has changed since the tray last listed it.
+        crate::tray::invalidate_device_cache();
     }
 
     // When falling back to the default, check whether it is a Bluetooth device.
@@ -235,9 +237,32 @@ pub fn get_recording_device(device_id: Option<&str>) -> Option<cpal::Device> {
     device
 }
 
+/// Input devices to record together, from `audio.mix_device_ids`
+///
+/// Ids that are not connected are skipped with a warning. Returns an empty
+/// list when fewer than two ids are configured, since there is nothing to mix.
+pub fn get_mix_devices(ids: &[String]) -> Vec<cpal::Device> {
+    if ids.len() < 2 {
+        return Vec::new();
+    }
+    ids.iter()
+        .filter_map(|id| {
+            let device = find_input_device_by_id(id);
+            if device.is_none() {
+                tracing::warn!("Mix device '{}' not found, leaving it out", id);
+            }
+            device
+        })
+        .collect()
+}
+
 /// Tauri command to list audio devices
+///
+/// The settings window calls this to refresh its device list, so the tray's
+/// cached list is dropped as well.
 #[tauri::command]
 pub fn list_audio_devices() -> Vec<AudioDevice> {
+    crate::tray::invalidate_device_cache();
     let devices = list_input_devices();
     tracing::debug!("Found {} audio input devices", devices.len());
     devices
@@ -2,7 +2,13 @@
 //!
 //! Provides high-quality resampling from device sample rate (typically 48kHz)
 //! to 16kHz for transcription, including stereo to mono conversion.
+//!
+//! Resampling is band-limited sinc interpolation for every ratio, so the same
+//! converter handles downsampling (48kHz, 44.1kHz) without aliasing and
+//! upsampling (8kHz telephone audio) without imaging. There is no linear or
+//! nearest-sample fallback.
 
+use crate::config::ChannelMode;
 use audioadapter_buffers::direct::InterleavedSlice;
 use rubato::{
     Async, FixedAsync, Indexing, Resampler, SincInterpolationParameters, SincInterpolationType,
@@ -13,6 +19,7 @@ use rubato::{
 pub struct AudioConverter {
     resampler: Async<f32>,
     source_channels: usize,
+    channel_mode: ChannelMode,
 }
 
 impl AudioConverter {
@@ -21,7 +28,7 @@ impl AudioConverter {
     /// # Arguments
     /// * `source_rate` - Source sample rate (e.g., 48000)
     /// * `target_rate` - Target sample rate (typically 16000)
-    /// * `source_channels` - Number of source channels (1 or 2)
+    /// * `source_channels` - Number of interleaved source channels
     /// * `chunk_size` - Size of input chunks in frames (e.g., 1024)
     pub fn new(
         source_rate: u32,
@@ -49,12 +56,27 @@ impl AudioConverter {
         Ok(Self {
             resampler,
             source_channels,
+            channel_mode: ChannelMode::Mix,
         })
     }
 
+    /// Select which channel(s) feed the mono output (default: mix)
+    pub fn with_channel_mode(mut self, channel_mode: ChannelMode) -> Self {
+        self.channel_mode = channel_mode;
+        self
+    }
+
+    /// Output frames of latency the resampler adds at the start of a stream
+    ///
+    /// The first `output_delay()` frames produced are the filter warming up;
+    /// callers that need output aligned with the input should drop them.
+    pub fn output_delay(&self) -> usize {
+        self.resampler.output_delay()
+    }
+
     /// Process audio samples
     ///
-    /// Converts stereo to mono, resamples to target rate, and returns f32 samples.
+    /// Downmixes to mono per the channel mode, resamples to target rate, and returns f32 samples.
     pub fn process(&mut self, input: &[f32]) -> Result<Vec<f32>, rubato::ResampleError> {
         let mono = self.to_mono(input);
         let frames = mono.len();
@@ -113,6 +135,11 @@ impl AudioConverter {
     /// `leftover` is the remaining interleaved source samples that did not fill a
     /// whole `chunk_size` chunk (may be empty).
     pub fn finish_to_i16(&mut self, leftover: &[f32]) -> Result<Vec<i16>, rubato::ResampleError> {
+        Ok(f32_to_i16(&self.finish(leftover)?))
+    }
+
+    /// Finalise the stream as f32 samples; see [`finish_to_i16`](Self::finish_to_i16).
+    pub fn finish(&mut self, leftover: &[f32]) -> Result<Vec<f32>, rubato::ResampleError> {
         let mono = self.to_mono(leftover);
         let leftover_frames = mono.len();
 
@@ -153,19 +180,59 @@ impl AudioConverter {
         )?;
 
         out_data.truncate(out_frames);
-        Ok(f32_to_i16(&out_data))
+        Ok(out_data)
     }
 
     /// Downmix interleaved source frames to mono.
     fn to_mono(&self, input: &[f32]) -> Vec<f32> {
-        if self.source_channels == 2 {
-            stereo_to_mono(input)
-        } else {
-            input.to_vec()
-        }
+        downmix(input, self.source_channels, self.channel_mode)
     }
 }
 
+/// Resample a whole in-memory mono buffer in one call.
+///
+/// Feeds the buffer through an [`AudioConverter`] chunk by chunk, drains the
+/// delay line and trims the resampler's leading latency, so the output lines
+/// up with the input and has the length the rate ratio implies. Returns the
+/// input unchanged when the rates already match.
+pub fn resample_mono(
+    samples: &[f32],
+    source_rate: u32,
+    target_rate: u32,
+) -> Result<Vec<f32>, String> {
+    const CHUNK_SIZE: usize = 1024;
+
+    if source_rate == target_rate {
+        return Ok(samples.to_vec());
+    }
+
+    let mut converter = AudioConverter::new(source_rate, target_rate, 1, CHUNK_SIZE)
+        .map_err(|e| format!("Failed to create resampler: {}", e))?;
+    let delay = converter.output_delay();
+    let expected_len =
+        (samples.len() as u64 * target_rate as u64).div_ceil(source_rate as u64) as usize;
+    let mut output =
+        Vec::with_capacity(samples.len() * target_rate as usize / source_rate as usize + 64);
+
+    let mut chunks = samples.chunks_exact(CHUNK_SIZE);
+    for chunk in &mut chunks {
+        output.extend(
+            converter
+                .process(chunk)
+                .map_err(|e| format!("Resampling error: {}", e))?,
+        );
+    }
+    output.extend(
+        converter
+            .finish(chunks.remainder())
+            .map_err(|e| format!("Resampling error during finalise: {}", e))?,
+    );
+
+    output.drain(..delay.min(output.len()));
+    output.truncate(expected_len);
+    Ok(output)
+}
+
 /// Convert f32 samples to i16 with proper scaling
 pub fn f32_to_i16(samples: &[f32]) -> Vec<i16> {
     samples
@@ -179,6 +246,26 @@ pub fn i16_to_f32(samples: &[i16]) -> Vec<f32> {
     samples.iter().map(|&s| s as f32 / 32768.0).collect()
 }
 
+/// Reduce interleaved `channels`-channel audio to mono
+///
+/// `Mix` averages every channel of a frame; `Left` and `Right` keep the first
+/// or second channel, which separates hard-panned speakers (one party per
+/// channel, as in many phone and interview recordings). `Right` on a frame
+/// with only one channel falls back to that channel.
+pub fn downmix(samples: &[f32], channels: usize, mode: ChannelMode) -> Vec<f32> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+    samples
+        .chunks(channels)
+        .map(|frame| match mode {
+            ChannelMode::Mix => frame.iter().sum::<f32>() / frame.len() as f32,
+            ChannelMode::Left => frame[0],
+            ChannelMode::Right => frame.get(1).copied().unwrap_or(frame[0]),
+        })
+        .collect()
+}
+
 /// Mix stereo to mono
 pub fn stereo_to_mono(samples: &[f32]) -> Vec<f32> {
     samples
@@ -280,6 +367,129 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resample_mono_length_matches_ratio() {
+        let input: Vec<f32> = (0..44_100).map(|i| (i as f32 * 0.05).sin() * 0.3).collect();
+        let output = resample_mono(&input, 44_100, 16_000).unwrap();
+        // Allow for the few frames rubato may hold back at the very end.
+        let expected = 16_000usize;
+        assert!(
+            output.len() <= expected && expected - output.len() <= expected / 50,
+            "expected ~{} samples, got {}",
+            expected,
+            output.len()
+        );
+    }
+
+    #[test]
+    fn test_resample_mono_same_rate_is_passthrough() {
+        let input = vec![0.1, -0.2, 0.3];
+        assert_eq!(resample_mono(&input, 16_000, 16_000).unwrap(), input);
+    }
+
+    /// Linear sine sweep from `f0` to `f1` Hz over one second, with 20 ms
+    /// raised-cosine fades so the onset itself adds no broadband energy.
+    fn sweep(rate: u32, f0: f64, f1: f64) -> Vec<f32> {
+        let n = rate as usize;
+        let fade = rate as usize / 50;
+        (0..n)
+            .map(|i| {
+                let t = i as f64 / rate as f64;
+                let phase = 2.0 * std::f64::consts::PI * (f0 * t + (f1 - f0) * t * t / 2.0);
+                let edge = i.min(n - 1 - i);
+                let gain = if edge < fade {
+                    0.5 - 0.5 * (std::f64::consts::PI * edge as f64 / fade as f64).cos()
+                } else {
+                    1.0
+                };
+                (phase.sin() * 0.5 * gain) as f32
+            })
+            .collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    /// Power at `freq` Hz over a Hann-windowed buffer (Goertzel)
+    fn power_at(samples: &[f32], freq: f64, rate: u32) -> f64 {
+        let n = samples.len();
+        let coeff = 2.0 * (2.0 * std::f64::consts::PI * freq / rate as f64).cos();
+        let (mut s1, mut s2) = (0.0f64, 0.0f64);
+        for (i, &x) in samples.iter().enumerate() {
+            let window = 0.5 - 0.5 * (2.0 * std::f64::consts::PI * i as f64 / (n - 1) as f64).cos();
+            let s0 = x as f64 * window + coeff * s1 - s2;
+            s2 = s1;
+            s1 = s0;
+        }
+        s1 * s1 + s2 * s2 - coeff * s1 * s2
+    }
+
+    #[test]
+    fn test_resample_8k_to_16k_length() {
+        let output = resample_mono(&sweep(8_000, 200.0, 3_500.0), 8_000, 16_000).unwrap();
+        assert!(
+            output.len() <= 16_000 && 16_000 - output.len() <= 16_000 / 50,
+            "expected ~16000 samples, got {}",
+            output.len()
+        );
+    }
+
+    #[test]
+    fn test_resample_48k_to_16k_length() {
+        let output = resample_mono(&sweep(48_000, 100.0, 6_000.0), 48_000, 16_000).unwrap();
+        assert!(
+            output.len() <= 16_000 && 16_000 - output.len() <= 16_000 / 50,
+            "expected ~16000 samples, got {}",
+            output.len()
+        );
+    }
+
+    #[test]
+    fn test_downsample_sweep_above_nyquist_does_not_alias() {
+        // Everything from 9kHz up is above the 8kHz output Nyquist. A naive
+        // decimator folds it back into the speech band; a sinc filter removes it.
+        let input = sweep(48_000, 9_000.0, 20_000.0);
+        let output = resample_mono(&input, 48_000, 16_000).unwrap();
+        assert!(
+            rms(&output) < rms(&input) * 0.01,
+            "aliased energy: output rms {} vs input rms {}",
+            rms(&output),
+            rms(&input)
+        );
+    }
+
+    #[test]
+    fn test_downsample_sweep_in_band_is_preserved() {
+        let input = sweep(48_000, 100.0, 6_000.0);
+        let output = resample_mono(&input, 48_000, 16_000).unwrap();
+        let ratio = rms(&output) / rms(&input);
+        assert!(
+            (0.9..1.1).contains(&ratio),
+            "in-band level changed: ratio {}",
+            ratio
+        );
+    }
+
+    #[test]
+    fn test_upsample_sweep_has_no_images() {
+        // Upsampling 8kHz -> 16kHz mirrors a 200-3500Hz sweep into 4.5-7.8kHz
+        // unless the interpolator low-passes at the old Nyquist.
+        let output = resample_mono(&sweep(8_000, 200.0, 3_500.0), 8_000, 16_000).unwrap();
+        let in_band: f64 = (2..=17)
+            .map(|k| power_at(&output, k as f64 * 200.0, 16_000))
+            .sum();
+        let images: f64 = (23..=38)
+            .map(|k| power_at(&output, k as f64 * 200.0, 16_000))
+            .sum();
+        assert!(
+            images < in_band * 1e-3,
+            "image energy {} vs in-band {}",
+            images,
+            in_band
+        );
+    }
+
     #[test]
     fn test_stereo_to_mono() {
         let stereo = vec![0.5, -0.5, 0.3, -0.3, 0.1, -0.1];
@@ -290,6 +500,44 @@ mod tests {
         assert!((mono[2] - 0.0).abs() < 0.0001); // (0.1 + -0.1) / 2
     }
 
+    #[test]
+    fn test_downmix_channel_modes() {
+        let stereo = vec![0.4, -0.2, 0.6, 0.0];
+        assert_eq!(downmix(&stereo, 2, ChannelMode::Mix), vec![0.1, 0.3]);
+        assert_eq!(downmix(&stereo, 2, ChannelMode::Left), vec![0.4, 0.6]);
+        assert_eq!(downmix(&stereo, 2, ChannelMode::Right), vec![-0.2, 0.0]);
+    }
+
+    #[test]
+    fn test_downmix_mono_ignores_mode() {
+        let mono = vec![0.1, 0.2];
+        assert_eq!(downmix(&mono, 1, ChannelMode::Right), mono);
+    }
+
+    #[test]
+    fn test_downmix_mixes_more_than_two_channels() {
+        let quad = vec![0.4, 0.4, 0.0, 0.0];
+        assert_eq!(downmix(&quad, 4, ChannelMode::Mix), vec![0.2]);
+    }
+
+    #[test]
+    fn test_converter_left_channel_only() {
+        // Speaker on the left, silence on the right: Right mode must be silent
+        let mut left = AudioConverter::new(48000, 16000, 2, 512)
+            .unwrap()
+            .with_channel_mode(ChannelMode::Left);
+        let mut right = AudioConverter::new(48000, 16000, 2, 512)
+            .unwrap()
+            .with_channel_mode(ChannelMode::Right);
+        let input: Vec<f32> = (0..512)
+            .flat_map(|i| [(i as f32 * 0.05).sin() * 0.5, 0.0])
+            .collect();
+        let left_out = left.process(&input).unwrap();
+        let right_out = right.process(&input).unwrap();
+        assert!(left_out.iter().any(|s| s.abs() > 0.01));
+        assert!(right_out.iter().all(|s| s.abs() < 1e-6));
+    }
+
     #[test]
     fn test_f32_to_i16() {
         let f32_samples = vec![1.0, 0.5, 0.0, -0.5, -1.0];
@@ -0,0 +1,40 @@
+//! Audio of the recording in progress, for live transcription.
+//!
+//! The WAV writer only produces a readable file once the recording stops. When
+//! a live consumer (insert-as-you-speak) has called [`begin`], the writer
+//! thread also appends every resampled 16 kHz block here, and the consumer
+//! takes [`snapshot`]s of the audio so far. Outside a live session
+//! [`append`] is a no-op, so normal recordings pay nothing.
+
+use parking_lot::Mutex;
+
+/// 16 kHz mono samples captured since [`begin`]; `None` when no live
+/// session is active.
+static LIVE: Mutex<Option<Vec<f32>>> = Mutex::new(None);
+
+/// Start collecting the next recording's audio.
+pub fn begin() {
+    *LIVE.lock() = Some(Vec::new());
+}
+
+/// Stop collecting and release the buffer.
+pub fn end() {
+    *LIVE.lock() = None;
+}
+
+/// Append a resampled block from the writer thread.
+pub(crate) fn append(samples: &[i16]) {
+    if let Some(buffer) = LIVE.lock().as_mut() {
+        buffer.extend(samples.iter().map(|&s| s as f32 / 32768.0));
+    }
+}
+
+/// Number of samples collected so far, or `None` outside a live session.
+pub fn len() -> Option<usize> {
+    LIVE.lock().as_ref().map(Vec::len)
+}
+
+/// Copy of the audio collected so far, or `None` outside a live session.
+pub fn snapshot() -> Option<Vec<f32>> {
+    LIVE.lock().clone()
+}
@@ -0,0 +1,257 @@
+//! Mixing several input devices into one recording (conference capture).
+//!
+//! Each configured microphone keeps its own warm stream, and its blocks reach
+//! the writer tagged with the device's index. [`Mixer`] resamples every device
+//! to 16kHz mono on its own — the mics need not share a sample rate or channel
+//! count — and sums them once every device has delivered the same stretch.
+//!
+//! Devices do not deliver in lockstep, and one may stall or vanish outright (a
+//! USB mic unplugged mid-meeting). Waiting on the slowest device would stall
+//! the whole recording, so a device that falls more than
+//! [`STALL_TOLERANCE_SAMPLES`] behind the leader is filled with silence and
+//! the rest carry on. Audio it later delivers for the stretch already filled
+//! is dropped, keeping it aligned with the others.
+
+use super::format::AudioConverter;
+use crate::config::ChannelMode;
+use anyhow::{Result, anyhow};
+use std::collections::VecDeque;
+
+/// How far a device may fall behind the others before it is filled with
+/// silence, in 16kHz output samples (half a second). Well above the few
+/// callback blocks devices normally drift apart by.
+pub const STALL_TOLERANCE_SAMPLES: usize = 8000;
+
+/// One device feeding the mix
+struct MixSource {
+    converter: AudioConverter,
+    /// Device-native interleaved samples waiting to fill a resampler chunk
+    accumulator: Vec<f32>,
+    /// Interleaved samples in one resampler chunk
+    samples_per_chunk: usize,
+    /// Resampled samples not yet mixed
+    pending: VecDeque<i16>,
+    /// Silence filled in while this device stalled; as many samples are
+    /// dropped from what it delivers next
+    owed: usize,
+    /// Whether the device is currently being filled with silence (for logging)
+    stalled: bool,
+}
+
+impl MixSource {
+    fn queue(&mut self, samples: &[i16]) {
+        let skipped = self.owed.min(samples.len());
+        self.owed -= skipped;
+        self.pending.extend(&samples[skipped..]);
+    }
+}
+
+/// Resamples, aligns and sums the blocks of several input devices
+pub struct Mixer {
+    sources: Vec<MixSource>,
+}
+
+impl Mixer {
+    /// A mixer for devices with the given `(sample_rate, channels)`, indexed
+    /// in the order given
+    pub fn new(
+        formats: &[(u32, usize)],
+        target_rate: u32,
+        chunk_size: usize,
+        channel_mode: ChannelMode,
+    ) -> Result<Self> {
+        let sources = formats
+            .iter()
+            .map(|&(rate, channels)| {
+                let converter = AudioConverter::new(rate, target_rate, channels, chunk_size)
+                    .map_err(|e| anyhow!("Failed to create resampler: {}", e))?
+                    .with_channel_mode(channel_mode);
+                Ok(MixSource {
+                    converter,
+                    accumulator: Vec::new(),
+                    samples_per_chunk: chunk_size * channels,
+                    pending: VecDeque::new(),
+                    owed: 0,
+                    stalled: false,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { sources })
+    }
+
+    /// Queue one block of device-native interleaved samples from `source`,
+    /// resampling every whole chunk it completes
+    pub fn push(&mut self, source: usize, block: &[f32]) -> Result<()> {
+        let source = self
+            .sources
+            .get_mut(source)
+            .ok_or_else(|| anyhow!("Unknown mix source {}", source))?;
+        source.accumulator.extend_from_slice(block);
+        while source.accumulator.len() >= source.samples_per_chunk {
+            let chunk: Vec<f32> = source
+                .accumulator
+                .drain(..source.samples_per_chunk)
+                .collect();
+            let resampled = source
+                .converter
+                .process_to_i16(&chunk)
+                .map_err(|e| anyhow!("Resampling error: {}", e))?;
+            source.queue(&resampled);
+        }
+        Ok(())
+    }
+
+    /// Mix what every device has delivered
+    ///
+    /// Devices more than [`STALL_TOLERANCE_SAMPLES`] behind the leader are
+    /// filled with silence so a stalled device cannot hold up the rest.
+    pub fn take_ready(&mut self) -> Vec<i16> {
+        let lead = self.longest_pending();
+        let slowest = self
+            .sources
+            .iter()
+            .map(|s| s.pending.len())
+            .min()
+            .unwrap_or(0);
+        let ready = slowest.max(lead.saturating_sub(STALL_TOLERANCE_SAMPLES));
+
+        for (index, source) in self.sources.iter_mut().enumerate() {
+            let stalled = source.pending.len() < ready;
+            if stalled && !source.stalled {
+                tracing::warn!(
+                    "Mix: device {} stalled; filling it with silence so the others keep recording",
+                    index
+                );
+            } else if !stalled && source.stalled {
+                tracing::info!("Mix: device {} is delivering again", index);
+            }
+            source.stalled = stalled;
+        }
+        self.mix(ready)
+    }
+
+    /// Flush every device's resampler and mix everything left, filling the
+    /// shorter tails with silence
+    pub fn finish(&mut self) -> Result<Vec<i16>> {
+        for source in &mut self.sources {
+            let leftover = std::mem::take(&mut source.accumulator);
+            let tail = source
+                .converter
+                .finish_to_i16(&leftover)
+                .map_err(|e| anyhow!("Resampling error during finalise: {}", e))?;
+            source.queue(&tail);
+        }
+        let lead = self.longest_pending();
+        Ok(self.mix(lead))
+    }
+
+    fn longest_pending(&self) -> usize {
+        self.sources
+            .iter()
+            .map(|s| s.pending.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Sum the next `len` samples of every device, filling any device short
+    /// of `len` with silence (and owing that much)
+    fn mix(&mut self, len: usize) -> Vec<i16> {
+        let mut mixed = vec![0i16; len];
+        for source in &mut self.sources {
+            let available = source.pending.len().min(len);
+            source.owed += len - available;
+            for (out, sample) in mixed.iter_mut().zip(source.pending.drain(..available)) {
+                *out = out.saturating_add(sample);
+            }
+        }
+        mixed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHUNK: usize = 1024;
+
+    fn mixer(formats: &[(u32, usize)]) -> Mixer {
+        Mixer::new(formats, 16000, CHUNK, ChannelMode::Mix).unwrap()
+    }
+
+    /// Push `frames` of a constant level from `source`, in callback-sized blocks
+    fn push_constant(mixer: &mut Mixer, source: usize, channels: usize, frames: usize, level: f32) {
+        let mut sent = 0;
+        while sent < frames {
+            let n = 480usize.min(frames - sent);
+            mixer.push(source, &vec![level; n * channels]).unwrap();
+            sent += n;
+        }
+    }
+
+    #[test]
+    fn test_sums_aligned_devices() {
+        let mut mixer = mixer(&[(16000, 1), (16000, 1)]);
+        push_constant(&mut mixer, 0, 1, 16000, 0.1);
+        push_constant(&mut mixer, 1, 1, 16000, 0.2);
+        let mut mixed = mixer.take_ready();
+        mixed.extend(mixer.finish().unwrap());
+
+        assert!(
+            (15000..=17000).contains(&mixed.len()),
+            "expected ~1s, got {} samples",
+            mixed.len()
+        );
+        // Past the resampler's warm-up both devices contribute: ~0.3 full scale
+        let middle = mixed[mixed.len() / 2];
+        assert!((9000..=10600).contains(&middle), "got {}", middle);
+    }
+
+    #[test]
+    fn test_mixes_devices_with_different_formats() {
+        let mut mixer = mixer(&[(48000, 2), (16000, 1)]);
+        push_constant(&mut mixer, 0, 2, 48000, 0.1);
+        push_constant(&mut mixer, 1, 1, 16000, 0.1);
+        let mut mixed = mixer.take_ready();
+        mixed.extend(mixer.finish().unwrap());
+
+        assert!(
+            (15000..=17000).contains(&mixed.len()),
+            "expected ~1s, got {} samples",
+            mixed.len()
+        );
+        let middle = mixed[mixed.len() / 2];
+        assert!((6000..=7100).contains(&middle), "got {}", middle);
+    }
+
+    #[test]
+    fn test_waits_for_a_slightly_late_device() {
+        let mut mixer = mixer(&[(16000, 1), (16000, 1)]);
+        push_constant(&mut mixer, 0, 1, 4000, 0.1);
+        assert!(mixer.take_ready().is_empty());
+        push_constant(&mut mixer, 1, 1, 4000, 0.1);
+        assert!(!mixer.take_ready().is_empty());
+    }
+
+    #[test]
+    fn test_stalled_device_does_not_hold_up_the_mix() {
+        let mut mixer = mixer(&[(16000, 1), (16000, 1)]);
+        push_constant(&mut mixer, 0, 1, 32000, 0.1);
+        let ready = mixer.take_ready();
+        assert!(
+            ready.len() >= 32000 - STALL_TOLERANCE_SAMPLES - 2 * CHUNK,
+            "a stalled device held the mix back to {} samples",
+            ready.len()
+        );
+
+        // When it comes back, what it delivers for the filled stretch is
+        // dropped rather than mixed in late
+        push_constant(&mut mixer, 1, 1, 32000, 0.1);
+        assert!(mixer.sources[1].pending.len() < 32000 - ready.len() + 2 * CHUNK);
+    }
+
+    #[test]
+    fn test_unknown_source_rejected() {
+        let mut mixer = mixer(&[(16000, 1)]);
+        assert!(mixer.push(3, &[0.0; 16]).is_err());
+    }
+}
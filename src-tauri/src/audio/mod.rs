@@ -6,7 +6,9 @@ pub mod capture;
 pub mod decode;
 pub mod device;
 pub mod format;
+pub mod live;
 pub mod metering;
+pub mod mix;
 pub mod preview;
 pub mod ring_buffer;
 pub mod vad;
@@ -16,10 +18,11 @@ pub use device::{AudioDevice, get_device_display_name, get_recording_device, lis
 pub use format::AudioConverter;
 pub use metering::{AudioLevel, AudioMeter};
 pub use preview::{start_recording_metering, stop_recording_metering};
-pub use ring_buffer::AudioRingBuffer;
+pub use ring_buffer::{AudioRingBuffer, RingBufferStats};
 
 use crate::error::Error;
 use parking_lot::Mutex;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, OnceLock};
 
@@ -34,13 +37,29 @@ static METERING_BUFFER: OnceLock<Mutex<Option<Arc<AudioRingBuffer>>>> = OnceLock
 
 /// Display name of the device used for the most recent (or current) recording.
 /// Set inside start_recording so pipeline.rs can read it without a duplicate
-/// device resolution call.
+/// device resolution call. Mixed devices are joined with [`MIX_NAME_SEPARATOR`].
 static LAST_DEVICE_NAME: OnceLock<Mutex<Option<String>>> = OnceLock::new();
 
 fn get_last_device_name() -> &'static Mutex<Option<String>> {
     LAST_DEVICE_NAME.get_or_init(|| Mutex::new(None))
 }
 
+/// Joins the device names of a mixed recording in [`last_device_name`]
+const MIX_NAME_SEPARATOR: &str = " + ";
+
+/// Event emitted at recording start when the input device is muted at the
+/// OS level (payload: [`MicrophoneMuted`]). Recording goes ahead regardless.
+pub const MICROPHONE_MUTED_EVENT: &str = "microphone-muted";
+
+/// Payload of [`MICROPHONE_MUTED_EVENT`]
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MicrophoneMuted {
+    /// Display name of the muted device, or `None` when the platform can only
+    /// tell that the default input is muted (Linux)
+    pub device: Option<String>,
+}
+
 /// Idle-teardown generation counter.
 ///
 /// Every `stop_recording` call bumps this. The teardown thread captures the
@@ -48,6 +67,45 @@ fn get_last_device_name() -> &'static Mutex<Option<String>> {
 /// recording (meaning the user recorded again before the 45s timer fired).
 static IDLE_GENERATION: AtomicU64 = AtomicU64::new(0);
 
+/// Directory new recordings and decoded imports are written to
+///
+/// `config.audio.recordings_dir` when set, otherwise `~/.thoth/Recordings`.
+pub fn recordings_dir() -> PathBuf {
+    crate::config::get_config()
+        .map(|c| c.audio.resolved_recordings_dir())
+        .unwrap_or_else(|_| crate::config::default_recordings_dir())
+}
+
+/// [`recordings_dir`], created if missing
+pub fn ensure_recordings_dir() -> Result<PathBuf, String> {
+    let dir = recordings_dir();
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create recordings directory: {}", e))?;
+    Ok(dir)
+}
+
+/// Whether the user has pointed recordings at a directory of their own
+///
+/// Such a directory may hold other audio, so cleanup there only touches files
+/// Thoth named itself (see [`is_thoth_recording`]).
+pub fn uses_custom_recordings_dir() -> bool {
+    crate::config::get_config()
+        .map(|c| c.audio.custom_recordings_dir().is_some())
+        .unwrap_or(false)
+}
+
+/// Whether `path` is a WAV Thoth wrote (`thoth_recording_*` or `thoth_import_*`)
+pub fn is_thoth_recording(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    name.starts_with("thoth_")
+        && path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("wav"))
+}
+
 fn get_recorder() -> &'static Mutex<AudioRecorder> {
     RECORDER.get_or_init(|| Mutex::new(AudioRecorder::new()))
 }
@@ -80,6 +138,42 @@ pub fn cool_down_recording() {
     tracing::info!("Audio: warm stream cooled down");
 }
 
+/// Open the configured input on `recorder` and remember its name for
+/// [`last_device_name`].
+///
+/// With two or more `audio.mix_device_ids` connected, all of them are opened
+/// and mixed; otherwise the single recording device is used. The metering
+/// buffer must already be attached.
+fn open_configured_input(
+    recorder: &mut AudioRecorder,
+    audio: &crate::config::AudioConfig,
+) -> Result<(), String> {
+    let mix_devices = device::get_mix_devices(&audio.mix_device_ids);
+    let name = if mix_devices.len() >= 2 {
+        recorder
+            .warm_up_mixed(&mix_devices)
+            .map_err(|e| e.to_string())?;
+        mix_devices
+            .iter()
+            .map(device::get_device_display_name)
+            .collect::<Vec<_>>()
+            .join(MIX_NAME_SEPARATOR)
+    } else {
+        if !audio.mix_device_ids.is_empty() {
+            tracing::warn!(
+                "Audio: fewer than two mix devices available — recording from one device"
+            );
+        }
+        let audio_device = device::get_recording_device(audio.device_id.as_deref())
+            .ok_or_else(|| "No audio input device available".to_string())?;
+        recorder.warm_up(&audio_device).map_err(|e| e.to_string())?;
+        device::get_device_display_name(&audio_device)
+    };
+    // Store device name for pipeline.rs to read without a second resolution.
+    *get_last_device_name().lock() = Some(name);
+    Ok(())
+}
+
 /// Pre-warm the recorder on the configured device without arming.
 ///
 /// Callers may invoke this proactively (e.g., at startup or after device
@@ -93,10 +187,6 @@ pub fn warm_up_recording() -> Result<(), Error> {
         return Ok(());
     }
 
-    let device_id = config.audio.device_id.as_deref();
-    let audio_device = device::get_recording_device(device_id)
-        .ok_or_else(|| "No audio input device available".to_string())?;
-
     let mut recorder = get_recorder().lock();
     if recorder.is_warm() {
         return Ok(());
@@ -107,7 +197,7 @@ pub fn warm_up_recording() -> Result<(), Error> {
     recorder.set_metering_buffer(metering_buf.clone());
     *get_metering_buffer().lock() = Some(metering_buf);
 
-    recorder.warm_up(&audio_device).map_err(|e| e.to_string())?;
+    open_configured_input(&mut recorder, &config.audio)?;
 
     tracing::info!("Audio: pre-warm complete");
     Ok(())
@@ -176,11 +266,8 @@ pub fn start_recording() -> Result<String, Error> {
     // warm stream mid-capture — silently killing the recording (data loss).
     IDLE_GENERATION.fetch_add(1, Ordering::Relaxed);
 
-    // Generate output path in ~/.thoth/Recordings/
-    let home = dirs::home_dir().ok_or("Could not find home directory")?;
-    let recordings_dir = home.join(".thoth").join("Recordings");
-    std::fs::create_dir_all(&recordings_dir)
-        .map_err(|e| format!("Failed to create recordings directory: {}", e))?;
+    // Generate output path in the recordings directory
+    let recordings_dir = ensure_recordings_dir()?;
 
     let filename = format!(
         "thoth_recording_{}.wav",
@@ -192,7 +279,6 @@ pub fn start_recording() -> Result<String, Error> {
 
     let config = crate::config::get_config().map_err(|e| format!("Failed to get config: {}", e))?;
     let use_warm = config.audio.warm_stream;
-    let device_id = config.audio.device_id.clone();
 
     if use_warm {
         // Warm path: when the stream is already warm we do NOT resolve the
@@ -202,16 +288,12 @@ pub fn start_recording() -> Result<String, Error> {
         // feel instant. The device is only resolved on the cold warm-up below.
         if !recorder.is_warm() {
             tracing::info!("Audio: stream not warm — opening device (first record after idle)");
-            let audio_device = device::get_recording_device(device_id.as_deref())
-                .ok_or_else(|| "No audio input device available".to_string())?;
-            // Store device name for pipeline.rs to read without a second resolution.
-            *get_last_device_name().lock() = Some(device::get_device_display_name(&audio_device));
             // Metering buffer must be set before warm_up so the callback captures it.
             let metering_buf = Arc::new(AudioRingBuffer::new());
             recorder.set_metering_buffer(metering_buf.clone());
             *get_metering_buffer().lock() = Some(metering_buf);
 
-            recorder.warm_up(&audio_device).map_err(|e| e.to_string())?;
+            open_configured_input(&mut recorder, &config.audio)?;
         } else {
             tracing::info!("Audio: stream already warm — instant start");
         }
@@ -220,22 +302,48 @@ pub fn start_recording() -> Result<String, Error> {
         recorder.arm(&output_path).map_err(|e| e.to_string())?;
     } else {
         // Cold path (warm_stream disabled): open/close on every record.
-        let audio_device = device::get_recording_device(device_id.as_deref())
-            .ok_or_else(|| "No audio input device available".to_string())?;
-        // Store device name for pipeline.rs to read without a second resolution.
-        *get_last_device_name().lock() = Some(device::get_device_display_name(&audio_device));
         let metering_buf = Arc::new(AudioRingBuffer::new());
         recorder.set_metering_buffer(metering_buf.clone());
         *get_metering_buffer().lock() = Some(metering_buf);
 
-        recorder
-            .start(&audio_device, &output_path)
-            .map_err(|e| e.to_string())?;
+        open_configured_input(&mut recorder, &config.audio)?;
+        recorder.arm(&output_path).map_err(|e| e.to_string())?;
     }
 
+    warn_if_muted();
+
     Ok(output_path.to_string_lossy().to_string())
 }
 
+/// Emit [`MICROPHONE_MUTED_EVENT`] if a recording device is muted.
+///
+/// Runs on its own thread: the query (CoreAudio or `pactl`) must not delay
+/// the start of a warm recording.
+fn warn_if_muted() {
+    let Some(names) = last_device_name() else {
+        return;
+    };
+    std::thread::spawn(move || {
+        let muted = if cfg!(target_os = "linux") {
+            // Only the default source's mute state is visible, so check it
+            // once rather than once per device, and do not pin it on a device
+            (crate::platform::default_input_is_muted() == Some(true)).then_some(None)
+        } else {
+            names
+                .split(MIX_NAME_SEPARATOR)
+                .find(|name| crate::platform::input_device_is_muted(name) == Some(true))
+                .map(|name| Some(name.to_string()))
+        };
+        if let Some(device) = muted {
+            match &device {
+                Some(name) => tracing::warn!("Audio: input device '{}' is muted", name),
+                None => tracing::warn!("Audio: default input is muted"),
+            }
+            crate::app_handle::emit(MICROPHONE_MUTED_EVENT, MicrophoneMuted { device });
+        }
+    });
+}
+
 /// Stop recording and return the path to the recorded file
 #[tauri::command]
 pub fn stop_recording() -> Result<String, Error> {
@@ -254,12 +362,16 @@ pub fn stop_recording() -> Result<String, Error> {
     // — e.g. when the default input is AirPods but recording was redirected to
     // the built-in mic (get_recording_device's Bluetooth-avoidance). Querying
     // the *default* input here would wrongly report Bluetooth and cool down the
-    // built-in stream, losing its warm-stream latency benefit.
+    // built-in stream, losing its warm-stream latency benefit. A mix counts as
+    // Bluetooth if any of its devices is.
     let recording_is_bluetooth = get_last_device_name()
         .lock()
         .as_deref()
-        .map(crate::platform::device_name_is_bluetooth)
-        .unwrap_or(false);
+        .is_some_and(|names| {
+            names
+                .split(MIX_NAME_SEPARATOR)
+                .any(crate::platform::device_name_is_bluetooth)
+        });
 
     let path = if use_warm {
         let p = recorder.disarm().map_err(|e| e.to_string())?;
@@ -275,6 +387,21 @@ pub fn stop_recording() -> Result<String, Error> {
             // Bump generation so any pre-existing teardown timer aborts.
             IDLE_GENERATION.fetch_add(1, Ordering::Relaxed);
         } else {
+            if recorder.take_rewarm_pending() {
+                // The input changed (e.g. the mixed devices) during the
+                // recording: reopen on the new configuration.
+                tracing::info!("Audio: re-warming stream after a deferred cool down");
+                recorder.cool_down();
+                let metering_buf = Arc::new(AudioRingBuffer::new());
+                recorder.set_metering_buffer(metering_buf.clone());
+                *get_metering_buffer().lock() = Some(metering_buf);
+                if let Err(e) = open_configured_input(&mut recorder, &config.audio) {
+                    // The next start opens the device cold instead
+                    tracing::warn!("Audio: re-warm failed: {}", e);
+                    recorder.cool_down();
+                    *get_metering_buffer().lock() = None;
+                }
+            }
             // Built-in or USB device: keep warm for IDLE_TEARDOWN_SECS.
             let idle_gen = IDLE_GENERATION.fetch_add(1, Ordering::Relaxed) + 1;
             spawn_idle_teardown(idle_gen);
@@ -312,6 +439,18 @@ mod tests {
         assert!(!idle_teardown_superseded(0, 0));
     }
 
+    #[test]
+    fn thoth_recordings_are_recognised_by_name() {
+        assert!(is_thoth_recording(Path::new(
+            "/x/thoth_recording_20250101_120000.wav"
+        )));
+        assert!(is_thoth_recording(Path::new(
+            "/x/thoth_import_20250101_120000_123.WAV"
+        )));
+        assert!(!is_thoth_recording(Path::new("/x/interview.wav")));
+        assert!(!is_thoth_recording(Path::new("/x/thoth_notes.txt")));
+    }
+
     /// Re-checkable measurement (#68) of the cold device-resolution cost the warm
     /// path avoids: `default_input_device()` + `default_input_config()`. Ignored
     /// in CI (needs an audio device); run manually with
@@ -338,3 +477,39 @@ mod tests {
 pub fn is_recording() -> bool {
     get_recorder().lock().is_recording()
 }
+
+/// Dropouts detected during the current or most recent recording
+///
+/// Read right after [`stop_recording`] to learn whether the finished
+/// recording lost input before capture.
+pub fn last_recording_dropouts() -> u64 {
+    get_recorder().lock().dropouts()
+}
+
+/// Audio buffer health for diagnosing lost samples
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioBufferStats {
+    /// Whether a recording is in progress
+    pub recording: bool,
+    /// Dropouts (stream errors and capture gaps) during the current or most
+    /// recent recording
+    pub recording_dropouts: u64,
+    /// Counters of the metering ring buffer, when the stream is warm. Its
+    /// drops only affect the level display, never the recording.
+    pub metering: Option<RingBufferStats>,
+}
+
+/// Get overrun/underrun and dropout counters for the audio buffers
+#[tauri::command]
+pub fn get_audio_buffer_stats() -> AudioBufferStats {
+    let (recording, recording_dropouts) = {
+        let recorder = get_recorder().lock();
+        (recorder.is_recording(), recorder.dropouts())
+    };
+    AudioBufferStats {
+        recording,
+        recording_dropouts,
+        metering: current_metering_buffer().map(|b| b.stats()),
+    }
+}
@@ -161,6 +161,188 @@ pub fn is_audio_preview_running() -> bool {
     state_guard.is_some()
 }
 
+// =============================================================================
+// Microphone Test (fixed-length level check, no file written)
+// =============================================================================
+
+/// Shortest and longest microphone test the command will run
+const MIC_TEST_MIN_MS: u64 = 500;
+const MIC_TEST_MAX_MS: u64 = 10_000;
+
+/// Sample magnitude at or above which a sample is counted as clipped
+const CLIP_THRESHOLD: f32 = 0.99;
+
+/// Peak below which the device is considered to have produced only silence
+/// (-60 dB; well under any real room noise floor on a live mic)
+const SILENT_PEAK_THRESHOLD: f32 = 0.001;
+
+/// Summary of a [`run_microphone_test`] run
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MicrophoneTestResult {
+    /// Display name of the device that was tested
+    pub device_name: String,
+    /// How long the test listened for, in milliseconds
+    pub duration_ms: u64,
+    /// Number of mono samples the device delivered
+    pub samples_received: usize,
+    /// Whether the device delivered any samples at all
+    pub produced_samples: bool,
+    /// Highest absolute sample level on any channel, 0.0-1.0
+    pub peak: f32,
+    /// RMS level over the whole test, 0.0-1.0
+    pub rms: f32,
+    /// Peak level in dBFS
+    pub peak_db: f32,
+    /// RMS level in dBFS
+    pub rms_db: f32,
+    /// Number of samples, on any channel, at or above the clipping threshold
+    pub clipped_samples: usize,
+    /// Whether any clipping occurred
+    pub clipped: bool,
+    /// Whether the signal was effectively silent (muted or dead mic)
+    pub silent: bool,
+}
+
+/// Running statistics for a microphone test.
+#[derive(Debug, Default)]
+struct MicTestStats {
+    samples: usize,
+    sum_sq: f64,
+    peak: f32,
+    clipped: usize,
+}
+
+impl MicTestStats {
+    /// Add a block of interleaved samples with `channels` channels.
+    ///
+    /// Peak and clipping are measured per channel, before the downmix, so one
+    /// channel clipping on its own is still caught; RMS is of the mono mix.
+    fn push(&mut self, data: &[f32], channels: usize) {
+        for &s in data {
+            let a = s.abs();
+            self.peak = self.peak.max(a);
+            if a >= CLIP_THRESHOLD {
+                self.clipped += 1;
+            }
+        }
+        let channels = channels.max(1);
+        for frame in data.chunks(channels) {
+            let s = frame.iter().sum::<f32>() / channels as f32;
+            self.sum_sq += (s as f64) * (s as f64);
+            self.samples += 1;
+        }
+    }
+
+    fn finish(self, device_name: String, duration_ms: u64) -> MicrophoneTestResult {
+        let rms = if self.samples > 0 {
+            (self.sum_sq / self.samples as f64).sqrt() as f32
+        } else {
+            0.0
+        };
+        let to_db = |v: f32| super::metering::amplitude_to_db(v).max(-96.0);
+        MicrophoneTestResult {
+            device_name,
+            duration_ms,
+            samples_received: self.samples,
+            produced_samples: self.samples > 0,
+            peak: self.peak.min(1.0),
+            rms: rms.min(1.0),
+            peak_db: to_db(self.peak),
+            rms_db: to_db(rms),
+            clipped_samples: self.clipped,
+            clipped: self.clipped > 0,
+            silent: self.peak < SILENT_PEAK_THRESHOLD,
+        }
+    }
+}
+
+/// Listen to the input device for a fixed time and report levels.
+///
+/// Unlike a recording this writes no file, and unlike `start_audio_preview` it
+/// runs for a bounded `duration_ms` (clamped to 0.5-10 s) and returns a single
+/// summary: peak/RMS, whether clipping occurred, and whether the device
+/// produced any samples at all. Uses the configured device unless `device_id`
+/// is given. Refuses to run while a recording is in progress.
+#[tauri::command]
+pub async fn run_microphone_test(
+    duration_ms: u64,
+    device_id: Option<String>,
+) -> Result<MicrophoneTestResult, Error> {
+    if crate::audio::is_recording() {
+        return Err("Cannot run a microphone test while recording"
+            .to_string()
+            .into());
+    }
+
+    let duration_ms = duration_ms.clamp(MIC_TEST_MIN_MS, MIC_TEST_MAX_MS);
+    let device_id = device_id.or_else(|| {
+        crate::config::get_config()
+            .ok()
+            .and_then(|c| c.audio.device_id)
+    });
+
+    // Only one client reliably receives audio from some USB mics, so release
+    // the preview stream and the idle warm stream before opening our own.
+    stop_audio_preview_inner();
+    crate::audio::cool_down_recording();
+
+    tokio::task::spawn_blocking(move || microphone_test_blocking(device_id, duration_ms))
+        .await
+        .map_err(|e| format!("Microphone test task failed: {}", e))?
+        .map_err(Into::into)
+}
+
+/// Blocking body of [`run_microphone_test`]; owns the cpal stream for its lifetime.
+fn microphone_test_blocking(
+    device_id: Option<String>,
+    duration_ms: u64,
+) -> Result<MicrophoneTestResult, String> {
+    let device = get_recording_device(device_id.as_deref())
+        .ok_or_else(|| "No audio input device available".to_string())?;
+    let device_name = get_device_display_name(&device);
+    tracing::info!(
+        "Running {}ms microphone test on device: {}",
+        duration_ms,
+        device_name
+    );
+
+    let config = device.default_input_config().map_err(|e| e.to_string())?;
+    let channels = (config.channels() as usize).max(1);
+
+    let stats = Arc::new(Mutex::new(MicTestStats::default()));
+    let stream = {
+        let stats = stats.clone();
+        device
+            .build_input_stream(
+                config.into(),
+                move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                    stats.lock().push(data, channels);
+                },
+                |err| {
+                    tracing::error!("Microphone test stream error: {}", err);
+                },
+                None,
+            )
+            .map_err(|e| e.to_string())?
+    };
+    stream.play().map_err(|e| e.to_string())?;
+
+    std::thread::sleep(std::time::Duration::from_millis(duration_ms));
+    drop(stream);
+
+    let stats = std::mem::take(&mut *stats.lock());
+    let result = stats.finish(device_name, duration_ms);
+    tracing::info!(
+        "Microphone test: {} samples, peak {:.1} dB, rms {:.1} dB, clipped {}",
+        result.samples_received,
+        result.peak_db,
+        result.rms_db,
+        result.clipped_samples
+    );
+    Ok(result)
+}
+
 // =============================================================================
 // Recording Metering (for the recording indicator overlay)
 // =============================================================================
@@ -326,4 +508,48 @@ mod tests {
         );
         assert!(level.peak > 0.4, "peak should be ~0.5, got {}", level.peak);
     }
+
+    #[test]
+    fn test_mic_test_stats_detects_clipping_and_levels() {
+        let mut stats = MicTestStats::default();
+        stats.push(&[0.5, -0.5, 0.5, -0.5], 1);
+        stats.push(&[1.0, -1.0], 1);
+        let result = stats.finish("Test Mic".to_string(), 1000);
+
+        assert_eq!(result.samples_received, 6);
+        assert!(result.produced_samples);
+        assert!(result.clipped);
+        assert_eq!(result.clipped_samples, 2);
+        assert!((result.peak - 1.0).abs() < 1e-6);
+        assert!(!result.silent);
+    }
+
+    #[test]
+    fn test_mic_test_stats_reports_silence_and_no_samples() {
+        let mut stats = MicTestStats::default();
+        stats.push(&[0.0; 256], 1);
+        let silent = stats.finish("Muted".to_string(), 1000);
+        assert!(silent.produced_samples);
+        assert!(silent.silent);
+        assert!(!silent.clipped);
+        assert_eq!(silent.peak_db, -96.0);
+
+        let empty = MicTestStats::default().finish("Dead".to_string(), 1000);
+        assert!(!empty.produced_samples);
+        assert!(empty.silent);
+    }
+
+    #[test]
+    fn test_mic_test_stats_catches_one_channel_clipping() {
+        // Left clips while right is silent; the mono mix only reaches 0.5
+        let mut stats = MicTestStats::default();
+        stats.push(&[1.0, 0.0, -1.0, 0.0], 2);
+        let result = stats.finish("Stereo Mic".to_string(), 1000);
+
+        assert_eq!(result.samples_received, 2);
+        assert!(result.clipped);
+        assert_eq!(result.clipped_samples, 2);
+        assert!((result.peak - 1.0).abs() < 1e-6);
+        assert!((result.rms - 0.5).abs() < 1e-6);
+    }
 }
@@ -3,13 +3,29 @@
 //! This module provides a pre-allocated, lock-free ring buffer suitable for
 //! use in audio callbacks. The audio callback MUST NOT allocate memory, so
 //! all storage is pre-allocated.
+//!
+//! The buffer never blocks the producer: a write that finds it full drops
+//! what does not fit. Those drops are counted (see [`RingBufferStats`]) so
+//! they are observable rather than silent.
 
 use std::cell::UnsafeCell;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
 /// Ring buffer size: ~4 seconds at 16kHz mono
 const BUFFER_SIZE: usize = 65536;
 
+/// Overrun/underrun counters of an [`AudioRingBuffer`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RingBufferStats {
+    /// Samples dropped because a write found the buffer full of unread data
+    pub overrun_samples: u64,
+    /// Writes that dropped at least one sample
+    pub overrun_events: u64,
+    /// Reads that found no data waiting (normal for a polling consumer)
+    pub underrun_reads: u64,
+}
+
 /// A lock-free single-producer single-consumer ring buffer for audio samples
 ///
 /// This buffer is designed for real-time audio use:
@@ -21,6 +37,9 @@ pub struct AudioRingBuffer {
     buffer: UnsafeCell<Box<[f32; BUFFER_SIZE]>>,
     write_pos: AtomicUsize,
     read_pos: AtomicUsize,
+    overrun_samples: AtomicU64,
+    overrun_events: AtomicU64,
+    underrun_reads: AtomicU64,
 }
 
 // Safety: The buffer uses atomic operations for thread safety and is SPSC.
@@ -42,9 +61,28 @@ impl AudioRingBuffer {
             buffer: UnsafeCell::new(Box::new([0.0; BUFFER_SIZE])),
             write_pos: AtomicUsize::new(0),
             read_pos: AtomicUsize::new(0),
+            overrun_samples: AtomicU64::new(0),
+            overrun_events: AtomicU64::new(0),
+            underrun_reads: AtomicU64::new(0),
+        }
+    }
+
+    /// Overrun/underrun counts since creation or the last [`reset_stats`](Self::reset_stats)
+    pub fn stats(&self) -> RingBufferStats {
+        RingBufferStats {
+            overrun_samples: self.overrun_samples.load(Ordering::Relaxed),
+            overrun_events: self.overrun_events.load(Ordering::Relaxed),
+            underrun_reads: self.underrun_reads.load(Ordering::Relaxed),
         }
     }
 
+    /// Zero the overrun/underrun counters
+    pub fn reset_stats(&self) {
+        self.overrun_samples.store(0, Ordering::Relaxed);
+        self.overrun_events.store(0, Ordering::Relaxed);
+        self.underrun_reads.store(0, Ordering::Relaxed);
+    }
+
     /// Returns the total capacity of the buffer
     pub fn capacity(&self) -> usize {
         BUFFER_SIZE
@@ -68,7 +106,8 @@ impl AudioRingBuffer {
     /// from the audio callback thread.
     ///
     /// Returns the number of samples actually written (may be less than
-    /// requested if the buffer is full).
+    /// requested if the buffer is full). Samples that do not fit are dropped
+    /// and counted as an overrun.
     pub fn write(&self, samples: &[f32]) -> usize {
         let write = self.write_pos.load(Ordering::Acquire);
         let read = self.read_pos.load(Ordering::Acquire);
@@ -82,6 +121,13 @@ impl AudioRingBuffer {
 
         let to_write = samples.len().min(available);
 
+        let dropped = samples.len() - to_write;
+        if dropped > 0 {
+            self.overrun_samples
+                .fetch_add(dropped as u64, Ordering::Relaxed);
+            self.overrun_events.fetch_add(1, Ordering::Relaxed);
+        }
+
         if to_write == 0 {
             return 0;
         }
@@ -122,6 +168,10 @@ impl AudioRingBuffer {
 
         let to_read = output.len().min(available);
 
+        if available == 0 && !output.is_empty() {
+            self.underrun_reads.fetch_add(1, Ordering::Relaxed);
+        }
+
         if to_read == 0 {
             return 0;
         }
@@ -241,6 +291,43 @@ mod tests {
         assert!(written < BUFFER_SIZE);
     }
 
+    #[test]
+    fn test_overrun_is_counted() {
+        let buffer = AudioRingBuffer::new();
+        assert_eq!(buffer.stats(), RingBufferStats::default());
+
+        let huge = vec![0.5; BUFFER_SIZE + 100];
+        let written = buffer.write(&huge);
+        let stats = buffer.stats();
+        assert_eq!(stats.overrun_samples, (huge.len() - written) as u64);
+        assert_eq!(stats.overrun_events, 1);
+
+        // A full buffer drops the whole write
+        buffer.write(&[1.0; 10]);
+        assert_eq!(
+            buffer.stats().overrun_samples,
+            (huge.len() - written + 10) as u64
+        );
+        assert_eq!(buffer.stats().overrun_events, 2);
+
+        buffer.reset_stats();
+        assert_eq!(buffer.stats(), RingBufferStats::default());
+    }
+
+    #[test]
+    fn test_underrun_is_counted() {
+        let buffer = AudioRingBuffer::new();
+        let mut output = [0.0; 4];
+        assert_eq!(buffer.read(&mut output), 0);
+        assert_eq!(buffer.stats().underrun_reads, 1);
+
+        // A short read with data waiting is not an underrun
+        buffer.write(&[1.0, 2.0]);
+        assert_eq!(buffer.read(&mut output), 2);
+        assert_eq!(buffer.stats().underrun_reads, 1);
+        assert_eq!(buffer.stats().overrun_events, 0);
+    }
+
     #[test]
     fn test_clear() {
         let buffer = AudioRingBuffer::new();
@@ -5,8 +5,17 @@
 //! pipeline to strip *leading* silence (dead air before the user starts
 //! talking) from long recordings. The trailing edge is never trimmed — see
 //! `trim_silence` for why.
+//!
+//! With `config.audio.trim_silence` on, `trim_silence_edges` trims the
+//! trailing edge as well, keeping a configurable margin on both sides;
+//! `trim_for_transcription` picks between the two.
+//!
+//! It also runs a live speech-state tracker over the recording stream so the
+//! frontend can visualise detection while the user is speaking (see
+//! `VadStateTracker` and `get_vad_status`).
 
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use webrtc_vad::{SampleRate, Vad, VadMode};
 
 /// Frame duration for VAD processing
@@ -39,6 +48,216 @@ impl VadFrameDuration {
     }
 }
 
+/// Live speech state reported by the VAD during recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum VadSpeechState {
+    /// No speech detected
+    #[default]
+    Silence,
+    /// Voiced frames seen, but fewer than `speech_start_frames` in a row
+    PossibleSpeech,
+    /// Speech confirmed
+    Speaking,
+    /// Unvoiced frames seen after speech, but fewer than `speech_end_frames`
+    PossibleSilence,
+}
+
+impl VadSpeechState {
+    const fn to_u8(self) -> u8 {
+        match self {
+            VadSpeechState::Silence => 0,
+            VadSpeechState::PossibleSpeech => 1,
+            VadSpeechState::Speaking => 2,
+            VadSpeechState::PossibleSilence => 3,
+        }
+    }
+
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            1 => VadSpeechState::PossibleSpeech,
+            2 => VadSpeechState::Speaking,
+            3 => VadSpeechState::PossibleSilence,
+            _ => VadSpeechState::Silence,
+        }
+    }
+}
+
+/// Thresholds for the live speech-state tracker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VadConfig {
+    /// Frame duration fed to WebRTC VAD
+    pub frame_duration: VadFrameDuration,
+    /// Consecutive voiced frames required to move into `Speaking`
+    pub speech_start_frames: u32,
+    /// Consecutive unvoiced frames required to fall back to `Silence`
+    pub speech_end_frames: u32,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            frame_duration: VadFrameDuration::Ms30,
+            // 3 × 30 ms = 90 ms of voicing before speech is confirmed
+            speech_start_frames: 3,
+            // 10 × 30 ms = 300 ms of quiet before speech is considered over
+            speech_end_frames: 10,
+        }
+    }
+}
+
+/// Payload for the `vad-state-change` event
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VadStateChange {
+    /// State before the transition
+    pub previous: VadSpeechState,
+    /// State after the transition
+    pub state: VadSpeechState,
+}
+
+/// Current live state, published by the recording writer thread
+static LIVE_STATE: AtomicU8 = AtomicU8::new(0);
+
+/// Whether a recording is currently feeding the live tracker
+static LIVE_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Advance the speech state machine by one frame.
+///
+/// `run` counts consecutive frames supporting the pending transition and is
+/// reset whenever the state settles. Returns the new `(state, run)` pair.
+fn next_state(
+    state: VadSpeechState,
+    run: u32,
+    is_voice: bool,
+    config: &VadConfig,
+) -> (VadSpeechState, u32) {
+    match (state, is_voice) {
+        (VadSpeechState::Silence, false) | (VadSpeechState::Speaking, true) => (state, 0),
+        (VadSpeechState::Silence, true) | (VadSpeechState::PossibleSpeech, true) => {
+            let run = run + 1;
+            if run >= config.speech_start_frames {
+                (VadSpeechState::Speaking, 0)
+            } else {
+                (VadSpeechState::PossibleSpeech, run)
+            }
+        }
+        (VadSpeechState::PossibleSpeech, false) => (VadSpeechState::Silence, 0),
+        (VadSpeechState::Speaking, false) | (VadSpeechState::PossibleSilence, false) => {
+            let run = run + 1;
+            if run >= config.speech_end_frames {
+                (VadSpeechState::Silence, 0)
+            } else {
+                (VadSpeechState::PossibleSilence, run)
+            }
+        }
+        (VadSpeechState::PossibleSilence, true) => (VadSpeechState::Speaking, 0),
+    }
+}
+
+/// Frame-by-frame speech state tracker for the live recording stream.
+///
+/// Owned by the recording writer thread, which feeds it the resampled 16 kHz
+/// mono stream. Every transition is published to the shared live state read by
+/// `get_vad_status` and emitted to the frontend as `vad-state-change`.
+pub struct VadStateTracker {
+    vad: Vad,
+    config: VadConfig,
+    state: VadSpeechState,
+    run: u32,
+    pending: Vec<i16>,
+}
+
+impl VadStateTracker {
+    /// Create a tracker and mark the live state as active (starting in `Silence`).
+    pub fn new(config: VadConfig) -> Self {
+        LIVE_STATE.store(VadSpeechState::Silence.to_u8(), Ordering::Release);
+        LIVE_ACTIVE.store(true, Ordering::Release);
+        Self {
+            vad: Vad::new_with_rate_and_mode(SampleRate::Rate16kHz, VadMode::Aggressive),
+            config,
+            state: VadSpeechState::Silence,
+            run: 0,
+            pending: Vec::with_capacity(config.frame_duration.samples_at_16khz() * 2),
+        }
+    }
+
+    /// Current speech state
+    pub fn state(&self) -> VadSpeechState {
+        self.state
+    }
+
+    /// Feed 16 kHz mono samples, classifying each complete frame.
+    pub fn process(&mut self, samples: &[i16]) {
+        let frame_size = self.config.frame_duration.samples_at_16khz();
+        self.pending.extend_from_slice(samples);
+
+        let mut offset = 0;
+        while self.pending.len() - offset >= frame_size {
+            let frame = &self.pending[offset..offset + frame_size];
+            // A VAD error on one frame is treated as silence rather than
+            // interrupting the recording.
+            let is_voice = self.vad.is_voice_segment(frame).unwrap_or(false);
+            self.advance(is_voice);
+            offset += frame_size;
+        }
+        self.pending.drain(..offset);
+    }
+
+    fn advance(&mut self, is_voice: bool) {
+        let previous = self.state;
+        let (state, run) = next_state(previous, self.run, is_voice, &self.config);
+        self.state = state;
+        self.run = run;
+
+        if state != previous {
+            LIVE_STATE.store(state.to_u8(), Ordering::Release);
+            tracing::debug!("VAD state {:?} -> {:?}", previous, state);
+            crate::app_handle::emit("vad-state-change", VadStateChange { previous, state });
+        }
+    }
+}
+
+impl Drop for VadStateTracker {
+    fn drop(&mut self) {
+        // Recording ended: the live state no longer reflects any input.
+        LIVE_STATE.store(VadSpeechState::Silence.to_u8(), Ordering::Release);
+        LIVE_ACTIVE.store(false, Ordering::Release);
+        if self.state != VadSpeechState::Silence {
+            crate::app_handle::emit(
+                "vad-state-change",
+                VadStateChange {
+                    previous: self.state,
+                    state: VadSpeechState::Silence,
+                },
+            );
+        }
+    }
+}
+
+/// Live VAD status returned to the frontend
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VadStatus {
+    /// Whether a recording is currently feeding the tracker
+    pub active: bool,
+    /// Current speech state (`Silence` when not active)
+    pub state: VadSpeechState,
+    /// Thresholds the tracker is running with
+    pub config: VadConfig,
+}
+
+/// Get the live VAD speech state for the current recording
+#[tauri::command]
+pub fn get_vad_status() -> VadStatus {
+    VadStatus {
+        active: LIVE_ACTIVE.load(Ordering::Acquire),
+        state: VadSpeechState::from_u8(LIVE_STATE.load(Ordering::Acquire)),
+        config: VadConfig::default(),
+    }
+}
+
 /// Minimum recording duration (in samples) before silence trimming is applied.
 /// For shorter recordings the VAD overhead isn't worth it.
 /// 20 seconds × 16 000 Hz = 320 000 samples.
@@ -49,50 +268,31 @@ const TRIM_MIN_SAMPLES_16KHZ: usize = 320_000;
 /// soft onset.
 const TRIM_MARGIN_SECS: f32 = 0.5;
 
-/// Trim leading silence from audio samples using VAD.
-///
-/// For recordings shorter than ~20 seconds (at the given sample rate) the
-/// original slice is returned unchanged because the VAD overhead is not worth
-/// the saving.
-///
-/// The function runs a single-pass VAD scan over the audio, locates the first
-/// speech frame, and returns a sub-slice that starts a safety margin (500 ms)
-/// before it. **The trailing edge is never trimmed** — `end` is always
-/// `samples.len()`.
-///
-/// The trailing edge is never trimmed: WebRTC VAD in aggressive mode tags the
-/// quiet tail of a sentence (especially on a lapel mic) as non-speech, so
-/// trimming there risks slicing off the final words. Leading silence is where
-/// the latency saving lives; keeping the whole tail costs only a little decode
-/// time and never loses a word.
-///
-/// # Arguments
-/// * `samples` — mono f32 audio samples normalised to [-1.0, 1.0]
-/// * `sample_rate` — sample rate in Hz (typically 16 000)
+/// Smallest margin `trim_silence_edges` keeps, whatever is configured, so a
+/// soft onset is never clipped.
+const TRIM_MIN_MARGIN_SECS: f32 = 0.25;
+
+/// The trailing margin is this many times the leading one: aggressive VAD
+/// tags the quiet tail of a sentence as non-speech (#46), so the tail needs
+/// more room than the onset.
+const TRIM_TAIL_MARGIN_FACTOR: f32 = 2.0;
+
+/// Sample range of the outermost speech frames in `samples`, as
+/// `(start of first voiced frame, end of last voiced frame)`.
 ///
-/// # Returns
-/// A `(start, end)` range into `samples`. Callers should use `samples[start..end]`.
-/// `end` is always `samples.len()`. If no speech is detected the full range
-/// `(0, samples.len())` is returned so the downstream silence check can decide
-/// what to do.
-pub fn trim_silence(samples: &[f32], sample_rate: u32) -> (usize, usize) {
+/// With `first_only` the scan stops at the first voiced frame and the end is
+/// `samples.len()`. `None` when no frame is voiced.
+fn voiced_range(samples: &[f32], sample_rate: u32, first_only: bool) -> Option<(usize, usize)> {
     let total = samples.len();
 
-    // Scale threshold proportionally for non-16 kHz audio
-    let threshold = (TRIM_MIN_SAMPLES_16KHZ as f64 * sample_rate as f64 / 16_000.0) as usize;
-    if total < threshold {
-        return (0, total);
-    }
-
-    // Use a lightweight VAD config (30 ms frames, aggressive mode, minimal
-    // start/end thresholds so we detect the *outermost* speech edges).
-    let frame_dur = VadFrameDuration::Ms30;
-    let frame_size = frame_dur.samples_at_16khz();
+    // Use a lightweight VAD config (30 ms frames, aggressive mode) so we
+    // detect the *outermost* speech edges.
+    let frame_size = VadFrameDuration::Ms30.samples_at_16khz();
 
     // If the audio isn't 16 kHz the frame size needs scaling.
     let actual_frame_size = (frame_size as u64 * sample_rate as u64 / 16_000) as usize;
     if actual_frame_size == 0 {
-        return (0, total);
+        return None;
     }
 
     // webrtc-vad only accepts 16 kHz sample rate, so we need to work with
@@ -104,13 +304,10 @@ pub fn trim_silence(samples: &[f32], sample_rate: u32) -> (usize, usize) {
 
     let mut vad = Vad::new_with_rate_and_mode(SampleRate::Rate16kHz, VadMode::Aggressive);
 
-    let mut first_speech_frame: Option<usize> = None;
-
-    let mut frame_idx: usize = 0;
+    let mut bounds: Option<(usize, usize)> = None;
     let mut pos: usize = 0;
 
-    // We only need the first speech frame, so stop scanning once we've found it.
-    while pos + actual_frame_size <= total && first_speech_frame.is_none() {
+    while pos + actual_frame_size <= total {
         let frame_i16: Vec<i16> = if need_resample {
             // Cheap linear resample of this frame to 16 kHz
             (0..frame_size)
@@ -129,22 +326,64 @@ pub fn trim_silence(samples: &[f32], sample_rate: u32) -> (usize, usize) {
         };
 
         if let Ok(true) = vad.is_voice_segment(&frame_i16) {
-            first_speech_frame = Some(frame_idx);
+            let start = bounds.map_or(pos, |(start, _)| start);
+            bounds = Some((start, pos + actual_frame_size));
+            if first_only {
+                return Some((start, total));
+            }
         }
 
-        frame_idx += 1;
         pos += actual_frame_size;
     }
 
+    bounds
+}
+
+/// Trim leading silence from audio samples using VAD.
+///
+/// For recordings shorter than ~20 seconds (at the given sample rate) the
+/// original slice is returned unchanged because the VAD overhead is not worth
+/// the saving.
+///
+/// The function runs a single-pass VAD scan over the audio, locates the first
+/// speech frame, and returns a sub-slice that starts a safety margin (500 ms)
+/// before it. **The trailing edge is never trimmed** — `end` is always
+/// `samples.len()`.
+///
+/// The trailing edge is never trimmed: WebRTC VAD in aggressive mode tags the
+/// quiet tail of a sentence (especially on a lapel mic) as non-speech, so
+/// trimming there risks slicing off the final words. Leading silence is where
+/// the latency saving lives; keeping the whole tail costs only a little decode
+/// time and never loses a word.
+///
+/// # Arguments
+/// * `samples` — mono f32 audio samples normalised to [-1.0, 1.0]
+/// * `sample_rate` — sample rate in Hz (typically 16 000)
+///
+/// # Returns
+/// A `(start, end)` range into `samples`. Callers should use `samples[start..end]`.
+/// `end` is always `samples.len()`. If no speech is detected the full range
+/// `(0, samples.len())` is returned so the downstream silence check can decide
+/// what to do.
+pub fn trim_silence(samples: &[f32], sample_rate: u32) -> (usize, usize) {
+    let total = samples.len();
+
+    // Scale threshold proportionally for non-16 kHz audio
+    let threshold = (TRIM_MIN_SAMPLES_16KHZ as f64 * sample_rate as f64 / 16_000.0) as usize;
+    if total < threshold {
+        return (0, total);
+    }
+
+    // We only need the first speech frame, so stop scanning once we've found it.
     // No speech found — return the full range so downstream silence detection
     // can handle it (returning an empty range would silently discard audio).
-    let Some(first) = first_speech_frame else {
+    let Some((first, _)) = voiced_range(samples, sample_rate, true) else {
         return (0, total);
     };
 
     let margin_samples = (TRIM_MARGIN_SECS * sample_rate as f32) as usize;
 
-    let start = (first * actual_frame_size).saturating_sub(margin_samples);
+    let start = first.saturating_sub(margin_samples);
     // The trailing edge is never trimmed — keep every sample through to the end.
     let end = total;
 
@@ -159,6 +398,52 @@ pub fn trim_silence(samples: &[f32], sample_rate: u32) -> (usize, usize) {
     (start, end)
 }
 
+/// Trim leading and trailing silence from audio samples using VAD.
+///
+/// Unlike [`trim_silence`] this applies to recordings of any length and
+/// trims the tail too. `margin_secs` of audio is kept before the first
+/// speech frame (never less than 250 ms) and twice that after the last, so
+/// neither a soft onset nor a quiet final word is cut. If no speech is
+/// detected the full range is returned.
+pub fn trim_silence_edges(samples: &[f32], sample_rate: u32, margin_secs: f32) -> (usize, usize) {
+    let total = samples.len();
+    let Some((first, last)) = voiced_range(samples, sample_rate, false) else {
+        return (0, total);
+    };
+
+    let margin_secs = margin_secs.max(TRIM_MIN_MARGIN_SECS);
+    let lead_margin = (margin_secs * sample_rate as f32) as usize;
+    let tail_margin = (margin_secs * TRIM_TAIL_MARGIN_FACTOR * sample_rate as f32) as usize;
+
+    let start = first.saturating_sub(lead_margin);
+    let end = last.saturating_add(tail_margin).min(total);
+
+    tracing::info!(
+        "VAD silence trim: {:.2}s → {:.2}s (removed {:.2}s leading, {:.2}s trailing)",
+        total as f32 / sample_rate as f32,
+        (end - start) as f32 / sample_rate as f32,
+        start as f32 / sample_rate as f32,
+        (total - end) as f32 / sample_rate as f32,
+    );
+
+    (start, end)
+}
+
+/// Range of `samples` to transcribe, trimmed as the config asks.
+///
+/// [`trim_silence_edges`] with the configured margin when
+/// `config.audio.trim_silence` is on, otherwise [`trim_silence`].
+pub fn trim_for_transcription(samples: &[f32], sample_rate: u32) -> (usize, usize) {
+    match crate::config::get_config() {
+        Ok(config) if config.audio.trim_silence => trim_silence_edges(
+            samples,
+            sample_rate,
+            config.audio.trim_margin_ms as f32 / 1000.0,
+        ),
+        _ => trim_silence(samples, sample_rate),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +455,69 @@ mod tests {
         assert_eq!(VadFrameDuration::Ms30.samples_at_16khz(), 480);
     }
 
+    fn run_states(frames: &[bool], config: &VadConfig) -> Vec<VadSpeechState> {
+        let mut state = VadSpeechState::Silence;
+        let mut run = 0;
+        frames
+            .iter()
+            .map(|&voice| {
+                (state, run) = next_state(state, run, voice, config);
+                state
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_speech_state_transitions() {
+        let config = VadConfig {
+            speech_start_frames: 2,
+            speech_end_frames: 2,
+            ..Default::default()
+        };
+        let states = run_states(&[true, true, false, true, false, false], &config);
+        assert_eq!(
+            states,
+            vec![
+                VadSpeechState::PossibleSpeech,
+                VadSpeechState::Speaking,
+                VadSpeechState::PossibleSilence,
+                VadSpeechState::Speaking,
+                VadSpeechState::PossibleSilence,
+                VadSpeechState::Silence,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_brief_voicing_does_not_confirm_speech() {
+        let config = VadConfig::default();
+        let states = run_states(&[true, true, false], &config);
+        assert_eq!(
+            states,
+            vec![
+                VadSpeechState::PossibleSpeech,
+                VadSpeechState::PossibleSpeech,
+                VadSpeechState::Silence,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_speech_state_serialisation() {
+        assert_eq!(
+            serde_json::to_string(&VadSpeechState::PossibleSpeech).unwrap(),
+            "\"possible_speech\""
+        );
+        for state in [
+            VadSpeechState::Silence,
+            VadSpeechState::PossibleSpeech,
+            VadSpeechState::Speaking,
+            VadSpeechState::PossibleSilence,
+        ] {
+            assert_eq!(VadSpeechState::from_u8(state.to_u8()), state);
+        }
+    }
+
     #[test]
     fn test_trim_silence_short_recording_unchanged() {
         // 10 seconds at 16 kHz — below the 20-second threshold
@@ -227,6 +575,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_trim_silence_edges_keeps_margins() {
+        // 6 seconds at 16 kHz: 2 s silence, 2 s speech-like tone, 2 s silence
+        let sample_rate = 16_000u32;
+        let mut samples = vec![0.0f32; 6 * sample_rate as usize];
+        let speech_start = 2 * sample_rate as usize;
+        let speech_end = 4 * sample_rate as usize;
+        for (i, sample) in samples
+            .iter_mut()
+            .enumerate()
+            .take(speech_end)
+            .skip(speech_start)
+        {
+            let t = i as f32 / sample_rate as f32;
+            *sample = (t * 440.0 * 2.0 * std::f32::consts::PI).sin() * 0.5;
+        }
+
+        let (start, end) = trim_silence_edges(&samples, sample_rate, 0.3);
+
+        assert!(start > 0, "Should trim leading silence");
+        assert!(end < samples.len(), "Should trim trailing silence");
+        // The margins reach past the detected speech on both sides.
+        assert!(
+            start < speech_start,
+            "Trim start ({start}) cuts into speech"
+        );
+        assert!(end > speech_end, "Trim end ({end}) cuts into speech");
+    }
+
+    #[test]
+    fn test_trim_silence_edges_all_silence_returns_full_range() {
+        let samples = vec![0.0f32; 48_000];
+        assert_eq!(trim_silence_edges(&samples, 16_000, 0.5), (0, 48_000));
+    }
+
     #[test]
     fn test_trim_silence_non_16khz() {
         // 30 seconds at 48 kHz — tests the proportional threshold scaling
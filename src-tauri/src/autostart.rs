@@ -0,0 +1,143 @@
+//! Launch at login.
+//!
+//! Wraps `tauri-plugin-autostart`, which registers a LaunchAgent on macOS and
+//! an XDG autostart desktop entry on Linux, both pointing at the running
+//! binary. `config.general.launch_at_login` is the source of truth: it
+//! survives reinstalls, and on startup the registration is rewritten from it
+//! so the entry always points at the current install rather than wherever
+//! the app lived before.
+
+use std::path::Path;
+use tauri::AppHandle;
+use tauri_plugin_autostart::ManagerExt;
+
+use crate::config;
+use crate::error::Error;
+
+/// Why the app can't be registered to launch from `exe`, if it can't.
+///
+/// A login item pointing at a location that disappears would silently stop
+/// working, so these are refused up front with advice instead.
+fn launch_path_problem(exe: &Path, appimage: Option<&str>) -> Option<&'static str> {
+    let path = exe.to_string_lossy();
+    // Gatekeeper runs quarantined apps from a randomised read-only copy
+    if path.contains("/AppTranslocation/") {
+        return Some(
+            "macOS is running Thoth from a temporary location. Move it to the Applications folder and reopen it first",
+        );
+    }
+    if path.starts_with("/Volumes/") {
+        return Some(
+            "Thoth is running from its disk image. Move it to the Applications folder and reopen it first",
+        );
+    }
+    // An AppImage's binary lives in a mount that goes away on exit; the
+    // plugin registers the AppImage itself, which needs $APPIMAGE
+    if path.starts_with("/tmp/.mount_") && appimage.is_none() {
+        return Some("Can't find the AppImage Thoth was started from");
+    }
+    None
+}
+
+/// Register or unregister the login item, without touching config.
+fn apply(app: &AppHandle, enabled: bool) -> Result<(), String> {
+    let autolaunch = app.autolaunch();
+    if enabled {
+        let exe = std::env::current_exe()
+            .map_err(|e| format!("Failed to locate the app binary: {}", e))?;
+        let appimage = std::env::var("APPIMAGE").ok();
+        if let Some(problem) = launch_path_problem(&exe, appimage.as_deref()) {
+            return Err(problem.to_string());
+        }
+        autolaunch
+            .enable()
+            .map_err(|e| format!("Failed to enable launch at login: {}", e))
+    } else {
+        autolaunch
+            .disable()
+            .map_err(|e| format!("Failed to disable launch at login: {}", e))
+    }
+}
+
+/// Bring the login item in line with config at startup.
+///
+/// When enabled, the registration is rewritten so it points at this binary
+/// (after an update, move or reinstall the old path may be gone). A login
+/// item registered before the preference was kept in config is adopted
+/// rather than removed.
+pub fn sync_on_startup(app: &AppHandle) {
+    let configured = config::get_config()
+        .map(|c| c.general.launch_at_login)
+        .unwrap_or(false);
+    let registered = app.autolaunch().is_enabled().unwrap_or(false);
+    if !configured && !registered {
+        return;
+    }
+
+    if let Err(e) = apply(app, true) {
+        tracing::warn!("Launch at login: Failed to refresh registration: {}", e);
+        return;
+    }
+    if !configured {
+        tracing::info!("Launch at login: Adopting existing login item into config");
+        if let Err(e) = config::record_launch_at_login(true) {
+            tracing::warn!("Launch at login: Failed to save preference: {}", e);
+        }
+    }
+}
+
+// =============================================================================
+// Tauri Commands
+// =============================================================================
+
+/// Turn launch at login on or off and remember the choice in config.
+#[tauri::command]
+pub fn set_autostart(app: AppHandle, enabled: bool) -> Result<(), Error> {
+    apply(&app, enabled)?;
+    config::record_launch_at_login(enabled)
+        .map_err(|e| format!("Failed to save launch at login preference: {}", e))?;
+    tracing::info!("Launch at login set to: {}", enabled);
+    Ok(())
+}
+
+/// Whether the app is registered to launch at login.
+///
+/// Queries the LaunchAgent or desktop entry itself, so a login item removed
+/// outside the app reads as off.
+#[tauri::command]
+pub fn is_autostart_enabled(app: AppHandle) -> Result<bool, Error> {
+    app.autolaunch()
+        .is_enabled()
+        .map_err(|e| format!("Failed to check launch at login: {}", e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_installed_paths_accepted() {
+        let exe = Path::new("/Applications/Thoth.app/Contents/MacOS/thoth");
+        assert_eq!(launch_path_problem(exe, None), None);
+        assert_eq!(launch_path_problem(Path::new("/usr/bin/thoth"), None), None);
+        assert_eq!(
+            launch_path_problem(
+                Path::new("/tmp/.mount_ThothXk2/usr/bin/thoth"),
+                Some("/home/me/Apps/Thoth.AppImage")
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transient_paths_refused() {
+        let translocated = Path::new(
+            "/private/var/folders/x1/T/AppTranslocation/1A2B/d/Thoth.app/Contents/MacOS/thoth",
+        );
+        assert!(launch_path_problem(translocated, None).is_some());
+        let disk_image = Path::new("/Volumes/Thoth/Thoth.app/Contents/MacOS/thoth");
+        assert!(launch_path_problem(disk_image, None).is_some());
+        let mount = Path::new("/tmp/.mount_ThothXk2/usr/bin/thoth");
+        assert!(launch_path_problem(mount, None).is_some());
+    }
+}
@@ -86,10 +86,7 @@ pub struct CanonicalRegistry {
 static REGISTRY: OnceLock<RwLock<CanonicalRegistry>> = OnceLock::new();
 
 fn get_registry_path() -> PathBuf {
-    dirs::home_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join(".thoth")
-        .join("canonical_terms.json")
+    crate::storage::data_dir_or_fallback().join("canonical_terms.json")
 }
 
 fn load_or_seed() -> CanonicalRegistry {
@@ -192,7 +189,7 @@ fn fold_to_ascii(s: &str) -> String {
 }
 
 /// True when the two strings share at least one Double-Metaphone key.
-fn phonetic_match(a: &str, b: &str) -> bool {
+pub(crate) fn phonetic_match(a: &str, b: &str) -> bool {
     dm().is_encoded_equals(&fold_to_ascii(a), &fold_to_ascii(b))
 }
 
@@ -961,17 +958,17 @@ mod tests {
             crate::dictionary::DictionaryEntry {
                 from: "port cullis".to_string(),
                 to: "portcullis".to_string(),
-                case_sensitive: false,
+                ..Default::default()
             },
             crate::dictionary::DictionaryEntry {
                 from: "portcolours".to_string(),
                 to: "portcullis".to_string(),
-                case_sensitive: false,
+                ..Default::default()
             },
             crate::dictionary::DictionaryEntry {
                 from: "portcolors".to_string(),
                 to: "portcullis".to_string(),
-                case_sensitive: false,
+                ..Default::default()
             },
         ];
 
@@ -0,0 +1,158 @@
+//! Headless command-line mode.
+//!
+//! `thoth transcribe <path>` runs a file through the same decode → transcribe
+//! → filter → enhance core as an import, prints the text to stdout and exits.
+//! No window, tray or global shortcut is created, so it works on a machine
+//! without a display. Nothing is pasted, copied or saved to history.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use crate::enhancement;
+use crate::pipeline;
+use crate::transcription;
+
+/// How to run the command line
+const USAGE: &str = "Usage: thoth transcribe <path>";
+
+/// A command given on the command line
+#[derive(Debug, PartialEq, Eq)]
+pub enum CliCommand {
+    /// Transcribe an audio file and print the text
+    Transcribe(PathBuf),
+}
+
+/// Parse the arguments after the program name.
+///
+/// `Ok(None)` means no command was given and the app should start as usual;
+/// flags such as `--autostarted` are left to the app.
+pub fn parse_args(args: &[String]) -> Result<Option<CliCommand>, String> {
+    match args {
+        [command, rest @ ..] if command == "transcribe" => match rest {
+            [path] => Ok(Some(CliCommand::Transcribe(PathBuf::from(path)))),
+            _ => Err(USAGE.to_string()),
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Log warnings and errors to stderr, keeping stdout for the text.
+/// `RUST_LOG` raises the level.
+fn init_stderr_logging() {
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn"));
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .try_init();
+}
+
+/// Decode `path` and run it through the transcription pipeline, returning
+/// the final text (empty when the file holds no speech).
+async fn transcribe(path: &Path) -> Result<String, String> {
+    if !path.is_file() {
+        return Err(format!(
+            "file not found or not readable: {}",
+            path.display()
+        ));
+    }
+    pipeline::ensure_model_loading_for_import()?;
+    // The app does this in setup, which the command line never reaches.
+    if let Ok(cfg) = crate::config::get_config() {
+        crate::config::apply_enhancement_backend(&cfg.enhancement);
+    }
+
+    // Removed when dropped, however the run ends
+    let wav = tempfile::Builder::new()
+        .prefix("thoth_cli_")
+        .suffix(".wav")
+        .tempfile()
+        .map_err(|e| format!("Failed to create temporary file: {}", e))?;
+    let wav_path = wav.path().to_path_buf();
+    let input = path.to_path_buf();
+    let output = wav_path.clone();
+    tokio::task::spawn_blocking(move || {
+        crate::audio::decode::decode_audio_to_wav(&input, &output, &AtomicBool::new(false))
+    })
+    .await
+    .map_err(|e| format!("Decode task failed: {}", e))??;
+
+    let mut config = pipeline::effective_pipeline_config().map_err(|e| e.to_string())?;
+    config.auto_copy = false;
+    config.auto_paste = false;
+    config.save_to_history = false;
+
+    let mut transcriber =
+        transcription::LoadedTranscriber::default().chunked(pipeline::import_chunk_secs());
+    let output = pipeline::run_transcription_pipeline(
+        &(),
+        &mut transcriber,
+        &enhancement::ActiveEnhancer,
+        &wav_path.to_string_lossy(),
+        &config,
+        &pipeline::RunContext::default(),
+    )
+    .await;
+    match output {
+        Ok(output) => Ok(output.text),
+        Err(e) if pipeline::is_no_speech_error(&e) => Ok(String::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Run `command` to completion, returning the process exit code.
+pub fn run(command: CliCommand) -> i32 {
+    init_stderr_logging();
+
+    let runtime = match tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("thoth: failed to start the async runtime: {}", e);
+            return 1;
+        }
+    };
+
+    match command {
+        CliCommand::Transcribe(path) => match runtime.block_on(transcribe(&path)) {
+            Ok(text) => {
+                println!("{}", text);
+                0
+            }
+            Err(e) => {
+                eprintln!("thoth: {}", e);
+                1
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args_transcribe() {
+        assert_eq!(
+            parse_args(&args(&["transcribe", "memo.m4a"])),
+            Ok(Some(CliCommand::Transcribe(PathBuf::from("memo.m4a"))))
+        );
+        assert_eq!(parse_args(&args(&["transcribe"])), Err(USAGE.to_string()));
+        assert_eq!(
+            parse_args(&args(&["transcribe", "a.wav", "b.wav"])),
+            Err(USAGE.to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_args_leaves_app_launches_alone() {
+        assert_eq!(parse_args(&[]), Ok(None));
+        assert_eq!(parse_args(&args(&["--autostarted"])), Ok(None));
+    }
+}
@@ -309,6 +309,27 @@ pub async fn copy_transcription(
     Ok(true)
 }
 
+/// Flatten `text` to a single line: paragraph and line breaks become single
+/// spaces and surrounding whitespace is dropped.
+pub fn plain_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Text of the most recent transcription, ready for "copy last".
+///
+/// Flattened with [`plain_text`] when `config.output.copy_last_plain` is on.
+/// `None` when there is no history yet.
+pub fn last_transcription_text() -> Result<Option<String>, String> {
+    let last = crate::database::transcription::list_transcriptions(Some(1), Some(0))
+        .map_err(|e| format!("Failed to get last transcription: {}", e))?
+        .into_iter()
+        .next();
+    let plain = crate::config::get_config()
+        .map(|c| c.output.copy_last_plain)
+        .unwrap_or(false);
+    Ok(last.map(|t| if plain { plain_text(&t.text) } else { t.text }))
+}
+
 /// Get current clipboard settings.
 #[tauri::command]
 pub fn get_clipboard_settings() -> ClipboardSettings {
@@ -492,6 +513,16 @@ pub async fn paste_transcription(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_plain_text_flattens_breaks() {
+        assert_eq!(
+            plain_text("First thought.\n\nSecond thought.\nThird. "),
+            "First thought. Second thought. Third."
+        );
+        assert_eq!(plain_text("  already plain "), "already plain");
+        assert_eq!(plain_text(""), "");
+    }
+
     #[test]
     fn test_clipboard_manager_creation() {
         let manager = ClipboardManager::new();
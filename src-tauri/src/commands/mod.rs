@@ -3,12 +3,68 @@
 //! This module contains all IPC commands that can be invoked from the frontend.
 
 use crate::error::Error;
+use serde::Serialize;
+use std::sync::OnceLock;
 use tauri::{AppHandle, Manager};
 
-/// Greet command for testing
+/// Application and build metadata for diagnostics
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppInfo {
+    /// Application version (from Cargo.toml)
+    pub version: String,
+    /// Short git commit the binary was built from, if known at build time
+    pub build_commit: Option<String>,
+    /// Operating system (e.g. "macos", "linux")
+    pub os: String,
+    /// CPU architecture (e.g. "aarch64", "x86_64")
+    pub arch: String,
+    /// Active transcription backend, or `None` if no model is loaded
+    pub transcription_backend: Option<String>,
+    /// Selected transcription model ID, or `None` for the recommended default
+    pub model_id: Option<String>,
+    /// GPU information, or `None` if detection failed
+    pub gpu: Option<crate::platform::SystemGpuInfo>,
+    /// Directory holding the local log files
+    pub log_directory: String,
+    /// Path to the config file
+    pub config_path: String,
+}
+
+/// GPU details for [`get_app_info`], probed once: detection shells out to
+/// tools such as `nvidia-smi`, and the hardware does not change while running
+static GPU_INFO: OnceLock<Option<crate::platform::SystemGpuInfo>> = OnceLock::new();
+
+/// Get application and build metadata for support diagnostics
+///
+/// Reads cached state only, so the frontend can call it from a "copy
+/// diagnostics" action without waiting on any model load. The GPU probe runs
+/// once, on a blocking thread rather than the main one, and is reused after.
 #[tauri::command]
-pub fn greet(name: &str) -> String {
-    format!("Hello, {}! Welcome to Thoth.", name)
+pub async fn get_app_info() -> AppInfo {
+    let gpu = match GPU_INFO.get() {
+        Some(gpu) => gpu.clone(),
+        None => {
+            let probed = tokio::task::spawn_blocking(|| crate::platform::get_gpu_info().ok())
+                .await
+                .ok()
+                .flatten();
+            GPU_INFO.get_or_init(|| probed).clone()
+        }
+    };
+    AppInfo {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        build_commit: option_env!("THOTH_BUILD_COMMIT").map(str::to_string),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        transcription_backend: crate::transcription::get_transcription_backend(),
+        model_id: crate::transcription::get_selected_model_id(),
+        gpu,
+        log_directory: crate::log_dir().to_string_lossy().to_string(),
+        config_path: crate::config::get_config_path()
+            .to_string_lossy()
+            .to_string(),
+    }
 }
 
 /// Show a window by label
@@ -52,6 +108,105 @@ pub fn open_url(url: &str) -> Result<(), Error> {
     open::that_detached(url).map_err(|e| format!("Failed to open URL: {}", e).into())
 }
 
+/// Open the log directory in the system file manager
+#[tauri::command]
+pub fn open_log_directory() -> Result<(), Error> {
+    let dir = crate::log_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create log directory: {}", e))?;
+    open::that_detached(&dir).map_err(|e| format!("Failed to open log directory: {}", e).into())
+}
+
+/// Maximum number of lines `get_recent_logs` returns
+const MAX_RECENT_LOG_LINES: usize = 2000;
+
+/// Only the last part of the log file is read; 1 MiB comfortably covers
+/// `MAX_RECENT_LOG_LINES` lines without loading a whole day's log.
+const RECENT_LOG_READ_BYTES: u64 = 1024 * 1024;
+
+/// Get the last `lines` lines of the most recent log file
+///
+/// Returns an empty list when no log file exists yet. `lines` is capped at
+/// `MAX_RECENT_LOG_LINES`.
+#[tauri::command]
+pub fn get_recent_logs(lines: usize) -> Result<Vec<String>, Error> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let Some(path) = latest_log_file(&crate::log_dir()) else {
+        return Ok(Vec::new());
+    };
+
+    let mut file =
+        std::fs::File::open(&path).map_err(|e| format!("Failed to open log file: {}", e))?;
+    let len = file
+        .metadata()
+        .map_err(|e| format!("Failed to read log file: {}", e))?
+        .len();
+    let start = len.saturating_sub(RECENT_LOG_READ_BYTES);
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| format!("Failed to read log file: {}", e))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)
+        .map_err(|e| format!("Failed to read log file: {}", e))?;
+
+    let contents = String::from_utf8_lossy(&bytes);
+    let mut contents: &str = &contents;
+    // When reading from mid-file, the first line is almost certainly partial.
+    if start > 0 {
+        contents = contents
+            .split_once('\n')
+            .map(|(_, rest)| rest)
+            .unwrap_or("");
+    }
+    Ok(tail_lines(contents, lines.min(MAX_RECENT_LOG_LINES)))
+}
+
+/// Most recently modified `thoth*.log` file in `dir`
+fn latest_log_file(dir: &std::path::Path) -> Option<std::path::PathBuf> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("thoth") && name.ends_with(".log")
+        })
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((modified, entry.path()))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// The last `n` lines of `contents`, oldest first
+fn tail_lines(contents: &str, n: usize) -> Vec<String> {
+    let lines: Vec<&str> = contents.lines().collect();
+    lines[lines.len().saturating_sub(n)..]
+        .iter()
+        .map(|l| l.to_string())
+        .collect()
+}
+
+/// Change the local log level at runtime and persist it to config
+///
+/// Accepts one of `config::LOG_LEVELS`. Takes effect immediately, without a
+/// restart, and overrides any `RUST_LOG` value the app was started with.
+#[tauri::command]
+pub fn set_log_level(level: String) -> Result<(), Error> {
+    let level = level.trim().to_ascii_lowercase();
+    if !crate::config::LOG_LEVELS.contains(&level.as_str()) {
+        return Err(format!(
+            "Invalid log level '{}': expected one of {}",
+            level,
+            crate::config::LOG_LEVELS.join(", ")
+        )
+        .into());
+    }
+    crate::set_log_filter(&level)?;
+    crate::config::set_log_level_config(&level)?;
+    Ok(())
+}
+
 /// Set dock icon visibility (macOS) and persist to config
 #[tauri::command]
 pub fn set_show_in_dock(app: AppHandle, show: bool) -> Result<(), Error> {
@@ -198,5 +353,36 @@ pub fn open_privacy_pane(pane: String) -> Result<(), Error> {
 /// Quit and relaunch the application (used by troubleshooting flow)
 #[tauri::command]
 pub fn relaunch_app(app: AppHandle) -> Result<(), Error> {
+    let _ = crate::config::flush_pending_save();
     app.restart();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_log_level_rejects_unknown_level() {
+        let err = set_log_level("verbose".to_string()).unwrap_err();
+        assert!(err.to_string().contains("Invalid log level"));
+    }
+
+    #[test]
+    fn test_tail_lines() {
+        let contents = "one\ntwo\nthree\n";
+        assert_eq!(tail_lines(contents, 2), vec!["two", "three"]);
+        assert_eq!(tail_lines(contents, 10), vec!["one", "two", "three"]);
+        assert!(tail_lines(contents, 0).is_empty());
+    }
+
+    #[test]
+    fn test_latest_log_file_ignores_other_files() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(latest_log_file(dir.path()).is_none());
+
+        std::fs::write(dir.path().join("notes.txt"), "x").unwrap();
+        std::fs::write(dir.path().join("thoth.2026-01-01.log"), "x").unwrap();
+        let latest = latest_log_file(dir.path()).unwrap();
+        assert!(latest.ends_with("thoth.2026-01-01.log"));
+    }
+}
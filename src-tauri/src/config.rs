@@ -6,9 +6,12 @@
 
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 use crate::enhancement;
 use crate::error::Error;
@@ -32,6 +35,10 @@ pub struct IntegrationsConfig {
     /// Whether the MCP server is enabled
     #[serde(default, alias = "mcpEnabled")]
     pub mcp_enabled: bool,
+    /// Whether the Control API may start a recording and return its text
+    /// (`POST /dictate`); off unless the user opts in
+    #[serde(default, alias = "dictationEnabled")]
+    pub dictation_enabled: bool,
 }
 
 fn default_api_port() -> u16 {
@@ -48,10 +55,118 @@ impl Default for IntegrationsConfig {
             api_enabled: true,
             api_port: default_api_port(),
             mcp_enabled: true,
+            dictation_enabled: false,
         }
     }
 }
 
+/// Output configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OutputConfig {
+    /// Type text at the cursor while recording, as it is recognised, instead
+    /// of inserting it all once the recording stops (see
+    /// [`crate::realtime_insert`])
+    pub realtime_insert: bool,
+    /// When to leave a space after inserted text so the next dictation
+    /// doesn't run into it
+    pub trailing_space: TrailingSpace,
+    /// "Copy last transcription" (tray and shortcut) copies the text on one
+    /// line, without paragraph breaks, e.g. for code comments
+    pub copy_last_plain: bool,
+    /// With auto-paste on but no text field focused, copy the transcription
+    /// to the clipboard instead of pasting it into nothing
+    pub no_focus_fallback: bool,
+}
+
+/// Spacing after inserted text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TrailingSpace {
+    /// Always end with a space
+    #[default]
+    Always,
+    /// Never add a space, e.g. for filling form fields
+    Never,
+    /// Only add a space when the dictation follows on from the previous
+    /// one in the same app; it then goes before the new text
+    Smart,
+}
+
+/// Saved pipeline settings, the `pipeline` section of the config file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PipelineSettings {
+    /// Show a "no speech detected" notice when a recording or import held no
+    /// speech. Silent input is never reported as an error either way.
+    pub notify_on_silence: bool,
+    /// Keep each transcription in history. When off, dictations are still
+    /// output but never written to the database.
+    pub save_to_history: bool,
+    /// Recordings shorter than this many milliseconds (a stray tap of the
+    /// shortcut) are discarded without transcription or saving. 0 keeps
+    /// every recording.
+    pub min_recording_ms: u32,
+}
+
+impl Default for PipelineSettings {
+    fn default() -> Self {
+        Self {
+            notify_on_silence: true,
+            save_to_history: true,
+            min_recording_ms: 300,
+        }
+    }
+}
+
+/// Tray menu configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrayConfig {
+    /// Order and visibility of the tray menu items, top to bottom. Items not
+    /// listed follow in their default order and unknown IDs are ignored;
+    /// Quit is always shown last. Empty means the default menu.
+    pub items: Vec<TrayItemConfig>,
+}
+
+/// One entry of [`TrayConfig::items`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrayItemConfig {
+    /// Item ID, e.g. "copy_last" or "transcribe"
+    pub id: String,
+    /// Whether the item is shown
+    #[serde(default = "default_true")]
+    pub visible: bool,
+}
+
+/// Privacy configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrivacyConfig {
+    /// Delete the recording once it has been transcribed, keeping only the
+    /// text. Such history entries cannot be retranscribed.
+    pub delete_audio_after_transcription: bool,
+    /// Masking of sensitive numbers in stored and logged text
+    pub redaction: RedactionConfig,
+    /// Include a short preview of each transcription in the log. Off by
+    /// default, so the log records only lengths and timings.
+    pub log_transcription_text: bool,
+}
+
+/// Redaction of sensitive patterns (see [`crate::redaction`])
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedactionConfig {
+    /// Mask credit-card and SSN-like numbers, plus any custom patterns, in
+    /// the text saved to history and written to logs
+    pub enabled: bool,
+    /// Also mask the text that is pasted or copied. Off by default so the
+    /// real value is still inserted at the cursor.
+    pub redact_pasted_text: bool,
+    /// Additional regular expressions to mask
+    pub custom_patterns: Vec<String>,
+}
+
 /// Logging and telemetry configuration
 ///
 /// Local file logging is always on. The Loki layer is opt-in; changes apply on restart.
@@ -81,6 +196,15 @@ pub struct LoggingConfig {
     /// Minimum tracing level for telemetry events ("info", "debug", etc.)
     #[serde(default = "default_telemetry_level", alias = "telemetryLevel")]
     pub telemetry_level: String,
+    /// Local log verbosity ("error", "warn", "info", "debug", "trace").
+    /// Applied at startup unless `RUST_LOG` is set, and live via `set_log_level`.
+    #[serde(default = "default_log_level", alias = "logLevel")]
+    pub log_level: String,
+    /// Whether to write the local log files at all (default true). When off,
+    /// logs go to stdout only. Read at startup; `THOTH_NO_FILE_LOG` also
+    /// turns it off.
+    #[serde(default = "default_true", alias = "fileLogging")]
+    pub file_logging: bool,
 }
 
 fn default_local_retention_days() -> u32 {
@@ -91,6 +215,13 @@ fn default_telemetry_level() -> String {
     "info".to_string()
 }
 
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+/// Log levels accepted by `LoggingConfig::log_level`
+pub const LOG_LEVELS: &[&str] = &["error", "warn", "info", "debug", "trace"];
+
 impl Default for LoggingConfig {
     fn default() -> Self {
         Self {
@@ -101,6 +232,8 @@ impl Default for LoggingConfig {
             loki_tenant: None,
             loki_labels: Vec::new(),
             telemetry_level: default_telemetry_level(),
+            log_level: default_log_level(),
+            file_logging: true,
         }
     }
 }
@@ -165,6 +298,8 @@ impl std::fmt::Debug for LoggingConfig {
             .field("loki_tenant", &self.loki_tenant)
             .field("loki_labels", &self.loki_labels)
             .field("telemetry_level", &self.telemetry_level)
+            .field("log_level", &self.log_level)
+            .field("file_logging", &self.file_logging)
             .finish()
     }
 }
@@ -191,6 +326,14 @@ pub struct Config {
     pub integrations: IntegrationsConfig,
     /// Logging and telemetry settings
     pub logging: LoggingConfig,
+    /// Privacy settings
+    pub privacy: PrivacyConfig,
+    /// Output settings
+    pub output: OutputConfig,
+    /// Pipeline settings
+    pub pipeline: PipelineSettings,
+    /// Tray menu settings
+    pub tray: TrayConfig,
 }
 
 impl Default for Config {
@@ -205,6 +348,10 @@ impl Default for Config {
             recorder: RecorderConfig::default(),
             integrations: IntegrationsConfig::default(),
             logging: LoggingConfig::default(),
+            privacy: PrivacyConfig::default(),
+            output: OutputConfig::default(),
+            pipeline: PipelineSettings::default(),
+            tray: TrayConfig::default(),
         }
     }
 }
@@ -242,6 +389,49 @@ pub struct AudioConfig {
     /// behaviour); the mic indicator only shows levels during active recording.
     #[serde(default = "default_true")]
     pub warm_stream: bool,
+    /// How multi-channel input is reduced to mono before transcription
+    #[serde(default)]
+    pub channel_mode: ChannelMode,
+    /// Directory for new recordings and decoded imports (None for
+    /// `~/.thoth/Recordings`). Changed only via [`set_recordings_dir_config`],
+    /// which validates it; existing files are not moved.
+    #[serde(default)]
+    pub recordings_dir: Option<String>,
+    /// Input device IDs recorded together and mixed into one recording, for
+    /// capturing a meeting on several microphones. Two or more replace
+    /// `device_id`; with fewer connected, `device_id` is used as usual.
+    #[serde(default)]
+    pub mix_device_ids: Vec<String>,
+    /// Trim leading and trailing silence before transcription, not only the
+    /// leading silence of long recordings
+    #[serde(default)]
+    pub trim_silence: bool,
+    /// Audio kept before the first word when trimming, in milliseconds (at
+    /// least 250; twice this is kept after the last word)
+    pub trim_margin_ms: u32,
+}
+
+impl AudioConfig {
+    /// The recordings directory in effect: the configured one, or the default
+    pub fn resolved_recordings_dir(&self) -> PathBuf {
+        match self.custom_recordings_dir() {
+            Some(dir) => PathBuf::from(dir),
+            None => default_recordings_dir(),
+        }
+    }
+
+    /// The configured recordings directory, if one is set
+    pub fn custom_recordings_dir(&self) -> Option<&str> {
+        self.recordings_dir
+            .as_deref()
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+    }
+}
+
+/// Default recordings directory (~/.thoth/Recordings)
+pub fn default_recordings_dir() -> PathBuf {
+    crate::storage::data_dir_or_fallback().join("Recordings")
 }
 
 impl Default for AudioConfig {
@@ -251,10 +441,28 @@ impl Default for AudioConfig {
             sample_rate: 16000,
             play_sounds: true,
             warm_stream: true,
+            channel_mode: ChannelMode::default(),
+            recordings_dir: None,
+            mix_device_ids: Vec::new(),
+            trim_silence: false,
+            trim_margin_ms: 500,
         }
     }
 }
 
+/// Channel selection when downmixing to mono
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelMode {
+    /// Average all channels
+    #[default]
+    Mix,
+    /// Keep only the first (left) channel
+    Left,
+    /// Keep only the second (right) channel; mono input uses its only channel
+    Right,
+}
+
 /// Transcription engine configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -264,6 +472,10 @@ pub struct TranscriptionConfig {
     pub model_id: Option<String>,
     /// Transcription language code (e.g., "en", "auto")
     pub language: String,
+    /// Model to use for each language code, e.g. `{"ja": "ggml-large-v3"}`.
+    /// When the configured language has a downloaded model here it is loaded
+    /// in place of `model_id` (see [`TranscriptionConfig::language_model_id`]).
+    pub language_model_map: BTreeMap<String, String>,
     /// Whether to automatically copy transcription to clipboard
     pub auto_copy: bool,
     /// Whether to automatically paste transcription at cursor
@@ -297,17 +509,46 @@ pub struct TranscriptionConfig {
     /// macOS Dictation, Dragon and Talon.
     #[serde(default = "default_true")]
     pub voice_formatting_commands: bool,
+    /// Whether to collapse Whisper's repetition loops ("you you you you...")
+    /// to a single occurrence
+    #[serde(default = "default_true")]
+    pub collapse_repetitions: bool,
+    /// Whether to start a new line at long pauses in the speech, as a rough
+    /// marker of speaker turns in interviews and meetings
+    #[serde(default)]
+    pub mark_pauses: bool,
+    /// Whether to give Whisper the end of the previous dictation as context
+    /// for the next, so names and style stay consistent across chunks (see
+    /// [`crate::transcription::context`])
+    #[serde(default)]
+    pub context_carryover: bool,
+    /// Seconds after a dictation during which its context carries over
+    #[serde(default = "default_context_carryover_window_secs")]
+    pub context_carryover_window_secs: u32,
+    /// Length of the windows a long imported file is transcribed in, so the
+    /// whole recording never goes through the model at once (0 disables)
+    #[serde(default = "default_import_chunk_secs")]
+    pub import_chunk_secs: u32,
 }
 
 fn default_true() -> bool {
     true
 }
 
+fn default_context_carryover_window_secs() -> u32 {
+    120
+}
+
+fn default_import_chunk_secs() -> u32 {
+    300
+}
+
 impl Default for TranscriptionConfig {
     fn default() -> Self {
         Self {
             model_id: None,
             language: "en".to_string(),
+            language_model_map: BTreeMap::new(),
             auto_copy: false,
             auto_paste: true,
             add_leading_space: false,
@@ -318,10 +559,76 @@ impl Default for TranscriptionConfig {
             cleanup_punctuation: true,
             sentence_case: false,
             voice_formatting_commands: true,
+            collapse_repetitions: true,
+            mark_pauses: false,
+            context_carryover: false,
+            context_carryover_window_secs: default_context_carryover_window_secs(),
+            import_chunk_secs: default_import_chunk_secs(),
         }
     }
 }
 
+impl TranscriptionConfig {
+    /// The model `language_model_map` gives for the configured language
+    ///
+    /// An exact (case-insensitive) match wins; otherwise entries match on the
+    /// primary language, so "ja-JP" finds a "ja" entry and vice versa.
+    pub fn language_model_id(&self) -> Option<&str> {
+        let language = self.language.trim();
+        let primary = crate::transcription::manifest::primary_language(language);
+        let map = &self.language_model_map;
+        map.iter()
+            .find(|(code, _)| code.trim().eq_ignore_ascii_case(language))
+            .or_else(|| {
+                map.iter().find(|(code, _)| {
+                    crate::transcription::manifest::primary_language(code) == primary
+                })
+            })
+            .map(|(_, model_id)| model_id.trim())
+            .filter(|model_id| !model_id.is_empty())
+    }
+
+    /// Put the output filter settings back to their defaults, leaving the
+    /// model, language and output settings alone
+    ///
+    /// Names every field, so a field added later fails to compile here until
+    /// it is sorted into kept or reset.
+    fn reset_filters(&mut self) {
+        let defaults = Self::default();
+        let Self {
+            // Kept
+            model_id: _,
+            language: _,
+            language_model_map: _,
+            auto_copy: _,
+            auto_paste: _,
+            add_leading_space: _,
+            context_carryover: _,
+            context_carryover_window_secs: _,
+            import_chunk_secs: _,
+            // Reset
+            remove_fillers,
+            australian_spelling,
+            spoken_numbers_to_digits,
+            normalise_whitespace,
+            cleanup_punctuation,
+            sentence_case,
+            voice_formatting_commands,
+            collapse_repetitions,
+            mark_pauses,
+        } = self;
+        *remove_fillers = defaults.remove_fillers;
+        *australian_spelling = defaults.australian_spelling;
+        *spoken_numbers_to_digits = defaults.spoken_numbers_to_digits;
+        *normalise_whitespace = defaults.normalise_whitespace;
+        *cleanup_punctuation = defaults.cleanup_punctuation;
+        *sentence_case = defaults.sentence_case;
+        *voice_formatting_commands = defaults.voice_formatting_commands;
+        *collapse_repetitions = defaults.collapse_repetitions;
+        *mark_pauses = defaults.mark_pauses;
+    }
+}
+
 /// Recording mode options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -343,8 +650,45 @@ pub struct ShortcutConfig {
     pub copy_last: Option<String>,
     /// Toggle AI enhancement on/off shortcut (unbound by default)
     pub toggle_enhancement: Option<String>,
+    /// Toggle a recording that is output but not saved to history (unbound
+    /// by default)
+    pub dictate_without_saving: Option<String>,
+    /// Toggle a recording whose text is typed at the cursor whatever the
+    /// insertion method, for fields that reject paste (unbound by default)
+    pub dictate_by_typing: Option<String>,
+    /// Toggle a recording whose text is pasted at the cursor (unbound by
+    /// default)
+    pub dictate_by_pasting: Option<String>,
+    /// Toggle a recording output word for word, without filters, dictionary,
+    /// enhancement or added punctuation and spacing (unbound by default)
+    pub dictate_verbatim: Option<String>,
     /// Recording mode: toggle or push-to-talk
     pub recording_mode: RecordingMode,
+    /// Tap-vs-hold threshold for standalone modifier shortcuts (ms).
+    ///
+    /// A press released before this threshold is a tap: it toggles recording
+    /// and enters hands-free mode (press again to stop). A press held at least
+    /// this long is push-to-talk: releasing it stops recording. Clamped to
+    /// `HOLD_THRESHOLD_RANGE_MS` when applied.
+    #[serde(default = "default_hold_threshold_ms", alias = "holdThresholdMs")]
+    pub hold_threshold_ms: u32,
+}
+
+/// Accepted range for `ShortcutConfig::hold_threshold_ms`
+pub const HOLD_THRESHOLD_RANGE_MS: std::ops::RangeInclusive<u32> = 150..=2000;
+
+fn default_hold_threshold_ms() -> u32 {
+    500
+}
+
+impl ShortcutConfig {
+    /// The hold threshold clamped to `HOLD_THRESHOLD_RANGE_MS`
+    pub fn effective_hold_threshold_ms(&self) -> u32 {
+        self.hold_threshold_ms.clamp(
+            *HOLD_THRESHOLD_RANGE_MS.start(),
+            *HOLD_THRESHOLD_RANGE_MS.end(),
+        )
+    }
 }
 
 impl Default for ShortcutConfig {
@@ -354,7 +698,12 @@ impl Default for ShortcutConfig {
             toggle_recording_alt: Some("ShiftRight".to_string()),
             copy_last: Some("F14".to_string()),
             toggle_enhancement: None,
+            dictate_without_saving: None,
+            dictate_by_typing: None,
+            dictate_by_pasting: None,
+            dictate_verbatim: None,
             recording_mode: RecordingMode::default(),
+            hold_threshold_ms: default_hold_threshold_ms(),
         }
     }
 }
@@ -369,6 +718,10 @@ pub struct EnhancementConfig {
     pub model: String,
     /// Selected prompt template ID
     pub prompt_id: String,
+    /// Prompt template IDs applied in sequence, each pass enhancing the
+    /// previous output. Empty (the default) means the single `prompt_id`.
+    #[serde(default, alias = "promptChain")]
+    pub prompt_chain: Vec<String>,
     /// Ollama server URL (unchanged from pre-existing config)
     pub ollama_url: String,
     /// Active backend: "ollama" (default) or "openai_compat"
@@ -380,6 +733,35 @@ pub struct EnhancementConfig {
     /// Optional API key for the OpenAI-compatible endpoint
     #[serde(default)]
     pub api_key: Option<String>,
+    /// Load the Ollama model into memory at startup and after a model change,
+    /// so the first enhancement does not wait for it. Off by default: a
+    /// resident model holds RAM/VRAM for as long as Ollama keeps it loaded.
+    #[serde(default, alias = "warmupModel")]
+    pub warmup_model: bool,
+    /// Transcriptions with fewer words than this are never enhanced, even
+    /// with enhancement on, so a one-word answer is not expanded into a
+    /// paragraph. 0 enhances everything.
+    #[serde(alias = "minWords")]
+    pub min_words: u32,
+}
+
+impl EnhancementConfig {
+    /// The Ollama model enhancement uses, or `None` when enhancement is off
+    /// or runs on another backend
+    pub fn active_ollama_model(&self) -> Option<&str> {
+        (self.enabled && self.backend == "ollama" && !self.model.trim().is_empty())
+            .then_some(self.model.as_str())
+    }
+
+    /// The `(ollama_url, model)` to pre-load, or `None` when warmup does not
+    /// apply (disabled, enhancement off, or a non-Ollama backend).
+    pub fn warmup_target(&self) -> Option<(&str, &str)> {
+        (self.warmup_model
+            && self.enabled
+            && self.backend == "ollama"
+            && !self.model.trim().is_empty())
+        .then_some((self.ollama_url.as_str(), self.model.as_str()))
+    }
 }
 
 impl std::fmt::Debug for EnhancementConfig {
@@ -388,10 +770,13 @@ impl std::fmt::Debug for EnhancementConfig {
             .field("enabled", &self.enabled)
             .field("model", &self.model)
             .field("prompt_id", &self.prompt_id)
+            .field("prompt_chain", &self.prompt_chain)
             .field("ollama_url", &self.ollama_url)
             .field("backend", &self.backend)
             .field("openai_compat_url", &self.openai_compat_url)
             .field("api_key", &self.api_key.as_ref().map(|_| "***redacted***"))
+            .field("warmup_model", &self.warmup_model)
+            .field("min_words", &self.min_words)
             .finish()
     }
 }
@@ -410,10 +795,13 @@ impl Default for EnhancementConfig {
             enabled: false,
             model: "llama3.2".to_string(),
             prompt_id: "fix-grammar".to_string(),
+            prompt_chain: Vec::new(),
             ollama_url: "http://localhost:11434".to_string(),
             backend: default_backend(),
             openai_compat_url: default_openai_compat_url(),
             api_key: None,
+            warmup_model: false,
+            min_words: 3,
         }
     }
 }
@@ -429,13 +817,17 @@ pub enum IndicatorStyle {
     FixedFloat,
     /// Elongated horizontal bar with waveform visualisation
     Pill,
+    /// Stationary dot on the monitor holding the focused window, wherever
+    /// the cursor is; placed like `FixedFloat` within that monitor
+    FollowFocus,
 }
 
 /// General application settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct GeneralConfig {
-    /// Launch application on system startup
+    /// Launch application on system startup. Changed only through
+    /// [`crate::autostart::set_autostart`], which also registers the login item.
     pub launch_at_login: bool,
     /// Show menu bar icon
     pub show_in_menu_bar: bool,
@@ -460,6 +852,53 @@ pub struct GeneralConfig {
     /// `None` on a genuinely fresh install — no reset is triggered then.
     #[serde(default)]
     pub last_run_version: Option<String>,
+    /// Position and size of the main window when it was last moved, resized
+    /// or closed; `None` until then. Written only by
+    /// [`record_main_window_geometry`].
+    #[serde(default)]
+    pub main_window: Option<WindowGeometry>,
+    /// What closing the main window does. The window itself is never
+    /// destroyed: it hosts the pipeline event listeners.
+    pub close_behavior: CloseBehavior,
+}
+
+/// What closing the main window does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CloseBehavior {
+    /// Hide the window; it stays reachable from the tray or menu bar
+    HideToTray,
+    /// Quit the app
+    Quit,
+    /// Minimise the window
+    Minimize,
+}
+
+impl Default for CloseBehavior {
+    /// Hide to the menu bar on macOS, where it is always available.
+    /// Elsewhere minimise, as not every Linux desktop shows tray icons and a
+    /// hidden window could not be brought back.
+    fn default() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::HideToTray
+        } else {
+            Self::Minimize
+        }
+    }
+}
+
+/// A window's outer position and inner (content) size, in physical pixels,
+/// matching what `set_position` and `set_size` take back
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    /// Left edge, in virtual-desktop coordinates
+    pub x: i32,
+    /// Top edge, in virtual-desktop coordinates
+    pub y: i32,
+    /// Inner width, without decorations
+    pub width: u32,
+    /// Inner height, without decorations
+    pub height: u32,
 }
 
 impl Default for GeneralConfig {
@@ -473,6 +912,8 @@ impl Default for GeneralConfig {
             indicator_style: IndicatorStyle::default(),
             window_decorations: true,
             last_run_version: None,
+            main_window: None,
+            close_behavior: CloseBehavior::default(),
         }
     }
 }
@@ -592,20 +1033,12 @@ fn camel_to_snake(s: &str) -> String {
 
 /// Get the path to the config file (~/.thoth/config.json)
 pub fn get_config_path() -> PathBuf {
-    home_dir_or_fallback().join(".thoth").join("config.json")
+    get_config_dir().join("config.json")
 }
 
-/// Get the path to the config directory (~/.thoth)
+/// Get the path to the config directory (the data directory, ~/.thoth)
 pub(crate) fn get_config_dir() -> PathBuf {
-    home_dir_or_fallback().join(".thoth")
-}
-
-/// Get the home directory, falling back to /tmp if unavailable
-fn home_dir_or_fallback() -> PathBuf {
-    dirs::home_dir().unwrap_or_else(|| {
-        tracing::error!("Could not determine home directory, using /tmp");
-        PathBuf::from("/tmp")
-    })
+    crate::storage::data_dir_or_fallback()
 }
 
 /// Ensure the config directory exists
@@ -641,25 +1074,41 @@ fn load_from_disk() -> Result<Config, String> {
 
 /// Save configuration to disk
 fn save_to_disk(config: &Config) -> Result<(), String> {
+    if crate::storage::is_moving_data_dir() {
+        return Err(
+            "Thoth's data is being moved; settings can be changed after the restart".to_string(),
+        );
+    }
     ensure_config_dir()?;
 
     let path = get_config_path();
     let contents = serde_json::to_string_pretty(config)
         .map_err(|e| format!("Failed to serialise config: {}", e))?;
 
-    fs::write(&path, &contents).map_err(|e| format!("Failed to write config file: {}", e))?;
+    // Write to a temp file beside the config and rename it into place, so a
+    // crash mid-write never leaves a truncated config behind
+    let mut tmp = tempfile::Builder::new()
+        .prefix(".config.json.")
+        .tempfile_in(get_config_dir())
+        .map_err(|e| format!("Failed to create temp config file: {}", e))?;
 
     // Restrict config file permissions to owner-only (rw-------) because it
-    // may contain an API key in plaintext.
+    // may contain an API key in plaintext. Set before the contents go in.
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
         let permissions = fs::Permissions::from_mode(0o600);
-        if let Err(e) = fs::set_permissions(&path, permissions) {
+        if let Err(e) = tmp.as_file().set_permissions(permissions) {
             tracing::warn!("Failed to set config file permissions to 0o600: {}", e);
         }
     }
 
+    tmp.write_all(contents.as_bytes())
+        .and_then(|_| tmp.as_file().sync_all())
+        .map_err(|e| format!("Failed to write config file: {}", e))?;
+    tmp.persist(&path)
+        .map_err(|e| format!("Failed to write config file: {}", e.error))?;
+
     tracing::info!(
         "Config saved to disk: device_id={:?}, toggle_recording_alt={:?}",
         config.audio.device_id,
@@ -668,6 +1117,90 @@ fn save_to_disk(config: &Config) -> Result<(), String> {
     Ok(())
 }
 
+/// Quiet period after the last `set_config` before the config is written, so
+/// dragging a slider costs one write instead of dozens
+const SAVE_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(400);
+
+/// Coalesces rapid `set_config` calls into one deferred write
+struct SaveDebouncer {
+    /// Bumped by every scheduled save; only the newest one writes
+    generation: AtomicU64,
+    /// Whether the cached config has changes not yet on disk
+    pending: AtomicBool,
+}
+
+impl SaveDebouncer {
+    const fn new() -> Self {
+        Self {
+            generation: AtomicU64::new(0),
+            pending: AtomicBool::new(false),
+        }
+    }
+
+    /// Mark the config dirty and return this save's generation
+    fn schedule(&self) -> u64 {
+        self.pending.store(true, Ordering::SeqCst);
+        self.generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Whether no save was scheduled after `generation`
+    fn is_latest(&self, generation: u64) -> bool {
+        self.generation.load(Ordering::SeqCst) == generation
+    }
+
+    /// Clear the dirty flag, returning whether there was anything to write
+    fn take_pending(&self) -> bool {
+        self.pending.swap(false, Ordering::SeqCst)
+    }
+
+    /// Mark the config dirty again after a failed write, so the next flush
+    /// retries it
+    fn mark_pending(&self) {
+        self.pending.store(true, Ordering::SeqCst);
+    }
+}
+
+static SAVE_DEBOUNCER: SaveDebouncer = SaveDebouncer::new();
+
+/// Event emitted when a deferred config write fails (payload: the error
+/// message). `set_config` has already returned by then, so this is how the
+/// UI learns the settings did not reach disk.
+pub const CONFIG_SAVE_FAILED_EVENT: &str = "config-save-failed";
+
+/// Write the cached config once `set_config` has been quiet for
+/// [`SAVE_DEBOUNCE`]. The cache is already up to date, so readers see the new
+/// values immediately.
+fn schedule_save() {
+    let generation = SAVE_DEBOUNCER.schedule();
+    std::thread::spawn(move || {
+        std::thread::sleep(SAVE_DEBOUNCE);
+        if SAVE_DEBOUNCER.is_latest(generation) {
+            // Failures are reported through CONFIG_SAVE_FAILED_EVENT.
+            let _ = flush_pending_save();
+        }
+    });
+}
+
+/// Write the cached config now if a deferred save is pending.
+///
+/// Called when the debounce period ends, and before anything that must see
+/// the config on disk (exit, restart, moving the data directory). A failed
+/// write stays pending for the next flush and emits
+/// [`CONFIG_SAVE_FAILED_EVENT`].
+pub fn flush_pending_save() -> Result<(), String> {
+    if !SAVE_DEBOUNCER.take_pending() {
+        return Ok(());
+    }
+    let cached = get_config_instance().read();
+    if let Err(e) = save_to_disk(&cached) {
+        tracing::error!("Failed to save config: {}", e);
+        SAVE_DEBOUNCER.mark_pending();
+        crate::app_handle::emit(CONFIG_SAVE_FAILED_EVENT, e.clone());
+        return Err(e);
+    }
+    Ok(())
+}
+
 /// Migrate configuration from older schema versions
 fn migrate_config(mut config: Config) -> Result<Config, String> {
     let original_version = config.version;
@@ -727,6 +1260,36 @@ pub fn apply_enhancement_backend(enh: &EnhancementConfig) {
     );
 }
 
+/// The settings [`apply_enhancement_backend`] reads, for skipping it when a
+/// save changed something else
+fn backend_settings(enh: &EnhancementConfig) -> (&str, &str, &str, Option<&str>) {
+    (
+        &enh.backend,
+        &enh.ollama_url,
+        &enh.openai_compat_url,
+        enh.api_key.as_deref(),
+    )
+}
+
+/// Pre-load the enhancement model when `current` calls for a warmup that
+/// `previous` did not (enabled, switched model, or pointed at another server).
+fn warm_enhancement_model_if_changed(previous: &EnhancementConfig, current: &EnhancementConfig) {
+    let target = current.warmup_target();
+    if target.is_some() && target != previous.warmup_target() {
+        enhancement::warmup_model(current.model.clone(), std::time::Duration::ZERO);
+    }
+}
+
+/// Check a newly chosen Ollama model (or server) still has the model installed
+fn check_enhancement_model_if_changed(previous: &EnhancementConfig, current: &EnhancementConfig) {
+    let Some(model) = current.active_ollama_model() else {
+        return;
+    };
+    if previous.active_ollama_model() != Some(model) || previous.ollama_url != current.ollama_url {
+        enhancement::check_model_installed(model.to_string(), std::time::Duration::ZERO);
+    }
+}
+
 /// Get the global config instance
 fn get_config_instance() -> &'static RwLock<Config> {
     CONFIG.get_or_init(|| {
@@ -774,7 +1337,10 @@ pub fn get_config() -> Result<Config, Error> {
 /// Update the configuration
 ///
 /// Replaces the current configuration with the provided config and persists
-/// it to disk. The version field is automatically updated to the current schema.
+/// it to disk once calls have been quiet for [`SAVE_DEBOUNCE`], so rapid
+/// saves collapse into one write. A failed write is reported through
+/// [`CONFIG_SAVE_FAILED_EVENT`]. The version field is automatically updated
+/// to the current schema.
 #[tauri::command]
 pub fn set_config(mut config: Config) -> Result<(), Error> {
     // Ensure version is current
@@ -799,6 +1365,19 @@ pub fn set_config(mut config: Config) -> Result<(), Error> {
             config.audio.device_id = current.audio.device_id.clone();
         }
 
+        // The recordings directory is validated on change, which a generic save
+        // cannot do without failing every unrelated setting along with it.
+        // set_recordings_dir_config is the only way to change it.
+        config.audio.recordings_dir = current.audio.recordings_dir.clone();
+
+        // The window geometry is tracked by the window itself; a generic save
+        // from the frontend would otherwise reset it.
+        config.general.main_window = current.general.main_window;
+
+        // Launch at login is set alongside the OS login item by
+        // set_autostart; a stale frontend copy must not flip it back.
+        config.general.launch_at_login = current.general.launch_at_login;
+
         if config.transcription.model_id.is_none() && current.transcription.model_id.is_some() {
             tracing::debug!(
                 "Preserving model_id={:?} (incoming config had None)",
@@ -832,25 +1411,8 @@ pub fn set_config(mut config: Config) -> Result<(), Error> {
             config.shortcuts.toggle_recording_alt = current.shortcuts.toggle_recording_alt.clone();
         }
 
-        // Preserve toggle_enhancement if incoming is None but cached has a user-set value.
-        if config.shortcuts.toggle_enhancement.is_none()
-            && current.shortcuts.toggle_enhancement.is_some()
-        {
-            tracing::debug!(
-                "Preserving toggle_enhancement={:?} (incoming config had None)",
-                current.shortcuts.toggle_enhancement
-            );
-            config.shortcuts.toggle_enhancement = current.shortcuts.toggle_enhancement.clone();
-        }
-
-        // Preserve copy_last if incoming is None but cached has a user-set value.
-        if config.shortcuts.copy_last.is_none() && current.shortcuts.copy_last.is_some() {
-            tracing::debug!(
-                "Preserving copy_last={:?} (incoming config had None)",
-                current.shortcuts.copy_last
-            );
-            config.shortcuts.copy_last = current.shortcuts.copy_last.clone();
-        }
+        // Preserve each optional shortcut the same way when incoming is None.
+        preserve_optional_shortcuts(&mut config.shortcuts, &current.shortcuts);
 
         // Preserve enhancement.api_key if the incoming config has None but the cached
         // config has a key. The Settings panel sends api_key: null when the field is
@@ -901,26 +1463,158 @@ pub fn set_config(mut config: Config) -> Result<(), Error> {
         }
     }
 
-    // Save to disk first
-    save_to_disk(&config)?;
+    crate::redaction::validate_patterns(&config.privacy.redaction.custom_patterns)?;
+    // Only a changed level is checked, so a stored value this build does not
+    // know cannot block every other setting from saving
+    let log_level_changed =
+        config.logging.log_level != get_config_instance().read().logging.log_level;
+    if log_level_changed && !LOG_LEVELS.contains(&config.logging.log_level.as_str()) {
+        return Err(format!(
+            "Invalid log level '{}': expected one of {}",
+            config.logging.log_level,
+            LOG_LEVELS.join(", ")
+        )
+        .into());
+    }
 
-    // Update cached config
-    {
+    // Update cached config; the write to disk follows once saves go quiet
+    let previous = {
         let mut cached = get_config_instance().write();
-        *cached = config.clone();
+        let previous = std::mem::replace(&mut *cached, config.clone());
         tracing::info!(
             "Configuration updated (device_id: {:?}, toggle_recording_alt: {:?})",
             cached.audio.device_id,
             cached.shortcuts.toggle_recording_alt
         );
-    }
+        previous
+    };
+    schedule_save();
 
     // Reconfigure the enhancement backend to reflect any provider changes.
-    apply_enhancement_backend(&config.enhancement);
+    if backend_settings(&previous.enhancement) != backend_settings(&config.enhancement) {
+        apply_enhancement_backend(&config.enhancement);
+    }
+    warm_enhancement_model_if_changed(&previous.enhancement, &config.enhancement);
+    check_enhancement_model_if_changed(&previous.enhancement, &config.enhancement);
+    crate::keyboard_service::set_hold_threshold_ms(config.shortcuts.effective_hold_threshold_ms());
+    // Apply a new log level to the running subscriber, as set_log_level does
+    if log_level_changed {
+        if let Err(e) = crate::set_log_filter(&config.logging.log_level) {
+            tracing::warn!(
+                "Failed to apply log level {}: {}",
+                config.logging.log_level,
+                e
+            );
+        }
+    }
+    warn_if_language_unsupported(&previous.transcription, &config.transcription);
+    reload_model_if_language_changed(&previous.transcription, &config.transcription);
+    // A different set of mixed devices needs its streams opened fresh.
+    if previous.audio.mix_device_ids != config.audio.mix_device_ids {
+        crate::audio::cool_down_recording();
+    }
 
     Ok(())
 }
 
+/// Keep the cached value of each optional shortcut the incoming config left
+/// as None, so a save from a view that did not load a shortcut cannot clear
+/// it. Clearing one on purpose goes through `set_shortcut_config`.
+fn preserve_optional_shortcuts(incoming: &mut ShortcutConfig, current: &ShortcutConfig) {
+    let shortcuts = [
+        (
+            "toggle_enhancement",
+            &mut incoming.toggle_enhancement,
+            &current.toggle_enhancement,
+        ),
+        (
+            "dictate_without_saving",
+            &mut incoming.dictate_without_saving,
+            &current.dictate_without_saving,
+        ),
+        (
+            "dictate_by_typing",
+            &mut incoming.dictate_by_typing,
+            &current.dictate_by_typing,
+        ),
+        (
+            "dictate_by_pasting",
+            &mut incoming.dictate_by_pasting,
+            &current.dictate_by_pasting,
+        ),
+        (
+            "dictate_verbatim",
+            &mut incoming.dictate_verbatim,
+            &current.dictate_verbatim,
+        ),
+        ("copy_last", &mut incoming.copy_last, &current.copy_last),
+    ];
+    for (name, incoming, current) in shortcuts {
+        if incoming.is_none() && current.is_some() {
+            tracing::debug!(
+                "Preserving {}={:?} (incoming config had None)",
+                name,
+                current
+            );
+            *incoming = current.clone();
+        }
+    }
+}
+
+/// Log when a model or language change leaves the selected model unable to
+/// transcribe the configured language (an English-only model set to "de", say).
+fn warn_if_language_unsupported(previous: &TranscriptionConfig, current: &TranscriptionConfig) {
+    if previous.language == current.language && previous.model_id == current.model_id {
+        return;
+    }
+    let Some(model_id) = current.model_id.as_deref() else {
+        return;
+    };
+    if let Some(warning) =
+        crate::transcription::manifest::model_language_warning(model_id, &current.language)
+    {
+        tracing::warn!(
+            "Model {} with language {:?}: {}",
+            model_id,
+            current.language,
+            warning
+        );
+    }
+}
+
+/// Load the language's mapped model when a change of language (or of
+/// `language_model_map`) calls for a different one than is loaded.
+///
+/// Only reloads a model already in use; model selection in the UI loads its
+/// own choice.
+fn reload_model_if_language_changed(previous: &TranscriptionConfig, current: &TranscriptionConfig) {
+    if previous.language == current.language
+        && previous.language_model_map == current.language_model_map
+    {
+        return;
+    }
+    if let Some(mapped) = current.language_model_id() {
+        if !crate::transcription::is_model_id_downloaded(mapped) {
+            tracing::warn!(
+                "Model {} mapped to language {:?} is not downloaded; using the selected model",
+                mapped,
+                current.language
+            );
+        }
+    }
+    let before = crate::transcription::effective_model_id(previous);
+    let after = crate::transcription::effective_model_id(current);
+    if before == after || !crate::transcription::is_transcription_ready() {
+        return;
+    }
+    tracing::info!(
+        "Language {:?} uses model {:?}, reloading",
+        current.language,
+        after
+    );
+    std::thread::spawn(crate::transcription::warmup_transcription);
+}
+
 /// Set the audio device_id directly, bypassing set_config's preservation logic.
 ///
 /// This is the only correct way to change device_id (including clearing it to
@@ -938,6 +1632,55 @@ pub fn set_audio_device_config(device_id: Option<String>) -> Result<(), String>
     Ok(())
 }
 
+/// Set the recordings directory, bypassing set_config's preservation logic.
+///
+/// `None` (or a blank path) restores the default. A custom directory must be
+/// absolute and writable; it is created if missing. Files already recorded
+/// stay where they are.
+pub fn set_recordings_dir_config(dir: Option<String>) -> Result<(), String> {
+    let dir = dir.map(|d| d.trim().to_string()).filter(|d| !d.is_empty());
+    if let Some(dir) = &dir {
+        validate_recordings_dir(Path::new(dir))?;
+    }
+
+    let mut cached = get_config_instance().write();
+    cached.audio.recordings_dir = dir;
+    save_to_disk(&cached)?;
+    tracing::info!(
+        "Recordings directory updated: {}",
+        cached.audio.resolved_recordings_dir().display()
+    );
+    Ok(())
+}
+
+/// Check that `dir` can hold recordings: absolute, creatable, and writable.
+fn validate_recordings_dir(dir: &Path) -> Result<(), String> {
+    if !dir.is_absolute() {
+        return Err(format!(
+            "Recordings directory must be an absolute path: {}",
+            dir.display()
+        ));
+    }
+    fs::create_dir_all(dir).map_err(|e| {
+        format!(
+            "Cannot create recordings directory {}: {}",
+            dir.display(),
+            e
+        )
+    })?;
+    tempfile::Builder::new()
+        .prefix(".thoth_write_test")
+        .tempfile_in(dir)
+        .map(drop)
+        .map_err(|e| {
+            format!(
+                "Recordings directory {} is not writable: {}",
+                dir.display(),
+                e
+            )
+        })
+}
+
 /// Set the prompt_id directly, bypassing set_config's preservation logic.
 ///
 /// This is the correct way to change prompt_id from the tray menu. The
@@ -961,9 +1704,23 @@ pub fn set_prompt_config(prompt_id: String) -> Result<(), String> {
 /// with a full-config round-trip; this bypass touches only the `enabled` flag.
 pub fn set_enhancement_enabled(enabled: bool) -> Result<(), String> {
     let mut cached = get_config_instance().write();
+    let previous = cached.enhancement.clone();
     cached.enhancement.enabled = enabled;
     save_to_disk(&cached)?;
     tracing::info!("Enhancement enabled updated to: {}", enabled);
+    warm_enhancement_model_if_changed(&previous, &cached.enhancement);
+    Ok(())
+}
+
+/// Set the local log level directly, bypassing set_config's preservation logic.
+///
+/// Called by the `set_log_level` command after the live filter has been
+/// updated, so the chosen level survives a restart.
+pub fn set_log_level_config(level: &str) -> Result<(), String> {
+    let mut cached = get_config_instance().write();
+    cached.logging.log_level = level.to_string();
+    save_to_disk(&cached)?;
+    tracing::info!("Log level config updated to: {}", level);
     Ok(())
 }
 
@@ -1036,6 +1793,35 @@ pub fn record_last_run_version(version: &str) -> Result<Option<String>, Error> {
     Ok(prev) // Some(old) on a real update; None on a fresh install
 }
 
+/// Record the main window's geometry, persisting only when it changed.
+///
+/// Like [`record_last_run_version`], this writes the live config singleton
+/// directly rather than round-tripping through `set_config`.
+pub fn record_main_window_geometry(geometry: WindowGeometry) -> Result<(), Error> {
+    let mut cached = get_config_instance().write();
+    if cached.general.main_window == Some(geometry) {
+        return Ok(());
+    }
+    cached.general.main_window = Some(geometry);
+    save_to_disk(&cached)?;
+    Ok(())
+}
+
+/// Record the launch at login preference, persisting only when it changed.
+///
+/// Like [`record_last_run_version`], this writes the live config singleton
+/// directly rather than round-tripping through `set_config`, which preserves
+/// the stored value.
+pub fn record_launch_at_login(enabled: bool) -> Result<(), Error> {
+    let mut cached = get_config_instance().write();
+    if cached.general.launch_at_login == enabled {
+        return Ok(());
+    }
+    cached.general.launch_at_login = enabled;
+    save_to_disk(&cached)?;
+    Ok(())
+}
+
 /// Set shortcut config directly, bypassing set_config's preservation logic.
 ///
 /// Used by the Settings UI when intentionally changing shortcuts. The
@@ -1047,6 +1833,7 @@ pub fn set_shortcut_config(shortcuts: ShortcutConfig) -> Result<(), Error> {
     let mut cached = get_config_instance().write();
     cached.shortcuts = shortcuts;
     save_to_disk(&cached)?;
+    crate::keyboard_service::set_hold_threshold_ms(cached.shortcuts.effective_hold_threshold_ms());
     tracing::info!(
         "Shortcut config updated directly (toggle_recording_alt: {:?})",
         cached.shortcuts.toggle_recording_alt
@@ -1061,17 +1848,64 @@ pub fn set_shortcut_config(shortcuts: ShortcutConfig) -> Result<(), Error> {
 pub fn reset_config() -> Result<Config, Error> {
     let default_config = Config::default();
 
+    // A deferred save must not land after the reset and undo it.
+    SAVE_DEBOUNCER.take_pending();
+
     // Save to disk
     save_to_disk(&default_config)?;
 
     // Update cached config
     let mut cached = get_config_instance().write();
     *cached = default_config.clone();
+    crate::keyboard_service::set_hold_threshold_ms(
+        default_config.shortcuts.effective_hold_threshold_ms(),
+    );
 
     tracing::info!("Configuration reset to defaults");
     Ok(default_config)
 }
 
+/// Apply `change` to a copy of the config and persist it, replacing the
+/// cached config only once the write has succeeded, so a failed save leaves
+/// memory and disk agreeing on the old settings.
+fn update_and_save(change: impl FnOnce(&mut Config)) -> Result<Config, String> {
+    let mut cached = get_config_instance().write();
+    let mut updated = cached.clone();
+    change(&mut updated);
+    save_to_disk(&updated)?;
+    *cached = updated.clone();
+    Ok(updated)
+}
+
+/// Reset only the output filter settings (fillers, spelling, numbers,
+/// whitespace, punctuation, sentence case, voice commands) to defaults.
+#[tauri::command]
+pub fn reset_filter_config() -> Result<Config, Error> {
+    let config = update_and_save(|config| config.transcription.reset_filters())?;
+    tracing::info!("Filter settings reset to defaults");
+    Ok(config.with_masked_loki_auth())
+}
+
+/// Reset only the AI enhancement settings to defaults.
+///
+/// The API key is kept: it is a credential rather than a setting, and the
+/// dedicated `set_enhancement_api_key` command clears it.
+#[tauri::command]
+pub fn reset_enhancement_config() -> Result<Config, Error> {
+    let mut previous = EnhancementConfig::default();
+    let config = update_and_save(|config| {
+        previous = config.enhancement.clone();
+        config.enhancement = EnhancementConfig {
+            api_key: config.enhancement.api_key.take(),
+            ..EnhancementConfig::default()
+        };
+    })?;
+    apply_enhancement_backend(&config.enhancement);
+    warm_enhancement_model_if_changed(&previous, &config.enhancement);
+    tracing::info!("Enhancement settings reset to defaults");
+    Ok(config.with_masked_loki_auth())
+}
+
 /// Get the configuration file path
 ///
 /// Returns the path to the config file for debugging or user information.
@@ -1091,6 +1925,21 @@ mod tests {
     /// full lifetime.
     static CONFIG_TEST_LOCK: StdMutex<()> = StdMutex::new(());
 
+    #[test]
+    fn test_save_debouncer_coalesces() {
+        let debouncer = SaveDebouncer::new();
+        let first = debouncer.schedule();
+        let second = debouncer.schedule();
+        // Only the newest scheduled save writes, and it writes once.
+        assert!(!debouncer.is_latest(first));
+        assert!(debouncer.is_latest(second));
+        assert!(debouncer.take_pending());
+        assert!(!debouncer.take_pending());
+        // A failed write is retried by the next flush.
+        debouncer.mark_pending();
+        assert!(debouncer.take_pending());
+    }
+
     #[test]
     fn test_default_config_has_current_version() {
         let config = Config::default();
@@ -1105,6 +1954,10 @@ mod tests {
 
         assert_eq!(deserialised.version, config.version);
         assert_eq!(deserialised.audio.sample_rate, config.audio.sample_rate);
+        assert_eq!(
+            deserialised.audio.mix_device_ids,
+            config.audio.mix_device_ids
+        );
         assert_eq!(
             deserialised.transcription.language,
             config.transcription.language
@@ -1122,6 +1975,121 @@ mod tests {
         assert_eq!(audio.device_id, None);
         assert_eq!(audio.sample_rate, 16000);
         assert!(audio.play_sounds);
+        assert_eq!(audio.channel_mode, ChannelMode::Mix);
+        assert!(audio.mix_device_ids.is_empty());
+        assert!(!audio.trim_silence);
+        assert_eq!(audio.trim_margin_ms, 500);
+    }
+
+    #[test]
+    fn test_privacy_config_defaults_keep_audio() {
+        let config: Config = serde_json::from_str(r#"{"version": 1}"#).unwrap();
+        assert!(!config.privacy.delete_audio_after_transcription);
+        assert!(!config.privacy.redaction.enabled);
+        assert!(!config.privacy.log_transcription_text);
+    }
+
+    #[test]
+    fn test_language_model_id() {
+        let mut transcription = TranscriptionConfig {
+            language_model_map: BTreeMap::from([
+                ("ja".to_string(), "ggml-large-v3".to_string()),
+                ("en-GB".to_string(), "ggml-small.en".to_string()),
+                ("de".to_string(), " ".to_string()),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(transcription.language_model_id(), None);
+
+        transcription.language = "ja-JP".to_string();
+        assert_eq!(transcription.language_model_id(), Some("ggml-large-v3"));
+        transcription.language = "en-gb".to_string();
+        assert_eq!(transcription.language_model_id(), Some("ggml-small.en"));
+        transcription.language = "en".to_string();
+        assert_eq!(transcription.language_model_id(), Some("ggml-small.en"));
+        // A blank entry maps to nothing
+        transcription.language = "de".to_string();
+        assert_eq!(transcription.language_model_id(), None);
+    }
+
+    #[test]
+    fn test_context_carryover_defaults() {
+        let config: Config = serde_json::from_str(r#"{"version": 1}"#).unwrap();
+        assert!(!config.transcription.context_carryover);
+        assert_eq!(config.transcription.context_carryover_window_secs, 120);
+    }
+
+    #[test]
+    fn test_import_chunk_secs_default() {
+        let config: Config = serde_json::from_str(r#"{"version": 1}"#).unwrap();
+        assert_eq!(config.transcription.import_chunk_secs, 300);
+    }
+
+    #[test]
+    fn test_notify_on_silence_defaults_on() {
+        let config: Config = serde_json::from_str(r#"{"version": 1}"#).unwrap();
+        assert!(config.pipeline.notify_on_silence);
+        assert!(config.pipeline.save_to_history);
+        assert_eq!(config.pipeline.min_recording_ms, 300);
+    }
+
+    #[test]
+    fn test_realtime_insert_defaults_off() {
+        let config: Config = serde_json::from_str(r#"{"version": 1}"#).unwrap();
+        assert!(!config.output.realtime_insert);
+        assert!(config.tray.items.is_empty());
+        assert_eq!(config.output.trailing_space, TrailingSpace::Always);
+        assert!(!config.output.copy_last_plain);
+        assert!(!config.output.no_focus_fallback);
+    }
+
+    #[test]
+    fn test_close_behavior_default_per_platform() {
+        let config: Config = serde_json::from_str(r#"{"version": 1}"#).unwrap();
+        let expected = if cfg!(target_os = "macos") {
+            CloseBehavior::HideToTray
+        } else {
+            CloseBehavior::Minimize
+        };
+        assert_eq!(config.general.close_behavior, expected);
+
+        let json = serde_json::to_value(CloseBehavior::HideToTray).unwrap();
+        assert_eq!(json, "hide_to_tray");
+    }
+
+    #[test]
+    fn test_recordings_dir_resolution() {
+        let mut audio = AudioConfig::default();
+        assert_eq!(audio.resolved_recordings_dir(), default_recordings_dir());
+
+        audio.recordings_dir = Some("   ".to_string());
+        assert_eq!(audio.custom_recordings_dir(), None);
+        assert_eq!(audio.resolved_recordings_dir(), default_recordings_dir());
+
+        audio.recordings_dir = Some("/mnt/big/recordings".to_string());
+        assert_eq!(
+            audio.resolved_recordings_dir(),
+            PathBuf::from("/mnt/big/recordings")
+        );
+    }
+
+    #[test]
+    fn test_validate_recordings_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("new").join("recordings");
+        validate_recordings_dir(&nested).unwrap();
+        assert!(nested.is_dir(), "missing directory should be created");
+
+        let err = validate_recordings_dir(Path::new("relative/dir")).unwrap_err();
+        assert!(err.contains("absolute"));
+    }
+
+    #[test]
+    fn test_channel_mode_missing_defaults_to_mix() {
+        let audio: AudioConfig = serde_json::from_str(r#"{"device_id": null}"#).unwrap();
+        assert_eq!(audio.channel_mode, ChannelMode::Mix);
+        let audio: AudioConfig = serde_json::from_str(r#"{"channel_mode": "right"}"#).unwrap();
+        assert_eq!(audio.channel_mode, ChannelMode::Right);
     }
 
     #[test]
@@ -1133,6 +2101,52 @@ mod tests {
         assert!(!transcription.add_leading_space);
     }
 
+    #[test]
+    fn test_reset_filters_keeps_other_transcription_settings() {
+        let mut transcription = TranscriptionConfig {
+            model_id: Some("ggml-large-v3".to_string()),
+            language: "de".to_string(),
+            auto_paste: false,
+            remove_fillers: false,
+            australian_spelling: false,
+            spoken_numbers_to_digits: true,
+            sentence_case: true,
+            voice_formatting_commands: false,
+            collapse_repetitions: false,
+            mark_pauses: true,
+            context_carryover: true,
+            import_chunk_secs: 60,
+            ..Default::default()
+        };
+        transcription.reset_filters();
+
+        let defaults = TranscriptionConfig::default();
+        assert_eq!(transcription.remove_fillers, defaults.remove_fillers);
+        assert_eq!(
+            transcription.australian_spelling,
+            defaults.australian_spelling
+        );
+        assert_eq!(
+            transcription.spoken_numbers_to_digits,
+            defaults.spoken_numbers_to_digits
+        );
+        assert_eq!(transcription.sentence_case, defaults.sentence_case);
+        assert_eq!(
+            transcription.voice_formatting_commands,
+            defaults.voice_formatting_commands
+        );
+        assert_eq!(
+            transcription.collapse_repetitions,
+            defaults.collapse_repetitions
+        );
+        assert_eq!(transcription.mark_pauses, defaults.mark_pauses);
+        assert_eq!(transcription.model_id.as_deref(), Some("ggml-large-v3"));
+        assert_eq!(transcription.language, "de");
+        assert!(!transcription.auto_paste);
+        assert!(transcription.context_carryover);
+        assert_eq!(transcription.import_chunk_secs, 60);
+    }
+
     #[test]
     fn test_shortcut_config_defaults() {
         let shortcuts = ShortcutConfig::default();
@@ -1143,7 +2157,25 @@ mod tests {
         );
         assert_eq!(shortcuts.copy_last, Some("F14".to_string()));
         assert_eq!(shortcuts.toggle_enhancement, None);
+        assert_eq!(shortcuts.dictate_without_saving, None);
+        assert_eq!(shortcuts.dictate_by_typing, None);
+        assert_eq!(shortcuts.dictate_by_pasting, None);
+        assert_eq!(shortcuts.dictate_verbatim, None);
         assert_eq!(shortcuts.recording_mode, RecordingMode::Toggle);
+        assert_eq!(shortcuts.hold_threshold_ms, 500);
+    }
+
+    #[test]
+    fn test_hold_threshold_clamped_to_range() {
+        let mut shortcuts = ShortcutConfig {
+            hold_threshold_ms: 10,
+            ..Default::default()
+        };
+        assert_eq!(shortcuts.effective_hold_threshold_ms(), 150);
+        shortcuts.hold_threshold_ms = 60_000;
+        assert_eq!(shortcuts.effective_hold_threshold_ms(), 2000);
+        shortcuts.hold_threshold_ms = 600;
+        assert_eq!(shortcuts.effective_hold_threshold_ms(), 600);
     }
 
     #[test]
@@ -1192,6 +2224,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_indicator_style_serialisation() {
+        let styles = vec![
+            (IndicatorStyle::CursorDot, "\"cursor-dot\""),
+            (IndicatorStyle::FixedFloat, "\"fixed-float\""),
+            (IndicatorStyle::Pill, "\"pill\""),
+            (IndicatorStyle::FollowFocus, "\"follow-focus\""),
+        ];
+
+        for (style, expected_json) in styles {
+            let json = serde_json::to_string(&style).unwrap();
+            assert_eq!(json, expected_json);
+
+            let parsed: IndicatorStyle = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, style);
+        }
+    }
+
     #[test]
     fn test_partial_config_deserialisation() {
         // Config should use defaults for missing fields
@@ -1235,6 +2285,7 @@ mod tests {
         );
     }
 
+    #[serial_test::serial]
     #[test]
     fn test_config_path_format() {
         let path = get_config_path();
@@ -1255,10 +2306,19 @@ mod tests {
                 sample_rate: 44100,
                 play_sounds: false,
                 warm_stream: true,
+                channel_mode: ChannelMode::Right,
+                recordings_dir: Some("/Volumes/External/Thoth".to_string()),
+                mix_device_ids: vec!["usb-mic-1".to_string(), "usb-mic-2".to_string()],
+                trim_silence: true,
+                trim_margin_ms: 300,
             },
             transcription: TranscriptionConfig {
                 model_id: Some("test-model".to_string()),
                 language: "de".to_string(),
+                language_model_map: BTreeMap::from([(
+                    "ja".to_string(),
+                    "ggml-large-v3".to_string(),
+                )]),
                 auto_copy: false,
                 auto_paste: true,
                 add_leading_space: true,
@@ -1269,22 +2329,35 @@ mod tests {
                 cleanup_punctuation: true,
                 sentence_case: false,
                 voice_formatting_commands: true,
+                collapse_repetitions: false,
+                mark_pauses: true,
+                context_carryover: true,
+                context_carryover_window_secs: 300,
+                import_chunk_secs: 600,
             },
             shortcuts: ShortcutConfig {
                 toggle_recording: "F12".to_string(),
                 toggle_recording_alt: None,
                 copy_last: None,
                 toggle_enhancement: None,
+                dictate_without_saving: Some("F15".to_string()),
+                dictate_by_typing: Some("F16".to_string()),
+                dictate_by_pasting: None,
+                dictate_verbatim: Some("F17".to_string()),
                 recording_mode: RecordingMode::Toggle,
+                hold_threshold_ms: 650,
             },
             enhancement: EnhancementConfig {
                 enabled: true,
                 model: "mistral".to_string(),
                 prompt_id: "custom".to_string(),
+                prompt_chain: vec!["fix-grammar".to_string(), "bullet-points".to_string()],
                 ollama_url: "http://custom:8080".to_string(),
                 backend: "openai_compat".to_string(),
                 openai_compat_url: "http://localhost:1234".to_string(),
                 api_key: Some("sk-test".to_string()),
+                warmup_model: true,
+                min_words: 5,
             },
             general: GeneralConfig {
                 launch_at_login: true,
@@ -1295,6 +2368,13 @@ mod tests {
                 indicator_style: IndicatorStyle::CursorDot,
                 window_decorations: true,
                 last_run_version: None,
+                main_window: Some(WindowGeometry {
+                    x: -1200,
+                    y: 40,
+                    width: 1100,
+                    height: 900,
+                }),
+                close_behavior: CloseBehavior::Quit,
             },
             recorder: RecorderConfig {
                 position: RecorderPosition::Centre,
@@ -1304,6 +2384,38 @@ mod tests {
             },
             integrations: IntegrationsConfig::default(),
             logging: LoggingConfig::default(),
+            privacy: PrivacyConfig {
+                delete_audio_after_transcription: true,
+                redaction: RedactionConfig {
+                    enabled: true,
+                    redact_pasted_text: true,
+                    custom_patterns: vec![r"ACME-\d+".to_string()],
+                },
+                log_transcription_text: true,
+            },
+            output: OutputConfig {
+                realtime_insert: true,
+                trailing_space: TrailingSpace::Smart,
+                copy_last_plain: true,
+                no_focus_fallback: true,
+            },
+            pipeline: PipelineSettings {
+                notify_on_silence: false,
+                save_to_history: false,
+                min_recording_ms: 0,
+            },
+            tray: TrayConfig {
+                items: vec![
+                    TrayItemConfig {
+                        id: "copy_last".to_string(),
+                        visible: true,
+                    },
+                    TrayItemConfig {
+                        id: "transcribe".to_string(),
+                        visible: false,
+                    },
+                ],
+            },
         };
 
         let json = serde_json::to_string_pretty(&config).unwrap();
@@ -1311,8 +2423,48 @@ mod tests {
 
         // Verify all fields were preserved
         assert_eq!(restored.audio.device_id, Some("test-device".to_string()));
+        assert!(restored.privacy.delete_audio_after_transcription);
+        assert!(restored.privacy.redaction.enabled);
+        assert!(restored.privacy.log_transcription_text);
+        assert!(restored.output.realtime_insert);
+        assert_eq!(restored.output.trailing_space, TrailingSpace::Smart);
+        assert!(restored.output.copy_last_plain);
+        assert!(restored.output.no_focus_fallback);
+        assert!(!restored.pipeline.notify_on_silence);
+        assert!(!restored.pipeline.save_to_history);
+        assert_eq!(restored.pipeline.min_recording_ms, 0);
+        assert_eq!(restored.tray.items.len(), 2);
+        assert!(!restored.tray.items[1].visible);
+        assert_eq!(
+            restored.shortcuts.dictate_without_saving,
+            Some("F15".to_string())
+        );
+        assert_eq!(
+            restored.shortcuts.dictate_by_typing,
+            Some("F16".to_string())
+        );
+        assert_eq!(restored.shortcuts.dictate_verbatim, Some("F17".to_string()));
+        assert!(restored.transcription.mark_pauses);
+        assert_eq!(
+            restored
+                .transcription
+                .language_model_map
+                .get("ja")
+                .map(String::as_str),
+            Some("ggml-large-v3")
+        );
+        assert!(restored.transcription.context_carryover);
+        assert_eq!(restored.transcription.context_carryover_window_secs, 300);
+        assert_eq!(restored.transcription.import_chunk_secs, 600);
+        assert!(restored.privacy.redaction.redact_pasted_text);
+        assert_eq!(
+            restored.privacy.redaction.custom_patterns,
+            vec![r"ACME-\d+"]
+        );
         assert_eq!(restored.audio.sample_rate, 44100);
         assert!(!restored.audio.play_sounds);
+        assert!(restored.audio.trim_silence);
+        assert_eq!(restored.audio.trim_margin_ms, 300);
 
         assert_eq!(restored.transcription.language, "de");
         assert!(!restored.transcription.auto_copy);
@@ -1324,11 +2476,17 @@ mod tests {
 
         assert!(restored.enhancement.enabled);
         assert_eq!(restored.enhancement.model, "mistral");
+        assert_eq!(restored.enhancement.min_words, 5);
 
         assert!(restored.general.launch_at_login);
         assert!(!restored.general.show_in_menu_bar);
 
         assert_eq!(restored.recorder.position, RecorderPosition::Centre);
+        assert_eq!(
+            restored.general.main_window.map(|w| (w.x, w.width)),
+            Some((-1200, 1100))
+        );
+        assert_eq!(restored.general.close_behavior, CloseBehavior::Quit);
     }
 
     #[test]
@@ -1364,6 +2522,11 @@ mod tests {
             sample_rate: 48000,
             play_sounds: false,
             warm_stream: false,
+            channel_mode: ChannelMode::Left,
+            recordings_dir: None,
+            mix_device_ids: Vec::new(),
+            trim_silence: false,
+            trim_margin_ms: 500,
         };
 
         assert_eq!(audio.device_id, Some("custom-mic".to_string()));
@@ -1385,6 +2548,44 @@ mod tests {
         assert_eq!(enhancement.ollama_url, "http://192.168.1.100:11434");
     }
 
+    #[test]
+    fn test_enhancement_warmup_target() {
+        let mut enh = EnhancementConfig {
+            enabled: true,
+            warmup_model: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            enh.warmup_target(),
+            Some(("http://localhost:11434", "llama3.2"))
+        );
+
+        // Off by default, and only for an enabled Ollama backend.
+        assert_eq!(EnhancementConfig::default().warmup_target(), None);
+        enh.backend = "openai_compat".to_string();
+        assert_eq!(enh.warmup_target(), None);
+        enh.backend = "ollama".to_string();
+        enh.enabled = false;
+        assert_eq!(enh.warmup_target(), None);
+    }
+
+    #[test]
+    fn test_enhancement_active_ollama_model() {
+        let mut enh = EnhancementConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        assert_eq!(enh.active_ollama_model(), Some("llama3.2"));
+
+        // Checked whether or not warmup is on, but only for enabled Ollama
+        enh.backend = "openai_compat".to_string();
+        assert_eq!(enh.active_ollama_model(), None);
+        enh.backend = "ollama".to_string();
+        enh.model = " ".to_string();
+        assert_eq!(enh.active_ollama_model(), None);
+        assert_eq!(EnhancementConfig::default().active_ollama_model(), None);
+    }
+
     // =========================================================================
     // OpenAI-compat provider field tests
     // =========================================================================
@@ -1395,10 +2596,13 @@ mod tests {
             enabled: true,
             model: "mistral".to_string(),
             prompt_id: "fix-grammar".to_string(),
+            prompt_chain: Vec::new(),
             ollama_url: "http://localhost:11434".to_string(),
             backend: "openai_compat".to_string(),
             openai_compat_url: "http://localhost:1234".to_string(),
             api_key: Some("test-key".to_string()),
+            warmup_model: false,
+            min_words: 3,
         };
 
         let json = serde_json::to_string(&enh).unwrap();
@@ -1449,6 +2653,7 @@ mod tests {
             "http://localhost:1234"
         );
         assert_eq!(config.enhancement.api_key, None);
+        assert_eq!(config.enhancement.min_words, 3);
         // Old field preserved
         assert_eq!(config.enhancement.ollama_url, "http://localhost:11434");
     }
@@ -1550,6 +2755,7 @@ mod tests {
         assert!(cfg.loki_tenant.is_none());
         assert!(cfg.loki_labels.is_empty());
         assert_eq!(cfg.telemetry_level, "info");
+        assert!(cfg.file_logging);
     }
 
     #[test]
@@ -1562,6 +2768,8 @@ mod tests {
             loki_tenant: Some("org1".to_string()),
             loki_labels: vec![["env".to_string(), "prod".to_string()]],
             telemetry_level: "debug".to_string(),
+            log_level: "debug".to_string(),
+            file_logging: false,
         };
 
         let json = serde_json::to_string(&cfg).unwrap();
@@ -1576,6 +2784,7 @@ mod tests {
         assert_eq!(restored.loki_labels.len(), 1);
         assert_eq!(restored.loki_labels[0], ["env", "prod"]);
         assert_eq!(restored.telemetry_level, "debug");
+        assert!(!restored.file_logging);
     }
 
     #[test]
@@ -1624,6 +2833,7 @@ mod tests {
         let config: Config = serde_json::from_str(json).unwrap();
         assert_eq!(config.logging.local_retention_days, 7);
         assert!(!config.logging.remote_enabled);
+        assert!(config.logging.file_logging);
     }
 
     #[test]
@@ -1915,6 +3125,8 @@ mod tests {
                 loki_tenant: Some("testorg".to_string()),
                 loki_labels: vec![["env".to_string(), "test".to_string()]],
                 telemetry_level: "debug".to_string(),
+                log_level: "info".to_string(),
+                file_logging: true,
             },
             ..Config::default()
         };
@@ -1944,6 +3156,14 @@ mod tests {
     // canonicalise_patch_keys / merge_json tests (Bug 1 + Bug 2)
     // =========================================================================
 
+    #[test]
+    fn test_dictation_over_api_is_opt_in() {
+        assert!(!IntegrationsConfig::default().dictation_enabled);
+        let parsed: IntegrationsConfig =
+            serde_json::from_str(r#"{"api_enabled": true, "mcp_enabled": true}"#).unwrap();
+        assert!(!parsed.dictation_enabled);
+    }
+
     #[test]
     fn test_camel_to_snake_identity_on_snake_case() {
         // Already-snake_case keys must pass through unchanged (idempotent).
@@ -1977,6 +3197,7 @@ mod tests {
             ("apiEnabled", "api_enabled"),
             ("apiPort", "api_port"),
             ("mcpEnabled", "mcp_enabled"),
+            ("dictationEnabled", "dictation_enabled"),
         ];
         for (camel, snake) in pairs {
             assert_eq!(
@@ -86,6 +86,8 @@ enum AppError {
     Internal(String),
     NotFound(String),
     BadRequest(String),
+    Forbidden(String),
+    Conflict(String),
 }
 
 impl IntoResponse for AppError {
@@ -103,12 +105,20 @@ impl IntoResponse for AppError {
                 let body = serde_json::json!({ "error": msg });
                 (StatusCode::BAD_REQUEST, Json(body)).into_response()
             }
+            AppError::Forbidden(msg) => {
+                let body = serde_json::json!({ "error": msg });
+                (StatusCode::FORBIDDEN, Json(body)).into_response()
+            }
+            AppError::Conflict(msg) => {
+                let body = serde_json::json!({ "error": msg });
+                (StatusCode::CONFLICT, Json(body)).into_response()
+            }
         }
     }
 }
 
 // AppError::Internal is the catch-all for `?` on Result<_, String> and similar.
-// NotFound / BadRequest must be constructed explicitly.
+// The other variants must be constructed explicitly.
 impl From<String> for AppError {
     fn from(e: String) -> Self {
         AppError::Internal(e)
@@ -213,6 +223,10 @@ async fn handle_get_settings() -> Result<impl IntoResponse, AppError> {
 /// the serialised base carries the mask sentinel (`"***"`), which `set_config`'s
 /// preservation guard restores to the stored real token. To clear the token
 /// explicitly, use the dedicated `set_loki_auth` Tauri command.
+///
+/// The `integrations` section cannot be changed here at all: it decides what
+/// API clients may do (including `POST /dictate` turning on the microphone),
+/// so only the settings UI may change it.
 async fn handle_patch_settings(
     Json(patch): Json<serde_json::Value>,
 ) -> Result<impl IntoResponse, AppError> {
@@ -222,6 +236,7 @@ async fn handle_patch_settings(
         ));
     }
     let patch = crate::config::canonicalise_patch_keys(patch);
+    check_patch_allowed(&patch)?;
     // `get_config()` returns the config with loki_auth replaced by the mask
     // sentinel "***". Merging onto this masked base is safe: if the patch does
     // not include loki_auth, the sentinel survives into the merged Value and
@@ -236,6 +251,17 @@ async fn handle_patch_settings(
     Ok(StatusCode::OK)
 }
 
+/// Reject a settings patch that touches the `integrations` section. Expects
+/// keys already canonicalised to snake_case.
+fn check_patch_allowed(patch: &serde_json::Value) -> Result<(), AppError> {
+    if patch.get("integrations").is_some() {
+        return Err(AppError::Forbidden(
+            "integrations settings can only be changed in the Thoth settings window".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 async fn handle_get_dictionary() -> Result<impl IntoResponse, AppError> {
     let entries = crate::dictionary::get_dictionary_entries()?;
     Ok(Json(entries))
@@ -248,6 +274,14 @@ struct AddEntryPayload {
     from: String,
     to: String,
     case_sensitive: bool,
+    #[serde(default)]
+    scope: Option<crate::dictionary::DictionaryScope>,
+    #[serde(default)]
+    priority: i32,
+    #[serde(default)]
+    fuzzy: bool,
+    #[serde(default)]
+    fuzzy_threshold: Option<f64>,
 }
 
 async fn handle_add_dictionary(
@@ -257,6 +291,10 @@ async fn handle_add_dictionary(
         from: payload.from,
         to: payload.to,
         case_sensitive: payload.case_sensitive,
+        scope: payload.scope,
+        priority: payload.priority,
+        fuzzy: payload.fuzzy,
+        fuzzy_threshold: payload.fuzzy_threshold,
     };
     crate::dictionary::add_dictionary_entry(entry)?;
     Ok(StatusCode::CREATED)
@@ -284,6 +322,10 @@ async fn handle_update_dictionary(
         from: payload.from,
         to: payload.to,
         case_sensitive: payload.case_sensitive,
+        scope: payload.scope,
+        priority: payload.priority,
+        fuzzy: payload.fuzzy,
+        fuzzy_threshold: payload.fuzzy_threshold,
     };
     crate::dictionary::update_dictionary_entry(index, entry)?;
     Ok(StatusCode::OK)
@@ -299,6 +341,7 @@ async fn handle_delete_dictionary(Path(index): Path<usize>) -> Result<impl IntoR
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct ImportPayload {
+    /// File content: JSON, CSV or TSV (detected)
     json: String,
     merge: bool,
 }
@@ -306,12 +349,12 @@ struct ImportPayload {
 async fn handle_import_dictionary(
     Json(payload): Json<ImportPayload>,
 ) -> Result<impl IntoResponse, AppError> {
-    let count = crate::dictionary::import_dictionary(payload.json, payload.merge)?;
-    Ok(Json(serde_json::json!({ "imported": count })))
+    let summary = crate::dictionary::import_dictionary_content(&payload.json, payload.merge)?;
+    Ok(Json(summary))
 }
 
 async fn handle_export_dictionary() -> Result<impl IntoResponse, AppError> {
-    let body = crate::dictionary::export_dictionary()?;
+    let body = crate::dictionary::export_dictionary(None)?;
     let value: serde_json::Value = serde_json::from_str(&body)?;
     Ok(Json(value))
 }
@@ -418,6 +461,50 @@ async fn handle_get_transcribe_job(Path(id): Path<String>) -> Result<impl IntoRe
     }
 }
 
+// ---------------------------------------------------------------------------
+// Dictation
+// ---------------------------------------------------------------------------
+
+/// Refuse dictation unless the user has opted in
+/// (`config.integrations.dictation_enabled`).
+fn require_dictation_enabled() -> Result<tauri::AppHandle, AppError> {
+    let cfg = crate::config::get_config()?;
+    if !cfg.integrations.dictation_enabled {
+        return Err(AppError::Forbidden(
+            "dictation over the API is off; turn it on in Settings › Integrations".to_string(),
+        ));
+    }
+    crate::app_handle::get().ok_or_else(|| "Thoth app handle is not available yet".into())
+}
+
+/// Start a recording and answer with its result once it stops.
+///
+/// The request stays open while the user speaks; `POST /dictate/stop`, the
+/// hotkey or the tray ends the recording. The text comes back in the response
+/// instead of being inserted or copied, and nothing is typed while recording.
+/// If the client disconnects first, the recording is handled as usual.
+async fn handle_post_dictate() -> Result<impl IntoResponse, AppError> {
+    let app = require_dictation_enabled()?;
+    crate::recording_indicator::maybe_play_start_indicator(&app);
+    let path = crate::pipeline::pipeline_start_recording_for_result(app)
+        .map_err(|e| AppError::Conflict(e.to_string()))?;
+    let result = crate::pipeline::wait_for_recording_result(&path)
+        .await
+        .map_err(|_| AppError::Internal("recording ended without a result".to_string()))??;
+    Ok(Json(result))
+}
+
+/// Stop the recording a `POST /dictate` is waiting on.
+async fn handle_post_dictate_stop() -> Result<impl IntoResponse, AppError> {
+    let app = require_dictation_enabled()?;
+    if !crate::audio::is_recording() {
+        return Err(AppError::Conflict("no recording in progress".to_string()));
+    }
+    let cfg = crate::pipeline::effective_pipeline_config()?;
+    crate::pipeline::pipeline_stop_and_process(app, Some(cfg)).await?;
+    Ok(Json(serde_json::json!({ "status": "stopped" })))
+}
+
 // ---------------------------------------------------------------------------
 // Bearer-token auth layer
 // ---------------------------------------------------------------------------
@@ -554,6 +641,8 @@ fn build_router(token: String, app_version: String, mcp_enabled: bool) -> Router
         .route("/transcriptions/{id}", get(handle_get_transcription))
         .route("/transcribe", post(handle_post_transcribe))
         .route("/transcribe/{id}", get(handle_get_transcribe_job))
+        .route("/dictate", post(handle_post_dictate))
+        .route("/dictate/stop", post(handle_post_dictate_stop))
         .layer(auth)
         .layer(host_validation_layer())
         .with_state(state);
@@ -642,6 +731,7 @@ pub struct IntegrationsStatus {
     pub api_running: bool,
     pub api_port: u16,
     pub mcp_enabled: bool,
+    pub dictation_enabled: bool,
     pub has_token: bool,
 }
 
@@ -655,6 +745,7 @@ pub async fn get_integrations_status() -> Result<IntegrationsStatus, Error> {
         api_running: running,
         api_port: cfg.integrations.api_port,
         mcp_enabled: cfg.integrations.mcp_enabled,
+        dictation_enabled: cfg.integrations.dictation_enabled,
         has_token: token_store::read_token().is_some(),
     })
 }
@@ -709,6 +800,17 @@ pub async fn set_mcp_enabled(enabled: bool) -> Result<(), Error> {
     Ok(())
 }
 
+/// Allow or refuse dictation over the Control API (`POST /dictate`).
+///
+/// Checked on every request, so no server restart is needed.
+#[tauri::command]
+pub async fn set_dictation_enabled(enabled: bool) -> Result<(), Error> {
+    let mut cfg = crate::config::get_config()?;
+    cfg.integrations.dictation_enabled = enabled;
+    crate::config::set_config(cfg)?;
+    Ok(())
+}
+
 /// Return the current API token for display/copy in the settings panel.
 #[tauri::command]
 pub async fn get_api_token() -> Result<Option<String>, Error> {
@@ -766,6 +868,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn settings_patch_cannot_touch_integrations() {
+        let patch = crate::config::canonicalise_patch_keys(serde_json::json!({
+            "integrations": { "dictationEnabled": true }
+        }));
+        assert!(matches!(
+            check_patch_allowed(&patch),
+            Err(AppError::Forbidden(_))
+        ));
+
+        let patch = crate::config::canonicalise_patch_keys(serde_json::json!({
+            "transcription": { "autoPaste": false }
+        }));
+        assert!(check_patch_allowed(&patch).is_ok());
+    }
+
     #[test]
     fn tokens_are_unique() {
         let a = generate_token();
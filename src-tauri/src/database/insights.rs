@@ -663,7 +663,7 @@ fn query_time_of_day(conn: &Connection, lower: Option<&str>) -> Result<Vec<u32>,
 fn compute_storage() -> Result<StorageBreakdown, DatabaseError> {
     let base = super::get_thoth_directory()?;
 
-    let recordings_dir = base.join("Recordings");
+    let recordings_dir = crate::audio::recordings_dir();
     let models_dir = base.join("models");
     let db_path = base.join("thoth.db");
 
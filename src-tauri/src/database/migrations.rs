@@ -7,9 +7,12 @@ use rusqlite::Connection;
 
 use crate::database::DatabaseError;
 use crate::database::schema::{
-    ALTER_ADD_ENHANCEMENT_DURATION, ALTER_ADD_ENHANCEMENT_MODEL_NAME,
-    ALTER_ADD_TRANSCRIPTION_DURATION, ALTER_ADD_TRANSCRIPTION_MODEL_NAME, CREATE_MIGRATIONS_TABLE,
-    CREATE_TRANSCRIPTIONS_CREATED_AT_INDEX, CREATE_TRANSCRIPTIONS_IS_ENHANCED_INDEX,
+    ALTER_ADD_DETECTED_LANGUAGE, ALTER_ADD_ENHANCEMENT_DURATION, ALTER_ADD_ENHANCEMENT_MODEL_NAME,
+    ALTER_ADD_SESSION_ID, ALTER_ADD_TIMING_BREAKDOWN, ALTER_ADD_TRANSCRIPTION_DURATION,
+    ALTER_ADD_TRANSCRIPTION_MODEL_NAME, ALTER_ADD_TRASH_DETECTED_LANGUAGE,
+    ALTER_ADD_TRASH_SESSION_ID, ALTER_ADD_TRASH_TIMING_BREAKDOWN, CREATE_MIGRATIONS_TABLE,
+    CREATE_SESSIONS_TABLE, CREATE_TRANSCRIPTIONS_CREATED_AT_INDEX,
+    CREATE_TRANSCRIPTIONS_IS_ENHANCED_INDEX, CREATE_TRANSCRIPTIONS_SESSION_ID_INDEX,
     CREATE_TRANSCRIPTIONS_TABLE, CREATE_TRASH_TABLE,
 };
 
@@ -46,6 +49,29 @@ const MIGRATIONS: &[Migration] = &[
         name: "create_trash_table",
         statements: &[CREATE_TRASH_TABLE],
     },
+    Migration {
+        version: 4,
+        name: "add_timing_breakdown",
+        statements: &[ALTER_ADD_TIMING_BREAKDOWN, ALTER_ADD_TRASH_TIMING_BREAKDOWN],
+    },
+    Migration {
+        version: 5,
+        name: "create_sessions",
+        statements: &[
+            CREATE_SESSIONS_TABLE,
+            ALTER_ADD_SESSION_ID,
+            CREATE_TRANSCRIPTIONS_SESSION_ID_INDEX,
+            ALTER_ADD_TRASH_SESSION_ID,
+        ],
+    },
+    Migration {
+        version: 6,
+        name: "add_detected_language",
+        statements: &[
+            ALTER_ADD_DETECTED_LANGUAGE,
+            ALTER_ADD_TRASH_DETECTED_LANGUAGE,
+        ],
+    },
 ];
 
 /// Returns the current schema version from the database.
@@ -160,7 +186,7 @@ mod tests {
         let version: i32 = conn
             .query_row("SELECT MAX(version) FROM migrations", [], |row| row.get(0))
             .unwrap();
-        assert_eq!(version, 3);
+        assert_eq!(version, 6);
     }
 
     #[test]
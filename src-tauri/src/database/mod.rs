@@ -6,6 +6,7 @@
 pub mod insights;
 pub mod migrations;
 pub mod schema;
+pub mod session;
 pub mod transcription;
 pub mod trash;
 
@@ -33,6 +34,12 @@ pub enum DatabaseError {
 
     #[error("Migration failed: {0}")]
     Migration(String),
+
+    #[error("No data directory: set THOTH_DATA_DIR or run with a home directory")]
+    NoDataDirectory,
+
+    #[error("Thoth's data is being moved; the database opens after the restart")]
+    DataDirMoving,
 }
 
 impl From<std::io::Error> for DatabaseError {
@@ -43,24 +50,16 @@ impl From<std::io::Error> for DatabaseError {
 
 /// Returns the path to the Thoth database directory (~/.thoth).
 ///
-/// `THOTH_DATA_DIR` overrides the location when set to a non-empty path. This
-/// lets tests point the database at a throwaway directory, and lets users
-/// relocate their data, without touching `~/.thoth`.
+/// Resolved by [`crate::storage::data_dir`], so `THOTH_DATA_DIR` and a
+/// relocated data directory are honoured. This lets tests point the
+/// database at a throwaway directory without touching `~/.thoth`.
 fn get_thoth_directory() -> Result<PathBuf, DatabaseError> {
-    if let Ok(dir) = std::env::var("THOTH_DATA_DIR") {
-        if !dir.is_empty() {
-            return Ok(PathBuf::from(dir));
-        }
-    }
-
-    let home = dirs::home_dir().ok_or_else(|| {
-        DatabaseError::DirectoryCreation(std::io::Error::new(
-            std::io::ErrorKind::NotFound,
-            "Could not find home directory",
-        ))
-    })?;
+    require_data_dir(crate::storage::data_dir())
+}
 
-    Ok(home.join(".thoth"))
+/// Turns an unresolved data directory into [`DatabaseError::NoDataDirectory`].
+fn require_data_dir(dir: Option<PathBuf>) -> Result<PathBuf, DatabaseError> {
+    dir.ok_or(DatabaseError::NoDataDirectory)
 }
 
 /// Returns the path to the database file (~/.thoth/thoth.db).
@@ -86,12 +85,19 @@ fn ensure_database_directory() -> Result<PathBuf, DatabaseError> {
 /// Each call creates a new connection. For thread safety in Tauri commands,
 /// create a new connection per command invocation.
 pub fn open_connection() -> Result<Connection, DatabaseError> {
-    // DATABASE_PATH is normally set by initialise_database() at startup.
-    // The expect here is a safeguard for direct open_connection() calls.
-    let db_path = DATABASE_PATH.get_or_init(|| {
-        ensure_database_directory()
-            .expect("database directory must be writable; called before initialise_database()?")
-    });
+    // DATABASE_PATH is normally set by initialise_database() at startup;
+    // direct open_connection() calls resolve it here, failing rather than
+    // panicking when there is no data directory.
+    if crate::storage::is_moving_data_dir() {
+        return Err(DatabaseError::DataDirMoving);
+    }
+    let db_path = match DATABASE_PATH.get() {
+        Some(path) => path,
+        None => {
+            let path = ensure_database_directory()?;
+            DATABASE_PATH.get_or_init(|| path)
+        }
+    };
 
     let conn = Connection::open(db_path)?;
 
@@ -173,6 +179,9 @@ pub use transcription::{
     save_transcription, search_transcriptions_text,
 };
 
+// Re-export session Tauri commands
+pub use session::{end_session, get_current_session, list_sessions, rename_session, start_session};
+
 // Re-export trash Tauri commands
 pub use trash::{list_trash, purge_trash, quarantine_recordings, restore_recordings};
 
@@ -180,10 +189,23 @@ pub use trash::{list_trash, purge_trash, quarantine_recordings, restore_recordin
 mod tests {
     use super::*;
 
+    #[serial_test::serial]
     #[test]
     fn test_database_path_format() {
         let path = get_database_path().unwrap();
         assert!(path.to_string_lossy().contains(".thoth"));
         assert!(path.to_string_lossy().ends_with("thoth.db"));
     }
+
+    #[test]
+    fn test_missing_data_dir_is_an_error() {
+        assert!(matches!(
+            require_data_dir(None),
+            Err(DatabaseError::NoDataDirectory)
+        ));
+        assert_eq!(
+            require_data_dir(Some(PathBuf::from("/srv/thoth"))).unwrap(),
+            PathBuf::from("/srv/thoth")
+        );
+    }
 }
@@ -48,6 +48,49 @@ pub const ALTER_ADD_ENHANCEMENT_MODEL_NAME: &str =
 pub const ALTER_ADD_ENHANCEMENT_DURATION: &str =
     "ALTER TABLE transcriptions ADD COLUMN enhancement_duration_seconds REAL;";
 
+/// SQL statements to add the per-stage timing column (v4 migration).
+///
+/// Holds compact JSON such as `{"decode":0.12,"wait":0.0,"filter":0.003}`;
+/// see `pipeline::TimingBreakdown`.
+pub const ALTER_ADD_TIMING_BREAKDOWN: &str =
+    "ALTER TABLE transcriptions ADD COLUMN timing_breakdown TEXT;";
+
+pub const ALTER_ADD_TRASH_TIMING_BREAKDOWN: &str =
+    "ALTER TABLE trash ADD COLUMN timing_breakdown TEXT;";
+
+/// SQL statement to create the sessions table (v5 migration).
+///
+/// A session groups related dictations (e.g. "Chapter 3"). At most one
+/// session is open (`ended_at IS NULL`) at a time; new transcriptions are
+/// stamped with it.
+pub const CREATE_SESSIONS_TABLE: &str = r#"
+CREATE TABLE IF NOT EXISTS sessions (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    started_at TEXT NOT NULL,
+    ended_at TEXT
+);
+"#;
+
+/// SQL statements to link transcriptions to sessions (v5 migration).
+pub const ALTER_ADD_SESSION_ID: &str = "ALTER TABLE transcriptions ADD COLUMN session_id TEXT REFERENCES sessions(id) ON DELETE SET NULL;";
+
+pub const CREATE_TRANSCRIPTIONS_SESSION_ID_INDEX: &str = r#"
+CREATE INDEX IF NOT EXISTS idx_transcriptions_session_id ON transcriptions(session_id);
+"#;
+
+pub const ALTER_ADD_TRASH_SESSION_ID: &str = "ALTER TABLE trash ADD COLUMN session_id TEXT;";
+
+/// SQL statements to add the language column (v6 migration).
+///
+/// Holds an ISO 639-1 code such as `en`; `NULL` for records made before it
+/// was tracked.
+pub const ALTER_ADD_DETECTED_LANGUAGE: &str =
+    "ALTER TABLE transcriptions ADD COLUMN detected_language TEXT;";
+
+pub const ALTER_ADD_TRASH_DETECTED_LANGUAGE: &str =
+    "ALTER TABLE trash ADD COLUMN detected_language TEXT;";
+
 /// SQL statement to create the trash table (v3 migration).
 ///
 /// Snapshots the full `transcriptions` row plus quarantine metadata so
@@ -0,0 +1,303 @@
+//! Named sessions grouping related dictations.
+//!
+//! Starting a session (e.g. "Chapter 3") ends any open one; until it is
+//! ended, every transcription the pipeline saves is stamped with its ID so
+//! the session can be searched and exported on its own. Ending a session
+//! returns to the default of no session. The open session is simply the one
+//! without an `ended_at`, so it survives a restart.
+
+use chrono::Utc;
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::database::{DatabaseError, open_connection};
+use crate::error::Error;
+
+/// A session record stored in the database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+    /// Unique identifier (UUID).
+    pub id: String,
+    /// Display name, e.g. "Chapter 3".
+    pub name: String,
+    /// When the session was started (ISO 8601).
+    pub started_at: String,
+    /// When the session was ended (ISO 8601); `None` while it is open.
+    pub ended_at: Option<String>,
+}
+
+/// Column list for all SELECT queries.
+const SELECT_COLUMNS: &str = "id, name, started_at, ended_at";
+
+/// Map a database row to a Session struct.
+fn row_to_session(row: &rusqlite::Row) -> rusqlite::Result<Session> {
+    Ok(Session {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        started_at: row.get(2)?,
+        ended_at: row.get(3)?,
+    })
+}
+
+/// Name given to a session started without one.
+fn default_name() -> String {
+    format!("Session {}", chrono::Local::now().format("%Y-%m-%d %H:%M"))
+}
+
+/// The open session, if any.
+pub(crate) fn current_session_with_conn(
+    conn: &Connection,
+) -> Result<Option<Session>, DatabaseError> {
+    let session = conn
+        .query_row(
+            &format!(
+                "SELECT {} FROM sessions WHERE ended_at IS NULL ORDER BY started_at DESC LIMIT 1",
+                SELECT_COLUMNS
+            ),
+            [],
+            row_to_session,
+        )
+        .optional()?;
+    Ok(session)
+}
+
+/// The session with this ID, if any.
+pub(crate) fn get_session_with_conn(
+    conn: &Connection,
+    id: &str,
+) -> Result<Option<Session>, DatabaseError> {
+    let session = conn
+        .query_row(
+            &format!("SELECT {} FROM sessions WHERE id = ?1", SELECT_COLUMNS),
+            params![id],
+            row_to_session,
+        )
+        .optional()?;
+    Ok(session)
+}
+
+/// Start a new session, ending any open one.
+pub(crate) fn start_session_with_conn(
+    conn: &mut Connection,
+    name: Option<&str>,
+) -> Result<Session, DatabaseError> {
+    let name = name
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .map_or_else(default_name, str::to_string);
+    let now = Utc::now().to_rfc3339();
+    let session = Session {
+        id: Uuid::new_v4().to_string(),
+        name,
+        started_at: now.clone(),
+        ended_at: None,
+    };
+
+    let tx = conn.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+    tx.execute(
+        "UPDATE sessions SET ended_at = ?1 WHERE ended_at IS NULL",
+        params![now],
+    )?;
+    tx.execute(
+        "INSERT INTO sessions (id, name, started_at) VALUES (?1, ?2, ?3)",
+        params![session.id, session.name, session.started_at],
+    )?;
+    tx.commit()?;
+
+    tracing::info!("Started session {} ({})", session.id, session.name);
+    Ok(session)
+}
+
+/// End the open session, returning it, or `None` when none was open.
+pub(crate) fn end_session_with_conn(conn: &Connection) -> Result<Option<Session>, DatabaseError> {
+    let Some(mut session) = current_session_with_conn(conn)? else {
+        return Ok(None);
+    };
+    let now = Utc::now().to_rfc3339();
+    conn.execute(
+        "UPDATE sessions SET ended_at = ?1 WHERE id = ?2",
+        params![now, session.id],
+    )?;
+    session.ended_at = Some(now);
+
+    tracing::info!("Ended session {} ({})", session.id, session.name);
+    Ok(Some(session))
+}
+
+/// Rename a session. Returns false when no session has that ID.
+pub(crate) fn rename_session_with_conn(
+    conn: &Connection,
+    id: &str,
+    name: &str,
+) -> Result<bool, DatabaseError> {
+    let rows = conn.execute(
+        "UPDATE sessions SET name = ?1 WHERE id = ?2",
+        params![name.trim(), id],
+    )?;
+    Ok(rows > 0)
+}
+
+/// All sessions, newest first.
+pub(crate) fn list_sessions_with_conn(conn: &Connection) -> Result<Vec<Session>, DatabaseError> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM sessions ORDER BY started_at DESC",
+        SELECT_COLUMNS
+    ))?;
+    let sessions = stmt
+        .query_map([], row_to_session)?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(sessions)
+}
+
+/// ID of the open session, for stamping new transcriptions.
+///
+/// A lookup failure is logged and treated as no session, so it never stops
+/// a transcription being saved.
+pub fn current_session_id() -> Option<String> {
+    let result = open_connection().and_then(|conn| current_session_with_conn(&conn));
+    match result {
+        Ok(session) => session.map(|s| s.id),
+        Err(e) => {
+            tracing::warn!("Failed to look up current session: {}", e);
+            None
+        }
+    }
+}
+
+// =============================================================================
+// Tauri Commands
+// =============================================================================
+
+/// Start a named session; later transcriptions are grouped under it.
+///
+/// Any open session is ended first. A blank name gets a dated default.
+#[tauri::command]
+pub fn start_session(name: Option<String>) -> Result<Session, Error> {
+    let mut conn = open_connection()?;
+    start_session_with_conn(&mut conn, name.as_deref()).map_err(|e| {
+        tracing::error!("start_session failed: {}", e);
+        Error::Database(e)
+    })
+}
+
+/// End the open session, returning to no session.
+#[tauri::command]
+pub fn end_session() -> Result<Option<Session>, Error> {
+    let conn = open_connection()?;
+    end_session_with_conn(&conn).map_err(|e| {
+        tracing::error!("end_session failed: {}", e);
+        Error::Database(e)
+    })
+}
+
+/// Rename a session. Returns false when no session has that ID.
+#[tauri::command]
+pub fn rename_session(id: String, name: String) -> Result<bool, Error> {
+    if name.trim().is_empty() {
+        return Err("Session name cannot be empty".to_string().into());
+    }
+    let conn = open_connection()?;
+    rename_session_with_conn(&conn, &id, &name).map_err(|e| {
+        tracing::error!("rename_session failed: {}", e);
+        Error::Database(e)
+    })
+}
+
+/// List all sessions, newest first.
+#[tauri::command]
+pub fn list_sessions() -> Result<Vec<Session>, Error> {
+    let conn = open_connection()?;
+    list_sessions_with_conn(&conn).map_err(|e| {
+        tracing::error!("list_sessions failed: {}", e);
+        Error::Database(e)
+    })
+}
+
+/// The open session, if any.
+#[tauri::command]
+pub fn get_current_session() -> Result<Option<Session>, Error> {
+    let conn = open_connection()?;
+    current_session_with_conn(&conn).map_err(|e| {
+        tracing::error!("get_current_session failed: {}", e);
+        Error::Database(e)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::migrations::run_migrations;
+
+    fn make_test_db() -> Connection {
+        let mut conn = Connection::open_in_memory().expect("in-memory DB");
+        conn.execute_batch("PRAGMA foreign_keys = ON;")
+            .expect("pragmas");
+        run_migrations(&mut conn).expect("migrations");
+        conn
+    }
+
+    #[test]
+    fn test_start_session_ends_previous() {
+        let mut conn = make_test_db();
+        let first = start_session_with_conn(&mut conn, Some("Chapter 2")).unwrap();
+        let second = start_session_with_conn(&mut conn, Some("Chapter 3")).unwrap();
+
+        let current = current_session_with_conn(&conn).unwrap().unwrap();
+        assert_eq!(current.id, second.id);
+        assert_eq!(current.name, "Chapter 3");
+
+        let sessions = list_sessions_with_conn(&conn).unwrap();
+        let ended = sessions.iter().find(|s| s.id == first.id).unwrap();
+        assert!(ended.ended_at.is_some(), "previous session must be ended");
+    }
+
+    #[test]
+    fn test_end_session_returns_to_no_session() {
+        let mut conn = make_test_db();
+        assert!(end_session_with_conn(&conn).unwrap().is_none());
+
+        let started = start_session_with_conn(&mut conn, None).unwrap();
+        assert!(started.name.starts_with("Session "));
+
+        let ended = end_session_with_conn(&conn).unwrap().unwrap();
+        assert_eq!(ended.id, started.id);
+        assert!(ended.ended_at.is_some());
+        assert!(current_session_with_conn(&conn).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rename_session() {
+        let mut conn = make_test_db();
+        let session = start_session_with_conn(&mut conn, Some("Draft")).unwrap();
+
+        assert!(rename_session_with_conn(&conn, &session.id, " Chapter 3 ").unwrap());
+        assert!(!rename_session_with_conn(&conn, "missing", "Other").unwrap());
+
+        let current = current_session_with_conn(&conn).unwrap().unwrap();
+        assert_eq!(current.name, "Chapter 3");
+        let fetched = get_session_with_conn(&conn, &session.id).unwrap().unwrap();
+        assert_eq!(fetched.name, "Chapter 3");
+        assert!(get_session_with_conn(&conn, "missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_transcriptions_reference_sessions() {
+        let mut conn = make_test_db();
+        let session = start_session_with_conn(&mut conn, Some("Chapter 3")).unwrap();
+        conn.execute(
+            "INSERT INTO transcriptions (id, text, created_at, is_enhanced, session_id)
+             VALUES ('t1', 'hello', '2025-01-01T00:00:00Z', 0, ?1)",
+            params![session.id],
+        )
+        .unwrap();
+
+        let unknown = conn.execute(
+            "INSERT INTO transcriptions (id, text, created_at, is_enhanced, session_id)
+             VALUES ('t2', 'hello', '2025-01-01T00:00:00Z', 0, 'no-such-session')",
+            [],
+        );
+        assert!(unknown.is_err(), "session_id must be a foreign key");
+    }
+}
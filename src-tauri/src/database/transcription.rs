@@ -39,6 +39,15 @@ pub struct Transcription {
     pub enhancement_model_name: Option<String>,
     /// Time taken for AI enhancement, in seconds.
     pub enhancement_duration_seconds: Option<f64>,
+    /// Per-stage timings (decode, model wait, filtering) as compact JSON.
+    #[serde(default)]
+    pub timing_breakdown: Option<String>,
+    /// Session this transcription was dictated in, if any.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// Language of the speech (ISO 639-1, e.g. "en"), if known.
+    #[serde(default)]
+    pub detected_language: Option<String>,
 }
 
 impl Transcription {
@@ -57,6 +66,9 @@ impl Transcription {
             transcription_duration_seconds: None,
             enhancement_model_name: None,
             enhancement_duration_seconds: None,
+            timing_breakdown: None,
+            session_id: None,
+            detected_language: None,
         }
     }
 
@@ -87,6 +99,9 @@ impl Transcription {
             transcription_duration_seconds,
             enhancement_model_name,
             enhancement_duration_seconds,
+            timing_breakdown: None,
+            session_id: None,
+            detected_language: None,
         }
     }
 }
@@ -105,9 +120,10 @@ pub fn create_transcription(transcription: &Transcription) -> Result<(), Databas
             id, text, raw_text, duration_seconds, created_at, audio_path,
             is_enhanced, enhancement_prompt,
             transcription_model_name, transcription_duration_seconds,
-            enhancement_model_name, enhancement_duration_seconds
+            enhancement_model_name, enhancement_duration_seconds,
+            timing_breakdown, session_id, detected_language
         )
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
         "#,
         params![
             transcription.id,
@@ -122,6 +138,9 @@ pub fn create_transcription(transcription: &Transcription) -> Result<(), Databas
             transcription.transcription_duration_seconds,
             transcription.enhancement_model_name,
             transcription.enhancement_duration_seconds,
+            transcription.timing_breakdown,
+            transcription.session_id,
+            transcription.detected_language,
         ],
     )?;
 
@@ -134,7 +153,8 @@ const SELECT_COLUMNS: &str = r#"
     id, text, raw_text, duration_seconds, created_at, audio_path,
     is_enhanced, enhancement_prompt,
     transcription_model_name, transcription_duration_seconds,
-    enhancement_model_name, enhancement_duration_seconds
+    enhancement_model_name, enhancement_duration_seconds,
+    timing_breakdown, session_id, detected_language
 "#;
 
 /// Map a database row to a Transcription struct.
@@ -152,6 +172,9 @@ fn row_to_transcription(row: &rusqlite::Row) -> rusqlite::Result<Transcription>
         transcription_duration_seconds: row.get(9)?,
         enhancement_model_name: row.get(10)?,
         enhancement_duration_seconds: row.get(11)?,
+        timing_breakdown: row.get(12)?,
+        session_id: row.get(13)?,
+        detected_language: row.get(14)?,
     })
 }
 
@@ -185,7 +208,8 @@ pub fn update_transcription(transcription: &Transcription) -> Result<(), Databas
         SET text = ?2, raw_text = ?3, duration_seconds = ?4, audio_path = ?5,
             is_enhanced = ?6, enhancement_prompt = ?7,
             transcription_model_name = ?8, transcription_duration_seconds = ?9,
-            enhancement_model_name = ?10, enhancement_duration_seconds = ?11
+            enhancement_model_name = ?10, enhancement_duration_seconds = ?11,
+            timing_breakdown = ?12, detected_language = ?13
         WHERE id = ?1
         "#,
         params![
@@ -200,6 +224,8 @@ pub fn update_transcription(transcription: &Transcription) -> Result<(), Databas
             transcription.transcription_duration_seconds,
             transcription.enhancement_model_name,
             transcription.enhancement_duration_seconds,
+            transcription.timing_breakdown,
+            transcription.detected_language,
         ],
     )?;
 
@@ -333,35 +359,30 @@ fn delete_all_transcriptions_with_conn(
     Ok(rows_affected)
 }
 
-/// Returns the recordings directory (~/.thoth/Recordings/).
-fn recordings_dir() -> Option<std::path::PathBuf> {
-    dirs::home_dir().map(|h| h.join(".thoth").join("Recordings"))
-}
-
-/// Scans ~/.thoth/Recordings/ for WAV files that are not referenced by any
-/// DB row and removes them, returning a count and bytes freed.
+/// Scans the configured recordings directory for WAV files that are not
+/// referenced by any DB row and removes them, returning a count and bytes freed.
+///
+/// In a user-chosen directory only Thoth-named files are candidates, so other
+/// audio kept alongside is never touched.
 pub fn reconcile_orphaned_recordings() -> Result<ReconcileResult, DatabaseError> {
     let conn = open_connection()?;
-    let dir = match recordings_dir() {
-        Some(d) => d,
-        None => {
-            return Ok(ReconcileResult {
-                removed_count: 0,
-                bytes_freed: 0,
-            });
-        }
-    };
-    reconcile_orphaned_recordings_with_conn(&conn, &dir)
+    reconcile_orphaned_recordings_with_conn(
+        &conn,
+        &crate::audio::recordings_dir(),
+        crate::audio::uses_custom_recordings_dir(),
+    )
 }
 
 /// Inner implementation used by tests.
 ///
 /// `dir` is the recordings directory to scan. Passing it explicitly avoids the
 /// footgun where a test or in-memory connection would scan (and potentially
-/// delete files from) the real ~/.thoth/Recordings/ directory.
+/// delete files from) the real recordings directory. With `only_thoth_files`
+/// set, WAVs Thoth did not name are left alone.
 fn reconcile_orphaned_recordings_with_conn(
     conn: &rusqlite::Connection,
     dir: &std::path::Path,
+    only_thoth_files: bool,
 ) -> Result<ReconcileResult, DatabaseError> {
     if !dir.exists() {
         return Ok(ReconcileResult {
@@ -396,6 +417,9 @@ fn reconcile_orphaned_recordings_with_conn(
         if !is_wav || !path.is_file() {
             continue;
         }
+        if only_thoth_files && !crate::audio::is_thoth_recording(&path) {
+            continue;
+        }
 
         // Canonicalise the disk path before comparing. If canonicalisation
         // fails (e.g. a broken symlink), skip the entry — never delete on doubt.
@@ -982,7 +1006,8 @@ mod tests {
         let conn = make_test_db();
         insert_row(&conn, "id-ref", Some(referenced.to_str().unwrap()));
 
-        let result = reconcile_orphaned_recordings_with_conn(&conn, dir.path()).expect("reconcile");
+        let result =
+            reconcile_orphaned_recordings_with_conn(&conn, dir.path(), false).expect("reconcile");
 
         assert_eq!(result.removed_count, 1, "one orphan should be removed");
         assert!(!orphan.exists(), "orphan should be gone");
@@ -990,6 +1015,24 @@ mod tests {
         assert!(result.bytes_freed > 0);
     }
 
+    /// In a user-chosen directory, unreferenced audio Thoth did not write survives.
+    #[test]
+    fn test_reconcile_custom_dir_only_removes_thoth_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let ours = dir.path().join("thoth_recording_20250101_120000.wav");
+        let theirs = dir.path().join("interview.wav");
+        std::fs::write(&ours, b"RIFF").expect("write ours");
+        std::fs::write(&theirs, b"RIFF").expect("write theirs");
+
+        let conn = make_test_db();
+        let result =
+            reconcile_orphaned_recordings_with_conn(&conn, dir.path(), true).expect("reconcile");
+
+        assert_eq!(result.removed_count, 1);
+        assert!(!ours.exists(), "Thoth orphan should be gone");
+        assert!(theirs.exists(), "foreign audio must survive");
+    }
+
     /// A referenced file stored with a path that differs only in representation
     /// (e.g. via a symlink to the same tempdir) must NOT be deleted.
     #[test]
@@ -1009,7 +1052,8 @@ mod tests {
 
         // Reconcile against the real directory; the file must not be deleted
         // because its canonicalised path matches the DB entry's canonical path.
-        let result = reconcile_orphaned_recordings_with_conn(&conn, dir.path()).expect("reconcile");
+        let result =
+            reconcile_orphaned_recordings_with_conn(&conn, dir.path(), false).expect("reconcile");
 
         assert_eq!(
             result.removed_count, 0,
@@ -118,12 +118,16 @@ pub(crate) fn quarantine_recordings_with_conn(
             Option<f64>,    // transcription_duration_seconds
             Option<String>, // enhancement_model_name
             Option<f64>,    // enhancement_duration_seconds
+            Option<String>, // timing_breakdown
+            Option<String>, // session_id
+            Option<String>, // detected_language
         )> = {
             let mut stmt = tx.prepare(
                 r#"SELECT text, raw_text, duration_seconds, created_at, audio_path,
                           is_enhanced, enhancement_prompt,
                           transcription_model_name, transcription_duration_seconds,
-                          enhancement_model_name, enhancement_duration_seconds
+                          enhancement_model_name, enhancement_duration_seconds,
+                          timing_breakdown, session_id, detected_language
                    FROM transcriptions WHERE id = ?1"#,
             )?;
             stmt.query_row(params![id], |r| {
@@ -139,6 +143,9 @@ pub(crate) fn quarantine_recordings_with_conn(
                     r.get(8)?,
                     r.get(9)?,
                     r.get(10)?,
+                    r.get(11)?,
+                    r.get(12)?,
+                    r.get(13)?,
                 ))
             })
             .optional()
@@ -157,6 +164,9 @@ pub(crate) fn quarantine_recordings_with_conn(
             transcription_duration_seconds,
             enhancement_model_name,
             enhancement_duration_seconds,
+            timing_breakdown,
+            session_id,
+            detected_language,
         ) = match row {
             Some(r) => r,
             None => {
@@ -207,8 +217,9 @@ pub(crate) fn quarantine_recordings_with_conn(
                    is_enhanced, enhancement_prompt,
                    transcription_model_name, transcription_duration_seconds,
                    enhancement_model_name, enhancement_duration_seconds,
-                   original_path, deleted_at, audio_moved
-               ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)"#,
+                   original_path, deleted_at, audio_moved, timing_breakdown, session_id,
+                   detected_language
+               ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)"#,
             params![
                 id,
                 text,
@@ -225,6 +236,9 @@ pub(crate) fn quarantine_recordings_with_conn(
                 audio_path, // original_path = where restore puts the file back
                 deleted_at,
                 will_move as i32,
+                timing_breakdown,
+                session_id,
+                detected_language,
             ],
         )?;
 
@@ -286,13 +300,17 @@ pub(crate) fn restore_recordings_with_conn(
             Option<f64>,    // enhancement_duration_seconds
             Option<String>, // original_path
             i32,            // audio_moved
+            Option<String>, // timing_breakdown
+            Option<String>, // session_id
+            Option<String>, // detected_language
         )> = {
             let mut stmt = conn.prepare(
                 r#"SELECT text, raw_text, duration_seconds, created_at, audio_path,
                           is_enhanced, enhancement_prompt,
                           transcription_model_name, transcription_duration_seconds,
                           enhancement_model_name, enhancement_duration_seconds,
-                          original_path, audio_moved
+                          original_path, audio_moved, timing_breakdown, session_id,
+                          detected_language
                    FROM trash WHERE id = ?1"#,
             )?;
             stmt.query_row(params![id], |r| {
@@ -310,6 +328,9 @@ pub(crate) fn restore_recordings_with_conn(
                     r.get(10)?,
                     r.get(11)?,
                     r.get(12)?,
+                    r.get(13)?,
+                    r.get(14)?,
+                    r.get(15)?,
                 ))
             })
             .optional()
@@ -330,6 +351,9 @@ pub(crate) fn restore_recordings_with_conn(
             enhancement_duration_seconds,
             original_path,
             audio_moved,
+            timing_breakdown,
+            session_id,
+            detected_language,
         ) = match snap {
             Some(s) => s,
             None => {
@@ -393,8 +417,10 @@ pub(crate) fn restore_recordings_with_conn(
                    id, text, raw_text, duration_seconds, created_at, audio_path,
                    is_enhanced, enhancement_prompt,
                    transcription_model_name, transcription_duration_seconds,
-                   enhancement_model_name, enhancement_duration_seconds
-               ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)"#,
+                   enhancement_model_name, enhancement_duration_seconds,
+                   timing_breakdown, session_id, detected_language
+               ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13,
+                         (SELECT id FROM sessions WHERE id = ?14), ?15)"#,
             params![
                 id,
                 text,
@@ -408,6 +434,10 @@ pub(crate) fn restore_recordings_with_conn(
                 transcription_duration_seconds,
                 enhancement_model_name,
                 enhancement_duration_seconds,
+                timing_breakdown,
+                // Rejoins its session only if the session still exists.
+                session_id,
+                detected_language,
             ],
         )?;
 
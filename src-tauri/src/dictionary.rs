@@ -2,18 +2,50 @@
 //!
 //! Provides persistent storage and CRUD operations for custom word replacements.
 //! Dictionary entries are stored in JSON format at `~/.thoth/dictionary.json`.
+//!
+//! An entry may carry a [`DictionaryScope`] restricting it to one application
+//! and/or spoken language. Entries written before scopes existed have no
+//! `scope` key and load as unscoped, so they keep applying everywhere.
+//!
+//! ## Ordering
+//!
+//! Entries apply in descending `priority`; entries with equal priority apply
+//! in list order. Every entry matches against the original text, and a span
+//! already rewritten by an earlier entry is never matched again, so each rule
+//! fires at most once per pass and the output of one rule is never fed to
+//! another. Two entries that rewrite each other ("ML" -> "machine learning"
+//! and back) therefore cannot ping-pong; the higher-priority one wins where
+//! their matches overlap.
+//!
+//! ## Fuzzy entries
+//!
+//! An entry with `fuzzy` set also matches close variants of its `from` text:
+//! a run of the same number of words that shares a Double-Metaphone key with
+//! it *and* whose normalised Damerau-Levenshtein similarity reaches the
+//! entry's threshold (default [`DEFAULT_FUZZY_THRESHOLD`]). Both gates must
+//! pass, the same rule the canonical-term registry uses. The similarity is
+//! measured ignoring case unless the entry is case-sensitive. Fuzzy matching
+//! is opt-in per entry because it is only safe for distinctive words such as
+//! names.
 
 use crate::error::Error;
 use parking_lot::RwLock;
 use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::OnceLock;
 
+/// Similarity a fuzzy entry requires unless it sets its own threshold
+pub const DEFAULT_FUZZY_THRESHOLD: f64 = 0.7;
+
+/// Fuzzy matching is skipped for shorter `from` text, where nearly every
+/// short word would collide phonetically.
+const MIN_FUZZY_LEN: usize = 4;
+
 /// A dictionary entry for word replacement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DictionaryEntry {
     /// The text to search for and replace
@@ -22,6 +54,148 @@ pub struct DictionaryEntry {
     pub to: String,
     /// Whether the match should be case-sensitive
     pub case_sensitive: bool,
+    /// Restricts the entry to a context; `None` applies everywhere
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<DictionaryScope>,
+    /// Higher priorities apply first (default 0; see the module docs)
+    #[serde(default)]
+    pub priority: i32,
+    /// Also match phonetically close variants (see the module docs)
+    #[serde(default)]
+    pub fuzzy: bool,
+    /// Similarity (0.0-1.0) a fuzzy match needs; `None` uses the default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuzzy_threshold: Option<f64>,
+}
+
+impl DictionaryEntry {
+    /// Whether this entry applies in the given context
+    fn applies_in(&self, context: &DictionaryContext) -> bool {
+        self.scope
+            .as_ref()
+            .is_none_or(|scope| scope.matches(context))
+    }
+
+    /// Check the parts of an entry a user can get wrong
+    fn validate(&self) -> Result<(), String> {
+        if self.from.trim().is_empty() {
+            return Err("The 'from' field cannot be empty".to_string());
+        }
+        if self.to.trim().is_empty() {
+            return Err("The 'to' field cannot be empty".to_string());
+        }
+        if let Some(threshold) = self.fuzzy_threshold {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(format!(
+                    "Fuzzy threshold must be between 0 and 1, got {}",
+                    threshold
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Key used to detect duplicates: the same `from` may appear once per scope
+    fn duplicate_key(&self) -> (String, Option<String>, Option<String>) {
+        let scope = self.scope.clone().and_then(DictionaryScope::normalised);
+        let field = |f: fn(DictionaryScope) -> Option<String>| {
+            scope.clone().and_then(f).map(|v| v.to_lowercase())
+        };
+        (
+            self.from.to_lowercase(),
+            field(|s| s.app),
+            field(|s| s.language),
+        )
+    }
+}
+
+/// Application and/or language an entry is restricted to
+///
+/// Both fields are optional; a scope with both set requires both to match.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DictionaryScope {
+    /// Application name, e.g. "Slack" (compared case-insensitively)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<String>,
+    /// Language code, e.g. "en" (matches regional variants such as "en-AU")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+impl DictionaryScope {
+    /// Trim both fields, returning `None` when nothing is left so a blank
+    /// scope is stored as unscoped.
+    fn normalised(self) -> Option<Self> {
+        let clean = |value: Option<String>| {
+            value
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        let scope = Self {
+            app: clean(self.app),
+            language: clean(self.language),
+        };
+        (scope.app.is_some() || scope.language.is_some()).then_some(scope)
+    }
+
+    /// Whether the context satisfies every field this scope sets. A field the
+    /// context does not know (e.g. language on auto-detect) never matches.
+    fn matches(&self, context: &DictionaryContext) -> bool {
+        let app_matches = self.app.as_deref().is_none_or(|app| {
+            context
+                .app
+                .as_deref()
+                .is_some_and(|current| current.trim().to_lowercase() == app.trim().to_lowercase())
+        });
+        let language_matches = self.language.as_deref().is_none_or(|language| {
+            context
+                .language
+                .as_deref()
+                .is_some_and(|current| language_matches(current, language))
+        });
+        app_matches && language_matches
+    }
+}
+
+/// Compare language codes on their primary subtag ("en-AU" matches "en")
+fn language_matches(current: &str, scoped: &str) -> bool {
+    let primary = |code: &str| {
+        code.trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase()
+    };
+    !scoped.trim().is_empty() && primary(current) == primary(scoped)
+}
+
+/// The context a transcription is being processed in
+///
+/// Scoped entries apply only when their scope matches; an empty context
+/// applies unscoped entries alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DictionaryContext {
+    /// Frontmost application name
+    pub app: Option<String>,
+    /// Transcription language code
+    pub language: Option<String>,
+}
+
+impl DictionaryContext {
+    /// Context for dictating into `app`, with the configured transcription
+    /// language (unknown when set to auto-detect).
+    ///
+    /// The app is the caller's to look up, once per run: finding it can
+    /// mean running a process, which must not happen per filter pass.
+    pub fn for_app(app: Option<String>) -> Self {
+        let language = crate::config::get_config()
+            .ok()
+            .map(|c| c.transcription.language)
+            .filter(|l| !l.is_empty() && l != "auto");
+        Self { app, language }
+    }
 }
 
 /// The dictionary storage structure
@@ -36,10 +210,7 @@ static DICTIONARY: OnceLock<RwLock<Dictionary>> = OnceLock::new();
 
 /// Get the dictionary file path (~/.thoth/dictionary.json)
 fn get_dictionary_path() -> PathBuf {
-    dirs::home_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join(".thoth")
-        .join("dictionary.json")
+    crate::storage::data_dir_or_fallback().join("dictionary.json")
 }
 
 /// Get the global dictionary instance, loading from disk if needed
@@ -88,24 +259,16 @@ pub fn get_dictionary_entries() -> Result<Vec<DictionaryEntry>, Error> {
 
 /// Add a new dictionary entry
 #[tauri::command]
-pub fn add_dictionary_entry(entry: DictionaryEntry) -> Result<(), Error> {
-    // Validate entry
-    if entry.from.trim().is_empty() {
-        return Err("The 'from' field cannot be empty".to_string().into());
-    }
-    if entry.to.trim().is_empty() {
-        return Err("The 'to' field cannot be empty".to_string().into());
-    }
+pub fn add_dictionary_entry(mut entry: DictionaryEntry) -> Result<(), Error> {
+    entry.validate()?;
+
+    entry.scope = entry.scope.and_then(DictionaryScope::normalised);
 
     let mut dictionary = get_dictionary().write();
 
-    // Check for duplicates
-    let from_lower = entry.from.to_lowercase();
-    if dictionary
-        .entries
-        .iter()
-        .any(|e| e.from.to_lowercase() == from_lower)
-    {
+    // Check for duplicates within the same scope
+    let key = entry.duplicate_key();
+    if dictionary.entries.iter().any(|e| e.duplicate_key() == key) {
         return Err(format!("An entry for '{}' already exists", entry.from).into());
     }
 
@@ -121,14 +284,10 @@ pub fn add_dictionary_entry(entry: DictionaryEntry) -> Result<(), Error> {
 
 /// Update an existing dictionary entry
 #[tauri::command]
-pub fn update_dictionary_entry(index: usize, entry: DictionaryEntry) -> Result<(), Error> {
-    // Validate entry
-    if entry.from.trim().is_empty() {
-        return Err("The 'from' field cannot be empty".to_string().into());
-    }
-    if entry.to.trim().is_empty() {
-        return Err("The 'to' field cannot be empty".to_string().into());
-    }
+pub fn update_dictionary_entry(index: usize, mut entry: DictionaryEntry) -> Result<(), Error> {
+    entry.validate()?;
+
+    entry.scope = entry.scope.and_then(DictionaryScope::normalised);
 
     let mut dictionary = get_dictionary().write();
 
@@ -136,13 +295,13 @@ pub fn update_dictionary_entry(index: usize, entry: DictionaryEntry) -> Result<(
         return Err(format!("Invalid entry index: {}", index).into());
     }
 
-    // Check for duplicates (excluding the current entry)
-    let from_lower = entry.from.to_lowercase();
+    // Check for duplicates within the same scope (excluding the current entry)
+    let key = entry.duplicate_key();
     if dictionary
         .entries
         .iter()
         .enumerate()
-        .any(|(i, e)| i != index && e.from.to_lowercase() == from_lower)
+        .any(|(i, e)| i != index && e.duplicate_key() == key)
     {
         return Err(format!("An entry for '{}' already exists", entry.from).into());
     }
@@ -174,63 +333,409 @@ pub fn remove_dictionary_entry(index: usize) -> Result<(), Error> {
     Ok(())
 }
 
-/// Import dictionary entries from JSON content
+/// Remove every dictionary entry
+///
+/// The emptied dictionary is written before the in-memory copy is cleared, so
+/// a failed write leaves the entries in place both on disk and in memory.
 #[tauri::command]
-pub fn import_dictionary(json_content: String, merge: bool) -> Result<usize, Error> {
-    let imported: Dictionary =
-        serde_json::from_str(&json_content).map_err(|e| format!("Invalid JSON format: {}", e))?;
-
+pub fn reset_dictionary() -> Result<(), Error> {
     let mut dictionary = get_dictionary().write();
-    let import_count;
+    save_dictionary(&Dictionary::default())?;
+    let removed = std::mem::take(&mut dictionary.entries).len();
+    tracing::info!("Dictionary reset, removed {} entries", removed);
+    Ok(())
+}
 
-    if merge {
-        // Build a set of existing 'from' values for deduplication
-        let existing: HashMap<String, usize> = dictionary
+/// Outcome of a dictionary import
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DictionaryImportSummary {
+    /// Entries added to the dictionary
+    pub imported: usize,
+    /// Entries left out because an entry with the same `from` and scope
+    /// already exists (or appeared earlier in the same file)
+    pub skipped_duplicates: usize,
+    /// Rows that could not be imported; the rest of the file still is
+    pub errors: Vec<ImportRowError>,
+}
+
+/// A row that was rejected during import
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRowError {
+    /// 1-based line in the source file (`None` for JSON, which has no rows)
+    pub line: Option<usize>,
+    /// Why the row was rejected
+    pub message: String,
+}
+
+/// Import dictionary entries from JSON, CSV or TSV content
+///
+/// The format is detected from the content: JSON (this app's export, or a
+/// bare array of entries) or a delimited file with a header row naming the
+/// `from`/`to` columns (see [`parse_delimited`]). Malformed rows are reported
+/// in the summary rather than aborting the import.
+///
+/// `jsonContent` is the name `content` had when only JSON was accepted; it
+/// is still read, so callers written against it keep working.
+#[tauri::command]
+pub fn import_dictionary(
+    content: Option<String>,
+    json_content: Option<String>,
+    merge: bool,
+) -> Result<DictionaryImportSummary, Error> {
+    let content = content
+        .or(json_content)
+        .ok_or_else(|| "No dictionary content to import".to_string())?;
+    import_dictionary_content(&content, merge)
+}
+
+/// [`import_dictionary`] for callers that hold the content directly
+pub fn import_dictionary_content(
+    content: &str,
+    merge: bool,
+) -> Result<DictionaryImportSummary, Error> {
+    let (entries, errors) = parse_import(content)?;
+
+    let mut dictionary = get_dictionary().write();
+    let mut summary = DictionaryImportSummary {
+        errors,
+        ..Default::default()
+    };
+
+    // Replacing starts from an empty dictionary; duplicates within the file
+    // are still collapsed to their first occurrence.
+    let mut seen: HashSet<_> = if merge {
+        dictionary
             .entries
             .iter()
-            .enumerate()
-            .map(|(i, e)| (e.from.to_lowercase(), i))
-            .collect();
-
-        let mut new_entries = Vec::new();
-        for entry in imported.entries {
-            if entry.from.trim().is_empty() || entry.to.trim().is_empty() {
-                continue;
-            }
-            if !existing.contains_key(&entry.from.to_lowercase()) {
-                new_entries.push(entry);
-            }
+            .map(DictionaryEntry::duplicate_key)
+            .collect()
+    } else {
+        HashSet::new()
+    };
+
+    let mut new_entries = Vec::new();
+    for mut entry in entries {
+        entry.scope = entry.scope.and_then(DictionaryScope::normalised);
+        if seen.insert(entry.duplicate_key()) {
+            new_entries.push(entry);
+        } else {
+            summary.skipped_duplicates += 1;
         }
-        import_count = new_entries.len();
+    }
+    summary.imported = new_entries.len();
+
+    if merge {
         dictionary.entries.extend(new_entries);
     } else {
-        // Replace entire dictionary
-        let valid_entries: Vec<_> = imported
-            .entries
-            .into_iter()
-            .filter(|e| !e.from.trim().is_empty() && !e.to.trim().is_empty())
-            .collect();
-        import_count = valid_entries.len();
-        dictionary.entries = valid_entries;
+        dictionary.entries = new_entries;
     }
 
     save_dictionary(&dictionary)?;
 
     tracing::info!(
-        "Imported {} dictionary entries (merge={})",
-        import_count,
-        merge
+        "Imported {} dictionary entries (merge={}, duplicates={}, errors={})",
+        summary.imported,
+        merge,
+        summary.skipped_duplicates,
+        summary.errors.len()
     );
-    Ok(import_count)
+    Ok(summary)
+}
+
+/// Parse import content into valid entries plus per-row errors.
+fn parse_import(content: &str) -> Result<(Vec<DictionaryEntry>, Vec<ImportRowError>), String> {
+    let trimmed = content.trim_start_matches('\u{feff}').trim_start();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        parse_json(trimmed)
+    } else {
+        parse_delimited(trimmed)
+    }
 }
 
-/// Export dictionary entries as JSON
+/// Parse this app's JSON export (`{"entries": [...]}`) or a bare entry array.
+fn parse_json(content: &str) -> Result<(Vec<DictionaryEntry>, Vec<ImportRowError>), String> {
+    let entries = if content.starts_with('[') {
+        serde_json::from_str::<Vec<DictionaryEntry>>(content)
+    } else {
+        serde_json::from_str::<Dictionary>(content).map(|d| d.entries)
+    }
+    .map_err(|e| format!("Invalid JSON format: {}", e))?;
+
+    let mut valid = Vec::with_capacity(entries.len());
+    let mut errors = Vec::new();
+    for (i, entry) in entries.into_iter().enumerate() {
+        match entry.validate() {
+            Ok(()) => valid.push(entry),
+            Err(message) => errors.push(ImportRowError {
+                line: None,
+                message: format!("Entry {}: {}", i + 1, message),
+            }),
+        }
+    }
+    Ok((valid, errors))
+}
+
+/// Columns of a delimited import, as indices into each row
+#[derive(Debug, Clone, Copy)]
+struct ImportColumns {
+    from: usize,
+    to: usize,
+    case_sensitive: Option<usize>,
+    app: Option<usize>,
+    language: Option<usize>,
+    priority: Option<usize>,
+    fuzzy: Option<usize>,
+    fuzzy_threshold: Option<usize>,
+}
+
+impl ImportColumns {
+    /// Map header names to columns. Common names from other tools are
+    /// accepted ("wrong,right", "shortcut,phrase", ...). Returns `None` when
+    /// the row names neither a `from` nor a `to` column, i.e. it is data.
+    fn from_header(header: &csv::StringRecord) -> Result<Option<Self>, String> {
+        let mut from = None;
+        let mut to = None;
+        let mut case_sensitive = None;
+        let mut app = None;
+        let mut language = None;
+        let mut priority = None;
+        let mut fuzzy = None;
+        let mut fuzzy_threshold = None;
+
+        for (i, name) in header.iter().enumerate() {
+            let name: String = name
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+                .to_lowercase();
+            let slot = match name.as_str() {
+                "from" | "wrong" | "find" | "original" | "source" | "replace" | "incorrect"
+                | "misspelling" | "heard" | "shortcut" | "trigger" => &mut from,
+                "to" | "right" | "with" | "replacement" | "correct" | "corrected" | "target"
+                | "phrase" | "expansion" => &mut to,
+                "casesensitive" | "case" | "matchcase" => &mut case_sensitive,
+                "app" | "application" => &mut app,
+                "language" | "lang" => &mut language,
+                "priority" => &mut priority,
+                "fuzzy" => &mut fuzzy,
+                "fuzzythreshold" | "threshold" => &mut fuzzy_threshold,
+                _ => continue,
+            };
+            slot.get_or_insert(i);
+        }
+
+        match (from, to) {
+            (Some(from), Some(to)) => Ok(Some(Self {
+                from,
+                to,
+                case_sensitive,
+                app,
+                language,
+                priority,
+                fuzzy,
+                fuzzy_threshold,
+            })),
+            (None, None) => Ok(None),
+            (Some(_), None) => Err("Header has no replacement ('to') column".to_string()),
+            (None, Some(_)) => Err("Header has no search ('from') column".to_string()),
+        }
+    }
+
+    /// Headerless files: first column is `from`, second is `to`
+    fn positional() -> Self {
+        Self {
+            from: 0,
+            to: 1,
+            case_sensitive: None,
+            app: None,
+            language: None,
+            priority: None,
+            fuzzy: None,
+            fuzzy_threshold: None,
+        }
+    }
+}
+
+/// Parse CSV or TSV content.
+///
+/// The delimiter is a tab if the first line contains one, otherwise a comma.
+/// A first row that names the `from`/`to` columns is used as the header;
+/// otherwise the first two columns are taken as `from` and `to`.
+fn parse_delimited(content: &str) -> Result<(Vec<DictionaryEntry>, Vec<ImportRowError>), String> {
+    let first_line = content.lines().next().unwrap_or_default();
+    let delimiter = if first_line.contains('\t') {
+        b'\t'
+    } else {
+        b','
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(content.as_bytes());
+
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    let mut columns: Option<ImportColumns> = None;
+
+    for result in reader.records() {
+        let record = match result {
+            Ok(record) => record,
+            Err(e) => {
+                errors.push(ImportRowError {
+                    line: e.position().map(|p| p.line() as usize),
+                    message: format!("Could not parse row: {}", e),
+                });
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line() as usize);
+
+        if record.iter().all(|field| field.trim().is_empty()) {
+            continue;
+        }
+
+        let cols = match columns {
+            Some(cols) => cols,
+            None => {
+                let header = ImportColumns::from_header(&record)?;
+                columns = Some(header.unwrap_or_else(ImportColumns::positional));
+                if header.is_some() {
+                    continue;
+                }
+                ImportColumns::positional()
+            }
+        };
+
+        match entry_from_row(&record, cols) {
+            Ok(entry) => entries.push(entry),
+            Err(message) => errors.push(ImportRowError { line, message }),
+        }
+    }
+
+    Ok((entries, errors))
+}
+
+fn entry_from_row(
+    record: &csv::StringRecord,
+    cols: ImportColumns,
+) -> Result<DictionaryEntry, String> {
+    let field = |i: Option<usize>| {
+        i.and_then(|i| record.get(i))
+            .map(|v| unescape_csv_field(v.trim()).to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    let from = field(Some(cols.from)).ok_or("missing 'from' value")?;
+    let to = field(Some(cols.to)).ok_or("missing 'to' value")?;
+    let case_sensitive = parse_flag(field(cols.case_sensitive), "case-sensitive")?;
+    let fuzzy = parse_flag(field(cols.fuzzy), "fuzzy")?;
+    let fuzzy_threshold = field(cols.fuzzy_threshold)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| format!("invalid fuzzy threshold '{}'", value))
+        })
+        .transpose()?;
+    let priority = match field(cols.priority) {
+        None => 0,
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("invalid priority '{}'", value))?,
+    };
+    let scope = DictionaryScope {
+        app: field(cols.app),
+        language: field(cols.language),
+    };
+
+    let entry = DictionaryEntry {
+        from,
+        to,
+        case_sensitive,
+        scope: scope.normalised(),
+        priority,
+        fuzzy,
+        fuzzy_threshold,
+    };
+    entry.validate()?;
+    Ok(entry)
+}
+
+/// Parse a yes/no column; blank means no.
+fn parse_flag(value: Option<String>, name: &str) -> Result<bool, String> {
+    match value.map(|v| v.to_lowercase()).as_deref() {
+        None | Some("false" | "no" | "n" | "0") => Ok(false),
+        Some("true" | "yes" | "y" | "1") => Ok(true),
+        Some(other) => Err(format!("invalid {} value '{}'", name, other)),
+    }
+}
+
+/// Undo the formula-injection guard applied by the CSV export
+/// ([`crate::export::sanitize_csv_field`]), so exported files round-trip
+/// unchanged. Must strip the apostrophe before exactly the characters that
+/// function guards.
+fn unescape_csv_field(value: &str) -> &str {
+    match value.strip_prefix('\'') {
+        Some(rest) if rest.starts_with(['=', '+', '-', '@', '\t', '\r']) => rest,
+        _ => value,
+    }
+}
+
+/// Export dictionary entries as JSON (default) or CSV (`format = "csv"`)
 #[tauri::command]
-pub fn export_dictionary() -> Result<String, Error> {
+pub fn export_dictionary(format: Option<String>) -> Result<String, Error> {
     let dictionary = get_dictionary().read();
-    serde_json::to_string_pretty(&*dictionary)
-        .map_err(|e| format!("Failed to serialise: {}", e))
-        .map_err(Into::into)
+    match format.as_deref() {
+        None | Some("json") => serde_json::to_string_pretty(&*dictionary)
+            .map_err(|e| format!("Failed to serialise: {}", e))
+            .map_err(Into::into),
+        Some("csv") => export_csv(&dictionary.entries).map_err(Into::into),
+        Some(other) => Err(format!("Unsupported export format: {}", other).into()),
+    }
+}
+
+/// Write entries as CSV with a header `import_dictionary` understands.
+fn export_csv(entries: &[DictionaryEntry]) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record([
+            "from",
+            "to",
+            "case_sensitive",
+            "app",
+            "language",
+            "priority",
+            "fuzzy",
+            "fuzzy_threshold",
+        ])
+        .map_err(|e| format!("Failed to write CSV: {}", e))?;
+
+    for entry in entries {
+        let scope = entry.scope.clone().unwrap_or_default();
+        writer
+            .write_record([
+                crate::export::sanitize_csv_field(&entry.from),
+                crate::export::sanitize_csv_field(&entry.to),
+                entry.case_sensitive.to_string(),
+                crate::export::sanitize_csv_field(scope.app.as_deref().unwrap_or_default()),
+                scope.language.unwrap_or_default(),
+                entry.priority.to_string(),
+                entry.fuzzy.to_string(),
+                entry
+                    .fuzzy_threshold
+                    .map(|t| t.to_string())
+                    .unwrap_or_default(),
+            ])
+            .map_err(|e| format!("Failed to write CSV: {}", e))?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| format!("Failed to write CSV: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("Failed to write CSV: {}", e))
 }
 
 /// Apply dictionary replacements to text
@@ -240,31 +745,59 @@ pub fn export_dictionary() -> Result<String, Error> {
 /// the phrase boundaries. Entries that begin or end with a non-word character
 /// (punctuation) simply don't anchor on that side, which is the closest sensible
 /// behaviour for a `\b`-based boundary.
-pub fn apply_dictionary(text: &str) -> String {
+///
+/// Only unscoped entries and entries whose scope matches `context` are applied,
+/// in the order described in the module docs.
+pub fn apply_dictionary(text: &str, context: &DictionaryContext) -> String {
     let dictionary = get_dictionary().read();
+    apply_entries(text, &dictionary.entries, context)
+}
 
-    if dictionary.entries.is_empty() {
-        return text.to_string();
-    }
-
-    let mut result = text.to_string();
-
-    for entry in &dictionary.entries {
-        result = replace_whole_word(&result, &entry.from, &entry.to, entry.case_sensitive);
+fn apply_entries(text: &str, entries: &[DictionaryEntry], context: &DictionaryContext) -> String {
+    let mut ordered: Vec<&DictionaryEntry> =
+        entries.iter().filter(|e| e.applies_in(context)).collect();
+    // Stable sort: equal priorities keep their list order.
+    ordered.sort_by_key(|e| std::cmp::Reverse(e.priority));
+
+    let mut claimed: Vec<(usize, usize, &str)> = Vec::new();
+    for entry in ordered {
+        let mut matches = find_whole_word(text, &entry.from, entry.case_sensitive);
+        if entry.fuzzy {
+            matches.extend(find_fuzzy(text, entry));
+        }
+        for (start, end) in matches {
+            let overlaps = claimed.iter().any(|&(s, e, _)| start < e && s < end);
+            if !overlaps {
+                claimed.push((start, end, entry.to.as_str()));
+            }
+        }
     }
 
-    result
+    splice(text, claimed)
 }
 
 /// Replace whole-word occurrences of `from` with `to`.
 ///
+/// The pipeline applies all entries at once through `apply_entries`; this
+/// single-entry form is kept for exercising the matcher in tests.
+#[cfg(test)]
+fn replace_whole_word(text: &str, from: &str, to: &str, case_sensitive: bool) -> String {
+    let spans = find_whole_word(text, from, case_sensitive)
+        .into_iter()
+        .map(|(start, end)| (start, end, to))
+        .collect();
+    splice(text, spans)
+}
+
+/// Byte ranges of whole-word occurrences of `from` in `text`.
+///
 /// Anchors the (regex-escaped) needle between `\b` word boundaries so substrings
-/// inside larger words are left alone. Falls back to plain substring replacement
+/// inside larger words are left alone. Falls back to plain substring matching
 /// only if the boundary pattern fails to compile (it never should for escaped
 /// input, but we never want a malformed entry to drop the whole transcription).
-fn replace_whole_word(text: &str, from: &str, to: &str, case_sensitive: bool) -> String {
+fn find_whole_word(text: &str, from: &str, case_sensitive: bool) -> Vec<(usize, usize)> {
     if from.is_empty() {
-        return text.to_string();
+        return Vec::new();
     }
 
     let pattern = format!(r"\b{}\b", regex::escape(from));
@@ -273,48 +806,154 @@ fn replace_whole_word(text: &str, from: &str, to: &str, case_sensitive: bool) ->
         .build();
 
     match regex {
-        Ok(re) => whole_word_replace_all(&re, text, to),
+        Ok(re) => whole_word_matches(&re, text),
         Err(e) => {
             tracing::warn!(
                 "Dictionary entry '{}' failed to compile as regex ({e}); falling back to substring replace",
                 from
             );
             if case_sensitive {
-                text.replace(from, to)
+                text.match_indices(from)
+                    .map(|(i, m)| (i, i + m.len()))
+                    .collect()
             } else {
-                text.to_string()
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Byte ranges of word runs in `text` that fuzzily match the entry.
+///
+/// Candidates are runs of as many whitespace-separated words as the entry's
+/// `from`, compared against `from`, ignoring case unless the entry is
+/// case-sensitive.
+fn find_fuzzy(text: &str, entry: &DictionaryEntry) -> Vec<(usize, usize)> {
+    let fold = |s: &str| {
+        if entry.case_sensitive {
+            s.to_string()
+        } else {
+            s.to_lowercase()
+        }
+    };
+    let reference = fold(entry.from.trim());
+    if reference.chars().count() < MIN_FUZZY_LEN {
+        return Vec::new();
+    }
+    let threshold = entry.fuzzy_threshold.unwrap_or(DEFAULT_FUZZY_THRESHOLD);
+    let width = entry.from.split_whitespace().count().max(1);
+
+    let words: Vec<(usize, usize)> = word_regex()
+        .find_iter(text)
+        .map(|m| (m.start(), m.end()))
+        .collect();
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + width <= words.len() {
+        let window = &words[i..i + width];
+        let joined_by_spaces = window
+            .windows(2)
+            .all(|pair| text[pair[0].1..pair[1].0].chars().all(char::is_whitespace));
+        if joined_by_spaces {
+            let candidate = window
+                .iter()
+                .map(|&(s, e)| fold(&text[s..e]))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let close = crate::canonical::phonetic_match(&candidate, &reference)
+                && strsim::normalized_damerau_levenshtein(&candidate, &reference) >= threshold;
+            if close {
+                matches.push((window[0].0, window[width - 1].1));
+                i += width;
+                continue;
             }
         }
+        i += 1;
     }
+    matches
+}
+
+fn word_regex() -> &'static Regex {
+    static WORD: OnceLock<Regex> = OnceLock::new();
+    WORD.get_or_init(|| Regex::new(r"[\w'’]+").expect("valid word regex"))
 }
 
-/// Replace all regex matches with a literal replacement (no `$`-capture expansion).
-fn whole_word_replace_all(re: &Regex, text: &str, to: &str) -> String {
-    re.replace_all(text, regex::NoExpand(to)).into_owned()
+fn whole_word_matches(re: &Regex, text: &str) -> Vec<(usize, usize)> {
+    re.find_iter(text).map(|m| (m.start(), m.end())).collect()
+}
+
+/// Build the output from non-overlapping `(start, end, replacement)` spans.
+/// Replacements are inserted literally (no `$`-capture expansion).
+fn splice(text: &str, mut spans: Vec<(usize, usize, &str)>) -> String {
+    if spans.is_empty() {
+        return text.to_string();
+    }
+    spans.sort_by_key(|&(start, _, _)| start);
+
+    let mut result = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for (start, end, to) in spans {
+        result.push_str(&text[cursor..start]);
+        result.push_str(to);
+        cursor = end;
+    }
+    result.push_str(&text[cursor..]);
+    result
 }
 
 /// Tauri command to apply dictionary replacements
+///
+/// Scoped entries apply only when `app`/`language` are given and match.
 #[tauri::command]
-pub fn apply_dictionary_to_text(text: String) -> String {
-    apply_dictionary(&text)
+pub fn apply_dictionary_to_text(
+    text: String,
+    app: Option<String>,
+    language: Option<String>,
+) -> String {
+    apply_dictionary(&text, &DictionaryContext { app, language })
 }
 
 /// Get vocabulary words for AI enhancement context
 ///
 /// Returns a list of unique words that appear in dictionary replacements.
 /// These can be included in AI prompts to help the model understand
-/// domain-specific terminology.
+/// domain-specific terminology. Scoped entries are included only when
+/// `app`/`language` are given and match.
 #[tauri::command]
-pub fn get_vocabulary_for_context() -> Vec<String> {
+pub fn get_vocabulary_for_context(app: Option<String>, language: Option<String>) -> Vec<String> {
     let dictionary = get_dictionary().read();
+    let context = DictionaryContext { app, language };
 
-    dictionary.entries.iter().map(|e| e.to.clone()).collect()
+    dictionary
+        .entries
+        .iter()
+        .filter(|e| e.applies_in(&context))
+        .map(|e| e.to.clone())
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// An unscoped, case-insensitive entry; other fields are set with
+    /// `DictionaryEntry { .., ..replacement(from, to) }`
+    fn replacement(from: &str, to: &str) -> DictionaryEntry {
+        DictionaryEntry {
+            from: from.to_string(),
+            to: to.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn scope(app: Option<&str>, language: Option<&str>) -> Option<DictionaryScope> {
+        Some(DictionaryScope {
+            app: app.map(str::to_string),
+            language: language.map(str::to_string),
+        })
+    }
+
     // =========================================================================
     // Whole-word replacement tests (#57)
     // =========================================================================
@@ -435,52 +1074,464 @@ mod tests {
         );
     }
 
+    // =========================================================================
+    // Scoped entry tests
+    // =========================================================================
+
+    fn context(app: Option<&str>, language: Option<&str>) -> DictionaryContext {
+        DictionaryContext {
+            app: app.map(str::to_string),
+            language: language.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_unscoped_entries_always_apply() {
+        let entries = vec![replacement("teh", "the")];
+        assert_eq!(
+            apply_entries("teh cat", &entries, &DictionaryContext::default()),
+            "the cat"
+        );
+        assert_eq!(
+            apply_entries("teh cat", &entries, &context(Some("Slack"), Some("en"))),
+            "the cat"
+        );
+    }
+
+    #[test]
+    fn test_app_scoped_entry_applies_only_in_that_app() {
+        let entries = vec![DictionaryEntry {
+            scope: scope(Some("Slack"), None),
+            ..replacement("pr", "PR")
+        }];
+        assert_eq!(
+            apply_entries("open a pr", &entries, &context(Some("slack"), None)),
+            "open a PR"
+        );
+        assert_eq!(
+            apply_entries("open a pr", &entries, &context(Some("Mail"), None)),
+            "open a pr"
+        );
+        // Unknown app never satisfies an app scope.
+        assert_eq!(
+            apply_entries("open a pr", &entries, &DictionaryContext::default()),
+            "open a pr"
+        );
+    }
+
+    #[test]
+    fn test_language_scope_matches_primary_subtag() {
+        let entries = vec![DictionaryEntry {
+            scope: scope(None, Some("en-US")),
+            ..replacement("colour", "color")
+        }];
+        assert_eq!(
+            apply_entries("the colour", &entries, &context(None, Some("en"))),
+            "the color"
+        );
+        assert_eq!(
+            apply_entries("the colour", &entries, &context(None, Some("de"))),
+            "the colour"
+        );
+    }
+
+    #[test]
+    fn test_scope_with_app_and_language_requires_both() {
+        let entries = vec![DictionaryEntry {
+            scope: scope(Some("Mail"), Some("de")),
+            ..replacement("ticket", "Ticket")
+        }];
+        assert_eq!(
+            apply_entries("ticket", &entries, &context(Some("Mail"), Some("de"))),
+            "Ticket"
+        );
+        assert_eq!(
+            apply_entries("ticket", &entries, &context(Some("Mail"), Some("en"))),
+            "ticket"
+        );
+    }
+
+    #[test]
+    fn test_blank_scope_normalises_to_unscoped() {
+        let scope = DictionaryScope {
+            app: Some("  ".to_string()),
+            language: None,
+        };
+        assert_eq!(scope.normalised(), None);
+    }
+
+    #[test]
+    fn test_same_from_in_different_scopes_is_not_duplicate() {
+        let slack = DictionaryEntry {
+            scope: scope(Some("Slack"), None),
+            ..replacement("pr", "PR")
+        };
+        let mail = DictionaryEntry {
+            scope: scope(Some("Mail"), None),
+            ..replacement("pr", "press release")
+        };
+        let slack_again = DictionaryEntry {
+            scope: scope(Some(" slack "), None),
+            ..replacement("PR", "pull request")
+        };
+        assert_ne!(slack.duplicate_key(), mail.duplicate_key());
+        assert_eq!(slack.duplicate_key(), slack_again.duplicate_key());
+    }
+
+    #[test]
+    fn test_legacy_entry_without_scope_deserialises() {
+        let json = r#"{"entries":[{"from":"teh","to":"the","caseSensitive":false}]}"#;
+        let dict: Dictionary = serde_json::from_str(json).unwrap();
+        assert!(dict.entries[0].scope.is_none());
+
+        // And unscoped entries are written back without a scope key.
+        let out = serde_json::to_string(&dict).unwrap();
+        assert!(!out.contains("scope"));
+    }
+
+    // =========================================================================
+    // Ordering / priority tests
+    // =========================================================================
+
+    #[test]
+    fn test_overlapping_rules_do_not_ping_pong() {
+        // Each rule rewrites the other's output; neither may undo the other.
+        let entries = vec![
+            replacement("ML", "machine learning"),
+            replacement("machine learning", "ML"),
+        ];
+        let ctx = DictionaryContext::default();
+        assert_eq!(
+            apply_entries("ML and machine learning", &entries, &ctx),
+            "machine learning and ML"
+        );
+    }
+
+    #[test]
+    fn test_higher_priority_claims_overlapping_span() {
+        let entries = vec![
+            replacement("machine", "device"),
+            DictionaryEntry {
+                priority: 10,
+                ..replacement("machine learning", "ML")
+            },
+        ];
+        let ctx = DictionaryContext::default();
+        assert_eq!(
+            apply_entries("machine learning on a machine", &entries, &ctx),
+            "ML on a device"
+        );
+
+        // Flip the priorities: the shorter rule now wins the shared span.
+        let entries = vec![
+            DictionaryEntry {
+                priority: 10,
+                ..replacement("machine", "device")
+            },
+            replacement("machine learning", "ML"),
+        ];
+        assert_eq!(
+            apply_entries("machine learning on a machine", &entries, &ctx),
+            "device learning on a device"
+        );
+    }
+
+    #[test]
+    fn test_equal_priority_keeps_list_order() {
+        let entries = vec![replacement("new york", "NYC"), replacement("york", "York")];
+        assert_eq!(
+            apply_entries("new york and york", &entries, &DictionaryContext::default()),
+            "NYC and York"
+        );
+    }
+
+    #[test]
+    fn test_rule_output_is_not_rewritten_by_later_rule() {
+        let entries = vec![
+            DictionaryEntry {
+                priority: 1,
+                ..replacement("teh", "the")
+            },
+            replacement("the", "THE"),
+        ];
+        assert_eq!(
+            apply_entries("teh cat", &entries, &DictionaryContext::default()),
+            "the cat"
+        );
+    }
+
+    #[test]
+    fn test_chained_entries_do_not_cascade() {
+        // Applied top to bottom over the running text, "colour" would become
+        // "color" and then "hue". Each entry now sees only the original text.
+        let entries = vec![replacement("colour", "color"), replacement("color", "hue")];
+        assert_eq!(
+            apply_entries("colour and color", &entries, &DictionaryContext::default()),
+            "color and hue"
+        );
+    }
+
+    #[test]
+    fn test_missing_priority_defaults_to_zero() {
+        let json = r#"{"from":"teh","to":"the","caseSensitive":false}"#;
+        let entry: DictionaryEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(entry.priority, 0);
+    }
+
+    // =========================================================================
+    // Fuzzy matching tests
+    // =========================================================================
+
+    #[test]
+    fn test_fuzzy_misspellings_collapse_to_target() {
+        let entries = vec![DictionaryEntry {
+            fuzzy: true,
+            ..replacement("Seersha", "Saoirse")
+        }];
+        let ctx = DictionaryContext::default();
+        for heard in ["Seersha", "Sersha", "Searsha", "Sirsha", "seersha"] {
+            assert_eq!(
+                apply_entries(&format!("Thanks {heard}, see you soon."), &entries, &ctx),
+                "Thanks Saoirse, see you soon.",
+                "variant {heard}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_leaves_dissimilar_words_alone() {
+        let entries = vec![DictionaryEntry {
+            fuzzy: true,
+            ..replacement("Seersha", "Saoirse")
+        }];
+        let ctx = DictionaryContext::default();
+        assert_eq!(
+            apply_entries("the searcher and her sister", &entries, &ctx),
+            "the searcher and her sister"
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_threshold_is_configurable() {
+        let strict = vec![DictionaryEntry {
+            fuzzy: true,
+            fuzzy_threshold: Some(0.9),
+            ..replacement("Seersha", "Saoirse")
+        }];
+        let ctx = DictionaryContext::default();
+        assert_eq!(apply_entries("hi Sersha", &strict, &ctx), "hi Sersha");
+        assert_eq!(apply_entries("hi Seersha", &strict, &ctx), "hi Saoirse");
+    }
+
+    #[test]
+    fn test_fuzzy_is_opt_in_per_entry() {
+        let mut entry = DictionaryEntry {
+            fuzzy: true,
+            ..replacement("Seersha", "Saoirse")
+        };
+        entry.fuzzy = false;
+        assert_eq!(
+            apply_entries("hi Sersha", &[entry], &DictionaryContext::default()),
+            "hi Sersha"
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_does_not_match_variants_of_to() {
+        // "Sersha" is close to the replacement, not to what is misheard
+        let entries = vec![DictionaryEntry {
+            fuzzy: true,
+            ..replacement("Kubernetes", "Seersha")
+        }];
+        assert_eq!(
+            apply_entries("hi Sersha", &entries, &DictionaryContext::default()),
+            "hi Sersha"
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_respects_case_sensitive() {
+        let entries = vec![DictionaryEntry {
+            case_sensitive: true,
+            fuzzy: true,
+            ..replacement("Seersha", "Saoirse")
+        }];
+        let ctx = DictionaryContext::default();
+        assert_eq!(apply_entries("hi Sirsha", &entries, &ctx), "hi Saoirse");
+        // The lower-case "s" is a third edit, which drops it under the threshold
+        assert_eq!(apply_entries("hi sirsha", &entries, &ctx), "hi sirsha");
+    }
+
+    #[test]
+    fn test_fuzzy_ignored_for_short_entries() {
+        let entries = vec![DictionaryEntry {
+            fuzzy: true,
+            fuzzy_threshold: Some(0.0),
+            ..replacement("ML", "machine learning")
+        }];
+        assert_eq!(
+            apply_entries("check the mail", &entries, &DictionaryContext::default()),
+            "check the mail"
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_threshold_out_of_range_is_rejected() {
+        assert!(
+            DictionaryEntry {
+                fuzzy: true,
+                fuzzy_threshold: Some(1.5),
+                ..replacement("Seersha", "Saoirse")
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            DictionaryEntry {
+                fuzzy: true,
+                fuzzy_threshold: Some(0.8),
+                ..replacement("Seersha", "Saoirse")
+            }
+            .validate()
+            .is_ok()
+        );
+    }
+
+    // =========================================================================
+    // Import / export format tests
+    // =========================================================================
+
+    #[test]
+    fn test_import_csv_with_header_aliases() {
+        let (entries, errors) = parse_import("wrong,right\nteh,the\nrecieve,receive\n").unwrap();
+        assert!(errors.is_empty());
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].from, "teh");
+        assert_eq!(entries[1].to, "receive");
+    }
+
+    #[test]
+    fn test_import_tsv_detected() {
+        let content = "from\tto\tcase_sensitive\nAPI\tAPI\ttrue\ngithub\tGitHub\tno\n";
+        let (entries, errors) = parse_import(content).unwrap();
+        assert!(errors.is_empty());
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].case_sensitive);
+        assert!(!entries[1].case_sensitive);
+    }
+
+    #[test]
+    fn test_import_csv_without_header_is_positional() {
+        let (entries, errors) = parse_import("teh,the\nk8s,Kubernetes").unwrap();
+        assert!(errors.is_empty());
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].from, "teh");
+    }
+
+    #[test]
+    fn test_import_csv_reports_bad_rows_with_line_numbers() {
+        let content = "from,to,case\nteh,the\n,missing\nok,fine,maybe\n\nlast,row\n";
+        let (entries, errors) = parse_import(content).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            errors.iter().map(|e| e.line).collect::<Vec<_>>(),
+            vec![Some(3), Some(4)]
+        );
+        assert!(errors[0].message.contains("from"));
+        assert!(errors[1].message.contains("maybe"));
+    }
+
+    #[test]
+    fn test_import_csv_header_missing_to_column_is_rejected() {
+        assert!(parse_import("from,notes\nteh,x\n").is_err());
+    }
+
+    #[test]
+    fn test_import_json_array_and_invalid_entries() {
+        let content = r#"[{"from":"teh","to":"the","caseSensitive":false},
+                          {"from":"","to":"x","caseSensitive":false}]"#;
+        let (entries, errors) = parse_import(content).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, None);
+        assert!(errors[0].message.starts_with("Entry 2"));
+    }
+
+    #[test]
+    fn test_import_invalid_json_fails() {
+        assert!(parse_import("{not json").is_err());
+    }
+
+    #[test]
+    fn test_csv_export_round_trips() {
+        let entries = vec![
+            DictionaryEntry {
+                from: "=cmd".to_string(),
+                to: "command, \"quoted\"".to_string(),
+                case_sensitive: true,
+                scope: Some(DictionaryScope {
+                    app: Some("Slack".to_string()),
+                    language: Some("en".to_string()),
+                }),
+                priority: 5,
+                ..Default::default()
+            },
+            replacement("teh", "the"),
+        ];
+
+        let csv = export_csv(&entries).unwrap();
+        // The formula trigger is neutralised in the file itself.
+        assert!(csv.contains("'=cmd"));
+
+        let (restored, errors) = parse_import(&csv).unwrap();
+        assert!(errors.is_empty());
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored[0].from, "=cmd");
+        assert_eq!(restored[0].to, "command, \"quoted\"");
+        assert!(restored[0].case_sensitive);
+        assert_eq!(restored[0].scope, entries[0].scope);
+        assert_eq!(restored[0].priority, 5);
+        assert_eq!(restored[1].scope, None);
+    }
+
+    #[test]
+    fn test_csv_export_round_trips_every_guarded_prefix() {
+        let values = ["=sum", "+1", "-flag", "@user", "\tindented", "\rreturn"];
+        let entries: Vec<_> = values.iter().map(|v| replacement(v, v)).collect();
+
+        let (restored, errors) = parse_import(&export_csv(&entries).unwrap()).unwrap();
+        assert!(errors.is_empty());
+        let round_tripped: Vec<_> = restored.iter().map(|e| e.from.as_str()).collect();
+        assert_eq!(round_tripped, values);
+        assert!(restored.iter().all(|e| e.from == e.to));
+    }
+
     // =========================================================================
     // Dictionary entry validation tests
     // =========================================================================
 
     #[test]
     fn test_entry_validation() {
-        let empty_from = DictionaryEntry {
-            from: "".to_string(),
-            to: "replacement".to_string(),
-            case_sensitive: false,
-        };
+        let empty_from = replacement("", "replacement");
         assert!(empty_from.from.trim().is_empty());
 
-        let valid_entry = DictionaryEntry {
-            from: "teh".to_string(),
-            to: "the".to_string(),
-            case_sensitive: false,
-        };
+        let valid_entry = replacement("teh", "the");
         assert!(!valid_entry.from.trim().is_empty());
         assert!(!valid_entry.to.trim().is_empty());
     }
 
     #[test]
     fn test_entry_validation_whitespace_only() {
-        let whitespace_from = DictionaryEntry {
-            from: "   ".to_string(),
-            to: "replacement".to_string(),
-            case_sensitive: false,
-        };
+        let whitespace_from = replacement("   ", "replacement");
         assert!(whitespace_from.from.trim().is_empty());
 
-        let whitespace_to = DictionaryEntry {
-            from: "valid".to_string(),
-            to: "   ".to_string(),
-            case_sensitive: false,
-        };
+        let whitespace_to = replacement("valid", "   ");
         assert!(whitespace_to.to.trim().is_empty());
     }
 
     #[test]
     fn test_entry_validation_with_newlines() {
-        let entry_with_newline = DictionaryEntry {
-            from: "from\ntext".to_string(),
-            to: "to\ntext".to_string(),
-            case_sensitive: false,
-        };
+        let entry_with_newline = replacement("from\ntext", "to\ntext");
         assert!(!entry_with_newline.from.trim().is_empty());
         assert!(!entry_with_newline.to.trim().is_empty());
     }
@@ -491,11 +1542,7 @@ mod tests {
 
     #[test]
     fn test_dictionary_entry_serialisation() {
-        let entry = DictionaryEntry {
-            from: "teh".to_string(),
-            to: "the".to_string(),
-            case_sensitive: false,
-        };
+        let entry = replacement("teh", "the");
         let json = serde_json::to_string(&entry).unwrap();
         assert!(json.contains("\"from\":\"teh\""));
         assert!(json.contains("\"to\":\"the\""));
@@ -514,9 +1561,8 @@ mod tests {
     #[test]
     fn test_dictionary_entry_clone() {
         let entry = DictionaryEntry {
-            from: "original".to_string(),
-            to: "replacement".to_string(),
             case_sensitive: true,
+            ..replacement("original", "replacement")
         };
         let cloned = entry.clone();
         assert_eq!(entry.from, cloned.from);
@@ -537,16 +1583,8 @@ mod tests {
     #[test]
     fn test_dictionary_serialisation() {
         let mut dict = Dictionary::default();
-        dict.entries.push(DictionaryEntry {
-            from: "teh".to_string(),
-            to: "the".to_string(),
-            case_sensitive: false,
-        });
-        dict.entries.push(DictionaryEntry {
-            from: "recieve".to_string(),
-            to: "receive".to_string(),
-            case_sensitive: false,
-        });
+        dict.entries.push(replacement("teh", "the"));
+        dict.entries.push(replacement("recieve", "receive"));
 
         let json = serde_json::to_string_pretty(&dict).unwrap();
         let restored: Dictionary = serde_json::from_str(&json).unwrap();
@@ -568,6 +1606,7 @@ mod tests {
     // Dictionary path tests
     // =========================================================================
 
+    #[serial_test::serial]
     #[test]
     fn test_dictionary_path_format() {
         let path = get_dictionary_path();
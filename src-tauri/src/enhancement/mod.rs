@@ -5,6 +5,7 @@
 //! - Any OpenAI-compatible endpoint (LM Studio, llama.cpp server, vLLM, etc.)
 
 pub mod context;
+pub mod model_cache;
 pub mod ollama;
 pub mod openai_compat;
 pub mod prompts;
@@ -12,7 +13,7 @@ pub mod prompts;
 pub use context::{
     ContextCapture, build_context, build_enhancement_context, get_clipboard_context,
 };
-pub use ollama::OllamaClient;
+pub use ollama::{OllamaClient, OllamaError};
 pub use openai_compat::OpenAiCompatClient;
 pub use prompts::{
     PromptTemplate, delete_custom_prompt_cmd, get_all_prompts, get_builtin_prompts_cmd,
@@ -20,9 +21,52 @@ pub use prompts::{
 };
 
 use crate::error::Error;
+use futures_util::future::BoxFuture;
 use parking_lot::Mutex;
+use serde::Serialize;
 use std::sync::OnceLock;
 
+/// Event emitted when the configured enhancement model is no longer
+/// installed in Ollama (payload: [`EnhancementModelMissing`])
+pub const ENHANCEMENT_MODEL_MISSING_EVENT: &str = "enhancement-model-missing";
+
+/// Payload of [`ENHANCEMENT_MODEL_MISSING_EVENT`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnhancementModelMissing {
+    /// The missing model
+    pub model: String,
+}
+
+/// Rewrites a transcript with an LLM.
+///
+/// [`ActiveEnhancer`] dispatches to the configured backend via [`enhance_text`];
+/// tests and library callers can substitute their own implementation.
+pub trait Enhancer: Send + Sync {
+    /// Enhance `text` with `model`, using a prompt template containing `{text}`
+    fn enhance<'a>(
+        &'a self,
+        text: String,
+        model: String,
+        prompt: String,
+    ) -> BoxFuture<'a, Result<String, Error>>;
+}
+
+/// The active enhancement backend (Ollama or OpenAI-compatible)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ActiveEnhancer;
+
+impl Enhancer for ActiveEnhancer {
+    fn enhance<'a>(
+        &'a self,
+        text: String,
+        model: String,
+        prompt: String,
+    ) -> BoxFuture<'a, Result<String, Error>> {
+        Box::pin(enhance_text(text, model, prompt))
+    }
+}
+
 /// Which enhancement backend is active
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BackendType {
@@ -114,21 +158,89 @@ pub fn configure_backend(
     b.backend_type
 }
 
+/// Load the configured Ollama model into memory ahead of the first enhancement.
+///
+/// The enhancement counterpart of `warmup_transcription`: Ollama loads models
+/// lazily, so without this the first enhanced transcription after launch (or
+/// after switching model) also waits for the load. Runs in the background after
+/// `delay`; an unreachable Ollama is logged and otherwise ignored.
+pub fn warmup_model(model: String, delay: std::time::Duration) {
+    let client = get_backend().lock().ollama.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(delay).await;
+        let start = std::time::Instant::now();
+        match client.load_model(&model).await {
+            Ok(()) => tracing::info!(
+                "Enhancement model '{}' warmed up in {:.2}s",
+                model,
+                start.elapsed().as_secs_f64()
+            ),
+            Err(e) => tracing::warn!("Enhancement model '{}' warmup failed: {}", model, e),
+        }
+    });
+}
+
+/// Whether `model` is among Ollama's `installed` model names. A name
+/// without a tag means `:latest`, as in Ollama itself.
+pub fn is_model_installed(installed: &[String], model: &str) -> bool {
+    let with_tag = |name: &str| {
+        let name = name.trim();
+        if name.contains(':') {
+            name.to_string()
+        } else {
+            format!("{name}:latest")
+        }
+    };
+    let wanted = with_tag(model);
+    installed.iter().any(|name| with_tag(name) == wanted)
+}
+
+/// Check that the configured Ollama model is still installed.
+///
+/// Catches a model removed with `ollama rm` before the next enhancement
+/// fails on it: logs a warning and emits [`ENHANCEMENT_MODEL_MISSING_EVENT`].
+/// Runs in the background after `delay`; an unreachable Ollama is only
+/// logged, since the model may well be there once it starts.
+pub fn check_model_installed(model: String, delay: std::time::Duration) {
+    let client = get_backend().lock().ollama.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(delay).await;
+        match model_cache::fetch(&client).await {
+            Ok(installed) if !is_model_installed(&installed, &model) => {
+                tracing::warn!(
+                    "Enhancement model '{}' is not installed in Ollama; enhancement will be skipped",
+                    model
+                );
+                crate::app_handle::emit(
+                    ENHANCEMENT_MODEL_MISSING_EVENT,
+                    EnhancementModelMissing { model },
+                );
+            }
+            Ok(_) => tracing::debug!("Enhancement model '{}' is installed", model),
+            Err(e) => tracing::debug!("Could not check enhancement model '{}': {}", model, e),
+        }
+    });
+}
+
 // --- Tauri Commands ---
 
 /// Check if the Ollama server is available
+///
+/// The result is reused for a few seconds (see [`model_cache`]).
 #[tauri::command]
 pub async fn check_ollama_available() -> bool {
     let client = get_backend().lock().ollama.clone();
-    client.is_available().await
+    model_cache::is_available(&client).await
 }
 
 /// List available Ollama models
+///
+/// Returns the last-known list immediately when one is cached and refreshes in
+/// the background, emitting `ollama-models-updated` if the list changed.
 #[tauri::command]
 pub async fn list_ollama_models() -> Result<Vec<String>, Error> {
     let client = get_backend().lock().ollama.clone();
-    client
-        .list_models()
+    model_cache::list_models(client)
         .await
         .map_err(|e| {
             tracing::error!("Failed to list Ollama models: {}", e);
@@ -173,7 +285,8 @@ pub async fn list_openai_compat_models() -> Result<Vec<String>, Error> {
 /// Ollama and OpenAI-compat backends use this single-message format.
 ///
 /// The public signature `(text, model, prompt)` is unchanged; only internal
-/// dispatch changed.
+/// dispatch changed. A model Ollama does not have fails with
+/// [`Error::EnhancementModelMissing`].
 #[tauri::command]
 pub async fn enhance_text(text: String, model: String, prompt: String) -> Result<String, Error> {
     if text.is_empty() {
@@ -197,13 +310,19 @@ pub async fn enhance_text(text: String, model: String, prompt: String) -> Result
     );
 
     let result = match backend_type {
-        BackendType::Ollama => ollama
-            .enhance_text(&text, &model, &prompt)
-            .await
-            .map_err(|e| {
-                tracing::error!("Ollama enhancement failed: {}", e);
-                format!("Enhancement failed: {}", e)
-            })?,
+        BackendType::Ollama => {
+            ollama
+                .enhance_text(&text, &model, &prompt)
+                .await
+                .map_err(|e| -> Error {
+                    if let Some(OllamaError::ModelNotFound(_)) = e.downcast_ref::<OllamaError>() {
+                        tracing::error!("Enhancement model '{}' is not installed in Ollama", model);
+                        return Error::EnhancementModelMissing(model.clone());
+                    }
+                    tracing::error!("Ollama enhancement failed: {}", e);
+                    format!("Enhancement failed: {}", e).into()
+                })?
+        }
         BackendType::OpenAiCompat => {
             let client = openai_compat
                 .ok_or_else(|| "OpenAI-compatible backend not configured".to_string())?;
@@ -226,6 +345,44 @@ pub async fn enhance_text(text: String, model: String, prompt: String) -> Result
     Ok(result)
 }
 
+/// Run a prompt over sample text, for a live preview in the prompt editor.
+///
+/// `prompt_id_or_template` is a saved prompt's ID or a template being
+/// written. Its context variables are filled in as for a recording, then it
+/// goes through [`enhance_text`] with `model`, or the configured model when
+/// `None`. Nothing is saved.
+#[tauri::command]
+pub async fn preview_prompt(
+    prompt_id_or_template: String,
+    sample_text: String,
+    model: Option<String>,
+) -> Result<String, Error> {
+    let template =
+        prompts::resolve_prompt_template(&prompt_id_or_template, &prompts::get_all_prompts());
+    if !template.contains("{text}") {
+        return Err(
+            "Prompt template must contain a {text} placeholder for the sample text"
+                .to_string()
+                .into(),
+        );
+    }
+
+    let config = crate::config::get_config()?;
+    let model = match model.filter(|m| !m.trim().is_empty()) {
+        Some(model) => model,
+        None => config.enhancement.model,
+    };
+    // Thoth's own window is frontmost while previewing, so {app} stays blank.
+    // There is no transcript, so {language} is the configured one, if set.
+    let language = Some(config.transcription.language).filter(|l| !l.is_empty() && l != "auto");
+    let prompt = prompts::render_prompt_variables(
+        &template,
+        &prompts::PromptVariables::capture(None, None, language),
+    );
+
+    enhance_text(sample_text, model, prompt).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -284,4 +441,15 @@ mod tests {
         );
         assert_eq!(bt, BackendType::Ollama);
     }
+
+    #[test]
+    fn test_is_model_installed_matches_latest_tag() {
+        let installed = vec!["llama3.2:latest".to_string(), "qwen2.5:7b".to_string()];
+        assert!(is_model_installed(&installed, "llama3.2"));
+        assert!(is_model_installed(&installed, "llama3.2:latest"));
+        assert!(is_model_installed(&installed, "qwen2.5:7b"));
+        assert!(!is_model_installed(&installed, "qwen2.5"));
+        assert!(!is_model_installed(&installed, "mistral"));
+        assert!(!is_model_installed(&[], "llama3.2"));
+    }
 }
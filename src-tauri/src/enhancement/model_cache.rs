@@ -0,0 +1,154 @@
+//! Last-known Ollama model list and availability, served without waiting on
+//! the server.
+//!
+//! Listing models hits `/api/tags`, which stalls the Settings UI for the full
+//! request timeout when Ollama is down or busy cold-starting a model. The list
+//! is persisted to `~/.thoth/ollama_models.json`; [`list_ollama_models`]
+//! returns it immediately and refreshes in the background, emitting
+//! `ollama-models-updated` when the fresh list differs.
+//!
+//! [`list_ollama_models`]: super::list_ollama_models
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use super::OllamaClient;
+
+/// Event emitted with the fresh `Vec<String>` after a background refresh
+pub const MODELS_UPDATED_EVENT: &str = "ollama-models-updated";
+
+/// How long an availability check result is reused
+const AVAILABILITY_TTL: Duration = Duration::from_secs(10);
+
+/// Model list persisted between runs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CachedModelList {
+    /// Unix seconds when the list was fetched
+    fetched_at: u64,
+    /// Server the list came from; a cache for another URL is ignored
+    base_url: String,
+    /// Model names as returned by Ollama
+    models: Vec<String>,
+}
+
+/// Last availability result: (base URL, available, checked at)
+static AVAILABILITY: parking_lot::Mutex<Option<(String, bool, Instant)>> =
+    parking_lot::Mutex::new(None);
+
+/// Set while a background refresh is in flight, so repeated opens of the
+/// Settings pane do not pile up requests against a slow server.
+static REFRESHING: AtomicBool = AtomicBool::new(false);
+
+fn cache_path() -> PathBuf {
+    crate::storage::data_dir_or_fallback().join("ollama_models.json")
+}
+
+fn load_cache(base_url: &str) -> Option<CachedModelList> {
+    let data = std::fs::read_to_string(cache_path()).ok()?;
+    let cached: CachedModelList = serde_json::from_str(&data).ok()?;
+    (cached.base_url == base_url).then_some(cached)
+}
+
+fn save_cache(base_url: &str, models: &[String]) {
+    let cached = CachedModelList {
+        fetched_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        base_url: base_url.to_string(),
+        models: models.to_vec(),
+    };
+    let path = cache_path();
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| {
+            let data = serde_json::to_string_pretty(&cached).map_err(std::io::Error::other)?;
+            std::fs::write(&path, data)
+        });
+    if let Err(e) = result {
+        tracing::warn!("Failed to cache Ollama model list: {}", e);
+    }
+}
+
+/// Fetch the model list from the server and update the cache.
+pub(super) async fn fetch(client: &OllamaClient) -> anyhow::Result<Vec<String>> {
+    let models = client.list_models().await?;
+    save_cache(client.base_url(), &models);
+    record_availability(client.base_url(), true);
+    Ok(models)
+}
+
+/// List models, preferring the cached list.
+///
+/// With a cache for this server, returns it at once and refreshes in the
+/// background. Without one (first run, or the URL changed) it fetches inline.
+pub async fn list_models(client: OllamaClient) -> anyhow::Result<Vec<String>> {
+    let Some(cached) = load_cache(client.base_url()) else {
+        return fetch(&client).await;
+    };
+
+    if !REFRESHING.swap(true, Ordering::SeqCst) {
+        let previous = cached.models.clone();
+        tauri::async_runtime::spawn(async move {
+            match fetch(&client).await {
+                Ok(models) if models != previous => {
+                    tracing::debug!("Ollama model list changed ({} models)", models.len());
+                    crate::app_handle::emit(MODELS_UPDATED_EVENT, models);
+                }
+                Ok(_) => {}
+                Err(e) => tracing::debug!("Background Ollama model refresh failed: {}", e),
+            }
+            REFRESHING.store(false, Ordering::SeqCst);
+        });
+    }
+
+    Ok(cached.models)
+}
+
+fn record_availability(base_url: &str, available: bool) {
+    *AVAILABILITY.lock() = Some((base_url.to_string(), available, Instant::now()));
+}
+
+/// Check availability, reusing a result younger than [`AVAILABILITY_TTL`].
+pub async fn is_available(client: &OllamaClient) -> bool {
+    let recent = AVAILABILITY
+        .lock()
+        .as_ref()
+        .filter(|(url, _, at)| url == client.base_url() && at.elapsed() < AVAILABILITY_TTL)
+        .map(|(_, available, _)| *available);
+    if let Some(available) = recent {
+        return available;
+    }
+
+    let available = client.is_available().await;
+    record_availability(client.base_url(), available);
+    available
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_model_list_roundtrip() {
+        let cached = CachedModelList {
+            fetched_at: 1_700_000_000,
+            base_url: "http://localhost:11434".to_string(),
+            models: vec!["llama3.2".to_string(), "qwen2.5:7b".to_string()],
+        };
+        let json = serde_json::to_string(&cached).unwrap();
+        let restored: CachedModelList = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, cached);
+    }
+
+    #[tokio::test]
+    async fn test_availability_result_is_reused_within_ttl() {
+        // Nothing listens on this port; a real check would return false.
+        let client = OllamaClient::with_base_url("http://127.0.0.1:9".to_string());
+        record_availability(client.base_url(), true);
+        assert!(is_available(&client).await);
+    }
+}
@@ -65,6 +65,9 @@ pub enum OllamaError {
     #[error("Server error ({status}): {message}")]
     ServerError { status: u16, message: String },
 
+    #[error("Model '{0}' not found")]
+    ModelNotFound(String),
+
     #[error("Failed to parse response: {0}")]
     ParseError(String),
 
@@ -129,6 +132,11 @@ impl OllamaClient {
         self.default_model = Some(model.into());
     }
 
+    /// Get the server base URL
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// Get the configured timeout
     pub fn timeout(&self) -> Duration {
         self.timeout
@@ -202,6 +210,10 @@ impl OllamaClient {
                 .text()
                 .await
                 .unwrap_or_else(|_| "unknown error".to_string());
+            // A model that was never pulled, or was removed with `ollama rm`
+            if status == 404 && message.contains("not found") {
+                return Err(OllamaError::ModelNotFound(request.model.clone()));
+            }
             return Err(OllamaError::ServerError { status, message });
         }
 
@@ -213,6 +225,25 @@ impl OllamaClient {
         Ok(generate_response.response)
     }
 
+    /// Load a model into memory without generating anything.
+    ///
+    /// Ollama treats a generate request with an empty prompt as a load request,
+    /// so the model is resident for the next real request. Not retried: this is
+    /// a best-effort warmup.
+    pub async fn load_model(&self, model: &str) -> Result<()> {
+        let request = GenerateRequest {
+            model: model.to_string(),
+            prompt: String::new(),
+            system: None,
+            temperature: None,
+            stream: false,
+        };
+        self.send_generate_request(&request)
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow!(e))
+    }
+
     /// Generate text using the specified model with retry logic
     ///
     /// Retries up to 3 times with exponential backoff (100ms, 200ms, 400ms).
@@ -260,6 +291,12 @@ impl OllamaClient {
                     }
                     return Ok(response);
                 }
+                // Retrying cannot bring a missing model back; keep the error
+                // typed so callers can tell it apart
+                Err(e @ OllamaError::ModelNotFound(_)) => {
+                    tracing::error!("Ollama request failed: {}", e);
+                    return Err(anyhow!(e));
+                }
                 Err(e) => {
                     let is_retryable = match &e {
                         OllamaError::ConnectionFailed(_) | OllamaError::Timeout(_) => true,
@@ -388,6 +425,25 @@ mod tests {
         assert!(json.contains("\"temperature\":0.3"));
     }
 
+    #[tokio::test]
+    async fn test_load_model_sends_empty_prompt() {
+        let mut server = mockito::Server::new_async().await;
+        let load_mock = server
+            .mock("POST", "/api/generate")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"model":"llama3.2","prompt":"","stream":false}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"response":"","done":true}"#)
+            .create_async()
+            .await;
+
+        let client = OllamaClient::with_base_url(server.url());
+        client.load_model("llama3.2").await.unwrap();
+        load_mock.assert_async().await;
+    }
+
     #[test]
     fn test_error_display() {
         let err = OllamaError::ConnectionFailed("connection refused".to_string());
@@ -407,6 +463,30 @@ mod tests {
             last_error: "timeout".to_string(),
         };
         assert_eq!(err.to_string(), "All 3 retry attempts failed: timeout");
+
+        let err = OllamaError::ModelNotFound("llama3.2".to_string());
+        assert_eq!(err.to_string(), "Model 'llama3.2' not found");
+    }
+
+    #[tokio::test]
+    async fn test_missing_model_is_typed_and_not_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let generate_mock = server
+            .mock("POST", "/api/generate")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error":"model \"llama3.2\" not found, try pulling it first"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = OllamaClient::with_base_url(server.url());
+        let err = client.generate("llama3.2", "hello").await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<OllamaError>(),
+            Some(OllamaError::ModelNotFound(model)) if model == "llama3.2"
+        ));
+        generate_mock.assert_async().await;
     }
 
     #[test]
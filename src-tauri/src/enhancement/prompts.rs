@@ -16,7 +16,8 @@ pub struct PromptTemplate {
     pub id: String,
     /// Human-readable name
     pub name: String,
-    /// The prompt template with `{text}` placeholder
+    /// The prompt template with `{text}` placeholder, optionally using the
+    /// context variables described on [`PromptVariables`]
     pub template: String,
     /// Whether this is a built-in prompt (cannot be deleted)
     pub is_builtin: bool,
@@ -31,6 +32,14 @@ pub fn get_builtin_prompts() -> Vec<PromptTemplate> {
             template: "Fix any grammar and spelling mistakes in the following text. Keep the original meaning, tone, and length. Do not add extra content or explanations. Only output the corrected text:\n\n{text}".to_string(),
             is_builtin: true,
         },
+        PromptTemplate {
+            id: "restore-punctuation".to_string(),
+            name: "Restore Punctuation".to_string(),
+            // Deliberately narrower than fix-grammar: for models that drop
+            // punctuation, where a grammar pass would over-edit the wording.
+            template: "Add punctuation and capitalisation to the following dictated text. Do not add, remove, reorder, or change any words, including filler words and informal phrasing, and do not fix grammar or spelling. Only insert punctuation marks and change letter case. Output only the punctuated text with no preamble, no explanation, and no quotes:\n\n{text}".to_string(),
+            is_builtin: true,
+        },
         PromptTemplate {
             id: "make-professional".to_string(),
             name: "Make Professional".to_string(),
@@ -79,10 +88,7 @@ pub fn get_builtin_prompts() -> Vec<PromptTemplate> {
 
 /// Get the path to custom prompts file
 pub fn get_custom_prompts_path() -> PathBuf {
-    dirs::home_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join(".thoth")
-        .join("prompts.json")
+    crate::storage::data_dir_or_fallback().join("prompts.json")
 }
 
 /// Load custom prompts from disk
@@ -92,10 +98,20 @@ pub fn load_custom_prompts(path: &PathBuf) -> Vec<PromptTemplate> {
     }
 
     match fs::read_to_string(path) {
-        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
-            tracing::warn!("Failed to parse custom prompts: {}", e);
-            Vec::new()
-        }),
+        Ok(content) => {
+            let prompts: Vec<PromptTemplate> = serde_json::from_str(&content).unwrap_or_else(|e| {
+                tracing::warn!("Failed to parse custom prompts: {}", e);
+                Vec::new()
+            });
+            // A hand-edited file can bypass save-time validation.
+            for prompt in prompts.iter().filter(|p| !p.template.contains("{text}")) {
+                tracing::warn!(
+                    "Custom prompt '{}' has no {{text}} placeholder; enhancement will not see the transcription",
+                    prompt.id
+                );
+            }
+            prompts
+        }
         Err(e) => {
             tracing::warn!("Failed to read custom prompts file: {}", e);
             Vec::new()
@@ -155,6 +171,66 @@ pub fn apply_prompt(template: &PromptTemplate, text: &str) -> String {
     template.template.replace("{text}", text)
 }
 
+/// Context values for the variables a template may use besides `{text}`:
+/// `{language}`, `{duration}`, `{date}` and `{app}`.
+///
+/// Substituted by [`render_prompt_variables`] before the template reaches the
+/// backend. A variable whose value is unknown renders as an empty string, and
+/// unrecognised `{names}` are left as written, so a template never fails to
+/// render.
+#[derive(Debug, Clone, Default)]
+pub struct PromptVariables {
+    /// Spoken language code, e.g. "en"
+    pub language: Option<String>,
+    /// Recording length in seconds
+    pub duration_seconds: Option<f64>,
+    /// Local date, e.g. "2026-03-14"
+    pub date: Option<String>,
+    /// Name of the application that was frontmost, e.g. "Mail"
+    pub app: Option<String>,
+}
+
+impl PromptVariables {
+    /// Capture today's date alongside the frontmost `app` and the transcript's
+    /// `language` the caller already knows.
+    pub fn capture(
+        duration_seconds: Option<f64>,
+        app: Option<String>,
+        language: Option<String>,
+    ) -> Self {
+        Self {
+            language,
+            duration_seconds,
+            date: Some(chrono::Local::now().format("%Y-%m-%d").to_string()),
+            app,
+        }
+    }
+}
+
+/// Substitute the [`PromptVariables`] into a template, leaving `{text}` for
+/// the backend to fill in.
+pub fn render_prompt_variables(template: &str, vars: &PromptVariables) -> String {
+    let duration = vars
+        .duration_seconds
+        .map(|secs| format!("{:.0} seconds", secs))
+        .unwrap_or_default();
+    template
+        .replace("{language}", vars.language.as_deref().unwrap_or(""))
+        .replace("{duration}", &duration)
+        .replace("{date}", vars.date.as_deref().unwrap_or(""))
+        .replace("{app}", vars.app.as_deref().unwrap_or(""))
+}
+
+/// The template of the prompt whose ID is `id_or_template`, or
+/// `id_or_template` itself when no prompt has that ID.
+pub fn resolve_prompt_template(id_or_template: &str, prompts: &[PromptTemplate]) -> String {
+    prompts
+        .iter()
+        .find(|p| p.id == id_or_template)
+        .map(|p| p.template.clone())
+        .unwrap_or_else(|| id_or_template.to_string())
+}
+
 // ============================================================================
 // Tauri Commands
 // ============================================================================
@@ -182,31 +258,64 @@ pub fn get_custom_prompts_cmd() -> Vec<PromptTemplate> {
     load_custom_prompts(&custom_path)
 }
 
-/// Add or update a custom prompt template
-#[tauri::command]
-pub fn save_custom_prompt_cmd(prompt: PromptTemplate) -> Result<(), Error> {
+/// Suffix appended to a template missing `{text}` when the caller opts in
+const TEXT_PLACEHOLDER_SUFFIX: &str = "\n\nText: {text}";
+
+/// Validate a custom prompt before it is saved.
+///
+/// A template without `{text}` never receives the transcription, so the model
+/// answers the bare instruction and every enhancement produces unrelated
+/// output. Such a template is rejected, or — with `append_missing_placeholder`
+/// — repaired by appending `Text: {text}`.
+fn validate_custom_prompt(
+    prompt: &mut PromptTemplate,
+    append_missing_placeholder: bool,
+) -> Result<(), String> {
     if prompt.is_builtin {
-        return Err("Cannot save a built-in prompt as custom".to_string().into());
+        return Err("Cannot save a built-in prompt as custom".to_string());
     }
 
     if prompt.id.is_empty() {
-        return Err("Prompt ID cannot be empty".to_string().into());
+        return Err("Prompt ID cannot be empty".to_string());
     }
 
     if prompt.name.is_empty() {
-        return Err("Prompt name cannot be empty".to_string().into());
+        return Err("Prompt name cannot be empty".to_string());
     }
 
     if prompt.template.is_empty() {
-        return Err("Prompt template cannot be empty".to_string().into());
+        return Err("Prompt template cannot be empty".to_string());
     }
 
     if !prompt.template.contains("{text}") {
-        return Err("Prompt template must contain {text} placeholder"
-            .to_string()
-            .into());
+        if !append_missing_placeholder {
+            return Err(
+                "Prompt template must contain a {text} placeholder where the \
+                        transcription is inserted; without it the AI never sees what you said"
+                    .to_string(),
+            );
+        }
+        tracing::info!(
+            "Appending missing {{text}} placeholder to prompt {}",
+            prompt.id
+        );
+        prompt.template.push_str(TEXT_PLACEHOLDER_SUFFIX);
     }
 
+    Ok(())
+}
+
+/// Add or update a custom prompt template
+///
+/// Rejects a template without `{text}` unless `append_missing_placeholder` is
+/// set, in which case `Text: {text}` is appended to it.
+#[tauri::command]
+pub fn save_custom_prompt_cmd(
+    mut prompt: PromptTemplate,
+    append_missing_placeholder: Option<bool>,
+) -> Result<(), Error> {
+    validate_custom_prompt(&mut prompt, append_missing_placeholder.unwrap_or(false))?;
+
     let custom_path = get_custom_prompts_path();
     save_custom_prompt(&custom_path, &prompt).map_err(Into::into)
 }
@@ -239,6 +348,17 @@ mod tests {
     // Built-in prompts tests
     // =========================================================================
 
+    #[test]
+    fn test_resolve_prompt_template_by_id_or_literal() {
+        let prompts = get_builtin_prompts();
+        let first = &prompts[0];
+        assert_eq!(resolve_prompt_template(&first.id, &prompts), first.template);
+        assert_eq!(
+            resolve_prompt_template("Shorten this: {text}", &prompts),
+            "Shorten this: {text}"
+        );
+    }
+
     #[test]
     fn test_builtin_prompts_exist() {
         let prompts = get_builtin_prompts();
@@ -291,6 +411,10 @@ mod tests {
             ids.contains(&"fix-grammar"),
             "Should have fix-grammar prompt"
         );
+        assert!(
+            ids.contains(&"restore-punctuation"),
+            "Should have restore-punctuation prompt"
+        );
         assert!(
             ids.contains(&"make-professional"),
             "Should have make-professional prompt"
@@ -313,6 +437,33 @@ mod tests {
     // Apply prompt tests
     // =========================================================================
 
+    #[test]
+    fn test_render_prompt_variables() {
+        let vars = PromptVariables {
+            language: Some("en".to_string()),
+            duration_seconds: Some(12.4),
+            date: Some("2026-03-14".to_string()),
+            app: Some("Mail".to_string()),
+        };
+        let rendered = render_prompt_variables(
+            "Format for {app} ({language}, {duration}, {date}):\n\n{text}",
+            &vars,
+        );
+        assert_eq!(
+            rendered,
+            "Format for Mail (en, 12 seconds, 2026-03-14):\n\n{text}"
+        );
+    }
+
+    #[test]
+    fn test_render_prompt_variables_unknown_values_blank_and_unknown_names_literal() {
+        let rendered = render_prompt_variables(
+            "[{app}] [{language}] {custom} {text}",
+            &PromptVariables::default(),
+        );
+        assert_eq!(rendered, "[] [] {custom} {text}");
+    }
+
     #[test]
     fn test_apply_prompt() {
         let template = PromptTemplate {
@@ -432,6 +583,7 @@ mod tests {
     // Custom prompts path test
     // =========================================================================
 
+    #[serial_test::serial]
     #[test]
     fn test_custom_prompts_path_format() {
         let path = get_custom_prompts_path();
@@ -489,6 +641,44 @@ mod tests {
         assert_eq!(prompts[0].id, "custom1");
     }
 
+    fn custom_prompt(template: &str) -> PromptTemplate {
+        PromptTemplate {
+            id: "custom".to_string(),
+            name: "Custom".to_string(),
+            template: template.to_string(),
+            is_builtin: false,
+        }
+    }
+
+    #[test]
+    fn test_validate_custom_prompt_requires_text_placeholder() {
+        let mut prompt = custom_prompt("Make this formal");
+        let err = validate_custom_prompt(&mut prompt, false).unwrap_err();
+        assert!(err.contains("{text}"));
+        assert_eq!(prompt.template, "Make this formal");
+
+        let mut prompt = custom_prompt("Make this formal: {text}");
+        assert!(validate_custom_prompt(&mut prompt, false).is_ok());
+        assert_eq!(prompt.template, "Make this formal: {text}");
+    }
+
+    #[test]
+    fn test_validate_custom_prompt_appends_placeholder_when_asked() {
+        let mut prompt = custom_prompt("Make this formal");
+        validate_custom_prompt(&mut prompt, true).unwrap();
+        assert_eq!(prompt.template, "Make this formal\n\nText: {text}");
+    }
+
+    #[test]
+    fn test_validate_custom_prompt_rejects_builtin_and_empty_fields() {
+        let mut prompt = custom_prompt("{text}");
+        prompt.is_builtin = true;
+        assert!(validate_custom_prompt(&mut prompt, true).is_err());
+
+        let mut prompt = custom_prompt("");
+        assert!(validate_custom_prompt(&mut prompt, true).is_err());
+    }
+
     #[test]
     fn test_save_custom_prompt_creates_file() {
         let temp_dir = TempDir::new().unwrap();
@@ -12,6 +12,8 @@
 //!   in via `#[from]`, so a command can `?`-propagate a [`DatabaseError`] or an
 //!   `anyhow::Error` directly; `#[error(transparent)]` makes the serialised
 //!   message identical to the source error's own `Display`.
+//! - [`Error::EnhancementModelMissing`] is raised when the enhancement model
+//!   is no longer installed, so the pipeline can tell it from other failures.
 //! - [`Error::Other`] is the context-carrying catch-all. Commands build a
 //!   message with `format!`/`ok_or_else`/string literals and propagate it with
 //!   `?`; the `From<String>`/`From<&str>` impls route it here, preserving the
@@ -31,6 +33,13 @@ pub enum Error {
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 
+    /// The enhancement model is not installed on the server (removed with
+    /// `ollama rm`, say). Carries the model name.
+    #[error(
+        "Enhancement model '{0}' is not installed in Ollama. Pull it with `ollama pull {0}` or choose another model in Settings."
+    )]
+    EnhancementModelMissing(String),
+
     /// A context-carrying message built at the command boundary (the former
     /// `Result<_, String>` payload). Carries the user-facing text verbatim.
     #[error("{0}")]
@@ -101,6 +110,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn enhancement_model_missing_names_the_model() {
+        let e = Error::EnhancementModelMissing("llama3.2".to_string());
+        assert!(e.to_string().contains("'llama3.2' is not installed"));
+        assert!(e.to_string().contains("ollama pull llama3.2"));
+    }
+
     #[test]
     fn anyhow_error_is_transparent() {
         let e: Error = anyhow::anyhow!("ane unavailable").into();
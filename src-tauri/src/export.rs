@@ -1,7 +1,8 @@
 //! Export and search functionality for transcription history.
 //!
 //! Provides commands for searching transcriptions and exporting them
-//! to various formats (JSON, CSV, TXT).
+//! to various formats (JSON, CSV, TXT), and for exporting a session as one
+//! continuous Markdown or plain text document.
 
 use crate::database;
 use crate::error::Error;
@@ -28,6 +29,7 @@ pub struct TranscriptionRecord {
     pub transcription_duration_seconds: Option<f64>,
     pub enhancement_model_name: Option<String>,
     pub enhancement_duration_seconds: Option<f64>,
+    pub detected_language: Option<String>,
 }
 
 /// Export format options.
@@ -37,20 +39,38 @@ pub enum ExportFormat {
     Json,
     Csv,
     Txt,
+    Markdown,
 }
 
 /// Search parameters for filtering transcriptions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SearchParams {
     pub query: Option<String>,
     pub from_date: Option<i64>,
     pub to_date: Option<i64>,
     pub enhanced_only: Option<bool>,
+    /// Only transcriptions dictated in this session
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// Only transcriptions made with this transcription model
+    #[serde(default)]
+    pub model_name: Option<String>,
+    /// Only transcriptions in this language (ISO 639-1, e.g. "en")
+    #[serde(default)]
+    pub language: Option<String>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
 }
 
+/// A transcription model and how many history entries it produced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelUsage {
+    pub model_name: String,
+    pub count: u32,
+}
+
 /// Search result containing records and pagination info.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -115,6 +135,27 @@ fn search_transcriptions_db(params: &SearchParams) -> Result<SearchResult, Strin
         }
     }
 
+    // Session filter
+    if let Some(session_id) = &params.session_id {
+        let param_idx = query_params.len() + 1;
+        where_clauses.push(format!("session_id = ?{}", param_idx));
+        query_params.push(Box::new(session_id.clone()));
+    }
+
+    // Model filter
+    if let Some(model_name) = &params.model_name {
+        let param_idx = query_params.len() + 1;
+        where_clauses.push(format!("transcription_model_name = ?{}", param_idx));
+        query_params.push(Box::new(model_name.clone()));
+    }
+
+    // Language filter
+    if let Some(language) = &params.language {
+        let param_idx = query_params.len() + 1;
+        where_clauses.push(format!("detected_language = ?{}", param_idx));
+        query_params.push(Box::new(language.clone()));
+    }
+
     // Build the WHERE clause
     let where_clause = if where_clauses.is_empty() {
         String::new()
@@ -141,10 +182,11 @@ fn search_transcriptions_db(params: &SearchParams) -> Result<SearchResult, Strin
         SELECT id, text, raw_text, duration_seconds, created_at,
                audio_path, is_enhanced, enhancement_prompt,
                transcription_model_name, transcription_duration_seconds,
-               enhancement_model_name, enhancement_duration_seconds
+               enhancement_model_name, enhancement_duration_seconds,
+               detected_language
         FROM transcriptions
         {}
-        ORDER BY created_at DESC
+        ORDER BY created_at DESC, id DESC
         LIMIT {} OFFSET {}
         "#,
         where_clause, limit, offset
@@ -168,6 +210,72 @@ fn search_transcriptions_db(params: &SearchParams) -> Result<SearchResult, Strin
     })
 }
 
+/// Records fetched per query when an export pages through its matches.
+const EXPORT_PAGE_SIZE: u32 = 1000;
+
+/// Every record matching `params`, newest first, fetched a page at a time.
+///
+/// `params.limit`, when set, caps how many are returned; otherwise nothing is
+/// left out however large the history.
+fn search_all_transcriptions(params: &SearchParams) -> Result<Vec<TranscriptionRecord>, String> {
+    collect_pages(params, search_transcriptions_db)
+}
+
+/// [`search_all_transcriptions`] over any page source.
+fn collect_pages(
+    params: &SearchParams,
+    mut fetch: impl FnMut(&SearchParams) -> Result<SearchResult, String>,
+) -> Result<Vec<TranscriptionRecord>, String> {
+    let mut records = Vec::new();
+    let mut page = SearchParams {
+        offset: Some(params.offset.unwrap_or(0)),
+        ..params.clone()
+    };
+    loop {
+        let remaining = params
+            .limit
+            .map(|limit| limit.saturating_sub(records.len() as u32));
+        if remaining == Some(0) {
+            break;
+        }
+        page.limit = Some(remaining.map_or(EXPORT_PAGE_SIZE, |r| r.min(EXPORT_PAGE_SIZE)));
+
+        let result = fetch(&page)?;
+        let fetched = result.records.len() as u32;
+        records.extend(result.records);
+        if !result.has_more || fetched == 0 {
+            break;
+        }
+        page.offset = Some(page.offset.unwrap_or(0) + fetched);
+    }
+    Ok(records)
+}
+
+/// Distinct transcription models in history with their counts, most used
+/// first. Entries without a recorded model are left out.
+fn models_used(conn: &rusqlite::Connection) -> Result<Vec<ModelUsage>, String> {
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT transcription_model_name, COUNT(*) AS uses
+            FROM transcriptions
+            WHERE transcription_model_name IS NOT NULL
+            GROUP BY transcription_model_name
+            ORDER BY uses DESC, transcription_model_name
+            "#,
+        )
+        .map_err(|e| e.to_string())?;
+    stmt.query_map([], |row| {
+        Ok(ModelUsage {
+            model_name: row.get(0)?,
+            count: row.get(1)?,
+        })
+    })
+    .map_err(|e| e.to_string())?
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(|e| e.to_string())
+}
+
 /// Map a database row to an export TranscriptionRecord.
 fn export_row_to_record(row: &rusqlite::Row) -> rusqlite::Result<TranscriptionRecord> {
     Ok(TranscriptionRecord {
@@ -183,6 +291,7 @@ fn export_row_to_record(row: &rusqlite::Row) -> rusqlite::Result<TranscriptionRe
         transcription_duration_seconds: row.get(9)?,
         enhancement_model_name: row.get(10)?,
         enhancement_duration_seconds: row.get(11)?,
+        detected_language: row.get(12)?,
     })
 }
 
@@ -204,7 +313,8 @@ fn get_transcriptions_by_ids(ids: &[String]) -> Result<Vec<TranscriptionRecord>,
         SELECT id, text, raw_text, duration_seconds, created_at,
                audio_path, is_enhanced, enhancement_prompt,
                transcription_model_name, transcription_duration_seconds,
-               enhancement_model_name, enhancement_duration_seconds
+               enhancement_model_name, enhancement_duration_seconds,
+               detected_language
         FROM transcriptions
         WHERE id IN ({})
         ORDER BY created_at DESC
@@ -277,6 +387,7 @@ fn export_csv(records: &[TranscriptionRecord], path: &Path) -> Result<(), String
         "transcription_duration_seconds",
         "enhancement_model_name",
         "enhancement_duration_seconds",
+        "detected_language",
     ])
     .map_err(|e| format!("Failed to write CSV header: {}", e))?;
 
@@ -310,6 +421,8 @@ fn export_csv(records: &[TranscriptionRecord], path: &Path) -> Result<(), String
             &record
                 .enhancement_duration_seconds
                 .map_or_else(String::new, |d| d.to_string()),
+            // Language code: set by the app, not user-controlled.
+            record.detected_language.as_deref().unwrap_or(""),
         ])
         .map_err(|e| format!("Failed to write CSV row: {}", e))?;
     }
@@ -360,6 +473,11 @@ fn export_txt(records: &[TranscriptionRecord], path: &Path) -> Result<(), String
             writeln!(file, "Enhanced: Yes").map_err(|e| format!("Failed to write: {}", e))?;
         }
 
+        if let Some(language) = &record.detected_language {
+            writeln!(file, "Language: {}", language)
+                .map_err(|e| format!("Failed to write: {}", e))?;
+        }
+
         writeln!(file).map_err(|e| format!("Failed to write: {}", e))?;
         writeln!(file, "{}", record.text).map_err(|e| format!("Failed to write: {}", e))?;
     }
@@ -368,41 +486,119 @@ fn export_txt(records: &[TranscriptionRecord], path: &Path) -> Result<(), String
     Ok(())
 }
 
+/// Formats a stored timestamp for a document heading, in local time.
+///
+/// Falls back to the stored string if it is not RFC 3339.
+fn heading_timestamp(created_at: &str) -> String {
+    DateTime::parse_from_rfc3339(created_at)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|_| created_at.to_string())
+}
+
+/// Joins records into one continuous document, in the order given.
+///
+/// Each transcription becomes its own paragraph; paragraph and line breaks
+/// within a transcription are kept as dictated. Markdown gets the title as a
+/// heading, plain text an underlined title. With `timestamps`, each
+/// transcription is preceded by its time as a subheading.
+fn session_document(
+    title: &str,
+    records: &[TranscriptionRecord],
+    markdown: bool,
+    timestamps: bool,
+) -> String {
+    let mut blocks = Vec::with_capacity(records.len() + 1);
+    if markdown {
+        blocks.push(format!("# {}", title));
+    } else {
+        blocks.push(format!("{}\n{}", title, "=".repeat(title.chars().count())));
+    }
+
+    for record in records {
+        let text = record.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        if timestamps {
+            let time = heading_timestamp(&record.created_at);
+            if markdown {
+                blocks.push(format!("## {}", time));
+            } else {
+                blocks.push(format!("[{}]", time));
+            }
+        }
+        blocks.push(text.to_string());
+    }
+
+    let mut document = blocks.join("\n\n");
+    document.push('\n');
+    document
+}
+
+/// Writes a session document to `path`.
+fn export_document(
+    title: &str,
+    records: &[TranscriptionRecord],
+    path: &Path,
+    markdown: bool,
+    timestamps: bool,
+) -> Result<(), String> {
+    let document = session_document(title, records, markdown, timestamps);
+
+    let mut file = File::create(path).map_err(|e| {
+        tracing::error!("Failed to create export file: {}", e);
+        format!("Failed to create file: {}", e)
+    })?;
+
+    file.write_all(document.as_bytes()).map_err(|e| {
+        tracing::error!("Failed to write document to file: {}", e);
+        format!("Failed to write file: {}", e)
+    })?;
+
+    tracing::info!(
+        "Exported {} records as one document: {:?}",
+        records.len(),
+        path
+    );
+    Ok(())
+}
+
 // =============================================================================
 // Tauri Commands
 // =============================================================================
 
 /// Searches transcription history with full-text search and date filtering.
 ///
-/// # Arguments
-/// * `query` - Optional search text (searches in text and raw_text fields)
-/// * `from_date` - Optional Unix timestamp for start of date range
-/// * `to_date` - Optional Unix timestamp for end of date range
-/// * `enhanced_only` - If true, only return enhanced transcriptions
+/// `params` holds the filters, all optional:
+/// * `query` - Search text (searches in text and raw_text fields)
+/// * `fromDate` / `toDate` - Unix timestamps bounding the date range
+/// * `enhancedOnly` - If true, only return enhanced transcriptions
+/// * `sessionId` - Only return transcriptions from that session
+/// * `modelName` - Only return transcriptions made with that model
+/// * `language` - Only return transcriptions in that language (e.g. "en")
 /// * `limit` - Maximum number of records to return (default: 100)
 /// * `offset` - Number of records to skip for pagination (default: 0)
 #[tauri::command]
-pub fn search_history(
-    query: Option<String>,
-    from_date: Option<i64>,
-    to_date: Option<i64>,
-    enhanced_only: Option<bool>,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> Result<SearchResult, Error> {
-    let params = SearchParams {
-        query,
-        from_date,
-        to_date,
-        enhanced_only,
-        limit,
-        offset,
-    };
-
+pub fn search_history(params: SearchParams) -> Result<SearchResult, Error> {
     tracing::debug!("Searching history with params: {:?}", params);
     search_transcriptions_db(&params).map_err(Into::into)
 }
 
+/// Lists the transcription models used in history, with how many
+/// transcriptions each produced, including models since deleted.
+///
+/// Pair with `search_history`'s `model_name` filter to find everything a
+/// model transcribed, e.g. to re-transcribe it with a better one.
+#[tauri::command]
+pub fn list_models_used() -> Result<Vec<ModelUsage>, Error> {
+    let conn = database::open_connection()?;
+    models_used(&conn).map_err(Into::into)
+}
+
 /// Generic export function that handles record fetching and calls the format-specific exporter.
 fn export_records<F>(
     ids: &[String],
@@ -414,15 +610,7 @@ where
     F: FnOnce(&[TranscriptionRecord], &Path) -> Result<(), String>,
 {
     let records = if ids.is_empty() {
-        let params = search_params.unwrap_or(SearchParams {
-            query: None,
-            from_date: None,
-            to_date: None,
-            enhanced_only: None,
-            limit: Some(10000),
-            offset: Some(0),
-        });
-        search_transcriptions_db(&params)?.records
+        search_all_transcriptions(&search_params.unwrap_or_default())?
     } else {
         get_transcriptions_by_ids(ids)?
     };
@@ -462,6 +650,50 @@ pub fn export_to_txt(
     export_records(&ids, Path::new(&path), search_params, export_txt).map_err(Into::into)
 }
 
+/// Exports a session's transcriptions, oldest first.
+///
+/// Markdown and plain text produce one continuous document titled with the
+/// session name; JSON and CSV write the session's records as the other
+/// exports do.
+///
+/// # Arguments
+/// * `session_id` - Session to export
+/// * `format` - Output format
+/// * `path` - Destination file
+/// * `timestamps` - If true, head each transcription with its time (documents only)
+#[tauri::command]
+pub fn export_session(
+    session_id: String,
+    format: ExportFormat,
+    path: String,
+    timestamps: Option<bool>,
+) -> Result<u32, Error> {
+    let conn = database::open_connection()?;
+    let session = database::session::get_session_with_conn(&conn, &session_id)
+        .map_err(|e| {
+            tracing::error!("export_session failed: {}", e);
+            Error::Database(e)
+        })?
+        .ok_or_else(|| format!("Session not found: {}", session_id))?;
+
+    let params = SearchParams {
+        session_id: Some(session_id),
+        ..SearchParams::default()
+    };
+    let mut records = search_all_transcriptions(&params)?;
+    records.reverse();
+
+    let path = Path::new(&path);
+    let timestamps = timestamps.unwrap_or(false);
+    match format {
+        ExportFormat::Json => export_json(&records, path)?,
+        ExportFormat::Csv => export_csv(&records, path)?,
+        ExportFormat::Txt => export_document(&session.name, &records, path, false, timestamps)?,
+        ExportFormat::Markdown => export_document(&session.name, &records, path, true, timestamps)?,
+    }
+    Ok(records.len() as u32)
+}
+
 /// Gets transcriptions by their IDs.
 ///
 /// # Arguments
@@ -563,6 +795,7 @@ mod tests {
             transcription_duration_seconds: None,
             enhancement_model_name: None,
             enhancement_duration_seconds: None,
+            detected_language: None,
         }];
 
         export_csv(&records, &path).expect("Export should succeed");
@@ -600,6 +833,7 @@ mod tests {
             transcription_duration_seconds: Some(1.0),
             enhancement_model_name: None,
             enhancement_duration_seconds: Some(0.5),
+            detected_language: None,
         }];
 
         export_csv(&records, &path).expect("Export should succeed");
@@ -636,6 +870,7 @@ mod tests {
             transcription_duration_seconds: None,
             enhancement_model_name: None,
             enhancement_duration_seconds: None,
+            detected_language: None,
         };
 
         assert_eq!(record.id, "test-id");
@@ -658,6 +893,7 @@ mod tests {
             transcription_duration_seconds: None,
             enhancement_model_name: None,
             enhancement_duration_seconds: None,
+            detected_language: None,
         };
 
         assert!(record.raw_text.is_none());
@@ -681,6 +917,7 @@ mod tests {
             transcription_duration_seconds: None,
             enhancement_model_name: None,
             enhancement_duration_seconds: None,
+            detected_language: None,
         };
 
         let json = serde_json::to_string(&record).unwrap();
@@ -727,6 +964,10 @@ mod tests {
             serde_json::to_string(&ExportFormat::Txt).unwrap(),
             "\"txt\""
         );
+        assert_eq!(
+            serde_json::to_string(&ExportFormat::Markdown).unwrap(),
+            "\"markdown\""
+        );
     }
 
     #[test]
@@ -756,6 +997,9 @@ mod tests {
             from_date: None,
             to_date: None,
             enhanced_only: None,
+            session_id: None,
+            model_name: None,
+            language: None,
             limit: None,
             offset: None,
         };
@@ -771,6 +1015,9 @@ mod tests {
             from_date: None,
             to_date: None,
             enhanced_only: None,
+            session_id: None,
+            model_name: None,
+            language: None,
             limit: Some(50),
             offset: Some(10),
         };
@@ -787,6 +1034,9 @@ mod tests {
             from_date: Some(1705311000), // Unix timestamp
             to_date: Some(1705397400),
             enhanced_only: Some(true),
+            session_id: None,
+            model_name: None,
+            language: None,
             limit: None,
             offset: None,
         };
@@ -803,6 +1053,9 @@ mod tests {
             from_date: Some(1705311000),
             to_date: None,
             enhanced_only: Some(false),
+            session_id: None,
+            model_name: None,
+            language: None,
             limit: Some(100),
             offset: Some(0),
         };
@@ -815,6 +1068,59 @@ mod tests {
         assert_eq!(restored.limit, params.limit);
     }
 
+    #[test]
+    fn test_models_used_counts_distinct_models() {
+        let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+        database::migrations::run_migrations(&mut conn).unwrap();
+        for (id, model) in [
+            ("a", Some("ggml-base.en")),
+            ("b", Some("ggml-large-v3")),
+            ("c", Some("ggml-base.en")),
+            ("d", None),
+        ] {
+            conn.execute(
+                "INSERT INTO transcriptions (id, text, created_at, is_enhanced, transcription_model_name) VALUES (?1, 'test', '2024-01-01T00:00:00Z', 0, ?2)",
+                rusqlite::params![id, model],
+            )
+            .unwrap();
+        }
+
+        let used = models_used(&conn).unwrap();
+        assert_eq!(
+            used,
+            vec![
+                ModelUsage {
+                    model_name: "ggml-base.en".to_string(),
+                    count: 2,
+                },
+                ModelUsage {
+                    model_name: "ggml-large-v3".to_string(),
+                    count: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_params_model_name_is_optional() {
+        let params: SearchParams = serde_json::from_str(r#"{"query": "hello"}"#).unwrap();
+        assert!(params.model_name.is_none());
+
+        let params: SearchParams =
+            serde_json::from_str(r#"{"modelName": "ggml-base.en"}"#).unwrap();
+        assert_eq!(params.model_name.as_deref(), Some("ggml-base.en"));
+    }
+
+    #[test]
+    fn test_search_params_session_id_is_optional() {
+        let params: SearchParams = serde_json::from_str(r#"{"query": "hello"}"#).unwrap();
+        assert!(params.session_id.is_none());
+
+        let params: SearchParams =
+            serde_json::from_str(r#"{"sessionId": "abc", "limit": 10}"#).unwrap();
+        assert_eq!(params.session_id.as_deref(), Some("abc"));
+    }
+
     // =========================================================================
     // SearchResult tests
     // =========================================================================
@@ -832,6 +1138,54 @@ mod tests {
         assert!(!result.has_more);
     }
 
+    /// A page of `params` from a history of `total` records
+    fn fake_page(params: &SearchParams, total: u32) -> SearchResult {
+        let offset = params.offset.unwrap_or(0);
+        let end = (offset + params.limit.unwrap_or(100)).min(total);
+        let records = (offset..end)
+            .map(|i| TranscriptionRecord {
+                id: i.to_string(),
+                ..create_test_records()[0].clone()
+            })
+            .collect::<Vec<_>>();
+        SearchResult {
+            has_more: end < total,
+            records,
+            total_count: total,
+        }
+    }
+
+    #[test]
+    fn test_collect_pages_exports_past_one_page() {
+        let total = EXPORT_PAGE_SIZE * 2 + 5;
+        let mut fetches = 0;
+        let records = collect_pages(&SearchParams::default(), |params| {
+            fetches += 1;
+            Ok(fake_page(params, total))
+        })
+        .unwrap();
+
+        assert_eq!(records.len() as u32, total);
+        assert_eq!(fetches, 3);
+        assert_eq!(records.last().unwrap().id, (total - 1).to_string());
+    }
+
+    #[test]
+    fn test_collect_pages_honours_an_explicit_limit() {
+        let params = SearchParams {
+            limit: Some(EXPORT_PAGE_SIZE + 10),
+            offset: Some(3),
+            ..SearchParams::default()
+        };
+        let records = collect_pages(&params, |params| {
+            Ok(fake_page(params, 10 * EXPORT_PAGE_SIZE))
+        })
+        .unwrap();
+
+        assert_eq!(records.len() as u32, EXPORT_PAGE_SIZE + 10);
+        assert_eq!(records[0].id, "3");
+    }
+
     #[test]
     fn test_search_result_with_records() {
         let records = vec![
@@ -848,6 +1202,7 @@ mod tests {
                 transcription_duration_seconds: None,
                 enhancement_model_name: None,
                 enhancement_duration_seconds: None,
+                detected_language: None,
             },
             TranscriptionRecord {
                 id: "2".to_string(),
@@ -862,6 +1217,7 @@ mod tests {
                 transcription_duration_seconds: None,
                 enhancement_model_name: None,
                 enhancement_duration_seconds: None,
+                detected_language: None,
             },
         ];
 
@@ -895,6 +1251,7 @@ mod tests {
                 transcription_duration_seconds: Some(1.2),
                 enhancement_model_name: Some("llama3.2:3b".to_string()),
                 enhancement_duration_seconds: Some(0.8),
+                detected_language: Some("en".to_string()),
             },
             TranscriptionRecord {
                 id: "id2".to_string(),
@@ -909,6 +1266,7 @@ mod tests {
                 transcription_duration_seconds: None,
                 enhancement_model_name: None,
                 enhancement_duration_seconds: None,
+                detected_language: None,
             },
         ]
     }
@@ -1024,6 +1382,49 @@ mod tests {
         let content = std::fs::read_to_string(&path).unwrap();
         assert!(content.contains("Duration: 3.5s"));
         assert!(content.contains("Enhanced: Yes"));
+        assert!(content.contains("Language: en"));
+    }
+
+    #[test]
+    fn test_session_document_markdown_is_continuous() {
+        let mut records = create_test_records();
+        records[0].text = "Chapter three.\n\nIt begins.".to_string();
+        records.reverse();
+
+        let doc = session_document("Chapter 3", &records, true, false);
+        assert_eq!(
+            doc,
+            "# Chapter 3\n\nSecond, with \"quotes\"\n\nChapter three.\n\nIt begins.\n"
+        );
+    }
+
+    #[test]
+    fn test_session_document_timestamp_headings() {
+        let mut records = create_test_records();
+        records[0].created_at = "not a timestamp".to_string();
+        records[1].text = "   ".to_string();
+
+        let md = session_document("Notes", &records, true, true);
+        assert_eq!(md, "# Notes\n\n## not a timestamp\n\nFirst transcription\n");
+
+        let txt = session_document("Notes", &records, false, true);
+        assert_eq!(
+            txt,
+            "Notes\n=====\n\n[not a timestamp]\n\nFirst transcription\n"
+        );
+    }
+
+    #[test]
+    fn test_export_document_writes_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("session.md");
+
+        let records = create_test_records();
+        export_document("Chapter 3", &records, &path, true, false).expect("Export should succeed");
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# Chapter 3\n"));
+        assert!(!content.contains("Date:"));
     }
 
     #[test]
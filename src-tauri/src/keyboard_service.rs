@@ -20,7 +20,7 @@ use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::sync::OnceLock;
-use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU64, Ordering};
 use std::thread;
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
@@ -35,9 +35,18 @@ const POLL_INTERVAL_MS: u64 = 20;
 /// Cooldown between shortcut triggers to prevent double-firing (ms)
 const TRIGGER_COOLDOWN_MS: u64 = 500;
 
-/// Threshold for "brief press" vs "hold" in toggle mode (ms)
+/// Default threshold for "brief press" vs "hold" in toggle mode (ms).
+/// Overridden at runtime by `config.shortcuts.hold_threshold_ms`.
 const BRIEF_PRESS_THRESHOLD_MS: u64 = 500;
 
+/// Minimum time a modifier must stay down before a release counts (ms).
+///
+/// Mechanical switches can chatter, producing a release/press pair a few
+/// milliseconds after the real press. A release inside this window is treated
+/// as bounce and ignored, so the key stays pressed. Must exceed
+/// `POLL_INTERVAL_MS` so a single bounced poll is absorbed.
+const KEY_DEBOUNCE_MS: u64 = 50;
+
 // ---------------------------------------------------------------------------
 // Mode state machine
 // ---------------------------------------------------------------------------
@@ -70,6 +79,9 @@ static MODE: AtomicU8 = AtomicU8::new(0); // Idle
 /// Whether the polling thread is currently alive
 static THREAD_RUNNING: AtomicBool = AtomicBool::new(false);
 
+/// Active tap-vs-hold threshold (ms), set from `config.shortcuts.hold_threshold_ms`
+static HOLD_THRESHOLD_MS: AtomicU64 = AtomicU64::new(BRIEF_PRESS_THRESHOLD_MS);
+
 // ---------------------------------------------------------------------------
 // Modifier key types (ported from modifier_monitor.rs)
 // ---------------------------------------------------------------------------
@@ -146,6 +158,10 @@ struct ModifierShortcut {
 }
 
 /// State for tracking key press timing (monitoring mode)
+///
+/// One instance per physical modifier key. Each key's timing and hands-free
+/// flag are only ever touched by that key's own transitions, so two modifier
+/// shortcuts held or tapped in an interleaved fashion cannot affect each other.
 #[derive(Debug, Default)]
 struct KeyState {
     is_pressed: bool,
@@ -154,13 +170,167 @@ struct KeyState {
     hands_free_mode: bool,
 }
 
+/// Edge detected for a modifier key during one poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyTransition {
+    Pressed,
+    Released,
+}
+
+impl KeyTransition {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Pressed => "pressed",
+            Self::Released => "released",
+        }
+    }
+}
+
+impl KeyState {
+    /// Advance this key's state for one poll at time `now`.
+    ///
+    /// Returns the transition to report, if any. Presses inside
+    /// `TRIGGER_COOLDOWN_MS` of the last trigger are ignored, as are releases
+    /// inside `KEY_DEBOUNCE_MS` of the press. A brief press (shorter than
+    /// `hold_threshold_ms`) enters hands-free mode and reports nothing on
+    /// release; a long press reports `Released`.
+    fn update(
+        &mut self,
+        is_pressed: bool,
+        now: Instant,
+        hold_threshold_ms: u64,
+    ) -> Option<KeyTransition> {
+        if is_pressed && !self.is_pressed {
+            // Cooldown only gates presses. Releases must always be tracked,
+            // otherwise a brief tap released inside the cooldown is only seen
+            // once the cooldown ends and is misread as a long press.
+            if let Some(last) = self.last_trigger {
+                if now.duration_since(last).as_millis() < TRIGGER_COOLDOWN_MS as u128 {
+                    return None;
+                }
+            }
+
+            // Key just pressed. In hands-free mode this press stops recording;
+            // otherwise it starts it. Either way the press is reported.
+            self.is_pressed = true;
+            self.press_time = Some(now);
+            self.last_trigger = Some(now);
+            self.hands_free_mode = false;
+            Some(KeyTransition::Pressed)
+        } else if !is_pressed && self.is_pressed {
+            // Key just released
+            let press_duration = self
+                .press_time
+                .map(|t| now.duration_since(t).as_millis())
+                .unwrap_or(0);
+            if self.press_time.is_some() && press_duration < KEY_DEBOUNCE_MS as u128 {
+                tracing::debug!(
+                    "Ignoring release {}ms after press (key chatter)",
+                    press_duration
+                );
+                return None;
+            }
+            self.is_pressed = false;
+            self.press_time = None;
+
+            if press_duration < hold_threshold_ms as u128 {
+                // Brief press: enter hands-free mode (don't stop yet)
+                self.hands_free_mode = true;
+                tracing::debug!(
+                    "Brief press detected ({}ms), entering hands-free mode",
+                    press_duration
+                );
+                None
+            } else {
+                // Long press: stop recording
+                self.hands_free_mode = false;
+                self.last_trigger = Some(now);
+                Some(KeyTransition::Released)
+            }
+        } else {
+            None
+        }
+    }
+}
+
 /// Registry of modifier shortcuts
+///
+/// Key state is kept per physical modifier, shared by every shortcut bound
+/// to that key.
 #[derive(Default)]
 struct ModifierRegistry {
     shortcuts: HashMap<String, ModifierShortcut>,
     key_states: HashMap<ModifierKey, KeyState>,
 }
 
+impl ModifierRegistry {
+    fn insert(&mut self, id: String, modifier: ModifierKey, description: String) {
+        // Re-registering an id under a different modifier must not leave the
+        // old key's state behind.
+        if self
+            .shortcuts
+            .get(&id)
+            .is_some_and(|s| s.modifier != modifier)
+        {
+            self.remove(&id);
+        }
+        self.shortcuts.insert(
+            id.clone(),
+            ModifierShortcut {
+                id,
+                modifier,
+                description,
+            },
+        );
+        self.key_states.entry(modifier).or_default();
+    }
+
+    fn remove(&mut self, id: &str) -> bool {
+        let Some(removed) = self.shortcuts.remove(id) else {
+            return false;
+        };
+        // Drop the key's state once no shortcut uses it, so a later binding of
+        // the same key starts fresh instead of inheriting hands-free/cooldown.
+        if !self
+            .shortcuts
+            .values()
+            .any(|s| s.modifier == removed.modifier)
+        {
+            self.key_states.remove(&removed.modifier);
+        }
+        true
+    }
+
+    /// Advance every registered key for one poll and collect the shortcut
+    /// events to emit, as `(shortcut_id, transition)` pairs.
+    ///
+    /// Each physical key is updated exactly once per poll, then its transition
+    /// is reported for every shortcut bound to it.
+    fn poll(
+        &mut self,
+        keys: &HashSet<Keycode>,
+        now: Instant,
+        hold_threshold_ms: u64,
+    ) -> Vec<(String, KeyTransition)> {
+        let mut events = Vec::new();
+        for (modifier, key_state) in self.key_states.iter_mut() {
+            let is_pressed = keys.contains(&modifier.to_keycode());
+            let Some(transition) = key_state.update(is_pressed, now, hold_threshold_ms) else {
+                continue;
+            };
+            let mut ids: Vec<&String> = self
+                .shortcuts
+                .values()
+                .filter(|s| s.modifier == *modifier)
+                .map(|s| &s.id)
+                .collect();
+            ids.sort();
+            events.extend(ids.into_iter().map(|id| (id.clone(), transition)));
+        }
+        events
+    }
+}
+
 static REGISTRY: OnceLock<RwLock<ModifierRegistry>> = OnceLock::new();
 
 fn get_registry() -> &'static RwLock<ModifierRegistry> {
@@ -210,16 +380,7 @@ pub fn register_modifier_shortcut(id: String, accelerator: String, description:
         return false;
     };
 
-    let mut registry = get_registry().write();
-    registry.shortcuts.insert(
-        id.clone(),
-        ModifierShortcut {
-            id,
-            modifier,
-            description,
-        },
-    );
-    registry.key_states.entry(modifier).or_default();
+    get_registry().write().insert(id, modifier, description);
 
     tracing::info!(
         "Registered modifier shortcut: {} -> {:?}",
@@ -231,8 +392,7 @@ pub fn register_modifier_shortcut(id: String, accelerator: String, description:
 
 /// Unregister a modifier shortcut
 pub fn unregister_modifier_shortcut(id: &str) -> bool {
-    let mut registry = get_registry().write();
-    if registry.shortcuts.remove(id).is_some() {
+    if get_registry().write().remove(id) {
         tracing::info!("Unregistered modifier shortcut: {}", id);
         true
     } else {
@@ -248,6 +408,18 @@ pub fn unregister_all_modifier_shortcuts() {
     tracing::info!("Unregistered all modifier shortcuts");
 }
 
+/// Set the tap-vs-hold threshold for modifier shortcuts.
+///
+/// Presses released before `ms` are taps (toggle / hands-free); longer presses
+/// are holds (push-to-talk). Callers pass the already-validated
+/// `ShortcutConfig::effective_hold_threshold_ms`.
+pub fn set_hold_threshold_ms(ms: u32) {
+    let previous = HOLD_THRESHOLD_MS.swap(u64::from(ms), Ordering::Relaxed);
+    if previous != u64::from(ms) {
+        tracing::info!("Modifier hold threshold set to {}ms", ms);
+    }
+}
+
 /// Check if a modifier shortcut is registered
 pub fn is_modifier_shortcut_registered(id: &str) -> bool {
     get_registry().read().shortcuts.contains_key(id)
@@ -617,94 +789,14 @@ fn polling_loop(app: AppHandle) {
 
 /// Process keys in monitoring mode: detect modifier shortcut presses/releases
 fn process_monitoring(app: &AppHandle, keys: &HashSet<Keycode>) {
-    let shortcuts: Vec<ModifierShortcut> = {
-        let registry = get_registry().read();
-        registry.shortcuts.values().cloned().collect()
-    };
-
-    for shortcut in shortcuts {
-        let keycode = shortcut.modifier.to_keycode();
-        let is_pressed = keys.contains(&keycode);
-
-        // Get current state
-        let (was_pressed, press_time, last_trigger, hands_free) = {
-            let registry = get_registry().read();
-            let key_state = registry.key_states.get(&shortcut.modifier);
-            (
-                key_state.map(|s| s.is_pressed).unwrap_or(false),
-                key_state.and_then(|s| s.press_time),
-                key_state.and_then(|s| s.last_trigger),
-                key_state.map(|s| s.hands_free_mode).unwrap_or(false),
-            )
-        };
-
-        // Check cooldown
-        if let Some(last) = last_trigger {
-            if last.elapsed().as_millis() < TRIGGER_COOLDOWN_MS as u128 {
-                continue;
-            }
-        }
-
-        if is_pressed && !was_pressed {
-            // Key just pressed
-            {
-                let mut registry = get_registry().write();
-                if let Some(key_state) = registry.key_states.get_mut(&shortcut.modifier) {
-                    key_state.is_pressed = true;
-                    key_state.press_time = Some(Instant::now());
-                }
-            }
-
-            if hands_free {
-                // In hands-free mode, pressing again stops recording
-                {
-                    let mut registry = get_registry().write();
-                    if let Some(key_state) = registry.key_states.get_mut(&shortcut.modifier) {
-                        key_state.hands_free_mode = false;
-                        key_state.last_trigger = Some(Instant::now());
-                    }
-                }
-                emit_shortcut_event(app, &shortcut.id, "pressed");
-            } else {
-                // Start recording on press
-                {
-                    let mut registry = get_registry().write();
-                    if let Some(key_state) = registry.key_states.get_mut(&shortcut.modifier) {
-                        key_state.last_trigger = Some(Instant::now());
-                    }
-                }
-                emit_shortcut_event(app, &shortcut.id, "pressed");
-            }
-        } else if !is_pressed && was_pressed {
-            // Key just released
-            let press_duration = press_time.map(|t| t.elapsed().as_millis()).unwrap_or(0);
-
-            {
-                let mut registry = get_registry().write();
-                if let Some(key_state) = registry.key_states.get_mut(&shortcut.modifier) {
-                    key_state.is_pressed = false;
-                    key_state.press_time = None;
-
-                    if press_duration < BRIEF_PRESS_THRESHOLD_MS as u128 {
-                        // Brief press: enter hands-free mode (don't stop yet)
-                        key_state.hands_free_mode = true;
-                        tracing::debug!(
-                            "Brief press detected ({}ms), entering hands-free mode",
-                            press_duration
-                        );
-                    } else {
-                        // Long press: stop recording
-                        key_state.hands_free_mode = false;
-                        key_state.last_trigger = Some(Instant::now());
-                    }
-                }
-            }
-
-            // Only emit release if it was a long press (not brief/hands-free)
-            if press_duration >= BRIEF_PRESS_THRESHOLD_MS as u128 {
-                emit_shortcut_event(app, &shortcut.id, "released");
-            }
-        }
+    // Update all key states under a single write lock, then emit without it.
+    let hold_threshold_ms = HOLD_THRESHOLD_MS.load(Ordering::Relaxed);
+    let events = get_registry()
+        .write()
+        .poll(keys, Instant::now(), hold_threshold_ms);
+
+    for (id, transition) in events {
+        emit_shortcut_event(app, &id, transition.as_str());
     }
 }
 
@@ -1207,6 +1299,153 @@ mod tests {
         ));
     }
 
+    // -- Monitoring state tests --
+
+    fn keys_of(pressed: &[Keycode]) -> HashSet<Keycode> {
+        pressed.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_two_modifiers_interleaved_are_isolated() {
+        let mut registry = ModifierRegistry::default();
+        registry.insert(
+            "toggle_recording".to_string(),
+            ModifierKey::ShiftRight,
+            "Toggle".to_string(),
+        );
+        registry.insert(
+            "copy_last".to_string(),
+            ModifierKey::AltRight,
+            "Copy".to_string(),
+        );
+        let t0 = Instant::now();
+        let at = |ms: u64| t0 + Duration::from_millis(ms);
+
+        // Shift down, then Alt down while Shift is held
+        assert_eq!(
+            registry.poll(
+                &keys_of(&[Keycode::RShift]),
+                at(0),
+                BRIEF_PRESS_THRESHOLD_MS
+            ),
+            vec![("toggle_recording".to_string(), KeyTransition::Pressed)]
+        );
+        assert_eq!(
+            registry.poll(
+                &keys_of(&[Keycode::RShift, Keycode::RAlt]),
+                at(100),
+                BRIEF_PRESS_THRESHOLD_MS
+            ),
+            vec![("copy_last".to_string(), KeyTransition::Pressed)]
+        );
+
+        // Brief Shift release enters hands-free for Shift only
+        assert!(
+            registry
+                .poll(
+                    &keys_of(&[Keycode::RAlt]),
+                    at(200),
+                    BRIEF_PRESS_THRESHOLD_MS
+                )
+                .is_empty()
+        );
+        assert!(registry.key_states[&ModifierKey::ShiftRight].hands_free_mode);
+        assert!(!registry.key_states[&ModifierKey::AltRight].hands_free_mode);
+        assert!(registry.key_states[&ModifierKey::AltRight].is_pressed);
+
+        // Long Alt release reports only Alt and leaves Shift's hands-free intact
+        assert_eq!(
+            registry.poll(&keys_of(&[]), at(900), BRIEF_PRESS_THRESHOLD_MS),
+            vec![("copy_last".to_string(), KeyTransition::Released)]
+        );
+        assert!(registry.key_states[&ModifierKey::ShiftRight].hands_free_mode);
+
+        // Next Shift press fires only the Shift shortcut
+        assert_eq!(
+            registry.poll(
+                &keys_of(&[Keycode::RShift]),
+                at(1_000),
+                BRIEF_PRESS_THRESHOLD_MS
+            ),
+            vec![("toggle_recording".to_string(), KeyTransition::Pressed)]
+        );
+        assert!(!registry.key_states[&ModifierKey::ShiftRight].hands_free_mode);
+    }
+
+    #[test]
+    fn test_chattering_press_triggers_once() {
+        let mut registry = ModifierRegistry::default();
+        registry.insert(
+            "toggle_recording".to_string(),
+            ModifierKey::ShiftRight,
+            "Toggle".to_string(),
+        );
+        let t0 = Instant::now();
+
+        // Press at 0ms bounces open for one poll at 20ms, then is held until
+        // 700ms. Polled at the real 20ms cadence.
+        let mut events = Vec::new();
+        for ms in (0..=800).step_by(POLL_INTERVAL_MS as usize) {
+            let held = ms != 20 && ms < 700;
+            let keys = if held {
+                keys_of(&[Keycode::RShift])
+            } else {
+                keys_of(&[])
+            };
+            events.extend(registry.poll(
+                &keys,
+                t0 + Duration::from_millis(ms),
+                BRIEF_PRESS_THRESHOLD_MS,
+            ));
+        }
+
+        assert_eq!(
+            events,
+            vec![
+                ("toggle_recording".to_string(), KeyTransition::Pressed),
+                ("toggle_recording".to_string(), KeyTransition::Released),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hold_threshold_selects_tap_or_hold() {
+        // A 600ms press is a hold at the default 500ms threshold...
+        let mut state = KeyState::default();
+        let t0 = Instant::now();
+        let at = |ms: u64| t0 + Duration::from_millis(ms);
+        assert_eq!(state.update(true, at(0), 500), Some(KeyTransition::Pressed));
+        assert_eq!(
+            state.update(false, at(600), 500),
+            Some(KeyTransition::Released)
+        );
+        assert!(!state.hands_free_mode);
+
+        // ...and a tap (hands-free) once the threshold is raised past it.
+        let mut state = KeyState::default();
+        assert_eq!(state.update(true, at(0), 700), Some(KeyTransition::Pressed));
+        assert_eq!(state.update(false, at(600), 700), None);
+        assert!(state.hands_free_mode);
+    }
+
+    #[test]
+    fn test_unregister_drops_unused_key_state() {
+        let mut registry = ModifierRegistry::default();
+        registry.insert("a".to_string(), ModifierKey::ShiftRight, "A".to_string());
+        registry.poll(
+            &keys_of(&[Keycode::RShift]),
+            Instant::now(),
+            BRIEF_PRESS_THRESHOLD_MS,
+        );
+
+        assert!(registry.remove("a"));
+        assert!(!registry.key_states.contains_key(&ModifierKey::ShiftRight));
+
+        // Re-binding the key starts from a clean state
+        registry.insert("b".to_string(), ModifierKey::ShiftRight, "B".to_string());
+        assert!(!registry.key_states[&ModifierKey::ShiftRight].is_pressed);
+    }
+
     // -- Key formatting tests (from keyboard_capture.rs) --
 
     #[test]
@@ -8,7 +8,9 @@ use crate::error::Error;
 
 pub mod app_handle;
 pub mod audio;
+pub mod autostart;
 pub mod canonical;
+pub mod cli;
 pub mod clipboard;
 pub mod commands;
 pub mod config;
@@ -23,7 +25,11 @@ pub mod mcp_server;
 pub mod mouse_tracker;
 pub mod pipeline;
 pub mod platform;
+pub mod queue;
+pub mod realtime_insert;
 pub mod recording_indicator;
+pub mod redaction;
+pub mod selftest;
 pub mod shortcuts;
 pub mod sound;
 pub mod storage;
@@ -33,6 +39,7 @@ pub mod text_insert;
 mod traffic_lights;
 pub mod transcription;
 pub mod tray;
+mod window_state;
 
 /// Header height in pixels (must match CSS --header-height)
 #[cfg(target_os = "macos")]
@@ -89,10 +96,42 @@ fn reregister_shortcuts(app: tauri::AppHandle) -> Result<(), Error> {
     Ok(())
 }
 
+/// Apply `config.general.close_behavior` when the main window is closed.
+///
+/// The main window hosts the pipeline event listeners — if it's destroyed,
+/// global shortcuts stop working — so closing it only ever hides it,
+/// minimises it or quits the whole app. The setting is read on each close so
+/// changes apply straight away.
+fn handle_main_window_close(window: &tauri::WebviewWindow) {
+    let win = window.clone();
+    window.on_window_event(move |event| {
+        if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+            api.prevent_close();
+            let behavior = config::get_config()
+                .map(|c| c.general.close_behavior)
+                .unwrap_or_default();
+            match behavior {
+                config::CloseBehavior::HideToTray => {
+                    let _ = win.hide();
+                }
+                config::CloseBehavior::Minimize => {
+                    let _ = win.minimize();
+                }
+                config::CloseBehavior::Quit => {
+                    tracing::info!("Main window closed; quitting");
+                    win.app_handle().exit(0);
+                }
+            }
+        }
+    });
+}
+
 /// Register shortcuts from saved configuration
 fn register_shortcuts_from_config(app: &tauri::AppHandle, cfg: &config::Config) {
     use shortcuts::manager::shortcut_ids;
 
+    keyboard_service::set_hold_threshold_ms(cfg.shortcuts.effective_hold_threshold_ms());
+
     // On Wayland, the actual global-shortcut binding is owned by the XDG portal,
     // set up once here. On X11 this is a no-op and the per-shortcut registration
     // below binds via the Tauri plugin as on macOS.
@@ -127,6 +166,34 @@ fn register_shortcuts_from_config(app: &tauri::AppHandle, cfg: &config::Config)
                 "Toggle AI enhancement",
             )
         }),
+        cfg.shortcuts
+            .dictate_without_saving
+            .as_deref()
+            .map(|accel| {
+                (
+                    shortcut_ids::DICTATE_WITHOUT_SAVING,
+                    accel,
+                    "Dictate without saving",
+                )
+            }),
+        cfg.shortcuts.dictate_by_typing.as_deref().map(|accel| {
+            (
+                shortcut_ids::DICTATE_BY_TYPING,
+                accel,
+                "Dictate and type the result",
+            )
+        }),
+        cfg.shortcuts.dictate_by_pasting.as_deref().map(|accel| {
+            (
+                shortcut_ids::DICTATE_BY_PASTING,
+                accel,
+                "Dictate and paste the result",
+            )
+        }),
+        cfg.shortcuts
+            .dictate_verbatim
+            .as_deref()
+            .map(|accel| (shortcut_ids::DICTATE_VERBATIM, accel, "Verbatim dictation")),
     ]
     .into_iter()
     .flatten()
@@ -242,6 +309,64 @@ fn build_loki_components(
     builder.build_url(url).ok()
 }
 
+/// Directory holding the local log files (`~/.thoth/logs`, or `/tmp` without a data directory).
+pub(crate) fn log_dir() -> std::path::PathBuf {
+    storage::data_dir()
+        .map(|dir| dir.join("logs"))
+        .unwrap_or_else(|| std::path::PathBuf::from("/tmp"))
+}
+
+/// Environment variable that turns the local log files off whatever the config says
+const NO_FILE_LOG_ENV: &str = "THOTH_NO_FILE_LOG";
+
+/// Whether to write the local log files: `logging.file_logging`, unless
+/// `THOTH_NO_FILE_LOG` is set to anything other than "" or "0".
+fn file_logging_enabled(cfg: &config::LoggingConfig) -> bool {
+    let disabled_by_env = std::env::var(NO_FILE_LOG_ENV).is_ok_and(|v| !v.is_empty() && v != "0");
+    cfg.file_logging && !disabled_by_env
+}
+
+/// Reload handle for the global `EnvFilter`, set once by `init_logging`.
+static LOG_FILTER_HANDLE: std::sync::OnceLock<
+    tracing_subscriber::reload::Handle<tracing_subscriber::EnvFilter, tracing_subscriber::Registry>,
+> = std::sync::OnceLock::new();
+
+/// Whether the filter must keep the "telemetry" target visible (remote logging on).
+static LOG_FILTER_KEEPS_TELEMETRY: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+/// Build the global `EnvFilter` from a base directive such as "info" or a
+/// full `RUST_LOG` value. Falls back to "info" if the directive is invalid.
+///
+/// When remote logging is enabled, guarantee that the "telemetry" target is
+/// always allowed through the global filter regardless of the base directive.
+/// A crate-scoped directive like `thoth=debug` does NOT match the bare
+/// "telemetry" target and would silently drop Loki events. We append
+/// `,telemetry=info` so the telemetry target is always at least INFO-visible.
+fn build_env_filter(base: &str, keep_telemetry: bool) -> tracing_subscriber::EnvFilter {
+    if keep_telemetry {
+        let directive = format!("{},telemetry=info", base);
+        tracing_subscriber::EnvFilter::try_new(&directive)
+            .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info,telemetry=info"))
+    } else {
+        tracing_subscriber::EnvFilter::try_new(base)
+            .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"))
+    }
+}
+
+/// Replace the global log filter at runtime (e.g. "debug" to capture a bug).
+pub(crate) fn set_log_filter(level: &str) -> Result<(), String> {
+    let handle = LOG_FILTER_HANDLE
+        .get()
+        .ok_or_else(|| "Logging has not been initialised".to_string())?;
+    let keep_telemetry = LOG_FILTER_KEEPS_TELEMETRY.load(std::sync::atomic::Ordering::Relaxed);
+    handle
+        .reload(build_env_filter(level, keep_telemetry))
+        .map_err(|e| format!("Failed to update log filter: {}", e))?;
+    tracing::info!("Log level set to: {}", level);
+    Ok(())
+}
+
 /// Initialise the layered tracing subscriber.
 ///
 /// Layers:
@@ -256,7 +381,8 @@ fn build_loki_components(
 ///   queue into its channel from first use; the task just needs to start before the first flush.
 ///
 /// Configuration is read synchronously from disk so the subscriber is ready before any event
-/// fires. Changes require a restart (documented in the UI).
+/// fires. Changes require a restart (documented in the UI), except the local log level,
+/// which `set_log_level` swaps live through the stored reload handle.
 fn init_logging() {
     use tracing_subscriber::Layer;
     use tracing_subscriber::prelude::*;
@@ -278,50 +404,44 @@ fn init_logging() {
 
     let logging_cfg = config::read_logging_config_early();
 
-    let log_dir = dirs::home_dir()
-        .map(|h| h.join(".thoth").join("logs"))
-        .unwrap_or_else(|| std::path::PathBuf::from("/tmp"));
-    let _ = std::fs::create_dir_all(&log_dir);
-
-    // Daily rolling appender with bounded retention. Falls back to a no-op writer on
-    // error (e.g. permission denied) so the app still starts without logging to disk.
-    let (appender, guard) = match tracing_appender::rolling::Builder::new()
-        .rotation(tracing_appender::rolling::Rotation::DAILY)
-        .filename_prefix("thoth")
-        .filename_suffix("log")
-        .max_log_files(logging_cfg.local_retention_days as usize)
-        .build(&log_dir)
-    {
-        Ok(a) => tracing_appender::non_blocking(a),
-        Err(_) => tracing_appender::non_blocking(std::io::sink()),
-    };
-    // Leak the WorkerGuard so the background writer lives for the process lifetime
-    // and flushes on shutdown.
-    std::mem::forget(guard);
+    // With file logging off nothing is created under the data directory and
+    // logs reach stdout only.
+    let file_layer = file_logging_enabled(&logging_cfg).then(|| {
+        let log_dir = log_dir();
+        let _ = std::fs::create_dir_all(&log_dir);
+
+        // Daily rolling appender with bounded retention. Falls back to a no-op writer on
+        // error (e.g. permission denied) so the app still starts without logging to disk.
+        let (appender, guard) = match tracing_appender::rolling::Builder::new()
+            .rotation(tracing_appender::rolling::Rotation::DAILY)
+            .filename_prefix("thoth")
+            .filename_suffix("log")
+            .max_log_files(logging_cfg.local_retention_days as usize)
+            .build(&log_dir)
+        {
+            Ok(a) => tracing_appender::non_blocking(a),
+            Err(_) => tracing_appender::non_blocking(std::io::sink()),
+        };
+        // Leak the WorkerGuard so the background writer lives for the process lifetime
+        // and flushes on shutdown.
+        std::mem::forget(guard);
 
-    let file_layer = tracing_subscriber::fmt::layer()
-        .with_writer(appender)
-        .with_timer(LocalTimer)
-        .with_ansi(false);
+        tracing_subscriber::fmt::layer()
+            .with_writer(appender)
+            .with_timer(LocalTimer)
+            .with_ansi(false)
+    });
 
     let stdout_layer = tracing_subscriber::fmt::layer().with_timer(LocalTimer);
 
-    // When remote logging is enabled, guarantee that the "telemetry" target is
-    // always allowed through the global filter regardless of RUST_LOG directives.
-    // A crate-scoped directive like `thoth=debug` does NOT match the bare
-    // "telemetry" target and would silently drop Loki events. We append
-    // `,telemetry=info` so the telemetry target is always at least INFO-visible.
-    // When RUST_LOG is unset we fall back to "info" as before, so this only
-    // matters when the user sets RUST_LOG explicitly.
-    let env_filter = if logging_cfg.remote_enabled && !logging_cfg.loki_url.is_empty() {
-        let base = std::env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string());
-        let directive = format!("{},telemetry=info", base);
-        tracing_subscriber::EnvFilter::try_new(&directive)
-            .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info,telemetry=info"))
-    } else {
-        tracing_subscriber::EnvFilter::try_from_default_env()
-            .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"))
-    };
+    // RUST_LOG wins at startup so developers keep their usual workflow; otherwise
+    // the persisted `logging.log_level` applies. `set_log_level` swaps it live.
+    let keep_telemetry = logging_cfg.remote_enabled && !logging_cfg.loki_url.is_empty();
+    LOG_FILTER_KEEPS_TELEMETRY.store(keep_telemetry, std::sync::atomic::Ordering::Relaxed);
+    let base = std::env::var("RUST_LOG").unwrap_or_else(|_| logging_cfg.log_level.clone());
+    let (env_filter, filter_handle) =
+        tracing_subscriber::reload::Layer::new(build_env_filter(&base, keep_telemetry));
+    let _ = LOG_FILTER_HANDLE.set(filter_handle);
 
     let registry = tracing_subscriber::registry()
         .with(env_filter)
@@ -370,6 +490,20 @@ fn init_logging() {
 pub fn run() {
     ensure_crypto_provider();
 
+    // Every data path hangs off this, so settle it before anything is written.
+    storage::init_data_dir();
+
+    // A command such as `thoth transcribe <path>` runs headless and exits
+    // before any window, tray or shortcut exists.
+    match cli::parse_args(&std::env::args().skip(1).collect::<Vec<_>>()) {
+        Ok(Some(command)) => std::process::exit(cli::run(command)),
+        Ok(None) => {}
+        Err(usage) => {
+            eprintln!("{}", usage);
+            std::process::exit(2);
+        }
+    }
+
     init_logging();
 
     let mut builder = tauri::Builder::default()
@@ -429,6 +563,22 @@ pub fn run() {
             // events without a handle of their own (e.g. audio device fallback).
             app_handle::set(app.handle().clone());
 
+            // A data directory move asked for last run happens now, with only
+            // the main window up to show it; the app restarts afterwards.
+            // Closing the window mid-move would leave the data half moved.
+            if storage::start_pending_move(app.handle()) {
+                if let Some(window) = app.get_webview_window("main") {
+                    window.on_window_event(|event| {
+                        if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                            api.prevent_close();
+                        }
+                    });
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+                return Ok(());
+            }
+
             // Request microphone permission BEFORE any audio enumeration.
             // cpal's device enumeration touches CoreAudio which triggers the
             // system mic prompt implicitly — but with no completion handler,
@@ -443,6 +593,7 @@ pub fn run() {
                     platform::macos::request_microphone_permission(app.handle().clone());
                 }
             }
+            platform::watch_microphone_permission(app.handle().clone());
 
             // Initialise database early so tray menu queries work immediately
             database::initialise_database().map_err(|e| {
@@ -453,11 +604,37 @@ pub fn run() {
             // Set up system tray
             tray::setup_tray(app)?;
 
+            // Put the main window back where it was last left, and keep it
+            // alive when closed (see handle_main_window_close)
+            if let Some(window) = app.get_webview_window("main") {
+                window_state::restore_and_track(&window);
+                handle_main_window_close(&window);
+            }
+
+            // Point the login item at this install, per config
+            autostart::sync_on_startup(app.handle());
+
             // Load config and register shortcuts
             if let Ok(cfg) = config::get_config() {
                 // Wire up the enhancement backend before the first pipeline run
                 config::apply_enhancement_backend(&cfg.enhancement);
 
+                // Optionally pre-load the Ollama model. Delayed so it does not
+                // compete with the transcription warmup for disk and memory.
+                if cfg.enhancement.warmup_target().is_some() {
+                    enhancement::warmup_model(
+                        cfg.enhancement.model.clone(),
+                        std::time::Duration::from_secs(5),
+                    );
+                }
+                // Catch a model removed with `ollama rm` since the last run
+                if let Some(model) = cfg.enhancement.active_ollama_model() {
+                    enhancement::check_model_installed(
+                        model.to_string(),
+                        std::time::Duration::from_secs(5),
+                    );
+                }
+
                 // Register shortcuts from config
                 let app_handle = app.handle().clone();
                 register_shortcuts_from_config(&app_handle, &cfg);
@@ -548,33 +725,21 @@ pub fn run() {
                     app.set_activation_policy(tauri::ActivationPolicy::Accessory);
                 }
 
-                // Position traffic lights and prevent main window destruction on close.
-                // The main window hosts the pipeline event listeners — if it's destroyed,
-                // global shortcuts stop working. Hide instead of close.
+                // Position traffic lights
                 if let Some(window) = app.get_webview_window("main") {
                     traffic_lights::setup_traffic_lights(&window, TRAFFIC_LIGHT_X, HEADER_HEIGHT);
-
-                    let win = window.clone();
-                    window.on_window_event(move |event| {
-                        if let tauri::WindowEvent::CloseRequested { api, .. } = event {
-                            api.prevent_close();
-                            let _ = win.hide();
-                        }
-                    });
                 }
 
-                // Log accessibility permission status on startup for diagnostics.
-                // The frontend pulls the authoritative state via check_accessibility +
-                // verify_accessibility_functional on mount — no event emission needed.
-                let has_accessibility = platform::check_accessibility();
+                // Check accessibility end to end on startup. A stale grant (trusted
+                // but not functional) is logged and flagged for the tray status
+                // and get_permission_health; the frontend pulls the state on mount.
+                let (has_accessibility, functional) = platform::refresh_accessibility_health();
                 if has_accessibility {
-                    if platform::verify_accessibility_functional() {
+                    if functional {
                         tracing::info!("Accessibility permission granted and functional");
                     } else {
-                        tracing::warn!(
-                            "Accessibility permission appears granted but is stale — \
-                             TCC entry may need resetting"
-                        );
+                        // The tray was built before this check; show the stale state.
+                        tray::refresh_tray_menu(app.handle().clone());
                     }
                 } else {
                     tracing::warn!(
@@ -585,9 +750,12 @@ pub fn run() {
 
             // Pre-warm the recording indicator window to eliminate first-show delay.
             // This loads the webview content in the background so it's ready instantly.
+            // Skipped when the indicator is disabled, so the window is never created.
             recording_indicator::prewarm_indicator_window(app.handle());
 
-            // Initialise mouse tracker for cursor-following recording indicator
+            // Initialise mouse tracker for cursor-following recording indicator.
+            // This only stores the app handle; the tracking thread starts when the
+            // indicator is shown, so it never runs while the indicator is disabled.
             mouse_tracker::init(app.handle());
 
             // Pre-warm the transcription model to trigger Metal shader compilation.
@@ -604,7 +772,10 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             // Commands
-            commands::greet,
+            commands::get_app_info,
+            commands::open_log_directory,
+            commands::get_recent_logs,
+            commands::set_log_level,
             commands::show_window,
             commands::hide_window,
             commands::toggle_window,
@@ -614,31 +785,44 @@ pub fn run() {
             commands::relaunch_app,
             commands::set_show_in_dock,
             commands::get_show_in_dock,
+            autostart::set_autostart,
+            autostart::is_autostart_enabled,
             commands::set_audio_device,
             commands::get_audio_device,
             // Platform
             platform::check_accessibility,
             platform::request_accessibility,
             platform::verify_accessibility_functional,
+            platform::get_permission_health,
+            platform::request_all_permissions,
             platform::reset_tcc_permissions,
             platform::check_microphone_permission,
             platform::request_microphone_permission,
             platform::get_gpu_info,
+            platform::get_macos_acceleration_info,
             // Audio
             audio::device::list_audio_devices,
             audio::preview::start_audio_preview,
             audio::preview::stop_audio_preview,
             audio::preview::is_audio_preview_running,
+            audio::preview::run_microphone_test,
+            audio::vad::get_vad_status,
             audio::start_recording,
             audio::stop_recording,
             audio::is_recording,
+            audio::get_audio_buffer_stats,
+            audio::decode::convert_audio_to_wav,
+            audio::decode::cancel_audio_conversion,
             audio::warm_up_recording,
             // Transcription
             transcription::init_transcription,
             transcription::init_whisper_transcription,
             transcription::init_parakeet_transcription,
             transcription::transcribe_file,
+            transcription::transcribe_file_segments,
+            transcription::transcribe_pcm,
             transcription::is_transcription_ready,
+            transcription::is_model_loading,
             transcription::get_transcription_backend,
             transcription::get_model_directory,
             transcription::get_whisper_model_directory,
@@ -655,15 +839,19 @@ pub fn run() {
             transcription::download::download_model,
             transcription::download::get_model_info,
             transcription::download::delete_model,
+            transcription::download::list_downloaded_models,
             transcription::download::reset_download_state,
+            transcription::download::cancel_download,
             transcription::manifest::fetch_model_manifest,
             transcription::manifest::get_manifest_update_time,
+            transcription::manifest::refresh_manifest,
             // Enhancement
             enhancement::check_ollama_available,
             enhancement::list_ollama_models,
             enhancement::check_openai_compat_available,
             enhancement::list_openai_compat_models,
             enhancement::enhance_text,
+            enhancement::preview_prompt,
             enhancement::context::get_clipboard_context,
             enhancement::context::build_enhancement_context,
             // Prompt Templates
@@ -692,11 +880,19 @@ pub fn run() {
             database::trash::restore_recordings,
             database::trash::purge_trash,
             database::trash::list_trash,
+            // Sessions
+            database::session::start_session,
+            database::session::end_session,
+            database::session::rename_session,
+            database::session::list_sessions,
+            database::session::get_current_session,
             // Export
             export::search_history,
+            export::list_models_used,
             export::export_to_json,
             export::export_to_csv,
             export::export_to_txt,
+            export::export_session,
             export::get_transcriptions,
             // Config
             config::get_config,
@@ -704,6 +900,8 @@ pub fn run() {
             config::set_shortcut_config,
             config::set_enhancement_api_key,
             config::reset_config,
+            config::reset_filter_config,
+            config::reset_enhancement_config,
             config::get_config_path_cmd,
             // Shortcuts
             shortcuts::register_shortcut,
@@ -716,12 +914,14 @@ pub fn run() {
             shortcuts::check_shortcut_available,
             shortcuts::get_shortcut_suggestions,
             shortcuts::validate_shortcut,
+            shortcuts::get_shortcut_backend_info,
             reregister_shortcuts,
             // Dictionary
             dictionary::get_dictionary_entries,
             dictionary::add_dictionary_entry,
             dictionary::update_dictionary_entry,
             dictionary::remove_dictionary_entry,
+            dictionary::reset_dictionary,
             dictionary::import_dictionary,
             dictionary::export_dictionary,
             dictionary::apply_dictionary_to_text,
@@ -761,10 +961,20 @@ pub fn run() {
             pipeline::pipeline_stop_and_process,
             pipeline::pipeline_toggle_recording,
             pipeline::pipeline_transcribe_file,
+            pipeline::pipeline_dry_run,
+            pipeline::pipeline_transcribe_files,
+            pipeline::pipeline_transcribe_clipboard_path,
             pipeline::pipeline_retranscribe,
+            pipeline::pipeline_retranscribe_bulk,
             pipeline::pipeline_cancel,
             pipeline::is_pipeline_running,
             pipeline::get_pipeline_state,
+            // Transcription queue
+            queue::enqueue_transcriptions,
+            queue::get_transcription_queue,
+            queue::remove_from_queue,
+            // Self-test
+            selftest::run_pipeline_selftest,
             // Recording indicator
             recording_indicator::show_recording_indicator,
             recording_indicator::hide_recording_indicator,
@@ -775,9 +985,14 @@ pub fn run() {
             // Storage management
             storage::get_storage_usage,
             storage::delete_all_recordings,
+            storage::get_recordings_location,
+            storage::set_recordings_dir,
             storage::delete_all_logs,
             storage::delete_fluidaudio_cache,
             storage::delete_all_data,
+            storage::get_data_location,
+            storage::migrate_data_dir,
+            storage::is_data_dir_moving,
             // Keyboard service (shortcut capture + modifier monitoring)
             keyboard_service::enter_capture_mode,
             keyboard_service::exit_capture_mode,
@@ -790,6 +1005,7 @@ pub fn run() {
             control_api::get_integrations_status,
             control_api::set_api_enabled,
             control_api::set_mcp_enabled,
+            control_api::set_dictation_enabled,
             control_api::get_api_token,
             control_api::rotate_api_token,
             control_api::set_api_port,
@@ -797,6 +1013,13 @@ pub fn run() {
             telemetry::test_loki_connection,
             config::set_loki_auth,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            if let tauri::RunEvent::Exit = event {
+                // Settings changed in the last moments before quitting are
+                // still waiting out the save debounce.
+                let _ = config::flush_pending_save();
+            }
+        });
 }
@@ -53,10 +53,25 @@ pub struct DictionaryParams {
     /// For `add`/`update`: whether the match is case-sensitive (default false).
     #[serde(default)]
     pub case_sensitive: Option<bool>,
+    /// For `add`/`update`: restrict the entry to this application name (optional).
+    #[serde(default)]
+    pub app: Option<String>,
+    /// For `add`/`update`: restrict the entry to this language code, e.g. "en" (optional).
+    #[serde(default)]
+    pub language: Option<String>,
+    /// For `add`/`update`: higher priorities apply first (default 0).
+    #[serde(default)]
+    pub priority: Option<i32>,
+    /// For `add`/`update`: also match phonetically close variants (default false).
+    #[serde(default)]
+    pub fuzzy: Option<bool>,
+    /// For `add`/`update`: similarity 0-1 a fuzzy match needs (default 0.7).
+    #[serde(default)]
+    pub fuzzy_threshold: Option<f64>,
     /// For `update`/`delete`: the zero-based index of the entry (from `list`).
     #[serde(default)]
     pub index: Option<usize>,
-    /// For `import`: a JSON string of dictionary entries.
+    /// For `import`: dictionary entries as JSON, or CSV/TSV with a from/to header.
     #[serde(default)]
     pub json: Option<String>,
     /// For `import`: merge with existing entries (true) or replace (false). Default true.
@@ -187,7 +202,7 @@ impl ThothMcp {
     }
 
     #[tool(
-        description = "Manage Thoth's personal dictionary (find/replace entries applied to transcriptions). Use this to view or change spelling corrections and word replacements. Action: list | add | update | delete | import | export. add/update require from + to (+ optional caseSensitive); update/delete require index (from list); import requires json (+ optional merge). Returns: the entry list (list), a compact ack {ok, action, index, count} (add/update/delete), a count (import), or a JSON string (export)."
+        description = "Manage Thoth's personal dictionary (find/replace entries applied to transcriptions). Use this to view or change spelling corrections and word replacements. Action: list | add | update | delete | import | export. add/update require from + to (+ optional caseSensitive, priority, fuzzy/fuzzy_threshold, and app/language to scope the entry); update/delete require index (from list); import requires json (JSON, or CSV/TSV with a from/to header; + optional merge). Returns: the entry list (list), a compact ack {ok, action, index, count} (add/update/delete), {imported, skippedDuplicates, errors} (import), or a JSON string (export)."
     )]
     async fn dictionary(
         &self,
@@ -208,6 +223,13 @@ impl ThothMcp {
                         .to
                         .ok_or_else(|| core_err("`to` required for add".into()))?,
                     case_sensitive: p.case_sensitive.unwrap_or(false),
+                    scope: Some(crate::dictionary::DictionaryScope {
+                        app: p.app,
+                        language: p.language,
+                    }),
+                    priority: p.priority.unwrap_or(0),
+                    fuzzy: p.fuzzy.unwrap_or(false),
+                    fuzzy_threshold: p.fuzzy_threshold,
                 };
                 crate::dictionary::add_dictionary_entry(entry)
                     .map_err(|e| core_err(e.to_string()))?;
@@ -229,6 +251,13 @@ impl ThothMcp {
                         .to
                         .ok_or_else(|| core_err("`to` required for update".into()))?,
                     case_sensitive: p.case_sensitive.unwrap_or(false),
+                    scope: Some(crate::dictionary::DictionaryScope {
+                        app: p.app,
+                        language: p.language,
+                    }),
+                    priority: p.priority.unwrap_or(0),
+                    fuzzy: p.fuzzy.unwrap_or(false),
+                    fuzzy_threshold: p.fuzzy_threshold,
                 };
                 crate::dictionary::update_dictionary_entry(index, entry)
                     .map_err(|e| core_err(e.to_string()))?;
@@ -252,13 +281,14 @@ impl ThothMcp {
                 let json = p
                     .json
                     .ok_or_else(|| core_err("`json` required for import".into()))?;
-                let count = crate::dictionary::import_dictionary(json, p.merge.unwrap_or(true))
-                    .map_err(|e| core_err(e.to_string()))?;
-                json_result(&serde_json::json!({ "imported": count }))
+                let summary =
+                    crate::dictionary::import_dictionary_content(&json, p.merge.unwrap_or(true))
+                        .map_err(|e| core_err(e.to_string()))?;
+                json_result(&summary)
             }
             "export" => {
-                let json =
-                    crate::dictionary::export_dictionary().map_err(|e| core_err(e.to_string()))?;
+                let json = crate::dictionary::export_dictionary(None)
+                    .map_err(|e| core_err(e.to_string()))?;
                 Ok(CallToolResult::success(vec![Content::text(json)]))
             }
             other => Err(core_err(format!("unknown action: {}", other))),
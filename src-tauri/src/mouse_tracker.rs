@@ -120,7 +120,7 @@ pub fn notify_wake() {
 ///
 /// Returns `(x, y)` in logical points with origin at top-left of primary display.
 #[cfg(target_os = "macos")]
-fn get_mouse_position() -> Option<(f64, f64)> {
+pub(crate) fn get_mouse_position() -> Option<(f64, f64)> {
     use core_graphics::event::CGEvent;
     use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
 
@@ -135,7 +135,7 @@ fn get_mouse_position() -> Option<(f64, f64)> {
 /// Returns `(x, y)` in logical pixels with origin at top-left of primary display.
 /// Returns `None` on Wayland (where X11 isn't available).
 #[cfg(target_os = "linux")]
-fn get_mouse_position() -> Option<(f64, f64)> {
+pub(crate) fn get_mouse_position() -> Option<(f64, f64)> {
     // Check if we're on Wayland - X11 won't work there
     if crate::shortcuts::is_wayland() {
         return None;
@@ -162,7 +162,7 @@ fn get_mouse_position() -> Option<(f64, f64)> {
 }
 
 #[cfg(all(not(target_os = "macos"), not(target_os = "linux")))]
-fn get_mouse_position() -> Option<(f64, f64)> {
+pub(crate) fn get_mouse_position() -> Option<(f64, f64)> {
     None
 }
 
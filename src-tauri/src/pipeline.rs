@@ -10,15 +10,17 @@
 
 use crate::canonical;
 use crate::clipboard;
+use crate::config::TrailingSpace;
 use crate::database;
 use crate::dictionary;
 use crate::enhancement;
 use crate::error::Error;
+use crate::redaction::Redactor;
 use crate::transcription;
 use crate::tray;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use tauri::{AppHandle, Emitter};
 
 /// Pipeline execution state
@@ -69,18 +71,60 @@ pub struct PipelineConfig {
     /// Whether to convert spoken formatting commands ("new paragraph" / "new
     /// line") into line breaks
     pub voice_formatting_commands: bool,
+    /// Whether to collapse Whisper's repetition loops to one occurrence
+    #[serde(default = "default_collapse_repetitions")]
+    pub collapse_repetitions: bool,
+    /// Whether to start a new line at long pauses, marking likely speaker
+    /// turns
+    #[serde(default)]
+    pub mark_pauses: bool,
     /// Whether AI enhancement is enabled
     pub enhancement_enabled: bool,
     /// Ollama model for enhancement
     pub enhancement_model: String,
     /// Enhancement prompt template
     pub enhancement_prompt: String,
+    /// Prompt templates applied in order, each pass enhancing the previous
+    /// pass's output. Empty means a single pass with `enhancement_prompt`.
+    #[serde(default)]
+    pub enhancement_chain: Vec<String>,
+    /// Transcriptions with fewer words than this are not enhanced, even with
+    /// enhancement enabled. 0 means no minimum.
+    #[serde(default = "default_enhancement_min_words")]
+    pub enhancement_min_words: u32,
     /// Whether to auto-copy to clipboard
     pub auto_copy: bool,
     /// Whether to auto-paste at cursor
     pub auto_paste: bool,
     /// Insertion method: "typing" or "paste"
     pub insertion_method: String,
+    /// Whether to keep the result in history. When false the text is output
+    /// but never written to the database, and the recording is deleted.
+    #[serde(default = "default_save_to_history")]
+    pub save_to_history: bool,
+    /// Insertion method chosen for this recording by the shortcut that
+    /// started it ("typing" or "paste"). Overrides `insertion_method` and
+    /// inserts even with auto-paste off. Only read when recording starts.
+    #[serde(default)]
+    pub forced_insertion_method: Option<String>,
+    /// Output the model's words exactly: no filters, dictionary, enhancement,
+    /// added full stop or spacing. Set by the "verbatim dictation" shortcut
+    /// and applied however the recording is stopped (see
+    /// [`PipelineConfig::make_verbatim`]).
+    #[serde(default)]
+    pub verbatim: bool,
+}
+
+fn default_save_to_history() -> bool {
+    true
+}
+
+fn default_collapse_repetitions() -> bool {
+    true
+}
+
+fn default_enhancement_min_words() -> u32 {
+    crate::config::EnhancementConfig::default().min_words
 }
 
 impl Default for PipelineConfig {
@@ -95,16 +139,52 @@ impl Default for PipelineConfig {
             cleanup_punctuation: true,
             sentence_case: false,
             voice_formatting_commands: true,
+            collapse_repetitions: true,
+            mark_pauses: false,
             enhancement_enabled: false,
             enhancement_model: "llama3.2".to_string(),
             enhancement_prompt: DEFAULT_ENHANCEMENT_PROMPT.to_string(),
+            enhancement_chain: Vec::new(),
+            enhancement_min_words: default_enhancement_min_words(),
             auto_copy: false,
             auto_paste: true,
             insertion_method: "paste".to_string(),
+            save_to_history: true,
+            forced_insertion_method: None,
+            verbatim: false,
+        }
+    }
+}
+
+impl PipelineConfig {
+    /// Switch off every step that rewrites the transcript, for when the
+    /// exact wording matters more than readability
+    pub fn make_verbatim(&mut self) {
+        self.verbatim = true;
+        self.apply_filtering = false;
+        self.apply_dictionary = false;
+        self.mark_pauses = false;
+        self.enhancement_enabled = false;
+    }
+
+    /// Whether the transcriber must keep timed segments for pause marking
+    fn keeps_segments(&self) -> bool {
+        self.apply_filtering && self.mark_pauses
+    }
+
+    /// The prompt templates to apply, in order
+    fn enhancement_passes(&self) -> Vec<&str> {
+        if self.enhancement_chain.is_empty() {
+            vec![self.enhancement_prompt.as_str()]
+        } else {
+            self.enhancement_chain.iter().map(String::as_str).collect()
         }
     }
 }
 
+/// Separates the prompts of a multi-pass chain in the history record
+const ENHANCEMENT_CHAIN_SEPARATOR: &str = "\n\n---\n\n";
+
 /// Build the effective [`PipelineConfig`] from the saved settings, mirroring the
 /// frontend's `getDefaultConfig()`. Used by entry points that trigger a recording
 /// without a frontend-supplied config (e.g. the bundled MCP server) so the result
@@ -114,14 +194,20 @@ pub(crate) fn effective_pipeline_config() -> Result<PipelineConfig, Error> {
     let cfg = crate::config::get_config()?;
     let t = &cfg.transcription;
     let e = &cfg.enhancement;
-    let enhancement_prompt = if e.enabled {
+    let resolve = |id: &str| {
         crate::enhancement::prompts::get_all_prompts()
             .into_iter()
-            .find(|p| p.id == e.prompt_id)
+            .find(|p| p.id == id)
             .map(|p| p.template)
             .unwrap_or_else(|| DEFAULT_ENHANCEMENT_PROMPT.to_string())
+    };
+    let (enhancement_prompt, enhancement_chain) = if e.enabled {
+        (
+            resolve(&e.prompt_id),
+            e.prompt_chain.iter().map(|id| resolve(id)).collect(),
+        )
     } else {
-        DEFAULT_ENHANCEMENT_PROMPT.to_string()
+        (DEFAULT_ENHANCEMENT_PROMPT.to_string(), Vec::new())
     };
     Ok(PipelineConfig {
         apply_dictionary: true,
@@ -133,12 +219,19 @@ pub(crate) fn effective_pipeline_config() -> Result<PipelineConfig, Error> {
         cleanup_punctuation: t.cleanup_punctuation,
         sentence_case: t.sentence_case,
         voice_formatting_commands: t.voice_formatting_commands,
+        collapse_repetitions: t.collapse_repetitions,
+        mark_pauses: t.mark_pauses,
         enhancement_enabled: e.enabled,
         enhancement_model: e.model.clone(),
         enhancement_prompt,
+        enhancement_chain,
+        enhancement_min_words: e.min_words,
         auto_copy: t.auto_copy,
         auto_paste: t.auto_paste,
         insertion_method: "paste".to_string(),
+        save_to_history: cfg.pipeline.save_to_history,
+        forced_insertion_method: None,
+        verbatim: false,
     })
 }
 
@@ -162,6 +255,10 @@ pub struct PipelineResult {
     pub is_enhanced: bool,
     /// Duration of the audio in seconds
     pub duration_seconds: Option<f64>,
+    /// Speaking pace: words in the final text over the audio duration, when
+    /// the recording is long enough to say
+    #[serde(default)]
+    pub words_per_minute: Option<f64>,
     /// Path to the audio file
     pub audio_path: Option<String>,
     /// Error message if the pipeline failed
@@ -176,6 +273,150 @@ pub struct PipelineResult {
     pub enhancement_model_name: Option<String>,
     /// Time taken for enhancement in seconds
     pub enhancement_duration_seconds: Option<f64>,
+    /// Time spent converting the input to 16 kHz WAV, when it needed it
+    #[serde(default)]
+    pub decode_duration_seconds: Option<f64>,
+    /// Time spent waiting for the transcription model to finish loading
+    #[serde(default)]
+    pub model_wait_seconds: Option<f64>,
+    /// Time spent in filters and the dictionary, when they ran
+    #[serde(default)]
+    pub filter_duration_seconds: Option<f64>,
+    /// Whether the input clipped enough to suggest lowering the gain
+    #[serde(default)]
+    pub clipped: bool,
+    /// Fraction of samples at or near full scale, when measured
+    #[serde(default)]
+    pub clip_ratio: Option<f32>,
+    /// Whether input was lost before capture (device dropouts) while recording
+    #[serde(default)]
+    pub had_dropouts: bool,
+    /// Whether the input held no speech; the text is empty and nothing was
+    /// saved to history
+    #[serde(default)]
+    pub was_silent: bool,
+}
+
+impl PipelineResult {
+    /// Result for input that contained no speech.
+    ///
+    /// Silence is not a failure: the result is successful with empty text,
+    /// and no recording or history entry is kept.
+    fn silent(duration_seconds: Option<f64>) -> Self {
+        Self {
+            success: true,
+            text: String::new(),
+            raw_text: String::new(),
+            is_enhanced: false,
+            duration_seconds,
+            words_per_minute: None,
+            audio_path: None,
+            error: None,
+            transcription_id: None,
+            transcription_model_name: None,
+            transcription_duration_seconds: None,
+            enhancement_model_name: None,
+            enhancement_duration_seconds: None,
+            decode_duration_seconds: None,
+            model_wait_seconds: None,
+            filter_duration_seconds: None,
+            clipped: false,
+            clip_ratio: None,
+            had_dropouts: false,
+            was_silent: true,
+        }
+    }
+}
+
+/// Event emitted when a recording or import contained no speech and
+/// `config.pipeline.notify_on_silence` is on (payload: none)
+pub const NO_SPEECH_DETECTED_EVENT: &str = "no-speech-detected";
+
+/// Event emitted once whenever a recording stops, however it was stopped
+/// (payload: [`RecordingStopped`])
+pub const RECORDING_STOPPED_EVENT: &str = "recording-stopped";
+
+/// Why a recording stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StopReason {
+    /// Stopped on request: the shortcut, tray, control API or MCP
+    User,
+    /// Stopped and discarded by [`pipeline_cancel`]
+    Cancelled,
+    /// Stopped on request but shorter than `config.pipeline.min_recording_ms`,
+    /// so discarded untranscribed
+    TooShort,
+    /// The capture could not be stopped cleanly
+    Error,
+}
+
+/// Payload of [`RECORDING_STOPPED_EVENT`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingStopped {
+    pub reason: StopReason,
+    /// The recording's audio file, when the stop produced one
+    pub audio_path: Option<String>,
+    /// What went wrong, for [`StopReason::Error`]
+    pub error: Option<String>,
+}
+
+/// Event emitted when auto-paste found no text field focused and the text
+/// was copied to the clipboard instead (payload: none)
+pub const NO_TEXT_FIELD_FOCUSED_EVENT: &str = "no-text-field-focused";
+
+/// Event emitted when a recording clipped (payload: [`RecordingClipped`])
+pub const RECORDING_CLIPPED_EVENT: &str = "recording-clipped";
+
+/// Payload of [`RECORDING_CLIPPED_EVENT`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingClipped {
+    /// The clipped audio file
+    pub audio_path: String,
+    /// Fraction of samples at or near full scale
+    pub clip_ratio: f32,
+}
+
+/// Event emitted when a dictation starts on a model that cannot transcribe
+/// the configured language (payload: [`ModelLanguageMismatch`]). Sent once
+/// per model and language pair; recording goes ahead regardless.
+pub const MODEL_LANGUAGE_MISMATCH_EVENT: &str = "model-language-mismatch";
+
+/// Payload of [`MODEL_LANGUAGE_MISMATCH_EVENT`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelLanguageMismatch {
+    pub model_id: String,
+    /// Display name of the model
+    pub model_name: String,
+    /// The configured transcription language
+    pub language: String,
+    /// Why the model does not fit the language
+    pub warning: String,
+}
+
+/// Event emitted on every pipeline state change (payload: [`PipelineState`]).
+///
+/// The one event to follow for tracking what Thoth is doing: each change is
+/// sent once, and `Completed`/`Failed`/cancellation are followed by `Idle`
+/// (or `Recording`, if a new clip started meanwhile) once nothing is left
+/// running.
+pub const PIPELINE_STATE_EVENT: &str = "pipeline-state";
+
+/// Event emitted when a stopped recording waits for the transcription model
+/// to finish loading (payload: [`QueuedForTranscription`])
+pub const QUEUED_FOR_TRANSCRIPTION_EVENT: &str = "queued-for-transcription";
+
+/// Payload of [`QUEUED_FOR_TRANSCRIPTION_EVENT`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedForTranscription {
+    /// The queued recording
+    pub audio_path: String,
+    /// Recordings ahead of this one in the queue
+    pub position: u64,
 }
 
 /// Progress event payload
@@ -195,13 +436,150 @@ pub struct PipelineProgress {
 /// A new recording is rejected only when this is true.
 static PIPELINE_RUNNING: AtomicBool = AtomicBool::new(false);
 
+/// Two start requests arriving within this window of each other (shortcut and
+/// tray, or the Wayland portal and tray, firing together) are one start: the
+/// second gets the first recording's path instead of an error.
+const DUPLICATE_START_WINDOW: std::time::Duration = std::time::Duration::from_millis(400);
+
+/// The most recent successful recording start. The lock is held for the whole
+/// of `pipeline_start_recording`, so a racing second start waits for the first
+/// to finish opening the device and then sees its result.
+static LAST_START: parking_lot::Mutex<Option<RecentStart>> = parking_lot::Mutex::new(None);
+
+/// A recording start remembered for duplicate detection
+struct RecentStart {
+    path: String,
+    at: std::time::Instant,
+}
+
+/// Outcome of trying to claim the capture slot
+#[derive(Debug, PartialEq, Eq)]
+enum StartClaim {
+    /// The slot was free and is now ours; proceed with the start
+    Claimed,
+    /// A recording started moments ago; this request duplicates it
+    Duplicate(String),
+    /// A recording is in progress and this is not a near-simultaneous start
+    Busy,
+}
+
+/// Claim `running` for a new recording, or classify why it cannot be claimed.
+fn claim_start(
+    running: &AtomicBool,
+    last_start: Option<&RecentStart>,
+    is_recording: bool,
+    now: std::time::Instant,
+) -> StartClaim {
+    if !running.swap(true, Ordering::SeqCst) {
+        return StartClaim::Claimed;
+    }
+    match last_start {
+        Some(start) if is_recording && now.duration_since(start.at) < DUPLICATE_START_WINDOW => {
+            StartClaim::Duplicate(start.path.clone())
+        }
+        _ => StartClaim::Busy,
+    }
+}
+
+/// Run `start` unless a recording is already running, holding `last_start`
+/// throughout so a racing second request waits for the first and then gets
+/// its path (see [`claim_start`]). A successful start is remembered in
+/// `last_start`.
+fn start_once(
+    running: &AtomicBool,
+    last_start: &parking_lot::Mutex<Option<RecentStart>>,
+    is_recording: impl FnOnce() -> bool,
+    start: impl FnOnce() -> Result<String, Error>,
+) -> Result<String, Error> {
+    let mut last_start = last_start.lock();
+    match claim_start(
+        running,
+        last_start.as_ref(),
+        is_recording(),
+        std::time::Instant::now(),
+    ) {
+        StartClaim::Claimed => {}
+        StartClaim::Duplicate(path) => {
+            tracing::info!("Pipeline: Duplicate start request, returning active recording");
+            return Ok(path);
+        }
+        StartClaim::Busy => {
+            tracing::warn!("Pipeline: Already running, rejecting start request");
+            return Err("Pipeline is already running".to_string().into());
+        }
+    }
+
+    let path = start()?;
+    *last_start = Some(RecentStart {
+        path: path.clone(),
+        at: std::time::Instant::now(),
+    });
+    Ok(path)
+}
+
+/// The path of a recording started within [`DUPLICATE_START_WINDOW`] that is
+/// still capturing, if any.
+fn recent_start_path() -> Option<String> {
+    let last_start = LAST_START.lock();
+    let start = last_start.as_ref()?;
+    (crate::audio::is_recording() && start.at.elapsed() < DUPLICATE_START_WINDOW)
+        .then(|| start.path.clone())
+}
+
+/// Set when the current recording was started with `save_to_history` off
+/// (the "dictate without saving" shortcut), so it is not saved however it is
+/// stopped.
+static UNSAVED_RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// Insertion method the current recording was started with by a "dictate by
+/// typing/pasting" shortcut, applied however it is stopped.
+static FORCED_INSERTION_METHOD: parking_lot::Mutex<Option<String>> = parking_lot::Mutex::new(None);
+
+/// Set when the current recording was started by the "verbatim dictation"
+/// shortcut, so it is output word for word however it is stopped.
+static VERBATIM_RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// Whether the current recording will be output verbatim
+pub(crate) fn is_verbatim_recording() -> bool {
+    VERBATIM_RECORDING.load(Ordering::SeqCst)
+}
+
+/// A caller's channel for the outcome of one recording
+type ResultSender = tokio::sync::oneshot::Sender<Result<PipelineResult, String>>;
+
+/// Callers waiting on a recording, keyed by its audio path (see
+/// [`wait_for_recording_result`]).
+static RESULT_WAITERS: parking_lot::Mutex<Vec<(String, ResultSender)>> =
+    parking_lot::Mutex::new(Vec::new());
+
 /// Counts how many detached process_audio tasks are in-flight.
 /// Used by get_pipeline_state to distinguish Recording vs Transcribing vs Idle.
 static PROCESSING_COUNT: AtomicUsize = AtomicUsize::new(0);
 
+/// The state last sent as [`PIPELINE_STATE_EVENT`], so repeats are skipped
+static LAST_EMITTED_STATE: parking_lot::Mutex<PipelineState> =
+    parking_lot::Mutex::new(PipelineState::Idle);
+
 /// Cancellation signal for file import operations
 static IMPORT_CANCELLED: AtomicBool = AtomicBool::new(false);
 
+/// Decode cancellation signal for files from the transcription queue.
+///
+/// Kept apart from [`IMPORT_CANCELLED`], which imports reset as they start
+/// and `pipeline_cancel` raises, so a queued file and an import never clear
+/// or trip each other's cancellation. Queued items run to completion (see
+/// [`crate::queue`]), so nothing raises it yet.
+static QUEUE_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// How long a recording may wait for the transcription model to load
+const MODEL_LOAD_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Tickets for recordings stopped while the model was still loading. Each
+/// takes the next ticket when it stops and is transcribed once the model is
+/// ready and every earlier ticket has gone, so the queue drains in order.
+static QUEUE_NEXT_TICKET: AtomicU64 = AtomicU64::new(0);
+static QUEUE_SERVING: AtomicU64 = AtomicU64::new(0);
+
 /// Error message emitted when transcription produces no text (silent recording).
 ///
 /// Used as a typed sentinel: callers that need to distinguish "nothing was said"
@@ -211,21 +589,32 @@ const NO_SPEECH_ERROR: &str = "Transcription produced no text";
 
 /// Returns true when the pipeline error string indicates a silent recording rather
 /// than a genuine failure. Used to suppress error UI and silently delete orphan WAVs.
-fn is_no_speech_error(e: &str) -> bool {
+pub(crate) fn is_no_speech_error(e: &str) -> bool {
     e == NO_SPEECH_ERROR
 }
 
-/// Deletes `audio_path` when `result` is the no-speech sentinel.
+/// Turns the no-speech sentinel into a benign [`PipelineResult::silent`].
 ///
-/// Returns `true` if the file was discarded (caller should suppress error UI),
-/// `false` if the result was either success or a genuine error (caller handles
-/// normally). Both the recording-path arm and the import-path arm delegate here
-/// so the discard decision is tested once in one place.
-fn discard_silent_wav(result: &Result<PipelineResult, String>, audio_path: &str) -> bool {
-    let Err(e) = result else { return false };
-    if !is_no_speech_error(e) {
-        return false;
+/// Deletes `audio_path`, since a silent recording has nothing worth keeping.
+/// Successes and genuine errors are returned unchanged, so the caller handles
+/// them normally. Both the recording-path arm and the import-path arm delegate
+/// here so the silence decision is tested once in one place.
+fn resolve_silence(
+    result: Result<PipelineResult, String>,
+    audio_path: &str,
+) -> Result<PipelineResult, String> {
+    match result {
+        Err(e) if is_no_speech_error(&e) => {
+            let duration_seconds = get_audio_duration(audio_path);
+            discard_silent_wav(audio_path);
+            Ok(PipelineResult::silent(duration_seconds))
+        }
+        other => other,
     }
+}
+
+/// Delete the WAV of a recording that contained no speech.
+fn discard_silent_wav(audio_path: &str) {
     tracing::info!(
         "Pipeline: Silent recording, deleting orphan WAV: {}",
         audio_path
@@ -238,7 +627,63 @@ fn discard_silent_wav(result: &Result<PipelineResult, String>, audio_path: &str)
             del_err
         );
     }
-    true
+}
+
+/// Whether to tell the user when a recording or import held no speech.
+fn notify_on_silence() -> bool {
+    crate::config::get_config()
+        .map(|c| c.pipeline.notify_on_silence)
+        .unwrap_or(true)
+}
+
+/// Emit the completion of a recording or import: `pipeline-complete`, plus
+/// [`NO_SPEECH_DETECTED_EVENT`] for silent input when the user wants notice.
+fn emit_complete(app: &AppHandle, result: &PipelineResult) {
+    tracing::info!("Pipeline: Emitting pipeline-complete event");
+    if let Err(e) = app.emit("pipeline-complete", result) {
+        tracing::error!("Pipeline: Failed to emit pipeline-complete: {}", e);
+    }
+    if result.was_silent && notify_on_silence() {
+        if let Err(e) = app.emit(NO_SPEECH_DETECTED_EVENT, ()) {
+            tracing::warn!(
+                "Pipeline: Failed to emit {}: {}",
+                NO_SPEECH_DETECTED_EVENT,
+                e
+            );
+        }
+    }
+}
+
+/// The configured minimum recording length (`config.pipeline.min_recording_ms`)
+fn min_recording_ms() -> u32 {
+    crate::config::get_config()
+        .map(|c| c.pipeline.min_recording_ms)
+        .unwrap_or_default()
+}
+
+/// Whether a recording of `duration_seconds` is shorter than `min_ms`.
+///
+/// A recording of unknown length is never taken as too short; the pipeline
+/// deals with unreadable audio itself.
+fn is_too_short(duration_seconds: Option<f64>, min_ms: u32) -> bool {
+    duration_seconds.is_some_and(|secs| secs * 1000.0 < f64::from(min_ms))
+}
+
+/// Whether the privacy setting asks for recordings to be deleted once
+/// transcribed (and after failures or cancellation, so none linger).
+fn delete_audio_after_transcription() -> bool {
+    crate::config::get_config()
+        .map(|c| c.privacy.delete_audio_after_transcription)
+        .unwrap_or(false)
+}
+
+/// Remove a recording for the privacy setting; a file already gone is fine.
+fn delete_audio_file(audio_path: &str) {
+    match std::fs::remove_file(audio_path) {
+        Ok(()) => tracing::info!("Pipeline: Deleted audio (privacy): {}", audio_path),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => tracing::warn!("Pipeline: Failed to delete audio {}: {}", audio_path, e),
+    }
 }
 
 /// Serialises clipboard-save → paste → clipboard-restore across concurrent
@@ -246,6 +691,71 @@ fn discard_silent_wav(result: &Result<PipelineResult, String>, audio_path: &str)
 /// clipboard and corrupt the restored content.
 static OUTPUT_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
 
+/// An insert within this long of the previous one, into the same app, is
+/// taken to continue it (for the `Smart` trailing-space mode).
+const APPEND_WINDOW: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// The most recent insert at the cursor, for the `Smart` trailing-space mode
+static LAST_INSERT: parking_lot::Mutex<Option<RecentInsert>> = parking_lot::Mutex::new(None);
+
+/// An insert remembered for append detection
+struct RecentInsert {
+    app: Option<String>,
+    at: std::time::Instant,
+}
+
+/// Whether an insert into `app` at `now` follows on from `last`.
+fn is_append(last: Option<&RecentInsert>, app: Option<&str>, now: std::time::Instant) -> bool {
+    last.is_some_and(|last| {
+        now.saturating_duration_since(last.at) <= APPEND_WINDOW && last.app.as_deref() == app
+    })
+}
+
+/// Spaces to put before and after inserted text for `mode`.
+///
+/// `Smart` leaves nothing trailing, so a single insert into a form field
+/// stays clean, and puts the separating space in front of an insert that
+/// follows on from the previous one instead.
+fn insert_spacing(mode: TrailingSpace, appending: bool) -> (&'static str, &'static str) {
+    match mode {
+        TrailingSpace::Always => ("", " "),
+        TrailingSpace::Never => ("", ""),
+        TrailingSpace::Smart if appending => (" ", ""),
+        TrailingSpace::Smart => ("", ""),
+    }
+}
+
+/// The configured trailing-space mode
+fn trailing_space_mode() -> TrailingSpace {
+    crate::config::get_config()
+        .map(|c| c.output.trailing_space)
+        .unwrap_or_default()
+}
+
+/// Whether auto-paste falls back to the clipboard when nothing editable is
+/// focused (`config.output.no_focus_fallback`)
+fn no_focus_fallback() -> bool {
+    crate::config::get_config()
+        .map(|c| c.output.no_focus_fallback)
+        .unwrap_or(false)
+}
+
+/// Apply the insertion method a shortcut chose when the recording started.
+///
+/// The shortcut's whole point is to put the text at the cursor, so auto-paste
+/// is switched on with it. Unknown methods are ignored.
+fn force_insertion_method(config: &mut PipelineConfig, method: Option<String>) {
+    let Some(method) = method else {
+        return;
+    };
+    if method != "typing" && method != "paste" {
+        tracing::warn!("Pipeline: Ignoring unknown insertion method {:?}", method);
+        return;
+    }
+    config.insertion_method = method;
+    config.auto_paste = true;
+}
+
 /// RAII guard that resets PIPELINE_RUNNING to false on drop.
 /// Used for recording capture only (not for processing).
 struct PipelineGuard;
@@ -277,15 +787,34 @@ impl Drop for ProcessingGuard {
 ///
 /// Emits `pipeline-progress` event with state updates.
 /// Also shows the recording indicator overlay and starts audio metering.
+///
+/// Idempotent for near-simultaneous starts: a second request within
+/// [`DUPLICATE_START_WINDOW`] of a successful start returns that recording's
+/// path and touches neither the indicator nor the UI state.
 #[tauri::command]
 pub fn pipeline_start_recording(app: AppHandle) -> Result<String, Error> {
-    tracing::info!("Pipeline: pipeline_start_recording called");
+    start_recording(app, crate::realtime_insert::is_enabled())
+}
 
-    if PIPELINE_RUNNING.swap(true, Ordering::SeqCst) {
-        tracing::warn!("Pipeline: Already running, rejecting start request");
-        return Err("Pipeline is already running".to_string().into());
-    }
+/// Start recording for a caller that takes the text itself (`POST /dictate`),
+/// so nothing is typed while it records, whatever insert-as-you-speak is set to.
+pub(crate) fn pipeline_start_recording_for_result(app: AppHandle) -> Result<String, Error> {
+    start_recording(app, false)
+}
+
+/// [`pipeline_start_recording`], with live insertion on or off
+fn start_recording(app: AppHandle, realtime_insert: bool) -> Result<String, Error> {
+    tracing::info!("Pipeline: pipeline_start_recording called");
+    start_once(
+        &PIPELINE_RUNNING,
+        &LAST_START,
+        crate::audio::is_recording,
+        || open_recording(app, realtime_insert),
+    )
+}
 
+/// Open the device and start capturing, once the capture slot is claimed
+fn open_recording(app: AppHandle, realtime_insert: bool) -> Result<String, Error> {
     // If the transcription model isn't loaded yet, try to load it in the
     // background while we record.  This avoids blocking the user — the model
     // will (hopefully) be ready by the time they stop speaking.
@@ -313,6 +842,11 @@ pub fn pipeline_start_recording(app: AppHandle) -> Result<String, Error> {
     // Emitting the device name later avoids blocking on the ~90ms CoreAudio device-resolution call before the UI updates.
     emit_progress(&app, PipelineState::Recording, "Recording audio...");
 
+    // Live insertion starts collecting before capture so no audio is missed.
+    if realtime_insert {
+        crate::realtime_insert::start();
+    }
+
     tracing::info!("Pipeline: Calling audio::start_recording");
     match crate::audio::start_recording() {
         Ok(path) => {
@@ -345,9 +879,14 @@ pub fn pipeline_start_recording(app: AppHandle) -> Result<String, Error> {
                 tracing::warn!("Pipeline: Failed to start recording metering: {}", e);
             }
 
+            UNSAVED_RECORDING.store(false, Ordering::SeqCst);
+            *FORCED_INSERTION_METHOD.lock() = None;
+            VERBATIM_RECORDING.store(false, Ordering::SeqCst);
+            warn_if_model_cannot_transcribe_language();
             Ok(path)
         }
         Err(e) => {
+            crate::realtime_insert::cancel();
             PIPELINE_RUNNING.store(false, Ordering::SeqCst);
             tracing::warn!(target: "telemetry", reason = "audio_start_failed", "audio_device_failure");
             emit_progress(
@@ -360,6 +899,58 @@ pub fn pipeline_start_recording(app: AppHandle) -> Result<String, Error> {
     }
 }
 
+/// The model and language pair last warned about by
+/// [`warn_if_model_cannot_transcribe_language`]
+static LANGUAGE_WARNED_FOR: parking_lot::Mutex<Option<(String, String)>> =
+    parking_lot::Mutex::new(None);
+
+/// Emit [`MODEL_LANGUAGE_MISMATCH_EVENT`] if the model this dictation will use
+/// cannot transcribe the configured language.
+///
+/// Runs on its own thread, as the manifest lookup must not delay the start of
+/// a recording. Warns once per model and language, not on every dictation.
+fn warn_if_model_cannot_transcribe_language() {
+    let Ok(config) = crate::config::get_config() else {
+        return;
+    };
+    std::thread::spawn(move || {
+        let transcription = config.transcription;
+        let Some(model_id) = transcription::effective_model_id(&transcription) else {
+            return;
+        };
+        let pair = (model_id, transcription.language);
+        let mut warned_for = LANGUAGE_WARNED_FOR.lock();
+        if warned_for.as_ref() == Some(&pair) {
+            return;
+        }
+        let (model_id, language) = pair.clone();
+        *warned_for = Some(pair);
+        drop(warned_for);
+
+        let manifest = transcription::manifest::get_fallback_manifest();
+        let Some(model) = manifest.models.iter().find(|m| m.id == model_id) else {
+            return;
+        };
+        if let Some(warning) = transcription::manifest::language_warning(model, &language) {
+            tracing::warn!(
+                "Pipeline: Model {} with language {:?}: {}",
+                model_id,
+                language,
+                warning
+            );
+            crate::app_handle::emit(
+                MODEL_LANGUAGE_MISMATCH_EVENT,
+                ModelLanguageMismatch {
+                    model_name: model.name.clone(),
+                    model_id,
+                    language,
+                    warning,
+                },
+            );
+        }
+    });
+}
+
 /// Stop recording and kick off the transcription pipeline asynchronously.
 ///
 /// Returns immediately after capture has stopped so a new recording can start
@@ -375,7 +966,14 @@ pub async fn pipeline_stop_and_process(
     config: Option<PipelineConfig>,
 ) -> Result<(), Error> {
     tracing::info!("Pipeline: stop_and_process called");
-    let config = config.unwrap_or_default();
+    let mut config = config.unwrap_or_default();
+    if UNSAVED_RECORDING.swap(false, Ordering::SeqCst) {
+        config.save_to_history = false;
+    }
+    force_insertion_method(&mut config, FORCED_INSERTION_METHOD.lock().take());
+    if VERBATIM_RECORDING.swap(false, Ordering::SeqCst) || config.verbatim {
+        config.make_verbatim();
+    }
 
     // Stop recording metering
     crate::audio::stop_recording_metering();
@@ -389,26 +987,88 @@ pub async fn pipeline_stop_and_process(
     }
 
     // Stop recording — releases the capture lock so a new recording can start.
-    let audio_path = match crate::audio::stop_recording() {
+    let stopped = crate::audio::stop_recording();
+    // Read before a new recording can start and reset the counter.
+    let had_dropouts = crate::audio::last_recording_dropouts() > 0;
+
+    // Stop live insertion before a new recording can start its own session.
+    // AI enhancement rewrites the whole text, which cannot be reconciled with
+    // words already typed, so it is skipped when any were.
+    let typed_words = run_blocking(crate::realtime_insert::finish);
+    if !typed_words.is_empty() {
+        config.enhancement_enabled = false;
+    }
+
+    // A successful stop is reported once it is known whether the recording
+    // is kept, so a too-short one is reported as that alone.
+    let audio_path = match stopped {
         Ok(path) => path,
         Err(e) => {
+            emit_recording_stopped(&app, StopReason::Error, None, Some(e.to_string()));
             emit_progress(
                 &app,
                 PipelineState::Failed,
                 &format!("Stop recording failed: {}", e),
             );
+            let started = LAST_START.lock().as_ref().map(|s| s.path.clone());
+            if let Some(path) = started {
+                resolve_waiters(&path, Err(format!("Stop recording failed: {}", e)));
+            }
             // Release capture flag so the next start is not blocked.
             PIPELINE_RUNNING.store(false, Ordering::SeqCst);
             return Err(e);
         }
     };
 
+    // A stray tap of the shortcut leaves a fraction of a second of noise:
+    // drop it before it takes a queue ticket or reaches the model. Words
+    // already typed live mean it held speech, whatever its length.
+    let min_ms = min_recording_ms();
+    let duration_seconds = get_audio_duration(&audio_path);
+    if typed_words.is_empty() && is_too_short(duration_seconds, min_ms) {
+        let duration_ms = (duration_seconds.unwrap_or(0.0) * 1000.0) as u64;
+        tracing::info!(
+            "Pipeline: Discarding {}ms recording (minimum {}ms): {}",
+            duration_ms,
+            min_ms,
+            audio_path
+        );
+        tracing::info!(target: "telemetry", event = "recording_too_short_dropped", "recording_too_short_dropped");
+        if let Err(e) = std::fs::remove_file(&audio_path) {
+            tracing::warn!("Pipeline: Failed to delete {}: {}", audio_path, e);
+        }
+        PIPELINE_RUNNING.store(false, Ordering::SeqCst);
+        emit_recording_stopped(&app, StopReason::TooShort, None, None);
+        emit_recording_state(&app);
+        resolve_waiters(
+            &audio_path,
+            Err(format!(
+                "Recording was shorter than the {}ms minimum",
+                min_ms
+            )),
+        );
+        return Ok(());
+    }
+
+    emit_recording_stopped(&app, StopReason::User, Some(&audio_path), None);
+
+    // A caller waiting on this recording takes the text itself.
+    if is_awaited(&audio_path) {
+        config.auto_copy = false;
+        config.auto_paste = false;
+    }
+
+    // A recording stopped before the model is ready is queued rather than
+    // blocking; the ticket is taken now so the queue keeps stop order.
+    let queue_ticket = (!transcription::is_transcription_ready())
+        .then(|| QUEUE_NEXT_TICKET.fetch_add(1, Ordering::SeqCst));
+
     // Capture has stopped — release PIPELINE_RUNNING so a new recording can start
     // immediately while this task processes.
     PIPELINE_RUNNING.store(false, Ordering::SeqCst);
 
     // Recording duration from the WAV header; 0.0 if unavailable.
-    let rec_duration = get_audio_duration(&audio_path).unwrap_or(0.0);
+    let rec_duration = duration_seconds.unwrap_or(0.0);
     tracing::info!(
         target: "telemetry",
         duration_seconds = rec_duration,
@@ -427,6 +1087,7 @@ pub async fn pipeline_stop_and_process(
     if let Err(e) = app.emit("recording-state", PipelineState::Transcribing) {
         tracing::warn!("Failed to emit recording-state (stop): {}", e);
     }
+    emit_state(&app, PipelineState::Transcribing);
 
     // Detach processing: transcription, filtering, enhancement, output and history
     // run in a separate task. PROCESSING_COUNT tracks in-flight tasks so
@@ -438,32 +1099,140 @@ pub async fn pipeline_stop_and_process(
         // Transcribing, leaving the UI stuck on "Processing" forever.
         let result = {
             let _processing_guard = ProcessingGuard::new();
-            process_audio(&app, &audio_path, &config).await
+            let queue_start = std::time::Instant::now();
+            let queued = match queue_ticket {
+                Some(ticket) => wait_in_model_queue(&app, &audio_path, ticket).await,
+                None => Ok(()),
+            };
+            let queue_wait_seconds = queue_start.elapsed().as_secs_f64();
+            match queued {
+                Ok(()) => process_audio(
+                    &app,
+                    &audio_path,
+                    &config,
+                    &typed_words,
+                    None,
+                    queue_wait_seconds,
+                    None,
+                )
+                .await
+                .map(|result| PipelineResult {
+                    had_dropouts,
+                    ..result
+                }),
+                Err(e) => Err(e),
+            }
         };
+        let result = resolve_silence(result, &audio_path);
         match &result {
-            Ok(r) => {
-                tracing::info!("Pipeline: Emitting pipeline-complete event");
-                if let Err(e) = app.emit("pipeline-complete", r) {
-                    tracing::error!("Pipeline: Failed to emit pipeline-complete: {}", e);
-                }
-            }
-            Err(_) if discard_silent_wav(&result, &audio_path) => {
-                // Silent recording suppressed — discard_silent_wav already deleted the WAV.
-            }
+            Ok(r) => emit_complete(&app, r),
             Err(e) => {
                 tracing::error!("Pipeline: Processing failed: {}", e);
                 emit_progress(&app, PipelineState::Failed, e);
+                if delete_audio_after_transcription() {
+                    delete_audio_file(&audio_path);
+                }
             }
         }
         // Emit authoritative state after the guard has dropped. get_pipeline_state()
         // returns Recording if a new clip started while this task ran, so this can
         // never clobber an active recording with Idle.
         emit_recording_state(&app);
+        resolve_waiters(&audio_path, result);
     });
 
     Ok(())
 }
 
+/// Wait until the model is loaded and every recording queued before this one
+/// has started processing.
+///
+/// Emits [`QUEUED_FOR_TRANSCRIPTION_EVENT`] first. Gives up after
+/// [`MODEL_LOAD_TIMEOUT`], passing the turn on so later recordings are not
+/// stuck behind it. The timeout in [`run_transcription_pipeline`] then never
+/// fires for a queued recording, so it no longer blocks.
+async fn wait_in_model_queue(app: &AppHandle, audio_path: &str, ticket: u64) -> Result<(), String> {
+    let position = ticket.saturating_sub(QUEUE_SERVING.load(Ordering::SeqCst));
+    tracing::info!(
+        "Pipeline: Model not ready, queued {} (position {})",
+        audio_path,
+        position
+    );
+    let payload = QueuedForTranscription {
+        audio_path: audio_path.to_string(),
+        position,
+    };
+    if let Err(e) = app.emit(QUEUED_FOR_TRANSCRIPTION_EVENT, payload) {
+        tracing::warn!(
+            "Pipeline: Failed to emit {}: {}",
+            QUEUED_FOR_TRANSCRIPTION_EVENT,
+            e
+        );
+    }
+    emit_progress(
+        app,
+        PipelineState::Transcribing,
+        "Queued until the transcription model has loaded...",
+    );
+
+    // Only the recording whose turn it is may leave the queue, even on timeout,
+    // so the serving counter always advances in ticket order.
+    let deadline = std::time::Instant::now() + MODEL_LOAD_TIMEOUT;
+    let result = loop {
+        let my_turn = QUEUE_SERVING.load(Ordering::SeqCst) == ticket;
+        if my_turn && transcription::is_transcription_ready() {
+            break Ok(());
+        }
+        if my_turn && std::time::Instant::now() > deadline {
+            tracing::warn!(target: "telemetry", reason = "load_timeout_60s", "model_load_failure");
+            break Err("Transcription model failed to load within 60 seconds".to_string());
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    };
+    QUEUE_SERVING.fetch_add(1, Ordering::SeqCst);
+    result
+}
+
+/// Receive the outcome of the recording at `audio_path` once it has stopped
+/// and been processed, however it is stopped.
+///
+/// The caller takes the text: an awaited recording is neither inserted nor
+/// copied. The sender is dropped without a value if the recording is lost
+/// some other way.
+pub(crate) fn wait_for_recording_result(
+    audio_path: &str,
+) -> tokio::sync::oneshot::Receiver<Result<PipelineResult, String>> {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    RESULT_WAITERS.lock().push((audio_path.to_string(), tx));
+    rx
+}
+
+/// Whether a caller is still waiting on the recording at `audio_path`.
+///
+/// Waiters whose caller has gone away (an HTTP client that disconnected) are
+/// dropped, so the recording is inserted and copied as usual.
+fn is_awaited(audio_path: &str) -> bool {
+    let mut waiters = RESULT_WAITERS.lock();
+    waiters.retain(|(_, tx)| !tx.is_closed());
+    waiters.iter().any(|(path, _)| path == audio_path)
+}
+
+/// Hand the outcome of the recording at `audio_path` to everyone waiting on it.
+fn resolve_waiters(audio_path: &str, result: Result<PipelineResult, String>) {
+    let waiting: Vec<ResultSender> = {
+        let mut waiters = RESULT_WAITERS.lock();
+        let (matched, rest) = std::mem::take(&mut *waiters)
+            .into_iter()
+            .partition(|(path, _)| path == audio_path);
+        *waiters = rest;
+        matched.into_iter().map(|(_, tx)| tx).collect()
+    };
+    for tx in waiting {
+        // The caller may have gone away; nothing to do then.
+        let _ = tx.send(result.clone());
+    }
+}
+
 /// Cancel the current pipeline execution
 #[tauri::command]
 pub fn pipeline_cancel(app: AppHandle) -> Result<(), Error> {
@@ -483,9 +1252,22 @@ pub fn pipeline_cancel(app: AppHandle) -> Result<(), Error> {
     // Signal cancellation for file import operations
     IMPORT_CANCELLED.store(true, Ordering::SeqCst);
 
+    crate::realtime_insert::cancel();
+
     // Stop recording if in progress
     if crate::audio::is_recording() {
-        let _ = crate::audio::stop_recording();
+        match crate::audio::stop_recording() {
+            Ok(path) => {
+                emit_recording_stopped(&app, StopReason::Cancelled, Some(&path), None);
+                if delete_audio_after_transcription() {
+                    delete_audio_file(&path);
+                }
+                resolve_waiters(&path, Err("Recording cancelled".to_string()));
+            }
+            Err(e) => {
+                emit_recording_stopped(&app, StopReason::Cancelled, None, Some(e.to_string()));
+            }
+        }
     }
 
     // Reset tray state
@@ -542,38 +1324,130 @@ fn catch_post_processing<F: FnOnce() -> String>(f: F) -> Result<String, String>
 /// Output of the core transcription pipeline (transcribe + filter + enhance).
 ///
 /// Shared by [`process_audio`] (recordings/imports) and [`pipeline_retranscribe`].
-struct TranscriptionPipelineOutput {
-    text: String,
-    raw_text: String,
-    is_enhanced: bool,
-    transcription_model_name: Option<String>,
-    transcription_duration_seconds: f64,
-    enhancement_model_name: Option<String>,
-    enhancement_duration_seconds: Option<f64>,
+#[derive(Debug, Clone)]
+pub struct TranscriptionPipelineOutput {
+    /// Final text after filtering and (if enabled) enhancement
+    pub text: String,
+    /// Raw model output before any post-processing
+    pub raw_text: String,
+    /// Text after filters and the dictionary, before enhancement
+    pub filtered_text: String,
+    /// Whether AI enhancement was applied to `text`
+    pub is_enhanced: bool,
+    /// Name of the transcription model used
+    pub transcription_model_name: Option<String>,
+    /// Language of the speech (ISO 639-1), detected or configured, if known
+    pub language: Option<String>,
+    /// Time spent in the transcription model, excluding any decode
+    pub transcription_duration_seconds: f64,
+    /// Time the transcriber spent converting the input to 16 kHz WAV
+    pub decode_duration_seconds: Option<f64>,
+    /// Time spent waiting for the model to finish loading, including any
+    /// [`RunContext::queue_wait_seconds`] before the run
+    pub model_wait_seconds: f64,
+    /// Time spent in filters and the dictionary, if they ran
+    pub filter_duration_seconds: Option<f64>,
+    /// Enhancement model used, if enhancement succeeded
+    pub enhancement_model_name: Option<String>,
+    /// Time spent enhancing across all passes, if enhancement succeeded
+    pub enhancement_duration_seconds: Option<f64>,
+    /// The prompt(s) actually applied, as recorded in history. A multi-pass
+    /// chain is joined with a separator; `None` when not enhanced.
+    pub enhancement_prompt: Option<String>,
+    /// Input levels the transcriber measured, if any
+    pub audio_levels: Option<transcription::AudioLevels>,
+}
+
+/// Receives progress updates from [`run_transcription_pipeline`].
+///
+/// The app forwards them to the frontend as `pipeline-progress` events via the
+/// `AppHandle` implementation. Library callers without a Tauri app can pass
+/// `&()` to ignore progress, or implement this to drive their own UI.
+pub trait PipelineObserver: Send + Sync {
+    /// Called when the pipeline enters a new stage
+    fn on_progress(&self, state: PipelineState, message: &str);
+
+    /// Called when enhancement was skipped because `model` is no longer
+    /// installed; the text is kept un-enhanced
+    fn on_enhancement_model_missing(&self, _model: &str) {}
+}
+
+impl PipelineObserver for AppHandle {
+    fn on_progress(&self, state: PipelineState, message: &str) {
+        emit_progress(self, state, message);
+    }
+
+    fn on_enhancement_model_missing(&self, model: &str) {
+        let payload = enhancement::EnhancementModelMissing {
+            model: model.to_string(),
+        };
+        if let Err(e) = self.emit(enhancement::ENHANCEMENT_MODEL_MISSING_EVENT, payload) {
+            tracing::warn!(
+                "Pipeline: Failed to emit {}: {}",
+                enhancement::ENHANCEMENT_MODEL_MISSING_EVENT,
+                e
+            );
+        }
+    }
+}
+
+/// Discards progress updates.
+impl PipelineObserver for () {
+    fn on_progress(&self, _state: PipelineState, _message: &str) {}
+}
+
+/// What the caller knows about a run before it starts, looked up once so the
+/// pipeline steps that need it agree and none of them blocks to find it
+#[derive(Debug, Clone, Default)]
+pub struct RunContext {
+    /// Application that was frontmost when the run started, if known
+    pub frontmost_app: Option<String>,
+    /// Time already spent waiting for the model before the run, e.g. in the
+    /// queue of recordings made while it loaded; counted as model wait
+    pub queue_wait_seconds: f64,
+}
+
+/// The frontmost application's name, looked up on a blocking thread: on
+/// Linux it runs `hyprctl`/`swaymsg`.
+async fn capture_frontmost_app() -> Option<String> {
+    tokio::task::spawn_blocking(crate::platform::frontmost_app_name)
+        .await
+        .ok()
+        .flatten()
 }
 
 /// Core pipeline: wait for model, transcribe audio, apply filters, optionally enhance.
 ///
 /// Does NOT handle output (clipboard/paste), saving to history, or tray updates;
-/// callers are responsible for those steps.
-async fn run_transcription_pipeline(
-    app: &AppHandle,
+/// callers are responsible for those steps. Needs no `AppHandle`, so it can be
+/// used directly when embedding the crate as a library.
+///
+/// The app passes [`transcription::LoadedTranscriber`] and
+/// [`enhancement::ActiveEnhancer`]; tests inject mocks returning canned text.
+/// `context` supplies the frontmost app for app-scoped dictionary entries
+/// and the `{app}` prompt variable; `{language}` is the transcriber's.
+pub async fn run_transcription_pipeline(
+    observer: &dyn PipelineObserver,
+    transcriber: &mut dyn transcription::Transcriber,
+    enhancer: &dyn enhancement::Enhancer,
     audio_path: &str,
     config: &PipelineConfig,
+    context: &RunContext,
 ) -> Result<TranscriptionPipelineOutput, String> {
-    let transcription_model_name = get_transcription_model_name();
+    let transcription_model_name = transcriber.model_name();
+    let language = transcriber.language();
 
     // 1. Transcribe (with timing)
     // Wait for the model to finish loading if eager background load is in progress.
     tracing::info!("Pipeline: Starting transcription of {}", audio_path);
-    if !transcription::is_transcription_ready() {
-        emit_progress(
-            app,
+    let wait_start = std::time::Instant::now();
+    if !transcriber.is_ready() {
+        observer.on_progress(
             PipelineState::Transcribing,
             "Loading transcription model...",
         );
-        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(60);
-        while !transcription::is_transcription_ready() {
+        let deadline = std::time::Instant::now() + MODEL_LOAD_TIMEOUT;
+        while !transcriber.is_ready() {
             if std::time::Instant::now() > deadline {
                 tracing::warn!(target: "telemetry", reason = "load_timeout_60s", "model_load_failure");
                 return Err("Transcription model failed to load within 60 seconds".to_string());
@@ -582,22 +1456,33 @@ async fn run_transcription_pipeline(
         }
         tracing::info!("Pipeline: Model loaded, proceeding with transcription");
     }
-    emit_progress(app, PipelineState::Transcribing, "Transcribing audio...");
+    let model_wait_seconds = context.queue_wait_seconds + wait_start.elapsed().as_secs_f64();
+    observer.on_progress(PipelineState::Transcribing, "Transcribing audio...");
     let transcription_start = std::time::Instant::now();
-    // transcribe_file is CPU-bound (whisper/sherpa inference). Running it on a
-    // dedicated blocking thread avoids starving the shared async worker pool,
-    // which matters now that process_audio runs as a detached task.
-    let audio_path_owned = audio_path.to_string();
-    let raw_text =
-        tokio::task::spawn_blocking(move || transcription::transcribe_file(audio_path_owned))
-            .await
-            .map_err(|e| format!("Transcription task panicked: {}", e))?
-            .map_err(|e| e.to_string())?;
-    let transcription_duration_seconds = transcription_start.elapsed().as_secs_f64();
+    // Transcription is CPU-bound (whisper/sherpa inference). block_in_place
+    // hands this worker's other tasks to the rest of the pool first, so the
+    // shared async runtime is not starved while process_audio runs detached.
+    let audio_path_buf = PathBuf::from(audio_path);
+    let raw_text = run_blocking(|| {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            transcriber.transcribe(&audio_path_buf)
+        }))
+    })
+    .map_err(|_| "Transcription task panicked".to_string())?
+    .map_err(|e| e.to_string())?;
+    // Any transcode inside the transcriber is reported as decode time.
+    let decode_duration_seconds = transcriber.decode_duration_seconds();
+    let transcription_duration_seconds = (transcription_start.elapsed().as_secs_f64()
+        - decode_duration_seconds.unwrap_or(0.0))
+    .max(0.0);
+    let audio_levels = transcriber.audio_levels();
+    let segments = transcriber.segments();
 
     tracing::info!(
-        "Pipeline: Transcription took {:.2}s",
-        transcription_duration_seconds
+        "Pipeline: Transcription took {:.2}s (decode {:.2}s, model wait {:.2}s)",
+        transcription_duration_seconds,
+        decode_duration_seconds.unwrap_or(0.0),
+        model_wait_seconds
     );
 
     if raw_text.trim().is_empty() {
@@ -628,14 +1513,31 @@ async fn run_transcription_pipeline(
         );
     }
 
-    tracing::info!(
-        "Pipeline: Transcribed {} characters: '{}'",
-        raw_text.len(),
-        raw_text.chars().take(100).collect::<String>()
-    );
-
-    // 2. Apply filtering
-    let mut text = raw_text.clone();
+    // Transcript text only reaches the log when the user opts in, and then
+    // masked like history when redaction is on.
+    let privacy = crate::config::get_config()
+        .map(|c| c.privacy)
+        .unwrap_or_default();
+    if privacy.log_transcription_text {
+        let preview: String = match Redactor::new(&privacy.redaction) {
+            Some(redactor) => redactor.redact(&raw_text),
+            None => raw_text.clone(),
+        }
+        .chars()
+        .take(100)
+        .collect();
+        tracing::info!(
+            "Pipeline: Transcribed {} characters: '{}'",
+            raw_text.len(),
+            preview
+        );
+    } else {
+        tracing::info!("Pipeline: Transcribed {} characters", raw_text.len());
+    }
+
+    // 2. Apply filtering
+    let mut text = raw_text.clone();
+    let mut filter_duration_seconds: Option<f64> = None;
 
     if config.apply_filtering || config.apply_dictionary {
         tracing::info!(
@@ -643,10 +1545,18 @@ async fn run_transcription_pipeline(
             config.apply_filtering,
             config.apply_dictionary
         );
-        emit_progress(app, PipelineState::Filtering, "Applying filters...");
+        observer.on_progress(PipelineState::Filtering, "Applying filters...");
 
         let apply_filtering = config.apply_filtering;
         let apply_dictionary = config.apply_dictionary;
+        let mark_pauses = config.mark_pauses;
+        // The app was captured by the caller while it was still frontmost,
+        // so app-scoped entries match the app being dictated into.
+        let dictionary_context = if apply_dictionary {
+            dictionary::DictionaryContext::for_app(context.frontmost_app.clone())
+        } else {
+            dictionary::DictionaryContext::default()
+        };
         let filter_opts = if apply_filtering {
             Some(transcription::FilterOptions {
                 remove_fillers: config.remove_fillers,
@@ -656,6 +1566,8 @@ async fn run_transcription_pipeline(
                 cleanup_punctuation: config.cleanup_punctuation,
                 sentence_case: config.sentence_case,
                 voice_formatting_commands: config.voice_formatting_commands,
+                collapse_repetitions: config.collapse_repetitions,
+                mark_pauses: config.mark_pauses,
                 // The dictionary is applied separately below, gated by
                 // config.apply_dictionary. Disable it inside the filter so it
                 // runs exactly once and honours the user's dictionary setting
@@ -667,14 +1579,22 @@ async fn run_transcription_pipeline(
             None
         };
 
+        let filter_start = std::time::Instant::now();
         text = catch_post_processing(move || {
             let mut t = text;
             if apply_filtering {
-                t = transcription::filter_transcription(t, filter_opts);
+                t = match segments {
+                    // Filtered turn by turn so the line breaks survive
+                    Some(segments) if mark_pauses => {
+                        transcription::OutputFilter::new(filter_opts.unwrap_or_default())
+                            .filter_segments(&segments)
+                    }
+                    _ => transcription::filter_transcription(t, filter_opts),
+                };
                 tracing::debug!("Pipeline: After filtering: {} chars", t.len());
             }
             if apply_dictionary {
-                t = dictionary::apply_dictionary(&t);
+                t = dictionary::apply_dictionary(&t, &dictionary_context);
                 tracing::debug!("Pipeline: After dictionary: {} chars", t.len());
                 t = canonical::apply_canonical(&t);
                 tracing::debug!("Pipeline: After canonical: {} chars", t.len());
@@ -682,57 +1602,115 @@ async fn run_transcription_pipeline(
             t
         })?;
 
+        filter_duration_seconds = Some(filter_start.elapsed().as_secs_f64());
         tracing::info!("Pipeline: Filtered text to {} characters", text.len());
     }
 
-    // 3. AI Enhancement (optional, with timing)
+    let filtered_text = text.clone();
+
+    // 3. AI Enhancement (optional, with timing). Each pass in the chain feeds
+    // the previous output into the next; a failed pass stops the chain and
+    // keeps the text from the passes that succeeded.
     let mut enhancement_model_name: Option<String> = None;
     let mut enhancement_duration_seconds: Option<f64> = None;
+    let mut enhancement_prompt: Option<String> = None;
 
-    let is_enhanced = if config.enhancement_enabled && !config.enhancement_model.is_empty() {
-        emit_progress(app, PipelineState::Enhancing, "Enhancing with AI...");
+    let wants_enhancement = config.enhancement_enabled && !config.enhancement_model.is_empty();
+    let word_count = text.split_whitespace().count();
+    let too_short = word_count < config.enhancement_min_words as usize;
+    if wants_enhancement && too_short {
+        tracing::info!(
+            "Pipeline: Skipping enhancement, {} word(s) is under the minimum of {}",
+            word_count,
+            config.enhancement_min_words
+        );
+    }
 
-        let enhancement_start = std::time::Instant::now();
-        match enhancement::enhance_text(
-            text.clone(),
-            config.enhancement_model.clone(),
-            config.enhancement_prompt.clone(),
-        )
-        .await
-        {
-            Ok(enhanced) => {
-                let elapsed = enhancement_start.elapsed().as_secs_f64();
-                text = enhanced;
-                enhancement_model_name = Some(config.enhancement_model.clone());
-                enhancement_duration_seconds = Some(elapsed);
-                tracing::info!(
-                    "Pipeline: Enhanced text to {} characters in {:.2}s",
-                    text.len(),
-                    elapsed
-                );
-                // Deliberately no prompt text/id: the telemetry stream is
-                // content-free, and enhancement-by-prompt analytics already
-                // live in the Insights dashboard (from the DB column).
-                tracing::info!(
-                    target: "telemetry",
-                    model = %config.enhancement_model,
-                    duration_seconds = elapsed,
-                    ok = true,
-                    "enhancement_complete"
-                );
-                true
-            }
-            Err(e) => {
-                tracing::warn!("Pipeline: Enhancement failed, using original text: {}", e);
-                tracing::warn!(
-                    target: "telemetry",
-                    model = %config.enhancement_model,
-                    ok = false,
-                    "enhancement_complete"
-                );
-                false
+    let is_enhanced = if wants_enhancement && !too_short {
+        let passes = config.enhancement_passes();
+        let total = passes.len();
+        let variables = enhancement::prompts::PromptVariables::capture(
+            get_audio_duration(audio_path),
+            context.frontmost_app.clone(),
+            language.clone(),
+        );
+        let mut applied: Vec<&str> = Vec::with_capacity(total);
+        let mut elapsed_total = 0.0;
+
+        for (index, prompt) in passes.into_iter().enumerate() {
+            let pass = index + 1;
+            let message = if total == 1 {
+                "Enhancing with AI...".to_string()
+            } else {
+                format!("Enhancing with AI ({pass}/{total})...")
+            };
+            observer.on_progress(PipelineState::Enhancing, &message);
+
+            let pass_start = std::time::Instant::now();
+            match enhancer
+                .enhance(
+                    text.clone(),
+                    config.enhancement_model.clone(),
+                    enhancement::prompts::render_prompt_variables(prompt, &variables),
+                )
+                .await
+            {
+                Ok(enhanced) => {
+                    let elapsed = pass_start.elapsed().as_secs_f64();
+                    text = enhanced;
+                    elapsed_total += elapsed;
+                    applied.push(prompt);
+                    tracing::info!(
+                        "Pipeline: Enhancement pass {}/{} produced {} characters in {:.2}s",
+                        pass,
+                        total,
+                        text.len(),
+                        elapsed
+                    );
+                    // Deliberately no prompt text/id: the telemetry stream is
+                    // content-free, and enhancement-by-prompt analytics already
+                    // live in the Insights dashboard (from the DB column).
+                    tracing::info!(
+                        target: "telemetry",
+                        model = %config.enhancement_model,
+                        duration_seconds = elapsed,
+                        pass = pass,
+                        passes = total,
+                        ok = true,
+                        "enhancement_complete"
+                    );
+                }
+                Err(e) => {
+                    if let Error::EnhancementModelMissing(model) = &e {
+                        observer.on_enhancement_model_missing(model);
+                    }
+                    tracing::warn!(
+                        "Pipeline: Enhancement pass {}/{} failed, keeping text from earlier passes: {}",
+                        pass,
+                        total,
+                        e
+                    );
+                    tracing::warn!(
+                        target: "telemetry",
+                        model = %config.enhancement_model,
+                        pass = pass,
+                        passes = total,
+                        ok = false,
+                        "enhancement_complete"
+                    );
+                    break;
+                }
             }
         }
+
+        if applied.is_empty() {
+            false
+        } else {
+            enhancement_model_name = Some(config.enhancement_model.clone());
+            enhancement_duration_seconds = Some(elapsed_total);
+            enhancement_prompt = Some(applied.join(ENHANCEMENT_CHAIN_SEPARATOR));
+            true
+        }
     } else {
         false
     };
@@ -740,52 +1718,184 @@ async fn run_transcription_pipeline(
     Ok(TranscriptionPipelineOutput {
         text,
         raw_text,
+        filtered_text,
         is_enhanced,
         transcription_model_name,
+        language,
         transcription_duration_seconds,
+        decode_duration_seconds,
+        model_wait_seconds,
+        filter_duration_seconds,
         enhancement_model_name,
         enhancement_duration_seconds,
+        enhancement_prompt,
+        audio_levels,
     })
 }
 
 /// Process audio through the transcription pipeline
+///
+/// `typed_words` are the words live insertion already typed at the cursor;
+/// only the rest of the text is inserted. `import_decode_seconds` is
+/// the time an import already spent decoding its input to `audio_path`, and
+/// `queue_wait_seconds` the time a recording waited for the model to load.
 async fn process_audio(
     app: &AppHandle,
     audio_path: &str,
     config: &PipelineConfig,
+    typed_words: &[String],
+    import_decode_seconds: Option<f64>,
+    queue_wait_seconds: f64,
+    cancelled: Option<&'static AtomicBool>,
 ) -> Result<PipelineResult, String> {
     let duration_seconds = get_audio_duration(audio_path);
 
+    // Dictations carry the tail of the previous one into the model as
+    // context; imports (which arrive already decoded) neither use nor set it.
+    let carryover = import_decode_seconds.is_none() && transcription::context::enabled();
+    if import_decode_seconds.is_none() && !carryover {
+        transcription::context::clear();
+    }
+
+    // Looked up only when something in this run reads it
+    let spacing = trailing_space_mode();
+    let wants_app = carryover
+        || config.apply_dictionary
+        || (config.enhancement_enabled && !config.enhancement_model.is_empty())
+        || (spacing == TrailingSpace::Smart && config.auto_paste && !config.verbatim);
+    let run_context = RunContext {
+        frontmost_app: if wants_app {
+            capture_frontmost_app().await
+        } else {
+            None
+        },
+        queue_wait_seconds,
+    };
+
+    let dictation_app = carryover.then(|| run_context.frontmost_app.clone());
+    let initial_prompt = dictation_app
+        .as_ref()
+        .and_then(|app| transcription::context::prompt_for(app.as_deref()));
+
+    // Imports can be hours long, so they go through the model in windows
+    let mut transcriber = transcription::LoadedTranscriber::with_initial_prompt(initial_prompt)
+        .keeping_segments(config.keeps_segments());
+    if import_decode_seconds.is_some() {
+        transcriber = transcriber.chunked(import_chunk_secs());
+    }
+    if let Some(cancelled) = cancelled {
+        transcriber = transcriber.cancelled_by(cancelled);
+    }
+
     // Run core transcription pipeline (transcribe + filter + enhance)
-    let output = run_transcription_pipeline(app, audio_path, config).await?;
+    let mut output = run_transcription_pipeline(
+        app,
+        &mut transcriber,
+        &enhancement::ActiveEnhancer,
+        audio_path,
+        config,
+        &run_context,
+    )
+    .await?;
+
+    // The raw transcript is what the model wrote, so it is what it should
+    // continue from. Unsaved dictations are not carried into the next one.
+    if !config.save_to_history {
+        transcription::context::clear();
+    } else if let Some(app_name) = dictation_app {
+        transcription::context::remember(&output.raw_text, app_name);
+    }
+
+    let clipped = output.audio_levels.is_some_and(|l| l.clipped());
+    if let Some(levels) = output.audio_levels.filter(|_| clipped) {
+        let payload = RecordingClipped {
+            audio_path: audio_path.to_string(),
+            clip_ratio: levels.clip_ratio,
+        };
+        if let Err(e) = app.emit(RECORDING_CLIPPED_EVENT, payload) {
+            tracing::error!(
+                "Pipeline: Failed to emit {}: {}",
+                RECORDING_CLIPPED_EVENT,
+                e
+            );
+        }
+    }
 
     // 4. Output (clipboard/paste)
     // The filtered text already carries any spoken-command line breaks (applied
     // in OutputFilter so history and the pasted text stay consistent).
-    let mut output_text = output.text.clone();
+    // Redaction is the final step: the pasted text keeps the real values
+    // unless the user asked for it to be masked as well.
+    let redactor = Redactor::from_config();
+    let mut output_text = match &redactor {
+        Some(r) if r.redacts_pasted_text() => r.redact(&output.text),
+        _ => output.text.clone(),
+    };
 
     // Ensure consecutive transcriptions don't run together when inserted at
     // the cursor. Add a sentence-ending period if the text has no trailing
-    // punctuation, then always ensure there is a trailing space so that the
-    // next paste doesn't glue directly onto this one.
+    // punctuation, then space it per `config.output.trailing_space` so that
+    // the next paste doesn't glue directly onto this one.
     //
-    // Examples:
+    // Examples (Always):
     //   "Hello world"  → "Hello world. "
     //   "Hello world." → "Hello world. "
     //   "Hello world," → "Hello world, "
-    {
+    //
+    // Verbatim output gets neither: the text goes in exactly as transcribed.
+    let insert_app = run_context.frontmost_app.clone();
+    let (leading, trailing) = if config.verbatim {
+        ("", "")
+    } else {
+        let appending = is_append(
+            LAST_INSERT.lock().as_ref(),
+            insert_app.as_deref(),
+            std::time::Instant::now(),
+        );
+        insert_spacing(spacing, appending)
+    };
+    if !config.verbatim {
         let last_meaningful = output_text.trim_end().chars().last().unwrap_or('.');
         if !last_meaningful.is_ascii_punctuation() {
             output_text = output_text.trim_end().to_string();
             output_text.push('.');
         }
-        output_text.push(' ');
+        output_text.push_str(trailing);
+    }
+
+    // Words typed live are already at the cursor; insert only the rest. The
+    // clipboard still receives the whole text. A leading space only matters
+    // at the cursor, so the clipboard never gets one.
+    let insert_text = if !typed_words.is_empty() {
+        crate::realtime_insert::remaining_text(&output_text, typed_words) + trailing
+    } else {
+        format!("{leading}{output_text}")
+    };
+
+    // A paste with nothing editable focused goes nowhere; copy instead so
+    // the text isn't lost. Words already typed live prove there is a field,
+    // and an unknown answer (no accessibility) keeps the paste.
+    let no_paste_target = config.auto_paste
+        && typed_words.is_empty()
+        && no_focus_fallback()
+        && crate::platform::focused_text_field() == Some(false);
+    let auto_paste = config.auto_paste && !no_paste_target;
+    let auto_copy = config.auto_copy || no_paste_target;
+    if no_paste_target {
+        tracing::info!("Pipeline: No text field focused, copying to clipboard instead of pasting");
+        if let Err(e) = app.emit(NO_TEXT_FIELD_FOCUSED_EVENT, ()) {
+            tracing::warn!(
+                "Pipeline: Failed to emit {}: {}",
+                NO_TEXT_FIELD_FOCUSED_EVENT,
+                e
+            );
+        }
     }
 
     tracing::info!(
         "Pipeline: Starting output (copy={}, paste={})",
-        config.auto_copy,
-        config.auto_paste
+        auto_copy,
+        auto_paste
     );
     emit_progress(app, PipelineState::Outputting, "Outputting text...");
 
@@ -795,7 +1905,7 @@ async fn process_audio(
     {
         let _output_guard = OUTPUT_LOCK.lock().await;
 
-        let uses_clipboard_paste = config.auto_paste && config.insertion_method != "typing";
+        let uses_clipboard_paste = auto_paste && config.insertion_method != "typing";
 
         // Save the user's original clipboard BEFORE any modification.
         // This must happen before copy_transcription or insert_text_by_paste,
@@ -808,7 +1918,7 @@ async fn process_audio(
             None
         };
 
-        if config.auto_copy {
+        if auto_copy {
             tracing::debug!("Pipeline: Copying to clipboard...");
             if let Err(e) =
                 clipboard::copy_transcription(app.clone(), output_text.clone(), output.is_enhanced)
@@ -820,20 +1930,24 @@ async fn process_audio(
             }
         }
 
-        if config.auto_paste {
+        if auto_paste {
             tracing::debug!("Pipeline: Pasting text...");
             tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
 
             let insert_result = if config.insertion_method == "typing" {
-                crate::text_insert::insert_text_by_typing(output_text.clone(), None, None)
+                crate::text_insert::insert_text_by_typing(insert_text, None, None)
             } else {
-                crate::text_insert::insert_text_by_paste(output_text.clone(), None)
+                crate::text_insert::insert_text_by_paste(insert_text, None)
             };
 
             if let Err(e) = insert_result {
                 tracing::warn!("Pipeline: Failed to insert text: {}", e);
             } else {
                 tracing::debug!("Pipeline: Pasted text successfully");
+                *LAST_INSERT.lock() = Some(RecentInsert {
+                    app: insert_app,
+                    at: std::time::Instant::now(),
+                });
             }
         }
 
@@ -852,61 +1966,152 @@ async fn process_audio(
         }
     } // OUTPUT_LOCK released
 
-    // 5. Save to history
-    tracing::info!("Pipeline: Saving to history...");
-    let transcription_id = save_to_history(
-        &output.text,
-        &output.raw_text,
-        duration_seconds,
-        audio_path,
-        output.is_enhanced,
-        if output.is_enhanced {
-            Some(&config.enhancement_prompt)
-        } else {
-            None
-        },
-        output.transcription_model_name.as_deref(),
-        Some(output.transcription_duration_seconds),
-        output.enhancement_model_name.as_deref(),
-        output.enhancement_duration_seconds,
-    );
-    tracing::info!("Pipeline: Saved to history, id={:?}", transcription_id);
+    if let Some(redactor) = &redactor {
+        redact_output(redactor, &mut output);
+    }
+
+    // 5. Save to history. In text-only privacy mode the record carries no
+    // audio path and the WAV is deleted straight after. With saving off
+    // nothing is written and the WAV is always deleted. If the record could
+    // not be written the WAV is kept, so the dictation is not lost entirely.
+    let delete_audio = !config.save_to_history || delete_audio_after_transcription();
+    let stored_audio_path = (!delete_audio).then_some(audio_path);
+    let decode_duration_seconds = import_decode_seconds
+        .into_iter()
+        .chain(output.decode_duration_seconds)
+        .reduce(|a, b| a + b);
+    let timing = TimingBreakdown::new(decode_duration_seconds, &output);
+    let transcription_id = if config.save_to_history {
+        tracing::info!("Pipeline: Saving to history...");
+        let id = save_to_history(
+            &output.text,
+            &output.raw_text,
+            duration_seconds,
+            stored_audio_path,
+            output.is_enhanced,
+            output.enhancement_prompt.as_deref(),
+            output.transcription_model_name.as_deref(),
+            Some(output.transcription_duration_seconds),
+            output.enhancement_model_name.as_deref(),
+            output.enhancement_duration_seconds,
+            output.language.as_deref(),
+            &timing,
+        );
+        tracing::info!("Pipeline: Saved to history, id={:?}", id);
+        id
+    } else {
+        tracing::info!("Pipeline: Saving to history is off, skipping");
+        None
+    };
+    let save_failed = config.save_to_history && transcription_id.is_none();
+    if delete_audio && !save_failed {
+        delete_audio_file(audio_path);
+    } else if delete_audio {
+        tracing::warn!(
+            "Pipeline: History save failed, keeping audio at {}",
+            audio_path
+        );
+    }
 
-    // Update tray with latest transcription
-    tray::set_last_transcription(app, Some(output.text.clone()));
+    // Update tray with latest transcription; an unsaved one never appears there
+    if config.save_to_history {
+        tray::set_last_transcription(app, Some(output.text.clone()));
+    }
 
     tracing::info!("Pipeline: Processing complete, emitting Completed state");
     emit_progress(app, PipelineState::Completed, "Done");
 
     Ok(PipelineResult {
         success: true,
+        words_per_minute: words_per_minute(&output.text, duration_seconds),
         text: output.text,
         raw_text: output.raw_text,
         is_enhanced: output.is_enhanced,
         duration_seconds,
-        audio_path: Some(audio_path.to_string()),
+        audio_path: (stored_audio_path.is_some() || save_failed).then(|| audio_path.to_string()),
         error: None,
         transcription_id,
         transcription_model_name: output.transcription_model_name,
         transcription_duration_seconds: Some(output.transcription_duration_seconds),
         enhancement_model_name: output.enhancement_model_name,
         enhancement_duration_seconds: output.enhancement_duration_seconds,
+        decode_duration_seconds,
+        model_wait_seconds: Some(output.model_wait_seconds),
+        filter_duration_seconds: output.filter_duration_seconds,
+        clipped,
+        clip_ratio: output.audio_levels.map(|l| l.clip_ratio),
+        had_dropouts: false,
+        was_silent: false,
     })
 }
 
+/// Recordings shorter than this give no pace; a word or two over a fraction
+/// of a second would read as hundreds of words per minute
+const MIN_PACE_SECONDS: f64 = 1.0;
+
+/// Speaking pace of `text` spoken over `duration_seconds`
+fn words_per_minute(text: &str, duration_seconds: Option<f64>) -> Option<f64> {
+    let seconds = duration_seconds.filter(|&s| s >= MIN_PACE_SECONDS)?;
+    let words = text.split_whitespace().count();
+    (words > 0).then(|| words as f64 * 60.0 / seconds)
+}
+
+/// Mask sensitive text in a pipeline output before it is stored or shown.
+fn redact_output(redactor: &Redactor, output: &mut TranscriptionPipelineOutput) {
+    output.text = redactor.redact(&output.text);
+    output.raw_text = redactor.redact(&output.raw_text);
+}
+
+/// Per-stage timings kept with a history record.
+///
+/// Stored as compact JSON, e.g. `{"decode":0.12,"wait":0.0,"filter":0.003}`,
+/// with stages that did not run left out and values rounded to milliseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimingBreakdown {
+    /// Time spent converting the input to 16 kHz WAV
+    #[serde(rename = "decode", default, skip_serializing_if = "Option::is_none")]
+    pub decode_seconds: Option<f64>,
+    /// Time spent waiting for the model to finish loading
+    #[serde(rename = "wait", default, skip_serializing_if = "Option::is_none")]
+    pub model_wait_seconds: Option<f64>,
+    /// Time spent in filters and the dictionary
+    #[serde(rename = "filter", default, skip_serializing_if = "Option::is_none")]
+    pub filter_seconds: Option<f64>,
+}
+
+impl TimingBreakdown {
+    /// Breakdown for a pipeline run; `decode_seconds` covers all decoding,
+    /// including an import's up-front conversion.
+    fn new(decode_seconds: Option<f64>, output: &TranscriptionPipelineOutput) -> Self {
+        let round_ms = |secs: f64| (secs * 1000.0).round() / 1000.0;
+        Self {
+            decode_seconds: decode_seconds.map(round_ms),
+            model_wait_seconds: Some(round_ms(output.model_wait_seconds)),
+            filter_seconds: output.filter_duration_seconds.map(round_ms),
+        }
+    }
+
+    /// JSON for the history record's `timing_breakdown` column
+    pub fn to_json(&self) -> Option<String> {
+        serde_json::to_string(self).ok()
+    }
+}
+
 /// Save transcription to history database
 #[allow(clippy::too_many_arguments)]
 fn save_to_history(
     text: &str,
     raw_text: &str,
     duration_seconds: Option<f64>,
-    audio_path: &str,
+    audio_path: Option<&str>,
     is_enhanced: bool,
     enhancement_prompt: Option<&str>,
     transcription_model_name: Option<&str>,
     transcription_duration_seconds: Option<f64>,
     enhancement_model_name: Option<&str>,
     enhancement_duration_seconds: Option<f64>,
+    language: Option<&str>,
+    timing: &TimingBreakdown,
 ) -> Option<String> {
     // Ensure database is initialised
     if database::transcription::get_transcription("test").is_err() {
@@ -914,22 +2119,27 @@ fn save_to_history(
         return None;
     }
 
-    let transcription = database::transcription::Transcription::with_details(
-        text.to_string(),
-        if is_enhanced {
-            Some(raw_text.to_string())
-        } else {
-            None
-        },
-        duration_seconds,
-        Some(audio_path.to_string()),
-        is_enhanced,
-        enhancement_prompt.map(|s| s.to_string()),
-        transcription_model_name.map(|s| s.to_string()),
-        transcription_duration_seconds,
-        enhancement_model_name.map(|s| s.to_string()),
-        enhancement_duration_seconds,
-    );
+    let transcription = database::transcription::Transcription {
+        timing_breakdown: timing.to_json(),
+        session_id: database::session::current_session_id(),
+        detected_language: language.map(str::to_string),
+        ..database::transcription::Transcription::with_details(
+            text.to_string(),
+            if is_enhanced {
+                Some(raw_text.to_string())
+            } else {
+                None
+            },
+            duration_seconds,
+            audio_path.map(str::to_string),
+            is_enhanced,
+            enhancement_prompt.map(|s| s.to_string()),
+            transcription_model_name.map(|s| s.to_string()),
+            transcription_duration_seconds,
+            enhancement_model_name.map(|s| s.to_string()),
+            enhancement_duration_seconds,
+        )
+    };
 
     match database::transcription::create_transcription(&transcription) {
         Ok(()) => {
@@ -943,15 +2153,17 @@ fn save_to_history(
     }
 }
 
-/// Get the name of the currently active transcription model.
-fn get_transcription_model_name() -> Option<String> {
-    // Try to get the selected model ID from config
-    let model_id = crate::config::get_config()
-        .ok()
-        .and_then(|c| c.transcription.model_id.clone());
-
-    // Fall back to backend name if no model ID configured
-    model_id.or_else(transcription::get_transcription_backend)
+/// Run CPU-bound work from async code without stalling the runtime.
+///
+/// On a multi-threaded runtime (the app's) this uses `block_in_place`; on a
+/// current-thread runtime, where that would panic, it simply runs inline.
+fn run_blocking<T>(f: impl FnOnce() -> T) -> T {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(f)
+        }
+        _ => f(),
+    }
 }
 
 /// Get audio file duration (placeholder - returns None for now)
@@ -1022,15 +2234,326 @@ pub async fn pipeline_transcribe_file(
     // RAII guard ensures PIPELINE_RUNNING is reset even on early return
     let _guard = PipelineGuard;
 
-    // If the model isn't loaded yet but is downloaded, start eager loading.
-    // The file decode step below takes time, so the model may be ready by
-    // the time we need it.
+    ensure_model_loading_for_import()?;
+
+    // Reset cancellation signal
+    IMPORT_CANCELLED.store(false, Ordering::SeqCst);
+
+    let config = import_config(config);
+
+    emit_progress(
+        &app,
+        PipelineState::Converting,
+        "Converting audio format...",
+    );
+    let decode_start = std::time::Instant::now();
+    let (wav_path, _decoded) = decode_import(&file_path, &IMPORT_CANCELLED).await?;
+    let decode_seconds = decode_start.elapsed().as_secs_f64();
+
+    // Run the standard processing pipeline
+    process_import(&app, &wav_path, &config, decode_seconds, &IMPORT_CANCELLED)
+        .await
+        .map_err(Into::into)
+}
+
+/// Every stage of a [`pipeline_dry_run`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunResult {
+    /// Raw model output
+    pub raw_text: String,
+    /// Text after filters and the dictionary
+    pub filtered_text: String,
+    /// Text after AI enhancement, when it ran and succeeded
+    pub enhanced_text: Option<String>,
+    /// The prompt(s) enhancement applied, joined as in history
+    pub enhancement_prompt: Option<String>,
+    /// Duration of the audio in seconds
+    pub duration_seconds: Option<f64>,
+    /// Name of the transcription model used
+    pub transcription_model_name: Option<String>,
+    /// Time spent in the transcription model
+    pub transcription_duration_seconds: f64,
+    /// Time spent in filters and the dictionary, when they ran
+    pub filter_duration_seconds: Option<f64>,
+    /// Enhancement model used, when enhancement succeeded
+    pub enhancement_model_name: Option<String>,
+    /// Time spent enhancing across all passes
+    pub enhancement_duration_seconds: Option<f64>,
+}
+
+impl DryRunResult {
+    fn new(output: TranscriptionPipelineOutput, duration_seconds: Option<f64>) -> Self {
+        Self {
+            enhanced_text: output.is_enhanced.then_some(output.text),
+            raw_text: output.raw_text,
+            filtered_text: output.filtered_text,
+            enhancement_prompt: output.enhancement_prompt,
+            duration_seconds,
+            transcription_model_name: output.transcription_model_name,
+            transcription_duration_seconds: output.transcription_duration_seconds,
+            filter_duration_seconds: output.filter_duration_seconds,
+            enhancement_model_name: output.enhancement_model_name,
+            enhancement_duration_seconds: output.enhancement_duration_seconds,
+        }
+    }
+}
+
+/// Run an audio file through transcription, filters and enhancement and
+/// return every stage, for tuning prompts and filters.
+///
+/// Nothing is copied, pasted or saved to history, no progress events are
+/// emitted, and dictation context is neither used nor updated. The file is
+/// decoded to a temporary WAV that is removed afterwards. `config` defaults
+/// to the saved settings.
+#[tauri::command]
+pub async fn pipeline_dry_run(
+    file_path: String,
+    config: Option<PipelineConfig>,
+) -> Result<DryRunResult, Error> {
+    tracing::info!("Pipeline: Dry run of {}", file_path);
+    ensure_model_loading_for_import()?;
+
+    let config = match config {
+        Some(config) => config,
+        None => effective_pipeline_config()?,
+    };
+
+    // Removed when `wav_file` drops, including on the early returns below
+    let wav_file = tempfile::Builder::new()
+        .prefix("thoth_dry_run_")
+        .suffix(".wav")
+        .tempfile()
+        .map_err(|e| format!("Failed to create temporary file: {}", e))?;
+    let wav_path = wav_file.path().to_path_buf();
+    let input = PathBuf::from(&file_path);
+    let output = wav_path.clone();
+    tokio::task::spawn_blocking(move || {
+        crate::audio::decode::decode_audio_to_wav(&input, &output, &AtomicBool::new(false))
+    })
+    .await
+    .map_err(|e| format!("Decode task failed: {}", e))??;
+
+    let wav = wav_path.to_string_lossy().to_string();
+    let duration_seconds = get_audio_duration(&wav);
+    let mut transcriber = transcription::LoadedTranscriber::default()
+        .chunked(import_chunk_secs())
+        .keeping_segments(config.keeps_segments());
+    let output = run_transcription_pipeline(
+        &(),
+        &mut transcriber,
+        &enhancement::ActiveEnhancer,
+        &wav,
+        &config,
+        &RunContext::default(),
+    )
+    .await?;
+
+    Ok(DryRunResult::new(output, duration_seconds))
+}
+
+/// Decode and transcribe one file from the transcription queue.
+///
+/// Unlike the import commands this does not claim the capture slot, so a
+/// recording can start while it runs; it counts as processing instead, so
+/// the pipeline state reads Transcribing meanwhile. Does NOT auto-copy or
+/// auto-paste.
+pub(crate) async fn transcribe_queued_file(
+    app: &AppHandle,
+    file_path: &str,
+    config: &PipelineConfig,
+) -> Result<PipelineResult, String> {
+    ensure_model_loading_for_import()?;
+    QUEUE_CANCELLED.store(false, Ordering::SeqCst);
+    let config = import_config(Some(config.clone()));
+
+    let result = {
+        let _processing_guard = ProcessingGuard::new();
+        emit_progress(app, PipelineState::Converting, "Converting audio format...");
+        let decode_start = std::time::Instant::now();
+        match decode_import(file_path, &QUEUE_CANCELLED).await {
+            Ok((wav_path, _decoded)) => {
+                let decode_seconds = decode_start.elapsed().as_secs_f64();
+                process_import(app, &wav_path, &config, decode_seconds, &QUEUE_CANCELLED).await
+            }
+            Err(e) => {
+                emit_progress(app, PipelineState::Failed, &e);
+                Err(e)
+            }
+        }
+    };
+    emit_recording_state(app);
+    result
+}
+
+/// Outcome of one file in a [`pipeline_transcribe_files`] batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchFileResult {
+    /// The input file path, as supplied
+    pub file_path: String,
+    /// Detected input format (absent when the file could not be identified)
+    pub format: Option<crate::audio::decode::InputFormat>,
+    /// Codec used to decode the file
+    pub codec: Option<String>,
+    /// Pipeline result when the file was transcribed successfully
+    pub result: Option<PipelineResult>,
+    /// Error message if this file failed
+    pub error: Option<String>,
+    /// True when the failure was an unsupported format/codec (the user must
+    /// convert this file) rather than a transcription failure
+    pub unsupported_format: bool,
+}
+
+/// Per-file progress event payload for batch import (`batch-file-progress`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchFileProgress {
+    /// Zero-based index of the file within the batch
+    pub index: usize,
+    /// Total number of files in the batch
+    pub total: usize,
+    /// The input file path
+    pub file_path: String,
+    /// Stage this file has reached
+    pub state: PipelineState,
+    /// Detected input format, once decoding has identified it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<crate::audio::decode::InputFormat>,
+    /// Codec used to decode the file, once known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub codec: Option<String>,
+    /// Error message when `state` is `Failed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Transcribe several imported audio files (e.g. a drag-and-drop batch) in order.
+///
+/// Files may mix formats; each is decoded and transcribed independently, so one
+/// undecodable file does not abort the rest. Emits `batch-file-progress` as each
+/// file moves through Converting → Transcribing → Completed/Failed, alongside the
+/// usual per-file `pipeline-progress`/`pipeline-complete` events. Cancelling via
+/// `pipeline_cancel` stops after the current file. Does NOT auto-copy or
+/// auto-paste.
+#[tauri::command]
+pub async fn pipeline_transcribe_files(
+    app: AppHandle,
+    file_paths: Vec<String>,
+    config: Option<PipelineConfig>,
+) -> Result<Vec<BatchFileResult>, Error> {
+    tracing::info!(
+        "Pipeline: transcribe_files called for {} files",
+        file_paths.len()
+    );
+
+    if file_paths.is_empty() {
+        return Err("No files to transcribe".to_string().into());
+    }
+
+    if PIPELINE_RUNNING.swap(true, Ordering::SeqCst) {
+        return Err("Pipeline is already running".to_string().into());
+    }
+
+    // RAII guard ensures PIPELINE_RUNNING is reset even on early return
+    let _guard = PipelineGuard;
+
+    ensure_model_loading_for_import()?;
+
+    IMPORT_CANCELLED.store(false, Ordering::SeqCst);
+
+    let config = import_config(config);
+    let total = file_paths.len();
+    let mut results = Vec::with_capacity(total);
+
+    for (index, file_path) in file_paths.into_iter().enumerate() {
+        if IMPORT_CANCELLED.load(Ordering::SeqCst) {
+            tracing::info!(
+                "Pipeline: Batch cancelled after {} of {} files",
+                index,
+                total
+            );
+            break;
+        }
+
+        let mut progress = BatchFileProgress {
+            index,
+            total,
+            file_path: file_path.clone(),
+            state: PipelineState::Converting,
+            format: None,
+            codec: None,
+            error: None,
+        };
+        emit_batch_progress(&app, &progress);
+        emit_progress(
+            &app,
+            PipelineState::Converting,
+            &format!("Converting file {} of {}...", index + 1, total),
+        );
+
+        let decode_start = std::time::Instant::now();
+        let entry = match decode_import(&file_path, &IMPORT_CANCELLED).await {
+            Ok((wav_path, decoded)) => {
+                let decode_seconds = decode_start.elapsed().as_secs_f64();
+                progress.state = PipelineState::Transcribing;
+                progress.format = Some(decoded.format);
+                progress.codec = Some(decoded.codec.clone());
+                emit_batch_progress(&app, &progress);
+
+                let result =
+                    process_import(&app, &wav_path, &config, decode_seconds, &IMPORT_CANCELLED)
+                        .await;
+                BatchFileResult {
+                    file_path,
+                    format: Some(decoded.format),
+                    codec: Some(decoded.codec),
+                    error: result.as_ref().err().cloned(),
+                    result: result.ok(),
+                    unsupported_format: false,
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Pipeline: Failed to decode {}: {}", file_path, e);
+                BatchFileResult {
+                    file_path,
+                    format: None,
+                    codec: None,
+                    result: None,
+                    unsupported_format: crate::audio::decode::is_unsupported_format_error(&e),
+                    error: Some(e),
+                }
+            }
+        };
+
+        progress.state = if entry.error.is_some() {
+            PipelineState::Failed
+        } else {
+            PipelineState::Completed
+        };
+        progress.error = entry.error.clone();
+        emit_batch_progress(&app, &progress);
+
+        results.push(entry);
+    }
+
+    tracing::info!(
+        "Pipeline: Batch finished, {} of {} files transcribed",
+        results.iter().filter(|r| r.result.is_some()).count(),
+        total
+    );
+    Ok(results)
+}
+
+/// Fail fast when no model is downloaded; otherwise start an eager background
+/// load if needed. The decode step takes time, so the model may be ready by
+/// the time transcription needs it.
+pub(crate) fn ensure_model_loading_for_import() -> Result<(), String> {
     if !transcription::is_transcription_ready() {
         if !transcription::download::check_model_downloaded(None) {
             return Err(
                 "No transcription model downloaded. Open Settings \u{2192} Models to get started."
-                    .to_string()
-                    .into(),
+                    .to_string(),
             );
         }
         tracing::info!("Pipeline: Model not loaded yet, starting eager background load for import");
@@ -1038,68 +2561,180 @@ pub async fn pipeline_transcribe_file(
             transcription::warmup_transcription();
         });
     }
+    Ok(())
+}
 
-    // Reset cancellation signal
-    IMPORT_CANCELLED.store(false, Ordering::SeqCst);
+/// Window length long imports are transcribed in, from config
+pub(crate) fn import_chunk_secs() -> u32 {
+    crate::config::get_config()
+        .map(|c| c.transcription)
+        .unwrap_or_default()
+        .import_chunk_secs
+}
 
-    // Build config with auto_copy and auto_paste disabled (manual copy from UI)
+/// Build the pipeline config for imports: auto_copy and auto_paste disabled
+/// (the user copies manually from the UI).
+fn import_config(config: Option<PipelineConfig>) -> PipelineConfig {
     let mut config = config.unwrap_or_default();
     config.auto_copy = false;
     config.auto_paste = false;
+    config
+}
 
+/// Decode an imported file to a 16kHz mono WAV in the recordings directory.
+///
+/// Returns the WAV path and what the decoder detected about the input. Runs the
+/// CPU-bound decode off the async runtime and stops when `cancelled` is raised.
+async fn decode_import(
+    file_path: &str,
+    cancelled: &'static AtomicBool,
+) -> Result<(String, crate::audio::decode::DecodedAudio), String> {
     // Generate output path for the decoded WAV
-    let home = dirs::home_dir().ok_or("Could not find home directory")?;
-    let recordings_dir = home.join(".thoth").join("Recordings");
-    std::fs::create_dir_all(&recordings_dir)
-        .map_err(|e| format!("Failed to create recordings directory: {}", e))?;
+    let recordings_dir = crate::audio::ensure_recordings_dir()?;
 
+    // Millisecond precision so files in a batch never share a name.
     let filename = format!(
         "thoth_import_{}.wav",
-        chrono::Utc::now().format("%Y%m%d_%H%M%S")
+        chrono::Utc::now().format("%Y%m%d_%H%M%S_%3f")
     );
     let output_wav = recordings_dir.join(&filename);
 
-    // Decode the audio file to 16kHz mono WAV (CPU-bound, run off async runtime)
-    emit_progress(
-        &app,
-        PipelineState::Converting,
-        "Converting audio format...",
-    );
-
-    let input_path = PathBuf::from(&file_path);
+    let input_path = PathBuf::from(file_path);
     let output_path = output_wav.clone();
-    let decode_result = tokio::task::spawn_blocking(move || {
-        crate::audio::decode::decode_audio_to_wav(&input_path, &output_path, &IMPORT_CANCELLED)
+    let decoded = tokio::task::spawn_blocking(move || {
+        crate::audio::decode::decode_audio_to_wav(&input_path, &output_path, cancelled)
     })
     .await
-    .map_err(|e| format!("Decode task failed: {}", e))?;
-
-    let _duration = decode_result?;
+    .map_err(|e| format!("Decode task failed: {}", e))??;
 
     let wav_path = output_wav.to_string_lossy().to_string();
-    tracing::info!("Pipeline: Decoded to {}", wav_path);
+    tracing::info!(
+        "Pipeline: Decoded {:?} ({}) to {}",
+        decoded.format,
+        decoded.codec,
+        wav_path
+    );
+    Ok((wav_path, decoded))
+}
 
-    // Run the standard processing pipeline
-    let result = process_audio(&app, &wav_path, &config).await;
+/// Run a decoded import through [`process_audio`] and emit its completion.
+///
+/// Emits `pipeline-complete` on success, including a silent result (with
+/// the WAV discarded) when no speech was found, and emits a Failed progress
+/// event otherwise. Raising `cancelled` stops a long import between windows.
+async fn process_import(
+    app: &AppHandle,
+    wav_path: &str,
+    config: &PipelineConfig,
+    decode_seconds: f64,
+    cancelled: &'static AtomicBool,
+) -> Result<PipelineResult, String> {
+    let result = resolve_silence(
+        process_audio(
+            app,
+            wav_path,
+            config,
+            &[],
+            Some(decode_seconds),
+            0.0,
+            Some(cancelled),
+        )
+        .await,
+        wav_path,
+    );
 
-    // Emit completion event
     match &result {
-        Ok(r) => {
-            tracing::info!("Pipeline: Emitting pipeline-complete event");
-            if let Err(e) = app.emit("pipeline-complete", r) {
-                tracing::error!("Pipeline: Failed to emit pipeline-complete: {}", e);
-            }
-        }
-        Err(_) if discard_silent_wav(&result, &wav_path) => {
-            // Silent import suppressed — discard_silent_wav already deleted the WAV.
-        }
+        Ok(r) => emit_complete(app, r),
         Err(e) => {
             tracing::error!("Pipeline: File transcription failed: {}", e);
-            emit_progress(&app, PipelineState::Failed, e);
+            emit_progress(app, PipelineState::Failed, e);
+            if delete_audio_after_transcription() {
+                delete_audio_file(wav_path);
+            }
         }
     }
 
-    result.map_err(Into::into)
+    result
+}
+
+/// Emit a batch import per-file progress event
+fn emit_batch_progress(app: &AppHandle, progress: &BatchFileProgress) {
+    if let Err(e) = app.emit("batch-file-progress", progress) {
+        tracing::warn!("Failed to emit batch file progress: {}", e);
+    }
+}
+
+/// Resolve clipboard contents to an importable audio file path.
+///
+/// Accepts a bare path (optionally wrapped in quotes, as Finder's "Copy as
+/// Pathname" and some shells produce), a `file://` URL, or a `~/`-relative path.
+/// Rejects anything that is not a single existing file with a supported audio
+/// extension, so arbitrary clipboard text never reaches the decoder.
+fn parse_clipboard_audio_path(contents: &str) -> Result<PathBuf, String> {
+    let trimmed = contents.trim();
+    if trimmed.is_empty() {
+        return Err("Clipboard is empty".to_string());
+    }
+    if trimmed.lines().count() > 1 {
+        return Err("Clipboard contains more than one line, not a single file path".to_string());
+    }
+
+    let unquoted = trimmed
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| {
+            trimmed
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+        })
+        .unwrap_or(trimmed);
+
+    let path = if unquoted.starts_with("file://") {
+        url::Url::parse(unquoted)
+            .ok()
+            .and_then(|u| u.to_file_path().ok())
+            .ok_or_else(|| format!("Clipboard contains an invalid file URL: {}", unquoted))?
+    } else if let Some(rest) = unquoted.strip_prefix("~/") {
+        dirs::home_dir()
+            .ok_or("Could not find home directory")?
+            .join(rest)
+    } else {
+        PathBuf::from(unquoted)
+    };
+
+    if !path.is_absolute() {
+        return Err("Clipboard does not contain a file path".to_string());
+    }
+    if !path.is_file() {
+        return Err(format!(
+            "Clipboard path does not point to an existing file: {}",
+            path.display()
+        ));
+    }
+    if !crate::audio::decode::has_supported_extension(&path) {
+        return Err(format!(
+            "Clipboard file is not a supported audio format ({}): {}",
+            crate::audio::decode::SUPPORTED_EXTENSIONS.join(", "),
+            path.display()
+        ));
+    }
+    Ok(path)
+}
+
+/// Transcribe the audio file whose path is currently on the clipboard.
+///
+/// Convenience wrapper for workflows (e.g. screen recorders) that copy the path
+/// of a freshly written audio file: reads the clipboard, validates it names an
+/// existing audio file, then delegates to [`pipeline_transcribe_file`].
+#[tauri::command]
+pub async fn pipeline_transcribe_clipboard_path(
+    app: AppHandle,
+    config: Option<PipelineConfig>,
+) -> Result<PipelineResult, Error> {
+    let contents = clipboard::read_clipboard(app.clone()).await?;
+    let path = parse_clipboard_audio_path(&contents)?;
+    tracing::info!("Pipeline: Transcribing clipboard path {}", path.display());
+    pipeline_transcribe_file(app, path.to_string_lossy().into_owned(), config).await
 }
 
 /// Re-transcribe an existing history record using the current model.
@@ -1114,23 +2749,79 @@ pub async fn pipeline_retranscribe(
 ) -> Result<PipelineResult, Error> {
     tracing::info!("Pipeline: retranscribe called for id={}", transcription_id);
 
-    // Look up the existing record from the database
-    let existing = database::transcription::get_transcription(&transcription_id)
-        .map_err(|e| format!("Failed to read transcription: {}", e))?
-        .ok_or_else(|| format!("Transcription '{}' not found", transcription_id))?;
+    // Look up the record first so a missing one or missing audio is reported
+    // even while another pipeline runs
+    let existing = retranscribable_record(&transcription_id)?;
 
-    let audio_path = existing
-        .audio_path
-        .as_deref()
-        .ok_or("This transcription has no associated audio file")?;
+    if PIPELINE_RUNNING.swap(true, Ordering::SeqCst) {
+        return Err("Pipeline is already running".to_string().into());
+    }
 
-    // Check the file still exists on disk
-    if !std::path::Path::new(audio_path).exists() {
-        return Err(
-            "Audio file no longer available. It may have been deleted via Storage cleanup."
-                .to_string()
-                .into(),
-        );
+    // RAII guard ensures PIPELINE_RUNNING is reset even on early return
+    let _guard = PipelineGuard;
+
+    ensure_model_loading_for_import()?;
+
+    retranscribe_record(&app, existing, &retranscribe_config(config), None)
+        .await
+        .map_err(Into::into)
+}
+
+/// Outcome of one record in a [`pipeline_retranscribe_bulk`] run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetranscribeOutcome {
+    /// The history record
+    pub transcription_id: String,
+    /// Pipeline result when the record was re-transcribed
+    pub result: Option<PipelineResult>,
+    /// Error message if this record failed, e.g. its audio file is gone
+    pub error: Option<String>,
+}
+
+/// Per-record progress event payload for [`pipeline_retranscribe_bulk`]
+/// ([`RETRANSCRIBE_PROGRESS_EVENT`])
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetranscribeProgress {
+    /// Zero-based index of the record within the run
+    pub index: usize,
+    /// Total number of records in the run
+    pub total: usize,
+    /// The history record
+    pub transcription_id: String,
+    /// Stage this record has reached: Transcribing, then Completed or Failed
+    pub state: PipelineState,
+    /// Error message when `state` is `Failed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Event emitted as each record of a bulk re-transcription moves on
+/// (payload: [`RetranscribeProgress`])
+pub const RETRANSCRIBE_PROGRESS_EVENT: &str = "retranscribe-progress";
+
+/// Re-transcribe several history records with the current model, in order.
+///
+/// Each record is handled like [`pipeline_retranscribe`]; one that fails
+/// (e.g. its audio file has been cleaned up) is reported and the rest carry
+/// on. Emits [`RETRANSCRIBE_PROGRESS_EVENT`] per record alongside the usual
+/// `pipeline-progress`/`pipeline-complete` events. Cancelling via
+/// `pipeline_cancel` stops after the current record; the outcomes returned
+/// cover the records reached.
+#[tauri::command]
+pub async fn pipeline_retranscribe_bulk(
+    app: AppHandle,
+    ids: Vec<String>,
+    config: Option<PipelineConfig>,
+) -> Result<Vec<RetranscribeOutcome>, Error> {
+    tracing::info!(
+        "Pipeline: retranscribe_bulk called for {} records",
+        ids.len()
+    );
+
+    if ids.is_empty() {
+        return Err("No transcriptions to re-transcribe".to_string().into());
     }
 
     if PIPELINE_RUNNING.swap(true, Ordering::SeqCst) {
@@ -1140,30 +2831,166 @@ pub async fn pipeline_retranscribe(
     // RAII guard ensures PIPELINE_RUNNING is reset even on early return
     let _guard = PipelineGuard;
 
-    // Ensure model is loaded
-    if !transcription::is_transcription_ready() {
-        if !transcription::download::check_model_downloaded(None) {
-            return Err(
-                "No transcription model downloaded. Open Settings \u{2192} Models to get started."
-                    .to_string()
-                    .into(),
-            );
+    ensure_model_loading_for_import()?;
+
+    IMPORT_CANCELLED.store(false, Ordering::SeqCst);
+
+    let config = retranscribe_config(config);
+    let total = ids.len();
+    let mut outcomes = Vec::with_capacity(total);
+
+    {
+        // Keeps the reported state at Transcribing between records
+        let _processing_guard = ProcessingGuard::new();
+        for (index, transcription_id) in ids.into_iter().enumerate() {
+            if IMPORT_CANCELLED.load(Ordering::SeqCst) {
+                tracing::info!(
+                    "Pipeline: Bulk retranscribe cancelled after {} of {} records",
+                    index,
+                    total
+                );
+                break;
+            }
+
+            let mut progress = RetranscribeProgress {
+                index,
+                total,
+                transcription_id: transcription_id.clone(),
+                state: PipelineState::Transcribing,
+                error: None,
+            };
+            emit_retranscribe_progress(&app, &progress);
+
+            let result = match retranscribable_record(&transcription_id) {
+                Ok(existing) => {
+                    retranscribe_record(&app, existing, &config, Some(&IMPORT_CANCELLED)).await
+                }
+                Err(e) => Err(e),
+            };
+            if let Err(e) = &result {
+                tracing::warn!(
+                    "Pipeline: Failed to retranscribe {}: {}",
+                    transcription_id,
+                    e
+                );
+            }
+
+            progress.state = if result.is_ok() {
+                PipelineState::Completed
+            } else {
+                PipelineState::Failed
+            };
+            progress.error = result.as_ref().err().cloned();
+            emit_retranscribe_progress(&app, &progress);
+
+            outcomes.push(RetranscribeOutcome {
+                transcription_id,
+                error: result.as_ref().err().cloned(),
+                result: result.ok(),
+            });
         }
-        tracing::info!(
-            "Pipeline: Model not loaded, starting eager background load for retranscribe"
+    }
+    emit_recording_state(&app);
+
+    tracing::info!(
+        "Pipeline: Bulk retranscribe finished, {} of {} records updated",
+        outcomes.iter().filter(|o| o.result.is_some()).count(),
+        total
+    );
+    Ok(outcomes)
+}
+
+/// Emit [`RECORDING_STOPPED_EVENT`]
+fn emit_recording_stopped(
+    app: &AppHandle,
+    reason: StopReason,
+    audio_path: Option<&str>,
+    error: Option<String>,
+) {
+    let payload = RecordingStopped {
+        reason,
+        audio_path: audio_path.map(str::to_string),
+        error,
+    };
+    if let Err(e) = app.emit(RECORDING_STOPPED_EVENT, payload) {
+        tracing::warn!(
+            "Pipeline: Failed to emit {}: {}",
+            RECORDING_STOPPED_EVENT,
+            e
+        );
+    }
+}
+
+/// Emit a bulk re-transcription per-record progress event
+fn emit_retranscribe_progress(app: &AppHandle, progress: &RetranscribeProgress) {
+    if let Err(e) = app.emit(RETRANSCRIBE_PROGRESS_EVENT, progress) {
+        tracing::warn!("Failed to emit {}: {}", RETRANSCRIBE_PROGRESS_EVENT, e);
+    }
+}
+
+/// Config for re-transcribing from history, with output disabled (the text
+/// goes back into the record, not at the cursor).
+fn retranscribe_config(config: Option<PipelineConfig>) -> PipelineConfig {
+    let mut config = config.unwrap_or_default();
+    config.auto_copy = false;
+    config.auto_paste = false;
+    config
+}
+
+/// Look up a history record whose audio is still on disk.
+fn retranscribable_record(
+    transcription_id: &str,
+) -> Result<database::transcription::Transcription, String> {
+    let existing = database::transcription::get_transcription(transcription_id)
+        .map_err(|e| format!("Failed to read transcription: {}", e))?
+        .ok_or_else(|| format!("Transcription '{}' not found", transcription_id))?;
+
+    let audio_path = existing
+        .audio_path
+        .as_deref()
+        .ok_or("This transcription has no associated audio file")?;
+
+    // Check the file still exists on disk
+    if !std::path::Path::new(audio_path).exists() {
+        return Err(
+            "Audio file no longer available. It may have been deleted via Storage cleanup."
+                .to_string(),
         );
-        std::thread::spawn(|| {
-            transcription::warmup_transcription();
-        });
     }
+    Ok(existing)
+}
 
-    // Build config with output disabled (retranscribe from history, not at cursor)
-    let mut config = config.unwrap_or_default();
-    config.auto_copy = false;
-    config.auto_paste = false;
+/// Re-run the pipeline over `existing`'s audio and update the record in place.
+///
+/// Raising `cancelled` stops a long record between windows.
+async fn retranscribe_record(
+    app: &AppHandle,
+    existing: database::transcription::Transcription,
+    config: &PipelineConfig,
+    cancelled: Option<&'static AtomicBool>,
+) -> Result<PipelineResult, String> {
+    let audio_path = existing.audio_path.clone().unwrap_or_default();
+
+    let mut transcriber = transcription::LoadedTranscriber::default()
+        .chunked(import_chunk_secs())
+        .keeping_segments(config.keeps_segments());
+    if let Some(cancelled) = cancelled {
+        transcriber = transcriber.cancelled_by(cancelled);
+    }
 
-    // Run the core transcription pipeline
-    let output = run_transcription_pipeline(&app, audio_path, &config).await?;
+    // Run the core transcription pipeline (the record may be a long import)
+    let mut output = run_transcription_pipeline(
+        app,
+        &mut transcriber,
+        &enhancement::ActiveEnhancer,
+        &audio_path,
+        config,
+        &RunContext::default(),
+    )
+    .await?;
+    if let Some(redactor) = Redactor::from_config() {
+        redact_output(&redactor, &mut output);
+    }
 
     // Read-modify-write: update only the fields that changed
     let mut updated = existing;
@@ -1174,15 +3001,14 @@ pub async fn pipeline_retranscribe(
         None
     };
     updated.is_enhanced = output.is_enhanced;
-    updated.enhancement_prompt = if output.is_enhanced {
-        Some(config.enhancement_prompt.clone())
-    } else {
-        None
-    };
+    updated.enhancement_prompt = output.enhancement_prompt.clone();
     updated.transcription_model_name = output.transcription_model_name.clone();
+    updated.detected_language = output.language.clone();
     updated.transcription_duration_seconds = Some(output.transcription_duration_seconds);
     updated.enhancement_model_name = output.enhancement_model_name.clone();
     updated.enhancement_duration_seconds = output.enhancement_duration_seconds;
+    updated.timing_breakdown =
+        TimingBreakdown::new(output.decode_duration_seconds, &output).to_json();
 
     // Persist to database
     database::transcription::update_transcription(&updated)
@@ -1190,10 +3016,11 @@ pub async fn pipeline_retranscribe(
 
     tracing::info!("Pipeline: Retranscribed and updated id={}", updated.id);
 
-    emit_progress(&app, PipelineState::Completed, "Done");
+    emit_progress(app, PipelineState::Completed, "Done");
 
     let result = PipelineResult {
         success: true,
+        words_per_minute: words_per_minute(&output.text, updated.duration_seconds),
         text: output.text,
         raw_text: output.raw_text,
         is_enhanced: output.is_enhanced,
@@ -1205,6 +3032,13 @@ pub async fn pipeline_retranscribe(
         transcription_duration_seconds: Some(output.transcription_duration_seconds),
         enhancement_model_name: output.enhancement_model_name,
         enhancement_duration_seconds: output.enhancement_duration_seconds,
+        decode_duration_seconds: output.decode_duration_seconds,
+        model_wait_seconds: Some(output.model_wait_seconds),
+        filter_duration_seconds: output.filter_duration_seconds,
+        clipped: output.audio_levels.is_some_and(|l| l.clipped()),
+        clip_ratio: output.audio_levels.map(|l| l.clip_ratio),
+        had_dropouts: false,
+        was_silent: false,
     };
 
     if let Err(e) = app.emit("pipeline-complete", &result) {
@@ -1231,6 +3065,16 @@ pub async fn pipeline_toggle_recording(
     app: AppHandle,
     config: Option<PipelineConfig>,
 ) -> Result<ToggleOutcome, Error> {
+    if let Some(path) = recent_start_path() {
+        // A second trigger that raced the one that just started this recording
+        // (e.g. tray and shortcut together) must not immediately stop it. Its
+        // flags still apply, so "dictate without saving" is not lost when the
+        // plain shortcut's start won the race.
+        tracing::info!("Pipeline: Toggle within duplicate-start window, keeping recording");
+        apply_start_flags(config);
+        return Ok(ToggleOutcome::Started { path });
+    }
+
     if crate::audio::is_recording() {
         // --- STOP ---
         // Play BONG now, before disarming, so the sound is always paired with
@@ -1245,10 +3089,35 @@ pub async fn pipeline_toggle_recording(
         // (keyboard_service.rs / manager.rs / tray.rs) so they fire before the
         // IPC round-trip.  pipeline_start_recording does not duplicate them.
         let path = pipeline_start_recording(app)?;
+        apply_start_flags(config);
         Ok(ToggleOutcome::Started { path })
     }
 }
 
+/// Fix the current recording's save, insertion and verbatim choices from the
+/// `config` of a toggle that started it.
+///
+/// Whether to save is fixed when the recording starts, so "dictate without
+/// saving" holds even if a plain toggle stops it. A duplicate start only adds
+/// its flags, never clearing those of the start it raced.
+fn apply_start_flags(config: Option<PipelineConfig>) {
+    let Some(config) = config else {
+        return;
+    };
+    if !config.save_to_history {
+        tracing::info!("Pipeline: Recording will not be saved to history");
+        UNSAVED_RECORDING.store(true, Ordering::SeqCst);
+    }
+    if let Some(method) = config.forced_insertion_method {
+        tracing::info!("Pipeline: Recording will be inserted by {}", method);
+        *FORCED_INSERTION_METHOD.lock() = Some(method);
+    }
+    if config.verbatim {
+        tracing::info!("Pipeline: Recording will be output verbatim");
+        VERBATIM_RECORDING.store(true, Ordering::SeqCst);
+    }
+}
+
 /// The outcome of a `pipeline_toggle_recording` call.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "action", rename_all = "snake_case")]
@@ -1279,6 +3148,46 @@ fn emit_progress_with_device(
     if let Err(e) = app.emit("pipeline-progress", &progress) {
         tracing::warn!("Failed to emit pipeline progress: {}", e);
     }
+
+    emit_state(app, state);
+    if let Some(baseline) = settled_state(state, get_pipeline_state()) {
+        emit_state(app, baseline);
+    }
+}
+
+/// The state to report straight after `state`, if any.
+///
+/// A finished run returns consumers to `baseline` (from
+/// [`get_pipeline_state`]) unless processing is still under way, in which
+/// case [`emit_recording_state`] settles it once the work is done.
+fn settled_state(state: PipelineState, baseline: PipelineState) -> Option<PipelineState> {
+    match state {
+        PipelineState::Completed | PipelineState::Failed
+            if baseline != PipelineState::Transcribing =>
+        {
+            Some(baseline)
+        }
+        _ => None,
+    }
+}
+
+/// Record `next` as the last state sent; false if it was already.
+fn is_transition(last: &mut PipelineState, next: PipelineState) -> bool {
+    if *last == next {
+        return false;
+    }
+    *last = next;
+    true
+}
+
+/// Emit [`PIPELINE_STATE_EVENT`] when the state has changed.
+fn emit_state(app: &AppHandle, state: PipelineState) {
+    if !is_transition(&mut LAST_EMITTED_STATE.lock(), state) {
+        return;
+    }
+    if let Err(e) = app.emit(PIPELINE_STATE_EVENT, state) {
+        tracing::warn!("Failed to emit {}: {}", PIPELINE_STATE_EVENT, e);
+    }
 }
 
 /// Emit the authoritative system state on the `recording-state` channel.
@@ -1292,6 +3201,7 @@ fn emit_recording_state(app: &AppHandle) {
     if let Err(e) = app.emit("recording-state", current) {
         tracing::warn!("Failed to emit recording-state: {}", e);
     }
+    emit_state(app, current);
 }
 
 #[cfg(test)]
@@ -1309,6 +3219,33 @@ mod tests {
         assert!(!config.enhancement_enabled);
         assert!(!config.auto_copy);
         assert!(config.auto_paste);
+        assert!(config.save_to_history);
+    }
+
+    #[test]
+    fn test_pipeline_config_saves_to_history_when_unspecified() {
+        let mut value = serde_json::to_value(PipelineConfig::default()).unwrap();
+        value.as_object_mut().unwrap().remove("saveToHistory");
+        let config: PipelineConfig = serde_json::from_value(value).unwrap();
+        assert!(config.save_to_history);
+    }
+
+    #[test]
+    fn test_force_insertion_method_overrides_config() {
+        let mut config = PipelineConfig {
+            auto_paste: false,
+            ..PipelineConfig::default()
+        };
+        force_insertion_method(&mut config, None);
+        assert_eq!(config.insertion_method, "paste");
+        assert!(!config.auto_paste);
+
+        force_insertion_method(&mut config, Some("typing".to_string()));
+        assert_eq!(config.insertion_method, "typing");
+        assert!(config.auto_paste);
+
+        force_insertion_method(&mut config, Some("dictation".to_string()));
+        assert_eq!(config.insertion_method, "typing");
     }
 
     #[test]
@@ -1334,6 +3271,7 @@ mod tests {
             raw_text: "hello world".to_string(),
             is_enhanced: false,
             duration_seconds: Some(5.5),
+            words_per_minute: Some(21.8),
             audio_path: Some("/tmp/test.wav".to_string()),
             error: None,
             transcription_id: Some("abc123".to_string()),
@@ -1341,12 +3279,87 @@ mod tests {
             transcription_duration_seconds: Some(1.2),
             enhancement_model_name: None,
             enhancement_duration_seconds: None,
+            decode_duration_seconds: Some(0.3),
+            model_wait_seconds: Some(0.0),
+            filter_duration_seconds: None,
+            clipped: true,
+            clip_ratio: Some(0.02),
+            had_dropouts: true,
+            was_silent: false,
         };
 
         let json = serde_json::to_string(&result).unwrap();
         assert!(json.contains("\"success\":true"));
+        assert!(json.contains("\"clipped\":true"));
+        assert!(json.contains("\"clipRatio\":0.02"));
+        assert!(json.contains("\"hadDropouts\":true"));
+        assert!(json.contains("\"decodeDurationSeconds\":0.3"));
+        assert!(json.contains("\"modelWaitSeconds\":0.0"));
         assert!(json.contains("\"text\":\"Hello world\""));
         assert!(json.contains("\"transcriptionModelName\""));
+        assert!(json.contains("\"wasSilent\":false"));
+        assert!(json.contains("\"wordsPerMinute\":21.8"));
+    }
+
+    #[test]
+    fn test_words_per_minute() {
+        assert_eq!(words_per_minute("one two three", Some(1.5)), Some(120.0));
+        assert_eq!(words_per_minute("one two three", None), None);
+        assert_eq!(words_per_minute("one two three", Some(0.0)), None);
+        assert_eq!(words_per_minute("hi", Some(0.4)), None);
+        assert_eq!(words_per_minute("   ", Some(10.0)), None);
+    }
+
+    #[test]
+    fn test_recording_stopped_serialisation() {
+        let stopped = RecordingStopped {
+            reason: StopReason::Cancelled,
+            audio_path: Some("/tmp/cancelled.wav".to_string()),
+            error: None,
+        };
+        let json = serde_json::to_value(&stopped).unwrap();
+        assert_eq!(json["reason"], "cancelled");
+        assert_eq!(json["audioPath"], "/tmp/cancelled.wav");
+        assert!(json["error"].is_null());
+
+        let too_short = serde_json::to_value(StopReason::TooShort).unwrap();
+        assert_eq!(too_short, "too_short");
+    }
+
+    #[test]
+    fn test_retranscribe_progress_serialisation() {
+        let progress = RetranscribeProgress {
+            index: 0,
+            total: 2,
+            transcription_id: "abc".to_string(),
+            state: PipelineState::Failed,
+            error: Some("Audio file no longer available".to_string()),
+        };
+        let json = serde_json::to_value(&progress).unwrap();
+        assert_eq!(json["transcriptionId"], "abc");
+        assert_eq!(json["state"], "failed");
+        assert_eq!(json["error"], "Audio file no longer available");
+    }
+
+    #[test]
+    fn test_batch_file_progress_serialisation() {
+        let progress = BatchFileProgress {
+            index: 1,
+            total: 3,
+            file_path: "/tmp/b.m4a".to_string(),
+            state: PipelineState::Transcribing,
+            format: Some(crate::audio::decode::InputFormat::M4a),
+            codec: Some("aac".to_string()),
+            error: None,
+        };
+        let json = serde_json::to_string(&progress).unwrap();
+        assert!(json.contains("\"filePath\":\"/tmp/b.m4a\""));
+        assert!(json.contains("\"state\":\"transcribing\""));
+        assert!(json.contains("\"format\":\"m4a\""));
+        assert!(
+            !json.contains("error"),
+            "absent error must be omitted: {json}"
+        );
     }
 
     /// What a toggle-recording press will do.
@@ -1429,8 +3442,9 @@ mod tests {
         );
     }
 
-    /// `discard_silent_wav` must delete the file and return `true` when given the
-    /// no-speech sentinel, exercising the real helper that both dispatch arms call.
+    /// `resolve_silence` must delete the file and return a silent result when
+    /// given the no-speech sentinel, exercising the real helper that both
+    /// dispatch arms call.
     #[test]
     fn test_no_speech_discard_deletes_wav_file() {
         let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
@@ -1439,11 +3453,15 @@ mod tests {
         assert!(wav_path.exists(), "temp WAV must exist before discard");
 
         let result: Result<PipelineResult, String> = Err(NO_SPEECH_ERROR.to_string());
-        let discarded = discard_silent_wav(&result, wav_path.to_str().unwrap());
+        let resolved = resolve_silence(result, wav_path.to_str().unwrap())
+            .expect("silence must not be reported as an error");
 
+        assert!(resolved.success);
+        assert!(resolved.was_silent);
+        assert!(resolved.text.is_empty());
         assert!(
-            discarded,
-            "helper must return true for the no-speech sentinel"
+            resolved.transcription_id.is_none(),
+            "nothing saved to history"
         );
         assert!(
             !wav_path.exists(),
@@ -1451,9 +3469,9 @@ mod tests {
         );
     }
 
-    /// `discard_silent_wav` must return `false` and leave the file intact when
-    /// given a genuine error — both the recording-path and import-path arms rely
-    /// on this to preserve diagnostic artefacts.
+    /// `resolve_silence` must pass a genuine error through and leave the file
+    /// intact — both the recording-path and import-path arms rely on this to
+    /// preserve diagnostic artefacts.
     #[test]
     fn test_genuine_error_retains_wav_file() {
         let tmp_dir = tempfile::tempdir().expect("failed to create temp dir");
@@ -1461,15 +3479,54 @@ mod tests {
         std::fs::write(&wav_path, b"RIFF").expect("failed to write temp file");
 
         let result: Result<PipelineResult, String> = Err("Transcription model crashed".to_string());
-        let discarded = discard_silent_wav(&result, wav_path.to_str().unwrap());
+        let resolved = resolve_silence(result, wav_path.to_str().unwrap());
 
-        assert!(!discarded, "helper must return false for a genuine error");
+        assert_eq!(
+            resolved.unwrap_err(),
+            "Transcription model crashed",
+            "a genuine error must be passed through"
+        );
         assert!(
             wav_path.exists(),
             "WAV file must be retained after a genuine pipeline error"
         );
     }
 
+    // ── Clipboard path parsing tests ───────────────────────────────────────────
+
+    #[test]
+    fn test_parse_clipboard_audio_path_accepts_plain_quoted_and_url() {
+        let dir = tempfile::tempdir().unwrap();
+        let wav = dir.path().join("clip.wav");
+        std::fs::write(&wav, b"RIFF").unwrap();
+        let plain = wav.to_str().unwrap();
+
+        assert_eq!(parse_clipboard_audio_path(plain).unwrap(), wav);
+        assert_eq!(
+            parse_clipboard_audio_path(&format!("  \"{}\"\n", plain)).unwrap(),
+            wav
+        );
+        let url = url::Url::from_file_path(&wav).unwrap();
+        assert_eq!(parse_clipboard_audio_path(url.as_str()).unwrap(), wav);
+    }
+
+    #[test]
+    fn test_parse_clipboard_audio_path_rejects_non_paths() {
+        assert!(parse_clipboard_audio_path("").is_err());
+        assert!(parse_clipboard_audio_path("hello world").is_err());
+        assert!(parse_clipboard_audio_path("/nonexistent/recording.wav").is_err());
+        assert!(parse_clipboard_audio_path("/a.wav\n/b.wav").is_err());
+    }
+
+    #[test]
+    fn test_parse_clipboard_audio_path_rejects_unsupported_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let txt = dir.path().join("notes.txt");
+        std::fs::write(&txt, b"not audio").unwrap();
+        let err = parse_clipboard_audio_path(txt.to_str().unwrap()).unwrap_err();
+        assert!(err.contains("not a supported audio format"), "got: {err}");
+    }
+
     // ── catch_post_processing tests ────────────────────────────────────────────
 
     /// A panicking closure must be caught and returned as Err containing the
@@ -1505,4 +3562,593 @@ mod tests {
             "error message must contain the formatted String panic payload"
         );
     }
+
+    /// Returns canned text and counts calls, standing in for a loaded model.
+    struct MockTranscriber {
+        text: Result<String, String>,
+        calls: usize,
+    }
+
+    impl MockTranscriber {
+        fn returning(text: &str) -> Self {
+            Self {
+                text: Ok(text.to_string()),
+                calls: 0,
+            }
+        }
+    }
+
+    impl transcription::Transcriber for MockTranscriber {
+        fn transcribe(&mut self, _audio_path: &std::path::Path) -> anyhow::Result<String> {
+            self.calls += 1;
+            self.text.clone().map_err(|e| anyhow::anyhow!(e))
+        }
+
+        fn model_name(&self) -> Option<String> {
+            Some("mock-model".to_string())
+        }
+
+        fn language(&self) -> Option<String> {
+            Some("en".to_string())
+        }
+    }
+
+    /// Upper-cases the transcript, or fails when `fail` is set.
+    struct MockEnhancer {
+        fail: bool,
+    }
+
+    impl enhancement::Enhancer for MockEnhancer {
+        fn enhance<'a>(
+            &'a self,
+            text: String,
+            _model: String,
+            _prompt: String,
+        ) -> futures_util::future::BoxFuture<'a, Result<String, Error>> {
+            let fail = self.fail;
+            Box::pin(async move {
+                if fail {
+                    Err("backend unreachable".into())
+                } else {
+                    Ok(text.to_uppercase())
+                }
+            })
+        }
+    }
+
+    /// Filtering on, dictionary off (it reads the user's dictionary file), and
+    /// no minimum length for enhancement.
+    fn mock_config() -> PipelineConfig {
+        PipelineConfig {
+            apply_dictionary: false,
+            enhancement_model: "mock-llm".to_string(),
+            enhancement_min_words: 0,
+            ..PipelineConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_with_mock_transcriber_applies_filters() {
+        let mut transcriber = MockTranscriber::returning("I um think   so");
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &mock_config(),
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(transcriber.calls, 1);
+        assert_eq!(output.raw_text, "I um think   so");
+        assert_eq!(output.text, "I think so");
+        assert!(!output.is_enhanced);
+        assert_eq!(
+            output.transcription_model_name.as_deref(),
+            Some("mock-model")
+        );
+        assert_eq!(output.language.as_deref(), Some("en"));
+        // Ready straight away, nothing to decode, filters ran
+        assert!(output.model_wait_seconds < 1.0);
+        assert_eq!(output.decode_duration_seconds, None);
+        assert!(output.filter_duration_seconds.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_counts_queue_wait_as_model_wait() {
+        let output = run_transcription_pipeline(
+            &(),
+            &mut MockTranscriber::returning("hello"),
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &mock_config(),
+            &RunContext {
+                queue_wait_seconds: 4.5,
+                ..RunContext::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(output.model_wait_seconds >= 4.5);
+    }
+
+    #[tokio::test]
+    async fn test_verbatim_config_keeps_the_transcript_untouched() {
+        let mut transcriber = MockTranscriber::returning("I um think   so");
+        let mut config = PipelineConfig {
+            enhancement_enabled: true,
+            ..mock_config()
+        };
+        config.make_verbatim();
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(config.verbatim);
+        assert_eq!(output.text, "I um think   so");
+        assert!(!output.is_enhanced);
+        assert_eq!(output.filter_duration_seconds, None);
+    }
+
+    #[tokio::test]
+    async fn test_timing_breakdown_is_compact_json() {
+        let mut transcriber = MockTranscriber::returning("hello there");
+        let config = PipelineConfig {
+            apply_filtering: false,
+            ..mock_config()
+        };
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(output.filter_duration_seconds, None);
+
+        let timing = TimingBreakdown::new(Some(0.12345), &output);
+        assert_eq!(timing.decode_seconds, Some(0.123));
+        let json = timing.to_json().unwrap();
+        assert!(
+            json.starts_with(r#"{"decode":0.123,"wait":"#),
+            "got: {json}"
+        );
+        assert!(!json.contains("filter"));
+
+        let parsed: TimingBreakdown = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, timing);
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_empty_transcription_is_no_speech() {
+        let mut transcriber = MockTranscriber::returning("   ");
+        let err = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &mock_config(),
+            &RunContext::default(),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(is_no_speech_error(&err));
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_transcriber_error_propagates() {
+        let mut transcriber = MockTranscriber {
+            text: Err("model crashed".to_string()),
+            calls: 0,
+        };
+        let err = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &mock_config(),
+            &RunContext::default(),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(err.contains("model crashed"));
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_enhancement_success_and_fallback() {
+        let config = PipelineConfig {
+            enhancement_enabled: true,
+            ..mock_config()
+        };
+
+        let mut transcriber = MockTranscriber::returning("hello world");
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+        assert!(output.is_enhanced);
+        assert_eq!(output.text, "HELLO WORLD");
+        assert_eq!(output.filtered_text, "hello world");
+        assert_eq!(output.enhancement_model_name.as_deref(), Some("mock-llm"));
+
+        // A failing backend keeps the filtered text rather than failing the run.
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: true },
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+        assert!(!output.is_enhanced);
+        assert_eq!(output.text, "hello world");
+        assert!(output.enhancement_model_name.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_result_reports_each_stage() {
+        let config = PipelineConfig {
+            enhancement_enabled: true,
+            ..mock_config()
+        };
+        let mut transcriber = MockTranscriber::returning("um hello   world");
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+        let result = DryRunResult::new(output, Some(2.0));
+        assert_eq!(result.raw_text, "um hello   world");
+        assert_eq!(result.filtered_text, "hello world");
+        assert_eq!(result.enhanced_text.as_deref(), Some("HELLO WORLD"));
+
+        // Without enhancement there is no enhanced stage
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: true },
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+        let result = DryRunResult::new(output, None);
+        assert_eq!(result.filtered_text, "hello world");
+        assert!(result.enhanced_text.is_none());
+        assert!(result.enhancement_prompt.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_short_text_skips_enhancement() {
+        let config = PipelineConfig {
+            enhancement_enabled: true,
+            enhancement_min_words: 3,
+            ..mock_config()
+        };
+
+        let mut transcriber = MockTranscriber::returning("yes");
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+        assert!(!output.is_enhanced);
+        assert_eq!(output.text, "yes");
+        assert!(output.enhancement_model_name.is_none());
+
+        let mut transcriber = MockTranscriber::returning("yes that works");
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &MockEnhancer { fail: false },
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+        assert!(output.is_enhanced);
+        assert_eq!(output.text, "YES THAT WORKS");
+    }
+
+    #[test]
+    fn test_claim_start_rapid_second_start_is_duplicate() {
+        let running = AtomicBool::new(false);
+        let now = std::time::Instant::now();
+
+        assert_eq!(claim_start(&running, None, false, now), StartClaim::Claimed);
+        let first = RecentStart {
+            path: "/tmp/first.wav".to_string(),
+            at: now,
+        };
+
+        let second = claim_start(
+            &running,
+            Some(&first),
+            true,
+            now + std::time::Duration::from_millis(50),
+        );
+        assert_eq!(second, StartClaim::Duplicate("/tmp/first.wav".to_string()));
+        assert!(running.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_claim_start_busy_outside_window_or_when_not_capturing() {
+        let running = AtomicBool::new(true);
+        let now = std::time::Instant::now();
+        let first = RecentStart {
+            path: "/tmp/first.wav".to_string(),
+            at: now,
+        };
+
+        let late = now + DUPLICATE_START_WINDOW + std::time::Duration::from_millis(1);
+        assert_eq!(
+            claim_start(&running, Some(&first), true, late),
+            StartClaim::Busy
+        );
+        // The first start is still opening the device (or failed): no path to share.
+        assert_eq!(claim_start(&running, None, false, now), StartClaim::Busy);
+        assert_eq!(
+            claim_start(&running, Some(&first), false, now),
+            StartClaim::Busy
+        );
+    }
+
+    #[test]
+    fn test_resolve_waiters_answers_only_that_recording() {
+        let mut first = wait_for_recording_result("/tmp/waited-first.wav");
+        let mut second = wait_for_recording_result("/tmp/waited-second.wav");
+        assert!(is_awaited("/tmp/waited-first.wav"));
+
+        resolve_waiters("/tmp/waited-first.wav", Ok(PipelineResult::silent(None)));
+        assert!(first.try_recv().unwrap().unwrap().was_silent);
+        assert!(!is_awaited("/tmp/waited-first.wav"));
+        assert!(second.try_recv().is_err());
+
+        resolve_waiters(
+            "/tmp/waited-second.wav",
+            Err("Recording cancelled".to_string()),
+        );
+        assert_eq!(
+            second.try_recv().unwrap().unwrap_err(),
+            "Recording cancelled"
+        );
+
+        let abandoned = wait_for_recording_result("/tmp/waited-abandoned.wav");
+        assert!(is_awaited("/tmp/waited-abandoned.wav"));
+        drop(abandoned);
+        assert!(!is_awaited("/tmp/waited-abandoned.wav"));
+    }
+
+    #[test]
+    fn test_is_too_short() {
+        assert!(is_too_short(Some(0.2), 300));
+        assert!(!is_too_short(Some(0.3), 300));
+        assert!(!is_too_short(Some(5.0), 300));
+        // Zero disables the minimum; unknown length is never too short
+        assert!(!is_too_short(Some(0.0), 0));
+        assert!(!is_too_short(None, 300));
+    }
+
+    #[test]
+    fn test_insert_spacing_modes() {
+        assert_eq!(insert_spacing(TrailingSpace::Always, false), ("", " "));
+        assert_eq!(insert_spacing(TrailingSpace::Always, true), ("", " "));
+        assert_eq!(insert_spacing(TrailingSpace::Never, true), ("", ""));
+        assert_eq!(insert_spacing(TrailingSpace::Smart, false), ("", ""));
+        assert_eq!(insert_spacing(TrailingSpace::Smart, true), (" ", ""));
+    }
+
+    #[test]
+    fn test_is_append_same_app_within_window() {
+        let now = std::time::Instant::now();
+        let last = RecentInsert {
+            app: Some("Pages".to_string()),
+            at: now,
+        };
+        assert!(is_append(Some(&last), Some("Pages"), now));
+        assert!(!is_append(Some(&last), Some("Safari"), now));
+        assert!(!is_append(
+            Some(&last),
+            Some("Pages"),
+            now + APPEND_WINDOW + std::time::Duration::from_secs(1)
+        ));
+        assert!(!is_append(None, Some("Pages"), now));
+    }
+
+    #[test]
+    fn test_two_concurrent_starts_share_one_recording() {
+        let running = AtomicBool::new(false);
+        let last_start: parking_lot::Mutex<Option<RecentStart>> = parking_lot::Mutex::new(None);
+        let opened = AtomicUsize::new(0);
+
+        let start = || {
+            start_once(
+                &running,
+                &last_start,
+                || opened.load(Ordering::SeqCst) > 0,
+                || {
+                    std::thread::sleep(std::time::Duration::from_millis(20));
+                    let n = opened.fetch_add(1, Ordering::SeqCst);
+                    Ok(format!("/tmp/recording-{n}.wav"))
+                },
+            )
+        };
+
+        let (a, b) = std::thread::scope(|s| {
+            let a = s.spawn(start);
+            let b = s.spawn(start);
+            (a.join().unwrap(), b.join().unwrap())
+        });
+
+        assert_eq!(opened.load(Ordering::SeqCst), 1);
+        assert_eq!(a.unwrap(), b.unwrap());
+    }
+
+    /// Substitutes `{text}` into the prompt; a prompt of "fail" errors.
+    struct TemplateEnhancer;
+
+    impl enhancement::Enhancer for TemplateEnhancer {
+        fn enhance<'a>(
+            &'a self,
+            text: String,
+            _model: String,
+            prompt: String,
+        ) -> futures_util::future::BoxFuture<'a, Result<String, Error>> {
+            Box::pin(async move {
+                if prompt == "fail" {
+                    Err("backend unreachable".into())
+                } else {
+                    Ok(prompt.replace("{text}", &text))
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_enhancement_chain_feeds_each_pass() {
+        let config = PipelineConfig {
+            enhancement_enabled: true,
+            enhancement_chain: vec!["grammar({text})".to_string(), "bullets({text})".to_string()],
+            ..mock_config()
+        };
+
+        let mut transcriber = MockTranscriber::returning("hello world");
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &TemplateEnhancer,
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(output.is_enhanced);
+        assert_eq!(output.text, "bullets(grammar(hello world))");
+        assert_eq!(
+            output.enhancement_prompt.as_deref(),
+            Some("grammar({text})\n\n---\n\nbullets({text})")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_language_variable_is_transcript_language() {
+        let config = PipelineConfig {
+            enhancement_enabled: true,
+            enhancement_chain: vec!["[{language}] {text}".to_string()],
+            ..mock_config()
+        };
+
+        let mut transcriber = MockTranscriber::returning("hello world");
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &TemplateEnhancer,
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output.text, "[en] hello world");
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_enhancement_chain_stops_at_failed_pass() {
+        let config = PipelineConfig {
+            enhancement_enabled: true,
+            enhancement_chain: vec![
+                "grammar({text})".to_string(),
+                "fail".to_string(),
+                "bullets({text})".to_string(),
+            ],
+            ..mock_config()
+        };
+
+        let mut transcriber = MockTranscriber::returning("hello world");
+        let output = run_transcription_pipeline(
+            &(),
+            &mut transcriber,
+            &TemplateEnhancer,
+            "missing.wav",
+            &config,
+            &RunContext::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(output.is_enhanced);
+        assert_eq!(output.text, "grammar(hello world)");
+        assert_eq!(
+            output.enhancement_prompt.as_deref(),
+            Some("grammar({text})")
+        );
+    }
+
+    #[test]
+    fn test_single_prompt_is_chain_of_one() {
+        let config = PipelineConfig::default();
+        assert_eq!(
+            config.enhancement_passes(),
+            vec![DEFAULT_ENHANCEMENT_PROMPT]
+        );
+    }
+
+    #[test]
+    fn test_state_repeats_skipped() {
+        let mut last = PipelineState::Idle;
+        assert!(is_transition(&mut last, PipelineState::Recording));
+        assert!(!is_transition(&mut last, PipelineState::Recording));
+        assert!(is_transition(&mut last, PipelineState::Transcribing));
+        assert_eq!(last, PipelineState::Transcribing);
+    }
+
+    #[test]
+    fn test_finished_run_settles_to_baseline() {
+        use PipelineState::*;
+        assert_eq!(settled_state(Completed, Idle), Some(Idle));
+        assert_eq!(settled_state(Failed, Idle), Some(Idle));
+        // A clip started while the last one was processing
+        assert_eq!(settled_state(Completed, Recording), Some(Recording));
+        // Still processing: settled later by emit_recording_state
+        assert_eq!(settled_state(Completed, Transcribing), None);
+        assert_eq!(settled_state(Filtering, Idle), None);
+    }
 }
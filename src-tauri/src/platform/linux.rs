@@ -364,6 +364,136 @@ impl std::fmt::Display for MicrophoneStatus {
     }
 }
 
+/// Centre of the active window on X11, from the root window's
+/// `_NET_ACTIVE_WINDOW` property.
+///
+/// Coordinates are physical root-window pixels; callers divide by the
+/// monitor's scale factor before comparing them with logical bounds. Returns `None` on Wayland, where clients cannot see
+/// other windows, or when the window manager does not set the property.
+pub fn focused_window_centre() -> Option<(f64, f64)> {
+    if crate::shortcuts::is_wayland() {
+        return None;
+    }
+
+    use x11rb::protocol::xproto::ConnectionExt;
+
+    let (conn, root, window) = x11_active_window()?;
+    let geometry = conn.get_geometry(window).ok()?.reply().ok()?;
+    let origin = conn
+        .translate_coordinates(window, root, 0, 0)
+        .ok()?
+        .reply()
+        .ok()?;
+
+    Some((
+        origin.dst_x as f64 + geometry.width as f64 / 2.0,
+        origin.dst_y as f64 + geometry.height as f64 / 2.0,
+    ))
+}
+
+/// Connect to the X server and read the root window's `_NET_ACTIVE_WINDOW`,
+/// returning the connection, the root window and the active window.
+fn x11_active_window() -> Option<(x11rb::rust_connection::RustConnection, u32, u32)> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+
+    let display = std::env::var("DISPLAY").ok()?;
+    let (conn, screen_num) = x11rb::connect(Some(&display)).ok()?;
+    let root = conn.setup().roots[screen_num].root;
+
+    let active_atom = conn
+        .intern_atom(false, b"_NET_ACTIVE_WINDOW")
+        .ok()?
+        .reply()
+        .ok()?
+        .atom;
+    let window = conn
+        .get_property(false, root, active_atom, AtomEnum::WINDOW, 0, 1)
+        .ok()?
+        .reply()
+        .ok()?
+        .value32()?
+        .next()
+        .filter(|&w| w != x11rb::NONE)?;
+    Some((conn, root, window))
+}
+
+/// Name of the application that owns the focused window.
+///
+/// On X11 this is the class in the active window's `WM_CLASS` (e.g.
+/// "firefox", "Slack"). Wayland clients cannot see other windows, so there
+/// the compositor is asked: `hyprctl` on Hyprland, `swaymsg` on Sway. `None`
+/// on other Wayland compositors.
+pub fn frontmost_app_name() -> Option<String> {
+    if !crate::shortcuts::is_wayland() {
+        return x11_active_class();
+    }
+    if crate::shortcuts::hyprland::is_hyprland() {
+        let output = command_stdout("hyprctl", &["activewindow", "-j"])?;
+        let window: serde_json::Value = serde_json::from_str(&output).ok()?;
+        return non_empty(window["class"].as_str());
+    }
+    if std::env::var_os("SWAYSOCK").is_some() {
+        let output = command_stdout("swaymsg", &["-t", "get_tree", "-r"])?;
+        let tree: serde_json::Value = serde_json::from_str(&output).ok()?;
+        return sway_focused_app(&tree);
+    }
+    None
+}
+
+/// The class part of the active X11 window's `WM_CLASS`
+fn x11_active_class() -> Option<String> {
+    use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+
+    let (conn, _, window) = x11_active_window()?;
+    let reply = conn
+        .get_property(false, window, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, 256)
+        .ok()?
+        .reply()
+        .ok()?;
+    parse_wm_class(&reply.value)
+}
+
+/// Parse `WM_CLASS`, two NUL-terminated strings (instance, then class),
+/// preferring the class
+fn parse_wm_class(value: &[u8]) -> Option<String> {
+    let mut parts = value
+        .split(|&b| b == 0)
+        .map(|part| String::from_utf8_lossy(part).trim().to_string());
+    let instance = parts.next();
+    non_empty(parts.next().as_deref()).or_else(|| non_empty(instance.as_deref()))
+}
+
+/// The app of the focused window in a `swaymsg -t get_tree` tree: its
+/// Wayland `app_id`, or the X11 class for Xwayland windows
+fn sway_focused_app(node: &serde_json::Value) -> Option<String> {
+    if node["focused"].as_bool() == Some(true) {
+        return non_empty(node["app_id"].as_str())
+            .or_else(|| non_empty(node["window_properties"]["class"].as_str()));
+    }
+    ["nodes", "floating_nodes"]
+        .iter()
+        .filter_map(|key| node[*key].as_array())
+        .flatten()
+        .find_map(sway_focused_app)
+}
+
+/// Stdout of a successful `program args` run
+fn command_stdout(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+}
+
 /// Check if accessibility permission is granted
 ///
 /// On Linux, accessibility permissions are not typically required
@@ -448,6 +578,33 @@ fn check_pipewire_microphone() -> MicrophoneStatus {
     }
 }
 
+/// Whether the default PulseAudio/PipeWire capture source is muted.
+///
+/// cpal's ALSA device names do not map onto PulseAudio sources, so the
+/// default source is checked: it is what the `default` and `pulse` ALSA
+/// devices record from. `None` when `pactl` is unavailable or its output is
+/// not understood.
+pub fn default_source_is_muted() -> Option<bool> {
+    let output = Command::new("pactl")
+        .args(["get-source-mute", "@DEFAULT_SOURCE@"])
+        .env("LC_ALL", "C")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_source_mute(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `pactl get-source-mute` output (`Mute: yes` / `Mute: no`).
+fn parse_source_mute(output: &str) -> Option<bool> {
+    match output.trim().strip_prefix("Mute:")?.trim() {
+        "yes" => Some(true),
+        "no" => Some(false),
+        _ => None,
+    }
+}
+
 /// Request microphone permission
 ///
 /// On Linux, microphone access is typically granted automatically by
@@ -549,6 +706,41 @@ mod tests {
         assert!(check_accessibility_permission());
     }
 
+    #[test]
+    fn test_parse_source_mute() {
+        assert_eq!(parse_source_mute("Mute: yes\n"), Some(true));
+        assert_eq!(parse_source_mute("Mute: no\n"), Some(false));
+        assert_eq!(parse_source_mute("Failure: No such entity\n"), None);
+    }
+
+    #[test]
+    fn test_parse_wm_class() {
+        assert_eq!(
+            parse_wm_class(b"Navigator\0firefox\0"),
+            Some("firefox".to_string())
+        );
+        assert_eq!(parse_wm_class(b"slack\0"), Some("slack".to_string()));
+        assert_eq!(parse_wm_class(b""), None);
+    }
+
+    #[test]
+    fn test_sway_focused_app() {
+        let tree = serde_json::json!({
+            "focused": false,
+            "nodes": [{
+                "focused": false,
+                "nodes": [
+                    { "focused": false, "app_id": "foot", "nodes": [] },
+                    { "focused": true, "app_id": null, "nodes": [],
+                      "window_properties": { "class": "Slack" } }
+                ]
+            }],
+            "floating_nodes": []
+        });
+        assert_eq!(sway_focused_app(&tree), Some("Slack".to_string()));
+        assert_eq!(sway_focused_app(&serde_json::json!({ "nodes": [] })), None);
+    }
+
     #[test]
     fn test_check_microphone_permission() {
         // Just ensure it doesn't panic
@@ -1,6 +1,7 @@
 //! macOS-specific platform functionality
 
-use objc2::runtime::AnyClass;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyClass, AnyObject};
 use objc2::{class, msg_send};
 use objc2_foundation::NSString;
 use std::process::Command;
@@ -27,6 +28,34 @@ pub fn check_input_monitoring_permission() -> bool {
     }
 }
 
+/// Check whether Metal is actually usable on this Mac
+///
+/// Creates (and immediately releases) the system default Metal device. This
+/// fails on machines whose GPU lacks Metal support, even though the app is
+/// always built with Metal.
+pub fn is_metal_usable() -> bool {
+    #[link(name = "Metal", kind = "framework")]
+    unsafe extern "C" {
+        fn MTLCreateSystemDefaultDevice() -> *mut AnyObject;
+    }
+
+    // The device is returned retained (+1); wrapping it releases it on drop.
+    unsafe { Retained::from_raw(MTLCreateSystemDefaultDevice()) }.is_some()
+}
+
+/// Check whether this Mac has Apple Silicon (and therefore a Neural Engine)
+///
+/// Asks the kernel about the hardware rather than trusting the build target,
+/// so an Intel build running under Rosetta still reports the real machine.
+/// `hw.optional.arm64` does not exist on Intel Macs, so sysctl fails there.
+pub fn is_apple_silicon() -> bool {
+    Command::new("sysctl")
+        .args(["-n", "hw.optional.arm64"])
+        .output()
+        .map(|o| o.status.success() && String::from_utf8_lossy(&o.stdout).trim() == "1")
+        .unwrap_or(false)
+}
+
 /// Open System Preferences to the Input Monitoring privacy pane
 pub fn open_input_monitoring_settings() {
     let result = Command::new("open")
@@ -128,6 +157,23 @@ pub fn check_microphone_permission() -> MicrophoneStatus {
     }
 }
 
+/// Name of the frontmost application via `NSWorkspace.frontmostApplication`.
+pub fn frontmost_app_name() -> Option<String> {
+    unsafe {
+        let workspace_cls: &AnyClass = class!(NSWorkspace);
+        let workspace: *mut AnyObject = msg_send![workspace_cls, sharedWorkspace];
+        if workspace.is_null() {
+            return None;
+        }
+        let app: *mut AnyObject = msg_send![workspace, frontmostApplication];
+        if app.is_null() {
+            return None;
+        }
+        let name: *mut NSString = msg_send![app, localizedName];
+        name.as_ref().map(|n| n.to_string())
+    }
+}
+
 /// Request microphone permission
 ///
 /// Triggers the system permission dialog for microphone access.
@@ -520,6 +566,186 @@ pub fn get_caret_position() -> Option<CaretPosition> {
     }
 }
 
+/// Centre of the focused application's focused window, via Accessibility.
+///
+/// Reads `AXPosition` and `AXSize` of the `AXFocusedWindow`, which are in
+/// global points from the top-left of the primary display. Returns `None`
+/// without accessibility permission or when no window has focus.
+pub fn focused_window_centre() -> Option<(f64, f64)> {
+    use core_foundation::base::TCFType;
+    use core_foundation::string::CFString;
+    use core_graphics::geometry::{CGPoint, CGSize};
+
+    unsafe {
+        #[link(name = "ApplicationServices", kind = "framework")]
+        unsafe extern "C" {
+            fn AXUIElementCreateSystemWide() -> *mut std::ffi::c_void;
+            fn AXUIElementCopyAttributeValue(
+                element: *mut std::ffi::c_void,
+                attribute: *const std::ffi::c_void,
+                value: *mut *mut std::ffi::c_void,
+            ) -> i32;
+            fn AXValueGetValue(
+                value: *mut std::ffi::c_void,
+                value_type: i32,
+                value_ptr: *mut std::ffi::c_void,
+            ) -> bool;
+            fn CFRelease(cf: *const std::ffi::c_void);
+        }
+
+        const AX_VALUE_TYPE_CGPOINT: i32 = 1;
+        const AX_VALUE_TYPE_CGSIZE: i32 = 2;
+
+        // Copy an attribute of `element`, or null on failure
+        let copy_attribute = |element: *mut std::ffi::c_void, name: &str| {
+            let attr = CFString::new(name);
+            let mut value: *mut std::ffi::c_void = std::ptr::null_mut();
+            let result = AXUIElementCopyAttributeValue(
+                element,
+                attr.as_concrete_TypeRef() as *const _,
+                &mut value,
+            );
+            if result != 0 && !value.is_null() {
+                CFRelease(value as *const _);
+                return std::ptr::null_mut();
+            }
+            value
+        };
+
+        let system_wide = AXUIElementCreateSystemWide();
+        if system_wide.is_null() {
+            return None;
+        }
+        let focused_app = copy_attribute(system_wide, "AXFocusedApplication");
+        CFRelease(system_wide as *const _);
+        if focused_app.is_null() {
+            tracing::debug!("No focused application");
+            return None;
+        }
+
+        let window = copy_attribute(focused_app, "AXFocusedWindow");
+        CFRelease(focused_app as *const _);
+        if window.is_null() {
+            tracing::debug!("Focused application has no focused window");
+            return None;
+        }
+
+        let position_value = copy_attribute(window, "AXPosition");
+        let size_value = copy_attribute(window, "AXSize");
+        CFRelease(window as *const _);
+
+        let mut point = CGPoint::default();
+        let mut size = CGSize::default();
+        let have_point = !position_value.is_null()
+            && AXValueGetValue(
+                position_value,
+                AX_VALUE_TYPE_CGPOINT,
+                &mut point as *mut CGPoint as *mut std::ffi::c_void,
+            );
+        let have_size = !size_value.is_null()
+            && AXValueGetValue(
+                size_value,
+                AX_VALUE_TYPE_CGSIZE,
+                &mut size as *mut CGSize as *mut std::ffi::c_void,
+            );
+
+        if !position_value.is_null() {
+            CFRelease(position_value as *const _);
+        }
+        if !size_value.is_null() {
+            CFRelease(size_value as *const _);
+        }
+
+        if !(have_point && have_size) {
+            tracing::debug!("Could not read focused window bounds");
+            return None;
+        }
+        Some((point.x + size.width / 2.0, point.y + size.height / 2.0))
+    }
+}
+
+/// Accessibility roles of elements that take typed text
+const EDITABLE_ROLES: &[&str] = &["AXTextField", "AXTextArea", "AXComboBox", "AXSearchField"];
+
+/// Whether the focused element takes typed text, via Accessibility.
+///
+/// An element counts when its `AXRole` is a text role or it reports a text
+/// selection (`AXSelectedTextRange`), which covers editable web content.
+/// `Some(false)` when nothing or something non-editable has focus; `None`
+/// without accessibility permission, when there is no telling.
+pub fn focused_text_field() -> Option<bool> {
+    use core_foundation::base::TCFType;
+    use core_foundation::string::{CFString, CFStringRef};
+
+    if !check_accessibility_permission() {
+        return None;
+    }
+
+    unsafe {
+        #[link(name = "ApplicationServices", kind = "framework")]
+        unsafe extern "C" {
+            fn AXUIElementCreateSystemWide() -> *mut std::ffi::c_void;
+            fn AXUIElementCopyAttributeValue(
+                element: *mut std::ffi::c_void,
+                attribute: *const std::ffi::c_void,
+                value: *mut *mut std::ffi::c_void,
+            ) -> i32;
+            fn CFRelease(cf: *const std::ffi::c_void);
+        }
+
+        // Copy an attribute of `element`, or null on failure
+        let copy_attribute = |element: *mut std::ffi::c_void, name: &str| {
+            let attr = CFString::new(name);
+            let mut value: *mut std::ffi::c_void = std::ptr::null_mut();
+            let result = AXUIElementCopyAttributeValue(
+                element,
+                attr.as_concrete_TypeRef() as *const _,
+                &mut value,
+            );
+            if result != 0 && !value.is_null() {
+                CFRelease(value as *const _);
+                return std::ptr::null_mut();
+            }
+            value
+        };
+
+        let system_wide = AXUIElementCreateSystemWide();
+        if system_wide.is_null() {
+            return None;
+        }
+        let focused_app = copy_attribute(system_wide, "AXFocusedApplication");
+        CFRelease(system_wide as *const _);
+        if focused_app.is_null() {
+            tracing::debug!("No focused application");
+            return Some(false);
+        }
+
+        let element = copy_attribute(focused_app, "AXFocusedUIElement");
+        CFRelease(focused_app as *const _);
+        if element.is_null() {
+            tracing::debug!("No focused UI element");
+            return Some(false);
+        }
+
+        let role = copy_attribute(element, "AXRole");
+        let role = (!role.is_null())
+            .then(|| CFString::wrap_under_create_rule(role as CFStringRef).to_string());
+        let selection = copy_attribute(element, "AXSelectedTextRange");
+        let has_selection = !selection.is_null();
+        if has_selection {
+            CFRelease(selection as *const _);
+        }
+        CFRelease(element as *const _);
+
+        let editable =
+            has_selection || role.as_deref().is_some_and(|r| EDITABLE_ROLES.contains(&r));
+        if !editable {
+            tracing::debug!("Focused element is not editable (role {:?})", role);
+        }
+        Some(editable)
+    }
+}
+
 /// Check if the screen is locked or the screensaver is active.
 ///
 /// Uses `CGSessionCopyCurrentDictionary()` from ApplicationServices to query
@@ -893,6 +1119,48 @@ fn read_device_name(dev_id: u32) -> Option<String> {
 /// differs (e.g. the default is AirPods but recording was redirected to the
 /// built-in mic). Returns `false` on any FFI failure so the warm path is kept.
 pub fn device_name_is_bluetooth(target_name: &str) -> bool {
+    let Some(device_ids) = audio_device_ids() else {
+        return false;
+    };
+
+    for &dev_id in &device_ids {
+        let Some(transport) = device_transport_type(dev_id) else {
+            continue;
+        };
+        if transport != transport::BLUETOOTH && transport != transport::BLUETOOTH_LE {
+            continue;
+        }
+        if read_device_name(dev_id).as_deref() == Some(target_name) {
+            tracing::debug!(
+                "CoreAudio: recording device '{}' has Bluetooth transport",
+                target_name
+            );
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether the CoreAudio input device named `target_name` is muted
+/// (`kAudioDevicePropertyMute` on its input scope).
+///
+/// `None` when no device has that name or it exposes no mute control.
+pub fn input_device_is_muted(target_name: &str) -> Option<bool> {
+    use objc2_core_audio::{kAudioDevicePropertyMute, kAudioObjectPropertyScopeInput};
+
+    let dev_id = audio_device_ids()?
+        .into_iter()
+        .find(|&id| read_device_name(id).as_deref() == Some(target_name))?;
+    let muted = read_audio_property_u32(
+        dev_id,
+        kAudioDevicePropertyMute,
+        kAudioObjectPropertyScopeInput,
+    )?;
+    Some(muted != 0)
+}
+
+/// IDs of every CoreAudio device object. `None` on any FFI failure.
+fn audio_device_ids() -> Option<Vec<u32>> {
     use core::ffi::c_void;
     use core::ptr::NonNull;
     use objc2_core_audio::{
@@ -920,11 +1188,11 @@ pub fn device_name_is_bluetooth(target_name: &str) -> bool {
         )
     };
     if status != kAudioHardwareNoError {
-        return false;
+        return None;
     }
     let device_count = devices_size as usize / std::mem::size_of::<u32>();
     if device_count == 0 {
-        return false;
+        return None;
     }
     let mut device_ids: Vec<u32> = vec![0u32; device_count];
     // SAFETY: device_ids matches the confirmed byte size.
@@ -939,25 +1207,9 @@ pub fn device_name_is_bluetooth(target_name: &str) -> bool {
         )
     };
     if status != kAudioHardwareNoError {
-        return false;
-    }
-
-    for &dev_id in &device_ids {
-        let Some(transport) = device_transport_type(dev_id) else {
-            continue;
-        };
-        if transport != transport::BLUETOOTH && transport != transport::BLUETOOTH_LE {
-            continue;
-        }
-        if read_device_name(dev_id).as_deref() == Some(target_name) {
-            tracing::debug!(
-                "CoreAudio: recording device '{}' has Bluetooth transport",
-                target_name
-            );
-            return true;
-        }
+        return None;
     }
-    false
+    Some(device_ids)
 }
 
 #[cfg(test)]
@@ -11,6 +11,7 @@ pub mod linux;
 pub use linux::{GpuBackend, GpuDetectionResult, GpuInfo};
 
 use crate::error::Error;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// GPU backend type (re-exported for all platforms)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -81,6 +82,100 @@ impl GpuBackendType {
     }
 }
 
+/// Transcription backend warmup prefers, given the acceleration available
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum AccelerationBackend {
+    /// FluidAudio on the Apple Neural Engine
+    #[serde(rename = "fluidaudio")]
+    FluidAudio,
+    /// whisper.cpp on the Metal GPU
+    #[serde(rename = "whisper_metal")]
+    WhisperMetal,
+    /// whisper.cpp on the CPU
+    #[serde(rename = "whisper_cpu")]
+    WhisperCpu,
+}
+
+impl AccelerationBackend {
+    /// Pick the fastest backend the hardware and build can actually run.
+    fn preferred(
+        metal_available: bool,
+        neural_engine_available: bool,
+        fluidaudio_compiled: bool,
+    ) -> Self {
+        if neural_engine_available && fluidaudio_compiled {
+            Self::FluidAudio
+        } else if metal_available {
+            Self::WhisperMetal
+        } else {
+            Self::WhisperCpu
+        }
+    }
+}
+
+/// macOS hardware acceleration usable on this machine
+///
+/// Reports what the hardware supports, not just what is compiled in: Intel
+/// Macs have no Neural Engine, and some older GPUs cannot create a Metal
+/// device. On other platforms everything is reported unavailable.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct MacosAccelerationInfo {
+    /// A Metal device can be created (whisper.cpp GPU path)
+    pub metal_available: bool,
+    /// Apple Silicon with a Neural Engine
+    pub neural_engine_available: bool,
+    /// The FluidAudio (Neural Engine) backend is compiled into this build
+    pub fluidaudio_compiled: bool,
+    /// The backend transcription warmup prefers
+    pub preferred_backend: AccelerationBackend,
+}
+
+/// Detect macOS acceleration once; the hardware does not change at runtime.
+pub fn macos_acceleration_info() -> MacosAccelerationInfo {
+    static INFO: std::sync::OnceLock<MacosAccelerationInfo> = std::sync::OnceLock::new();
+    *INFO.get_or_init(|| {
+        #[cfg(target_os = "macos")]
+        let (metal_available, neural_engine_available) =
+            (macos::is_metal_usable(), macos::is_apple_silicon());
+        #[cfg(not(target_os = "macos"))]
+        let (metal_available, neural_engine_available) = (false, false);
+
+        let fluidaudio_compiled = cfg!(all(target_os = "macos", feature = "fluidaudio"));
+        let info = MacosAccelerationInfo {
+            metal_available,
+            neural_engine_available,
+            fluidaudio_compiled,
+            preferred_backend: AccelerationBackend::preferred(
+                metal_available,
+                neural_engine_available,
+                fluidaudio_compiled,
+            ),
+        };
+        #[cfg(target_os = "macos")]
+        tracing::info!(
+            "macOS acceleration: Metal {}, Neural Engine {}, preferred backend {:?}",
+            if metal_available {
+                "available"
+            } else {
+                "unavailable"
+            },
+            if neural_engine_available {
+                "available"
+            } else {
+                "unavailable"
+            },
+            info.preferred_backend
+        );
+        info
+    })
+}
+
+/// Get the Metal/Neural Engine acceleration usable on this machine
+#[tauri::command]
+pub fn get_macos_acceleration_info() -> MacosAccelerationInfo {
+    macos_acceleration_info()
+}
+
 /// GPU information for the current system
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SystemGpuInfo {
@@ -134,7 +229,7 @@ pub fn get_gpu_info() -> Result<SystemGpuInfo, Error> {
     {
         Ok(SystemGpuInfo {
             compiled_backend: "Metal".to_string(),
-            gpu_available: true,
+            gpu_available: macos_acceleration_info().metal_available,
             gpu_name: get_macos_gpu_name(),
             vram_mb: None,
             detected_gpus: vec![DetectedGpu {
@@ -233,6 +328,39 @@ pub fn device_name_is_bluetooth(name: &str) -> bool {
     }
 }
 
+/// Whether the input device named `name` is muted at the OS level.
+///
+/// macOS reads the device's CoreAudio mute control. `None` when the state
+/// cannot be determined, including on other platforms; Linux only exposes
+/// [`default_input_is_muted`].
+pub fn input_device_is_muted(name: &str) -> Option<bool> {
+    #[cfg(target_os = "macos")]
+    {
+        macos::input_device_is_muted(name)
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = name;
+        None
+    }
+}
+
+/// Whether the default input is muted at the OS level.
+///
+/// Linux checks the default PulseAudio/PipeWire source, which is what cpal's
+/// default devices record from. `None` when the state cannot be determined,
+/// including on other platforms.
+pub fn default_input_is_muted() -> Option<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        linux::default_source_is_muted()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
 /// Check if the screen is locked or the screensaver is active.
 ///
 /// Used to suppress global shortcuts when the user is on the lock screen,
@@ -248,6 +376,49 @@ pub fn is_screen_locked() -> bool {
     }
 }
 
+/// Name of the frontmost application (e.g. "Mail"), for prompt context,
+/// app-scoped dictionary entries and smart spacing.
+///
+/// On Linux this is the window class on X11, Hyprland and Sway. Returns
+/// `None` where the platform offers no way to ask (other Wayland
+/// compositors hide the focused window from clients).
+pub fn frontmost_app_name() -> Option<String> {
+    #[cfg(target_os = "macos")]
+    {
+        macos::frontmost_app_name()
+    }
+    #[cfg(target_os = "linux")]
+    {
+        linux::frontmost_app_name()
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        None
+    }
+}
+
+/// Centre of the focused window from the top-left of the primary display,
+/// for placing the follow-focus indicator: logical points on macOS, physical
+/// pixels on X11.
+///
+/// Returns `None` where the focused window cannot be queried: Wayland (the
+/// compositor does not expose it), macOS without accessibility permission,
+/// and other platforms.
+pub fn focused_window_centre() -> Option<(f64, f64)> {
+    #[cfg(target_os = "macos")]
+    {
+        macos::focused_window_centre()
+    }
+    #[cfg(target_os = "linux")]
+    {
+        linux::focused_window_centre()
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        None
+    }
+}
+
 /// Check if accessibility permissions are available
 #[tauri::command]
 pub fn check_accessibility() -> bool {
@@ -340,6 +511,59 @@ pub fn verify_accessibility_functional() -> bool {
     }
 }
 
+/// Set while accessibility reports granted but does not work (a stale TCC
+/// entry, typically after a reinstall), as last checked.
+static ACCESSIBILITY_STALE: AtomicBool = AtomicBool::new(false);
+
+/// State of the permissions recording and shortcuts depend on
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionHealth {
+    /// Microphone status, as from [`check_microphone_permission`]
+    pub microphone: String,
+    /// Whether accessibility is reported as granted
+    pub accessibility_granted: bool,
+    /// Whether accessibility actually works when used
+    pub accessibility_functional: bool,
+    /// Granted but not working; shortcuts and pasting silently fail
+    pub accessibility_stale: bool,
+}
+
+/// Check accessibility end to end and record whether the grant is stale.
+///
+/// Returns `(granted, functional)`.
+pub fn refresh_accessibility_health() -> (bool, bool) {
+    let granted = check_accessibility();
+    let functional = granted && verify_accessibility_functional();
+    let stale = granted && !functional;
+    if stale && !ACCESSIBILITY_STALE.load(Ordering::SeqCst) {
+        tracing::warn!(
+            "Accessibility permission appears granted but is stale — \
+             TCC entry may need resetting"
+        );
+    }
+    ACCESSIBILITY_STALE.store(stale, Ordering::SeqCst);
+    (granted, functional)
+}
+
+/// Whether accessibility was granted but not working when last checked.
+pub fn is_accessibility_stale() -> bool {
+    ACCESSIBILITY_STALE.load(Ordering::SeqCst)
+}
+
+/// Check every permission, including whether a granted accessibility entry
+/// still works.
+#[tauri::command]
+pub fn get_permission_health() -> PermissionHealth {
+    let (accessibility_granted, accessibility_functional) = refresh_accessibility_health();
+    PermissionHealth {
+        microphone: check_microphone_permission(),
+        accessibility_granted,
+        accessibility_functional,
+        accessibility_stale: accessibility_granted && !accessibility_functional,
+    }
+}
+
 /// Reset the permissions an app update is likely to have invalidated.
 ///
 /// macOS keys TCC grants to the code-signing identity, which changes on each
@@ -400,6 +624,73 @@ pub fn check_microphone_permission() -> String {
     }
 }
 
+/// Event emitted when the microphone permission changes while the app runs
+/// (payload: the new status, as from [`check_microphone_permission`])
+pub const MICROPHONE_PERMISSION_CHANGED_EVENT: &str = "microphone-permission-changed";
+
+/// How often the microphone permission is re-checked until it is granted
+#[cfg(target_os = "macos")]
+const MICROPHONE_PERMISSION_POLL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// How long after launch the microphone permission is watched. A user who
+/// has not granted it by then is not in the middle of doing so; the tray and
+/// the overview pane still check it afresh whenever they are rebuilt.
+#[cfg(target_os = "macos")]
+const MICROPHONE_PERMISSION_WATCH: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+/// Watch for the microphone being granted in System Settings.
+///
+/// macOS sends no notification when the user flips the switch there, so the
+/// status is polled until it reads granted, for at most
+/// [`MICROPHONE_PERMISSION_WATCH`]. Each change is emitted as
+/// [`MICROPHONE_PERMISSION_CHANGED_EVENT`], and the tray is rebuilt once
+/// recording becomes possible. Linux has no per-app microphone permission,
+/// so there is nothing to watch.
+pub fn watch_microphone_permission(app: tauri::AppHandle) {
+    #[cfg(target_os = "macos")]
+    {
+        use tauri::Emitter;
+
+        let mut last = check_microphone_permission();
+        if last == "granted" {
+            return;
+        }
+        let watcher = std::thread::Builder::new()
+            .name("mic-permission".to_string())
+            .spawn(move || {
+                let deadline = std::time::Instant::now() + MICROPHONE_PERMISSION_WATCH;
+                while std::time::Instant::now() < deadline {
+                    std::thread::sleep(MICROPHONE_PERMISSION_POLL);
+                    let status = check_microphone_permission();
+                    if status == last {
+                        continue;
+                    }
+                    tracing::info!("Microphone permission changed: {} -> {}", last, status);
+                    if let Err(e) = app.emit(MICROPHONE_PERMISSION_CHANGED_EVENT, &status) {
+                        tracing::warn!(
+                            "Failed to emit {}: {}",
+                            MICROPHONE_PERMISSION_CHANGED_EVENT,
+                            e
+                        );
+                    }
+                    if status == "granted" {
+                        crate::tray::refresh_tray_menu(app);
+                        return;
+                    }
+                    last = status;
+                }
+                tracing::info!("Microphone permission still {}, no longer watching", last);
+            });
+        if let Err(e) = watcher {
+            tracing::warn!("Failed to start microphone permission watcher: {}", e);
+        }
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = app;
+    }
+}
+
 /// Caret (text cursor) position on screen
 #[derive(Debug, Clone, Copy, serde::Serialize)]
 pub struct CaretPosition {
@@ -441,6 +732,21 @@ pub fn get_caret_position() -> Option<CaretPosition> {
     }
 }
 
+/// Whether the focused element takes typed text
+///
+/// `None` where it cannot be told: without accessibility permission, and on
+/// Linux, which has no equivalent of the query yet.
+pub fn focused_text_field() -> Option<bool> {
+    #[cfg(target_os = "macos")]
+    {
+        macos::focused_text_field()
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        None
+    }
+}
+
 /// Request microphone permission
 ///
 /// Triggers the system permission dialog. If permission was already denied,
@@ -482,9 +788,152 @@ pub fn request_microphone_permission(app: tauri::AppHandle) {
     }
 }
 
+/// Outcome for one permission in [`request_all_permissions`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionRequestStatus {
+    /// Granted; not asked for again
+    Granted,
+    /// Asked for now; waiting on the user to respond
+    Requested,
+    /// Not asked yet because an earlier request is still waiting
+    Deferred,
+}
+
+/// Result of [`request_all_permissions`]
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionRequestResults {
+    /// Microphone access
+    pub microphone: PermissionRequestStatus,
+    /// Accessibility (pasting at the cursor, global shortcuts)
+    pub accessibility: PermissionRequestStatus,
+    /// Input Monitoring (modifier-key shortcuts)
+    pub input_monitoring: PermissionRequestStatus,
+}
+
+/// One step of the permission bootstrap. A granted permission is left
+/// alone; otherwise it is requested unless an earlier request is `waiting`
+/// on the user, so only one prompt or settings pane is up at a time.
+fn request_step(
+    waiting: &mut bool,
+    is_granted: impl Fn() -> bool,
+    request: impl FnOnce(),
+) -> PermissionRequestStatus {
+    if is_granted() {
+        return PermissionRequestStatus::Granted;
+    }
+    if *waiting {
+        return PermissionRequestStatus::Deferred;
+    }
+    request();
+    if is_granted() {
+        PermissionRequestStatus::Granted
+    } else {
+        *waiting = true;
+        PermissionRequestStatus::Requested
+    }
+}
+
+/// Check and request every permission Thoth needs, for onboarding.
+///
+/// Goes microphone, accessibility, then input monitoring. Granted ones are
+/// never prompted for again, and only the first missing one is requested;
+/// call again once the user has responded to move on to the next.
+#[tauri::command]
+pub fn request_all_permissions(app: tauri::AppHandle) -> PermissionRequestResults {
+    let mut waiting = false;
+    let microphone = request_step(
+        &mut waiting,
+        || check_microphone_permission() == "granted",
+        || request_microphone_permission(app),
+    );
+    let accessibility = request_step(&mut waiting, check_accessibility, || {
+        request_accessibility();
+    });
+    let input_monitoring = request_step(
+        &mut waiting,
+        check_input_monitoring_permission,
+        open_input_monitoring_settings,
+    );
+
+    let results = PermissionRequestResults {
+        microphone,
+        accessibility,
+        input_monitoring,
+    };
+    tracing::info!("Permission bootstrap: {:?}", results);
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_request_step_skips_granted_and_defers_after_request() {
+        let mut waiting = false;
+        let requests = Cell::new(0);
+
+        let granted = request_step(&mut waiting, || true, || requests.set(requests.get() + 1));
+        assert_eq!(granted, PermissionRequestStatus::Granted);
+        assert_eq!(requests.get(), 0, "granted permissions are not re-prompted");
+
+        let requested = request_step(&mut waiting, || false, || requests.set(requests.get() + 1));
+        assert_eq!(requested, PermissionRequestStatus::Requested);
+        assert!(waiting);
+
+        let deferred = request_step(&mut waiting, || false, || requests.set(requests.get() + 1));
+        assert_eq!(deferred, PermissionRequestStatus::Deferred);
+        assert_eq!(requests.get(), 1, "only one request is made at a time");
+    }
+
+    #[test]
+    fn test_request_step_granted_by_request() {
+        let mut waiting = false;
+        let granted = Cell::new(false);
+        let status = request_step(&mut waiting, || granted.get(), || granted.set(true));
+        assert_eq!(status, PermissionRequestStatus::Granted);
+        assert!(!waiting);
+    }
+
+    #[test]
+    fn test_preferred_acceleration_backend() {
+        assert_eq!(
+            AccelerationBackend::preferred(true, true, true),
+            AccelerationBackend::FluidAudio
+        );
+        // Apple Silicon without the FluidAudio feature still has Metal.
+        assert_eq!(
+            AccelerationBackend::preferred(true, true, false),
+            AccelerationBackend::WhisperMetal
+        );
+        // Intel Mac: no Neural Engine, whatever the build.
+        assert_eq!(
+            AccelerationBackend::preferred(true, false, true),
+            AccelerationBackend::WhisperMetal
+        );
+        assert_eq!(
+            AccelerationBackend::preferred(false, false, true),
+            AccelerationBackend::WhisperCpu
+        );
+    }
+
+    #[test]
+    fn test_permission_health_serialisation() {
+        let health = PermissionHealth {
+            microphone: "granted".to_string(),
+            accessibility_granted: true,
+            accessibility_functional: false,
+            accessibility_stale: true,
+        };
+        let json = serde_json::to_value(&health).unwrap();
+        assert_eq!(json["microphone"], "granted");
+        assert_eq!(json["accessibilityGranted"], true);
+        assert_eq!(json["accessibilityFunctional"], false);
+        assert_eq!(json["accessibilityStale"], true);
+    }
 
     #[test]
     fn test_gpu_backend_type_compiled_matches_features() {
@@ -0,0 +1,305 @@
+//! Queue of audio files waiting to be transcribed.
+//!
+//! Files added with [`enqueue_transcriptions`] are transcribed one at a time,
+//! oldest first, by a background worker that runs while the queue has work
+//! and stops when it empties. The queue runs alongside recordings: it does
+//! not claim the capture slot, so dictation stays available while a long
+//! queue drains. Pending items can be listed and removed; the item being
+//! transcribed runs to completion.
+//!
+//! Emits [`QUEUE_ITEM_STARTED_EVENT`] and [`QUEUE_ITEM_FINISHED_EVENT`] as each
+//! item is processed, alongside the usual `pipeline-progress` and
+//! `pipeline-complete` events.
+
+use chrono::Utc;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+use crate::error::Error;
+use crate::pipeline::{PipelineConfig, PipelineResult};
+
+/// Event emitted when a queued item starts transcribing (payload: [`QueueItem`])
+pub const QUEUE_ITEM_STARTED_EVENT: &str = "queue-item-started";
+
+/// Event emitted when a queued item finishes (payload: [`QueueItemFinished`])
+pub const QUEUE_ITEM_FINISHED_EVENT: &str = "queue-item-finished";
+
+/// Where a queued item is in its life
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueueItemState {
+    /// Waiting its turn; can still be removed
+    Pending,
+    /// Being decoded and transcribed
+    Processing,
+}
+
+/// A file in the transcription queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueItem {
+    /// Unique identifier (UUID)
+    pub id: String,
+    /// The source audio file, as supplied
+    pub file_path: String,
+    /// When the file was added (ISO 8601)
+    pub enqueued_at: String,
+    /// Whether it is waiting or being transcribed
+    pub state: QueueItemState,
+}
+
+/// Payload of [`QUEUE_ITEM_FINISHED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueItemFinished {
+    /// The item that finished
+    pub item: QueueItem,
+    /// Pipeline result when the file was transcribed
+    pub result: Option<PipelineResult>,
+    /// Error message if it failed
+    pub error: Option<String>,
+}
+
+/// A queued item with the settings it was queued under
+#[derive(Debug, Clone)]
+struct Entry {
+    item: QueueItem,
+    config: PipelineConfig,
+}
+
+/// The queue itself, kept in the order items were added
+#[derive(Debug, Default)]
+struct Queue {
+    entries: VecDeque<Entry>,
+}
+
+impl Queue {
+    /// Add a pending item for `file_path`.
+    fn push(&mut self, file_path: String, config: PipelineConfig) -> QueueItem {
+        let item = QueueItem {
+            id: Uuid::new_v4().to_string(),
+            file_path,
+            enqueued_at: Utc::now().to_rfc3339(),
+            state: QueueItemState::Pending,
+        };
+        self.entries.push_back(Entry {
+            item: item.clone(),
+            config,
+        });
+        item
+    }
+
+    /// Mark the oldest pending item as processing and return it.
+    fn start_next(&mut self) -> Option<Entry> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|e| e.item.state == QueueItemState::Pending)?;
+        entry.item.state = QueueItemState::Processing;
+        Some(entry.clone())
+    }
+
+    /// Drop a finished item.
+    fn finish(&mut self, id: &str) {
+        self.entries.retain(|e| e.item.id != id);
+    }
+
+    /// Remove a pending item. Returns false when no item has that ID, and
+    /// fails for the item being transcribed.
+    fn remove(&mut self, id: &str) -> Result<bool, String> {
+        let Some(index) = self.entries.iter().position(|e| e.item.id == id) else {
+            return Ok(false);
+        };
+        if self.entries[index].item.state == QueueItemState::Processing {
+            return Err("This file is already being transcribed".to_string());
+        }
+        self.entries.remove(index);
+        Ok(true)
+    }
+
+    /// The queued items, oldest first.
+    fn items(&self) -> Vec<QueueItem> {
+        self.entries.iter().map(|e| e.item.clone()).collect()
+    }
+}
+
+static QUEUE: Mutex<Queue> = Mutex::new(Queue {
+    entries: VecDeque::new(),
+});
+
+/// True while the worker task is draining the queue
+static WORKER_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Start the worker unless it is already running.
+fn ensure_worker(app: &AppHandle) {
+    if WORKER_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        run_worker(&app).await;
+    });
+}
+
+/// Transcribe queued items in order until none are left.
+async fn run_worker(app: &AppHandle) {
+    tracing::info!("Queue: Worker started");
+    loop {
+        // The flag is cleared under the queue lock, so an item added at the
+        // same moment is either seen here or starts a new worker.
+        let next = {
+            let mut queue = QUEUE.lock();
+            let next = queue.start_next();
+            if next.is_none() {
+                WORKER_RUNNING.store(false, Ordering::SeqCst);
+            }
+            next
+        };
+        let Some(entry) = next else {
+            break;
+        };
+
+        tracing::info!("Queue: Transcribing {}", entry.item.file_path);
+        emit(app, QUEUE_ITEM_STARTED_EVENT, &entry.item);
+
+        let result =
+            crate::pipeline::transcribe_queued_file(app, &entry.item.file_path, &entry.config)
+                .await;
+        QUEUE.lock().finish(&entry.item.id);
+
+        if let Err(e) = &result {
+            tracing::warn!(
+                "Queue: Failed to transcribe {}: {}",
+                entry.item.file_path,
+                e
+            );
+        }
+        let finished = QueueItemFinished {
+            item: entry.item,
+            error: result.as_ref().err().cloned(),
+            result: result.ok(),
+        };
+        emit(app, QUEUE_ITEM_FINISHED_EVENT, &finished);
+    }
+    tracing::info!("Queue: Worker finished, queue is empty");
+}
+
+/// Emit a queue event, logging failures
+fn emit<S: Serialize + Clone>(app: &AppHandle, event: &str, payload: &S) {
+    if let Err(e) = app.emit(event, payload) {
+        tracing::warn!("Failed to emit {}: {}", event, e);
+    }
+}
+
+// =============================================================================
+// Tauri Commands
+// =============================================================================
+
+/// Add audio files to the transcription queue, returning the new items.
+///
+/// Files are transcribed in the order they were added, after anything
+/// already queued. Like other imports, the results are not auto-copied or
+/// auto-pasted.
+#[tauri::command]
+pub fn enqueue_transcriptions(
+    app: AppHandle,
+    file_paths: Vec<String>,
+    config: Option<PipelineConfig>,
+) -> Result<Vec<QueueItem>, Error> {
+    if file_paths.is_empty() {
+        return Err("No files to transcribe".to_string().into());
+    }
+    let config = config.unwrap_or_default();
+    let items: Vec<QueueItem> = {
+        let mut queue = QUEUE.lock();
+        file_paths
+            .into_iter()
+            .map(|path| queue.push(path, config.clone()))
+            .collect()
+    };
+    tracing::info!("Queue: Added {} files", items.len());
+    ensure_worker(&app);
+    Ok(items)
+}
+
+/// List the queued items, oldest first.
+#[tauri::command]
+pub fn get_transcription_queue() -> Vec<QueueItem> {
+    QUEUE.lock().items()
+}
+
+/// Remove a pending item from the queue. Returns false when no item has
+/// that ID; fails for the item being transcribed.
+#[tauri::command]
+pub fn remove_from_queue(id: String) -> Result<bool, Error> {
+    let removed = QUEUE.lock().remove(&id)?;
+    if removed {
+        tracing::info!("Queue: Removed {}", id);
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push(queue: &mut Queue, path: &str) -> QueueItem {
+        queue.push(path.to_string(), PipelineConfig::default())
+    }
+
+    #[test]
+    fn test_queue_drains_in_order() {
+        let mut queue = Queue::default();
+        let first = push(&mut queue, "/a.m4a");
+        let second = push(&mut queue, "/b.mp3");
+
+        let started = queue.start_next().unwrap();
+        assert_eq!(started.item.id, first.id);
+        assert_eq!(started.item.state, QueueItemState::Processing);
+        // The processing item is not handed out twice.
+        assert_eq!(queue.start_next().unwrap().item.id, second.id);
+        assert!(queue.start_next().is_none());
+
+        queue.finish(&first.id);
+        queue.finish(&second.id);
+        assert!(queue.items().is_empty());
+    }
+
+    #[test]
+    fn test_remove_pending_item() {
+        let mut queue = Queue::default();
+        let first = push(&mut queue, "/a.m4a");
+        let second = push(&mut queue, "/b.mp3");
+        let third = push(&mut queue, "/c.wav");
+
+        assert_eq!(queue.remove(&second.id), Ok(true));
+        assert_eq!(queue.remove("missing"), Ok(false));
+
+        let ids: Vec<_> = queue.items().into_iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![first.id, third.id]);
+    }
+
+    #[test]
+    fn test_processing_item_cannot_be_removed() {
+        let mut queue = Queue::default();
+        let item = push(&mut queue, "/a.m4a");
+        queue.start_next();
+
+        assert!(queue.remove(&item.id).is_err());
+        assert_eq!(queue.items().len(), 1);
+    }
+
+    #[test]
+    fn test_queue_item_serialisation() {
+        let mut queue = Queue::default();
+        let item = push(&mut queue, "/a.m4a");
+        let json = serde_json::to_value(&item).unwrap();
+        assert_eq!(json["filePath"], "/a.m4a");
+        assert_eq!(json["state"], "pending");
+        assert!(json["enqueuedAt"].is_string());
+    }
+}
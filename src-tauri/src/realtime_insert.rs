@@ -0,0 +1,371 @@
+//! Insert-as-you-speak: type recognised text at the cursor while recording.
+//!
+//! With `config.output.realtime_insert` on, [`start`] runs a worker that
+//! re-transcribes the recording so far (from [`crate::audio::live`]) every
+//! [`INTERVAL`]. A word is typed once two consecutive partial results agree
+//! on it, and the newest word is always held back because the model is most
+//! likely to revise it. Only words beyond those already typed are sent, so
+//! earlier text is never inserted twice. Typed text is never retracted: if
+//! the model later revises a word already on screen, the revision is ignored.
+//!
+//! When the recording stops, [`finish`] waits for the worker and reports the
+//! words it typed; the pipeline then inserts only the rest of the final text
+//! ([`remaining_text`]). Partials are always typed as keystrokes, so the
+//! clipboard is left alone mid-dictation.
+//!
+//! Each pass re-transcribes the whole recording, so live updates stop after
+//! [`MAX_LIVE_SECONDS`]; the rest is inserted when the recording stops.
+
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::transcription;
+
+/// Sample rate of the live buffer
+const SAMPLE_RATE: usize = 16_000;
+
+/// How often the recording so far is re-transcribed
+const INTERVAL: Duration = Duration::from_millis(1500);
+
+/// Step at which the worker checks for a stop request while waiting
+const POLL: Duration = Duration::from_millis(100);
+
+/// New audio needed before another pass is worth running (0.5 s)
+const MIN_NEW_SAMPLES: usize = SAMPLE_RATE / 2;
+
+/// Recording length after which live updates stop
+const MAX_LIVE_SECONDS: usize = 120;
+
+/// The running worker; it returns the number of words it typed
+struct Session {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<Vec<String>>,
+}
+
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+
+/// Whether insert-as-you-speak is turned on.
+///
+/// Off while the inserted text is redacted: partials are typed before the
+/// redaction runs, and a number being spoken is typed a few digits at a
+/// time, before it is long enough to match a pattern.
+pub fn is_enabled() -> bool {
+    let enabled = crate::config::get_config()
+        .map(|c| c.output.realtime_insert)
+        .unwrap_or(false);
+    if enabled && crate::redaction::Redactor::from_config().is_some_and(|r| r.redacts_pasted_text())
+    {
+        tracing::debug!("Realtime insert: off while inserted text is redacted");
+        return false;
+    }
+    enabled
+}
+
+/// Start live insertion for the recording about to begin.
+///
+/// Call before the recording starts so no audio is missed.
+pub fn start() {
+    // A session left behind by a failed stop must not type into this one.
+    cancel();
+
+    crate::audio::live::begin();
+    let stop = Arc::new(AtomicBool::new(false));
+    let worker_stop = Arc::clone(&stop);
+    let handle = std::thread::spawn(move || run(&worker_stop));
+    *SESSION.lock() = Some(Session { stop, handle });
+    tracing::info!("Realtime insert: started");
+}
+
+/// Stop live insertion without waiting for the worker.
+///
+/// For callers on the main thread, which must not block on a pass in flight.
+/// The worker checks the stop flag before typing, so it types nothing more,
+/// and is joined on a thread of its own.
+pub fn cancel() {
+    let Some(session) = SESSION.lock().take() else {
+        return;
+    };
+    session.stop.store(true, Ordering::SeqCst);
+    crate::audio::live::end();
+    std::thread::spawn(move || {
+        if session.handle.join().is_err() {
+            tracing::error!("Realtime insert: worker panicked");
+        }
+    });
+    tracing::info!("Realtime insert: cancelled");
+}
+
+/// Stop live insertion and return the words typed.
+///
+/// Waits for a pass in flight, so nothing is typed after this returns; call
+/// it off the main thread. Returns no words when no session was running.
+pub fn finish() -> Vec<String> {
+    let session = SESSION.lock().take();
+    let typed = match session {
+        Some(session) => {
+            session.stop.store(true, Ordering::SeqCst);
+            session.handle.join().unwrap_or_else(|_| {
+                tracing::error!("Realtime insert: worker panicked");
+                Vec::new()
+            })
+        }
+        None => Vec::new(),
+    };
+    crate::audio::live::end();
+    if !typed.is_empty() {
+        tracing::info!(
+            "Realtime insert: finished after typing {} words",
+            typed.len()
+        );
+    }
+    typed
+}
+
+/// The part of `final_text` still to insert after the `typed` words went in
+/// live, with a leading space to separate it from them.
+///
+/// The final text has been through the dictionary, redaction and other
+/// rewrites the partials skip, so it can differ from what was typed. Words
+/// are only skipped while they agree with the typed ones; from the first
+/// difference on, the final text is inserted, as a repeated word is better
+/// than a lost one.
+pub fn remaining_text(final_text: &str, typed: &[String]) -> String {
+    let words: Vec<&str> = final_text.split_whitespace().collect();
+    let matched = words
+        .iter()
+        .zip(typed)
+        .take_while(|(word, typed)| normalise(word) == normalise(typed))
+        .count();
+    let rest = &words[matched..];
+    if rest.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", rest.join(" "))
+    }
+}
+
+/// Filters applied to partials, matching the user's transcription settings
+/// so the typed words line up with the final filtered text. None for a
+/// verbatim recording, whose final text is not filtered either.
+fn filter_options() -> Option<transcription::FilterOptions> {
+    if crate::pipeline::is_verbatim_recording() {
+        return None;
+    }
+    let config = crate::config::get_config()
+        .map(|c| c.transcription)
+        .unwrap_or_default();
+    Some(transcription::FilterOptions {
+        remove_fillers: config.remove_fillers,
+        australian_spelling: config.australian_spelling,
+        spoken_numbers_to_digits: config.spoken_numbers_to_digits,
+        normalise_whitespace: config.normalise_whitespace,
+        cleanup_punctuation: config.cleanup_punctuation,
+        sentence_case: config.sentence_case,
+        voice_formatting_commands: config.voice_formatting_commands,
+        collapse_repetitions: config.collapse_repetitions,
+        // Partials carry no segment timing
+        mark_pauses: false,
+        apply_dictionary: false,
+    })
+}
+
+/// Sleep for one interval, returning early (true) if asked to stop.
+fn wait_or_stop(stop: &AtomicBool) -> bool {
+    let mut waited = Duration::ZERO;
+    while waited < INTERVAL {
+        if stop.load(Ordering::SeqCst) {
+            return true;
+        }
+        std::thread::sleep(POLL);
+        waited += POLL;
+    }
+    stop.load(Ordering::SeqCst)
+}
+
+fn run(stop: &AtomicBool) -> Vec<String> {
+    let mut live = LiveText::default();
+    let mut transcribed = 0;
+
+    while !wait_or_stop(stop) {
+        let Some(available) = crate::audio::live::len() else {
+            break;
+        };
+        if available > MAX_LIVE_SECONDS * SAMPLE_RATE {
+            tracing::info!(
+                "Realtime insert: recording passed {}s, remaining text follows at stop",
+                MAX_LIVE_SECONDS
+            );
+            // Nothing reads the buffer any more; stop it growing with the
+            // rest of the recording.
+            if !stop.load(Ordering::SeqCst) {
+                crate::audio::live::end();
+            }
+            break;
+        }
+        if available < transcribed + MIN_NEW_SAMPLES || !transcription::is_transcription_ready() {
+            continue;
+        }
+        let Some(samples) = crate::audio::live::snapshot() else {
+            break;
+        };
+        transcribed = samples.len();
+
+        let partial = match transcription::transcribe_pcm(samples, SAMPLE_RATE as u32, 1) {
+            // Read each pass: the verbatim shortcut marks its recording
+            // only after it has started.
+            Ok(text) => match filter_options() {
+                Some(options) => transcription::filter_transcription(text, Some(options)),
+                None => text,
+            },
+            Err(e) => {
+                tracing::debug!("Realtime insert: partial transcription failed: {}", e);
+                continue;
+            }
+        };
+        // Once the user has stopped, the final pass inserts the rest.
+        if stop.load(Ordering::SeqCst) {
+            break;
+        }
+        if let Some(text) = live.update(&partial) {
+            if let Err(e) = crate::text_insert::insert_text_by_typing(text, None, None) {
+                tracing::warn!("Realtime insert: typing failed, stopping: {}", e);
+                break;
+            }
+        }
+    }
+
+    live.typed
+}
+
+/// Tracks consecutive partial results and the words already typed
+#[derive(Debug, Default)]
+struct LiveText {
+    previous: Vec<String>,
+    typed: Vec<String>,
+}
+
+impl LiveText {
+    /// Take a new partial result and return the text to type, if any.
+    fn update(&mut self, partial: &str) -> Option<String> {
+        let words: Vec<String> = partial.split_whitespace().map(str::to_string).collect();
+        let agreed = self
+            .previous
+            .iter()
+            .zip(&words)
+            .take_while(|(a, b)| normalise(a) == normalise(b))
+            .count();
+        let stable = agreed.min(words.len().saturating_sub(1));
+        self.previous = words;
+
+        let typed_words = self.typed.len();
+        if stable <= typed_words {
+            return None;
+        }
+        let new_words = &self.previous[typed_words..stable];
+        let text = if typed_words == 0 {
+            new_words.join(" ")
+        } else {
+            format!(" {}", new_words.join(" "))
+        };
+        self.typed.extend_from_slice(new_words);
+        Some(text)
+    }
+}
+
+/// Compare words by letters and digits only, so "world" and "world," agree.
+fn normalise(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_partial_types_nothing() {
+        let mut live = LiveText::default();
+        assert_eq!(live.update("hello there world"), None);
+    }
+
+    #[test]
+    fn test_agreed_words_are_typed_once() {
+        let mut live = LiveText::default();
+        live.update("hello there");
+        assert_eq!(
+            live.update("hello there world").as_deref(),
+            Some("hello there")
+        );
+        assert_eq!(
+            live.update("hello there world again").as_deref(),
+            Some(" world")
+        );
+        assert_eq!(live.update("hello there world again"), None);
+        assert_eq!(live.typed, ["hello", "there", "world"]);
+    }
+
+    #[test]
+    fn test_newest_word_is_held_back() {
+        let mut live = LiveText::default();
+        live.update("one two");
+        assert_eq!(live.update("one two").as_deref(), Some("one"));
+    }
+
+    #[test]
+    fn test_punctuation_changes_still_agree() {
+        let mut live = LiveText::default();
+        live.update("Hello world.");
+        assert_eq!(
+            live.update("Hello world, how").as_deref(),
+            Some("Hello world,")
+        );
+    }
+
+    #[test]
+    fn test_revised_typed_words_are_not_retyped() {
+        let mut live = LiveText::default();
+        live.update("the cat sat");
+        assert_eq!(
+            live.update("the cat sat down").as_deref(),
+            Some("the cat sat")
+        );
+        // The model revises "cat"; nothing typed is repeated.
+        assert_eq!(live.update("the hat sat down now"), None);
+        assert_eq!(
+            live.update("the hat sat down now please").as_deref(),
+            Some(" down now")
+        );
+    }
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_remaining_text() {
+        let typed = words(&["hello", "there"]);
+        assert_eq!(remaining_text("Hello there world.", &typed), " world.");
+        assert_eq!(remaining_text("Hello there", &typed), "");
+    }
+
+    #[test]
+    fn test_remaining_text_stops_skipping_where_the_final_text_differs() {
+        // The dictionary turned two typed words into one
+        let typed = words(&["send", "it", "to", "jay", "son"]);
+        assert_eq!(
+            remaining_text("Send it to Jason today.", &typed),
+            " Jason today."
+        );
+        // Redaction replaced a typed word
+        let typed = words(&["my", "pin", "is", "1234"]);
+        assert_eq!(
+            remaining_text("My pin is [REDACTED] thanks.", &typed),
+            " [REDACTED] thanks."
+        );
+    }
+}
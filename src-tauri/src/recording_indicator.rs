@@ -8,7 +8,14 @@
 //! when showing it for the first time.
 //!
 //! Can be disabled via config (general.show_recording_indicator) for users
-//! who prefer no visual indicator (e.g., tiling window manager users).
+//! who prefer no visual indicator (e.g., tiling window manager users). The
+//! window is declared with `create: false` in tauri.conf.json, so when the
+//! indicator is disabled no window is built and the cursor-tracking thread
+//! never starts. Enabling it later creates the window on first show.
+//!
+//! Show requests are debounced by [`SHOW_DEBOUNCE`]: the window only appears
+//! if no hide arrives in that time, so a recording that starts and stops
+//! straight away never flashes the indicator on screen.
 //!
 //! Note: On Wayland, mouse tracking and precise window positioning don't work
 //! reliably due to Wayland's security model. Users may want to disable the
@@ -18,6 +25,8 @@ use crate::config;
 use crate::config::IndicatorStyle;
 use crate::error::Error;
 use crate::mouse_tracker;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
 use tauri::{AppHandle, Emitter, LogicalPosition, LogicalSize, Manager, Runtime, WebviewWindow};
 
 /// Label for the recording indicator window (must match tauri.conf.json)
@@ -37,6 +46,51 @@ const BOTTOM_PADDING: f64 = 120.0;
 /// Padding from screen edge for pill style
 const PILL_EDGE_PADDING: f64 = 12.0;
 
+/// How long a recording must last before the indicator actually shows
+const SHOW_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Tracks the latest show/hide request so a pending show can tell whether
+/// it has been superseded
+struct ShowDebounce {
+    /// Bumped by every show and hide request
+    generation: AtomicU64,
+    /// Whether the latest request was a show
+    show_requested: AtomicBool,
+}
+
+impl ShowDebounce {
+    const fn new() -> Self {
+        Self {
+            generation: AtomicU64::new(0),
+            show_requested: AtomicBool::new(false),
+        }
+    }
+
+    /// Record a show request and return its ticket
+    fn request_show(&self) -> u64 {
+        self.show_requested.store(true, Ordering::SeqCst);
+        self.generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Record a hide request, cancelling any pending show
+    fn request_hide(&self) {
+        self.show_requested.store(false, Ordering::SeqCst);
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Whether the show with this ticket is still the latest request
+    fn is_current(&self, ticket: u64) -> bool {
+        self.show_requested.load(Ordering::SeqCst)
+            && self.generation.load(Ordering::SeqCst) == ticket
+    }
+
+    fn show_requested(&self) -> bool {
+        self.show_requested.load(Ordering::SeqCst)
+    }
+}
+
+static DEBOUNCE: ShowDebounce = ShowDebounce::new();
+
 /// Get the recording indicator window
 fn get_indicator_window(app: &AppHandle) -> Option<WebviewWindow> {
     app.get_webview_window(INDICATOR_WINDOW_LABEL)
@@ -47,6 +101,36 @@ fn get_indicator_window_generic<R: Runtime>(app: &AppHandle<R>) -> Option<tauri:
     app.get_webview_window(INDICATOR_WINDOW_LABEL)
 }
 
+/// Whether the recording indicator is enabled in config
+pub fn is_enabled() -> bool {
+    config::get_config()
+        .map(|c| c.general.show_recording_indicator)
+        .unwrap_or(true)
+}
+
+/// Get the indicator window, building it from tauri.conf.json if it has not
+/// been created yet.
+fn get_or_create_indicator_window<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<tauri::WebviewWindow<R>, String> {
+    if let Some(window) = get_indicator_window_generic(app) {
+        return Ok(window);
+    }
+
+    let window_config = app
+        .config()
+        .app
+        .windows
+        .iter()
+        .find(|w| w.label == INDICATOR_WINDOW_LABEL)
+        .ok_or_else(|| "Recording indicator window not configured".to_string())?;
+
+    tracing::info!("Creating recording indicator window");
+    tauri::WebviewWindowBuilder::from_config(app, window_config)
+        .and_then(|builder| builder.build())
+        .map_err(|e| format!("Failed to create recording indicator window: {}", e))
+}
+
 /// Find the monitor containing a point (in logical pixels).
 ///
 /// Returns `(mon_x, mon_y, mon_width, mon_height, scale_factor)` in logical pixels.
@@ -87,7 +171,9 @@ pub(crate) fn find_monitor_for_point(
 /// Get the window dimensions for a given indicator style.
 pub fn dimensions_for_style(style: IndicatorStyle) -> (f64, f64) {
     match style {
-        IndicatorStyle::CursorDot | IndicatorStyle::FixedFloat => (DOT_WIDTH, DOT_HEIGHT),
+        IndicatorStyle::CursorDot | IndicatorStyle::FixedFloat | IndicatorStyle::FollowFocus => {
+            (DOT_WIDTH, DOT_HEIGHT)
+        }
         IndicatorStyle::Pill => (PILL_WIDTH, PILL_HEIGHT),
     }
 }
@@ -117,18 +203,52 @@ fn position_at_fixed(
         .or_else(|| indicator.primary_monitor().ok().flatten())
         .ok_or_else(|| "Could not determine current monitor".to_string())?;
 
+    let (x, y) = fixed_anchor(pos, logical_bounds(&monitor), (iw, ih));
+
+    indicator
+        .set_position(tauri::Position::Logical(LogicalPosition::new(x, y)))
+        .map_err(|e| e.to_string())?;
+
+    tracing::debug!("Fixed-float indicator at ({}, {}) position={:?}", x, y, pos);
+    Ok(())
+}
+
+/// A monitor's `(x, y, width, height)` in logical pixels.
+fn logical_bounds(monitor: &tauri::Monitor) -> (f64, f64, f64, f64) {
     let scale = monitor.scale_factor();
-    let mp = monitor.position();
-    let ms = monitor.size();
-    let mx = mp.x as f64 / scale;
-    let my = mp.y as f64 / scale;
-    let mw = ms.width as f64 / scale;
-    let mh = ms.height as f64 / scale;
+    let pos = monitor.position();
+    let size = monitor.size();
+    (
+        pos.x as f64 / scale,
+        pos.y as f64 / scale,
+        size.width as f64 / scale,
+        size.height as f64 / scale,
+    )
+}
 
+/// Whether logical `bounds` hold the logical `point` (inclusive min,
+/// exclusive max, as in [`find_monitor_for_point`]).
+fn contains_point((mx, my, mw, mh): (f64, f64, f64, f64), (x, y): (f64, f64)) -> bool {
+    x >= mx && x < mx + mw && y >= my && y < my + mh
+}
+
+/// Whether logical `bounds` of a monitor at `scale` hold a `point` given in
+/// physical pixels.
+fn contains_physical_point(bounds: (f64, f64, f64, f64), scale: f64, (x, y): (f64, f64)) -> bool {
+    contains_point(bounds, (x / scale, y / scale))
+}
+
+/// Where a stationary indicator of `size` sits on a monitor for the
+/// configured `RecorderPosition`.
+fn fixed_anchor(
+    pos: config::RecorderPosition,
+    (mx, my, mw, mh): (f64, f64, f64, f64),
+    (iw, ih): (f64, f64),
+) -> (f64, f64) {
     let padding = 20.0;
-    let (x, y) = match pos {
+    match pos {
         config::RecorderPosition::Cursor => {
-            // For fixed-float with cursor position: use centre-bottom fallback
+            // For a stationary indicator with cursor position: use centre-bottom fallback
             (mx + (mw / 2.0) - (iw / 2.0), my + mh - ih - BOTTOM_PADDING)
         }
         config::RecorderPosition::TrayIcon => {
@@ -144,13 +264,70 @@ fn position_at_fixed(
         config::RecorderPosition::Centre => {
             (mx + (mw / 2.0) - (iw / 2.0), my + (mh / 2.0) - (ih / 2.0))
         }
+    }
+}
+
+/// Position a follow-focus indicator on the monitor holding the focused
+/// window.
+///
+/// Falls back to the monitor under the cursor where the focused window
+/// cannot be found (Wayland, no accessibility permission, other platforms),
+/// and to the primary monitor if neither is known.
+fn position_follow_focus<R: Runtime>(indicator: &tauri::WebviewWindow<R>) -> Result<(), String> {
+    let cfg = config::get_config().unwrap_or_default();
+    let pos = cfg.recorder.position;
+    let size = dimensions_for_style(IndicatorStyle::FollowFocus);
+
+    let monitors = indicator.available_monitors().unwrap_or_default();
+    let monitor_at = |(x, y): (f64, f64)| {
+        monitors
+            .iter()
+            .map(logical_bounds)
+            .find(|&bounds| contains_point(bounds, (x, y)))
+    };
+    // X11 reports the focused window in physical pixels, so each monitor's
+    // own scale factor converts it before the logical bounds are compared
+    let monitor_at_physical = |point: (f64, f64)| {
+        monitors
+            .iter()
+            .find(|m| contains_physical_point(logical_bounds(m), m.scale_factor(), point))
+            .map(logical_bounds)
+    };
+
+    let focused = crate::platform::focused_window_centre().and_then(|centre| {
+        if cfg!(target_os = "linux") {
+            monitor_at_physical(centre)
+        } else {
+            monitor_at(centre)
+        }
+    });
+    let bounds = match focused {
+        Some(bounds) => bounds,
+        None => {
+            tracing::debug!("Focused window monitor unknown, using the monitor under the cursor");
+            match mouse_tracker::get_mouse_position().and_then(monitor_at) {
+                Some(bounds) => bounds,
+                None => indicator
+                    .primary_monitor()
+                    .ok()
+                    .flatten()
+                    .map(|m| logical_bounds(&m))
+                    .ok_or_else(|| "Could not determine primary monitor".to_string())?,
+            }
+        }
     };
 
+    let (x, y) = fixed_anchor(pos, bounds, size);
     indicator
         .set_position(tauri::Position::Logical(LogicalPosition::new(x, y)))
         .map_err(|e| e.to_string())?;
 
-    tracing::debug!("Fixed-float indicator at ({}, {}) position={:?}", x, y, pos);
+    tracing::debug!(
+        "Follow-focus indicator at ({}, {}) position={:?}",
+        x,
+        y,
+        pos
+    );
     Ok(())
 }
 
@@ -205,8 +382,7 @@ where
     // placement is the compositor's choice.
     let cursor_following_possible = !crate::shortcuts::is_wayland();
 
-    let indicator = get_indicator_window(app)
-        .ok_or_else(|| "Recording indicator window not found".to_string())?;
+    let indicator = get_or_create_indicator_window(app)?;
 
     // Resize window for the current style
     resize_indicator(&indicator, style)?;
@@ -244,6 +420,9 @@ where
         IndicatorStyle::Pill => {
             position_pill(app, &indicator)?;
         }
+        IndicatorStyle::FollowFocus => {
+            position_follow_focus(&indicator)?;
+        }
     }
 
     // On macOS, show after positioning
@@ -287,14 +466,57 @@ fn position_at_primary_monitor<R: Runtime>(indicator: &tauri::WebviewWindow<R>)
 /// tracking. Falls back to bottom-centre of the main window's monitor if
 /// the mouse position cannot be determined.
 ///
-/// Returns silently if the recording indicator is disabled in config.
+/// Returns silently if the recording indicator is disabled in config. The
+/// show is debounced (see [`schedule_show`]), so failures are logged rather
+/// than returned.
 #[tauri::command]
 pub fn show_recording_indicator(app: AppHandle) -> Result<(), Error> {
     tracing::info!("show_recording_indicator called");
-    show_indicator_common(&app, |app_handle, indicator| {
-        position_at_bottom_centre(app_handle, indicator)
-    })
-    .map_err(Into::into)
+    schedule_show(&app, |app_handle| {
+        show_indicator_common(app_handle, position_at_bottom_centre)
+    });
+    Ok(())
+}
+
+/// Show the indicator after [`SHOW_DEBOUNCE`] unless a hide arrives first.
+///
+/// A hide within the debounce cancels the show outright, so a recording that
+/// is started and stopped straight away never flashes the indicator. Once
+/// the debounce has passed, `show` runs the normal show path.
+fn schedule_show<R, F>(app: &AppHandle<R>, show: F)
+where
+    R: Runtime,
+    F: FnOnce(&AppHandle<R>) -> Result<(), String> + Send + 'static,
+{
+    let ticket = DEBOUNCE.request_show();
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(SHOW_DEBOUNCE).await;
+
+        if !DEBOUNCE.is_current(ticket) {
+            tracing::debug!("Recording indicator show superseded within debounce, skipping");
+            return;
+        }
+        if let Err(e) = show(&app) {
+            tracing::warn!("Failed to show recording indicator: {}", e);
+        }
+
+        // A hide that arrived while the window was being shown has already
+        // run; hide again so the indicator is not left on screen.
+        if !DEBOUNCE.show_requested() {
+            hide_indicator_now(&app);
+        }
+    });
+}
+
+/// Stop tracking and hide the window if it exists, logging failures.
+fn hide_indicator_now<R: Runtime>(app: &AppHandle<R>) {
+    mouse_tracker::stop_tracking();
+    if let Some(window) = get_indicator_window_generic(app) {
+        if let Err(e) = window.hide() {
+            tracing::warn!("Failed to hide recording indicator: {}", e);
+        }
+    }
 }
 
 /// Position the indicator at the bottom centre of the main window's monitor
@@ -362,11 +584,16 @@ fn position_at_bottom_centre(app: &AppHandle, indicator: &WebviewWindow) -> Resu
 pub fn hide_recording_indicator(app: AppHandle) -> Result<(), Error> {
     tracing::info!("hide_recording_indicator called");
 
+    // Cancel a show still waiting out its debounce
+    DEBOUNCE.request_hide();
+
     // Stop mouse tracking before hiding
     mouse_tracker::stop_tracking();
 
-    let window = get_indicator_window(&app)
-        .ok_or_else(|| "Recording indicator window not found".to_string())?;
+    // Never created: the indicator has been disabled since startup
+    let Some(window) = get_indicator_window(&app) else {
+        return Ok(());
+    };
 
     window.hide().map_err(|e| e.to_string())?;
     tracing::info!("Recording indicator hidden");
@@ -385,22 +612,34 @@ pub fn hide_recording_indicator(app: AppHandle) -> Result<(), Error> {
 ///
 /// On Linux/Wayland, uses actual show()/hide() since compositor controls positioning.
 ///
+/// The show itself is debounced (see [`schedule_show`]); once the debounce
+/// has passed, [`show_indicator_now`] runs the fast path.
+///
 /// Returns silently if the recording indicator is disabled in config.
 pub fn show_indicator_instant<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
     tracing::info!("show_indicator_instant called (fast path)");
 
-    let indicator = get_indicator_window_generic(app)
-        .ok_or_else(|| "Recording indicator window not found".to_string())?;
+    if !is_enabled() {
+        tracing::info!("Recording indicator disabled in config, skipping instant show");
+        return Ok(());
+    }
+
+    schedule_show(app, show_indicator_now);
+    Ok(())
+}
 
-    // Check config
+/// Show the indicator straight away; the body of [`show_indicator_instant`].
+fn show_indicator_now<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
     let cfg = config::get_config().unwrap_or_default();
     let style = cfg.general.indicator_style;
 
+    // The indicator may have been disabled while the show was debounced
     if !cfg.general.show_recording_indicator {
-        tracing::info!("Recording indicator disabled in config, skipping instant show");
         return Ok(());
     }
 
+    let indicator = get_or_create_indicator_window(app)?;
+
     // On Wayland the compositor controls placement and the global cursor
     // position is unreadable, so cursor-following is impossible; fall back to a
     // fixed position and skip tracking.
@@ -443,6 +682,10 @@ pub fn show_indicator_instant<R: Runtime>(app: &AppHandle<R>) -> Result<(), Stri
             // Position at top-centre of screen
             position_pill_generic(&indicator)?;
         }
+        IndicatorStyle::FollowFocus => {
+            // Position on the monitor holding the focused window
+            position_follow_focus(&indicator)?;
+        }
     }
 
     // On macOS, show after positioning
@@ -472,30 +715,7 @@ fn position_fixed_generic<R: Runtime>(indicator: &tauri::WebviewWindow<R>) -> Re
         .flatten()
         .ok_or_else(|| "Could not determine primary monitor".to_string())?;
 
-    let scale = monitor.scale_factor();
-    let mp = monitor.position();
-    let ms = monitor.size();
-    let mx = mp.x as f64 / scale;
-    let my = mp.y as f64 / scale;
-    let mw = ms.width as f64 / scale;
-    let mh = ms.height as f64 / scale;
-
-    let padding = 20.0;
-    let (x, y) = match pos {
-        config::RecorderPosition::Cursor => {
-            (mx + (mw / 2.0) - (iw / 2.0), my + mh - ih - BOTTOM_PADDING)
-        }
-        config::RecorderPosition::TrayIcon => (mx + mw - iw - padding, my + padding + 30.0),
-        config::RecorderPosition::TopLeft => (mx + padding, my + padding + 30.0),
-        config::RecorderPosition::TopRight => (mx + mw - iw - padding, my + padding + 30.0),
-        config::RecorderPosition::BottomLeft => (mx + padding, my + mh - ih - BOTTOM_PADDING),
-        config::RecorderPosition::BottomRight => {
-            (mx + mw - iw - padding, my + mh - ih - BOTTOM_PADDING)
-        }
-        config::RecorderPosition::Centre => {
-            (mx + (mw / 2.0) - (iw / 2.0), my + (mh / 2.0) - (ih / 2.0))
-        }
-    };
+    let (x, y) = fixed_anchor(pos, logical_bounds(&monitor), (iw, ih));
 
     indicator
         .set_position(tauri::Position::Logical(LogicalPosition::new(x, y)))
@@ -560,7 +780,15 @@ pub(crate) fn maybe_play_start_indicator<R: Runtime>(app: &AppHandle<R>) {
 ///
 /// On Linux/Wayland, we show then hide the window during pre-warm to ensure
 /// it's properly mapped with the compositor. Subsequent hide/show should work.
+///
+/// Does nothing when the indicator is disabled in config, so no window is
+/// created.
 pub fn prewarm_indicator_window(app: &AppHandle) {
+    if !is_enabled() {
+        tracing::info!("Recording indicator disabled in config, not creating its window");
+        return;
+    }
+
     if crate::shortcuts::is_wayland() {
         tracing::info!(
             "Wayland session: recording indicator uses a fixed position (the compositor controls \
@@ -575,54 +803,110 @@ pub fn prewarm_indicator_window(app: &AppHandle) {
         // Small delay to let the main window initialise first
         tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 
-        if let Some(window) = app_handle.get_webview_window(INDICATOR_WINDOW_LABEL) {
-            tracing::info!("Pre-warming recording indicator window");
-
-            // On Linux we deliberately do NOT show-then-hide to pre-warm. A
-            // show()/hide() cycle risks leaving a stray indicator visible if the
-            // hide() is not honoured by the compositor (a real failure mode on
-            // some Wayland compositors). The webview is created and loads its
-            // content with the window; the first real show() maps it. The minor
-            // first-show latency is preferable to a stuck floating window.
-            #[cfg(target_os = "linux")]
-            {
-                let _ = &window; // used only in the non-linux branch below
-                tracing::info!(
-                    "Recording indicator window ready (Linux - left hidden; no show/hide pre-warm)"
-                );
+        let window = match get_or_create_indicator_window(&app_handle) {
+            Ok(window) => window,
+            Err(e) => {
+                tracing::warn!("Recording indicator not pre-warmed: {}", e);
+                return;
             }
+        };
+
+        tracing::info!("Pre-warming recording indicator window");
+
+        // On Linux we deliberately do NOT show-then-hide to pre-warm. A
+        // show()/hide() cycle risks leaving a stray indicator visible if the
+        // hide() is not honoured by the compositor (a real failure mode on
+        // some Wayland compositors). The webview is created and loads its
+        // content with the window; the first real show() maps it. The minor
+        // first-show latency is preferable to a stuck floating window.
+        #[cfg(target_os = "linux")]
+        {
+            let _ = &window; // used only in the non-linux branch below
+            tracing::info!(
+                "Recording indicator window ready (Linux - left hidden; no show/hide pre-warm)"
+            );
+        }
 
-            // On macOS, briefly show off-screen to map the window and load the
-            // webview, then hide it. A permanently-visible window would be
-            // remapped on-screen by the window server at launch/display-wake,
-            // causing a stray floating indicator.
-            #[cfg(not(target_os = "linux"))]
-            {
-                // Park off-screen first so the brief pre-warm show is never visible.
-                if let Err(e) = window.set_position(tauri::Position::Logical(LogicalPosition::new(
-                    -10000.0, -10000.0,
-                ))) {
-                    tracing::warn!("Failed to position indicator off-screen: {}", e);
-                }
-
-                if let Err(e) = window.show() {
-                    tracing::warn!("Failed to show indicator for pre-warming: {}", e);
-                }
+        // On macOS, briefly show off-screen to map the window and load the
+        // webview, then hide it. A permanently-visible window would be
+        // remapped on-screen by the window server at launch/display-wake,
+        // causing a stray floating indicator.
+        #[cfg(not(target_os = "linux"))]
+        {
+            // Park off-screen first so the brief pre-warm show is never visible.
+            if let Err(e) = window.set_position(tauri::Position::Logical(LogicalPosition::new(
+                -10000.0, -10000.0,
+            ))) {
+                tracing::warn!("Failed to position indicator off-screen: {}", e);
+            }
 
-                // Wait for the webview to fully load and render while off-screen.
-                tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            if let Err(e) = window.show() {
+                tracing::warn!("Failed to show indicator for pre-warming: {}", e);
+            }
 
-                // Hide for real — the webview is now warm; subsequent shows are fast.
-                if let Err(e) = window.hide() {
-                    tracing::warn!("Failed to hide indicator after pre-warming: {}", e);
-                }
+            // Wait for the webview to fully load and render while off-screen.
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
 
-                tracing::info!(
-                    "Recording indicator window pre-warmed and ready (hidden until needed)"
-                );
+            // Hide for real — the webview is now warm; subsequent shows are fast.
+            // Leave it alone if a recording asked for it in the meantime.
+            if DEBOUNCE.show_requested() {
+                tracing::info!("Recording indicator requested during pre-warm, leaving it shown");
+                return;
             }
-        } else {
-            tracing::warn!("Recording indicator window not found for pre-warming");
+            if let Err(e) = window.hide() {
+                tracing::warn!("Failed to hide indicator after pre-warming: {}", e);
+            }
+
+            tracing::info!("Recording indicator window pre-warmed and ready (hidden until needed)");
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_anchor_uses_given_monitor() {
+        // Secondary monitor to the right of a 1920-wide primary
+        let monitor = (1920.0, 0.0, 2560.0, 1440.0);
+        let (x, y) = fixed_anchor(config::RecorderPosition::Centre, monitor, (58.0, 58.0));
+        assert_eq!((x, y), (1920.0 + 1280.0 - 29.0, 720.0 - 29.0));
+
+        let (x, _) = fixed_anchor(config::RecorderPosition::TopLeft, monitor, (58.0, 58.0));
+        assert!(x >= 1920.0);
+    }
+
+    #[test]
+    fn test_physical_point_is_scaled_before_matching_a_monitor() {
+        // A 2560x1440 panel at 2x is 1280x720 logical
+        let monitor = (0.0, 0.0, 1280.0, 720.0);
+        // The centre of a window near the physical bottom right
+        assert!(contains_physical_point(monitor, 2.0, (2400.0, 1300.0)));
+        assert!(!contains_point(monitor, (2400.0, 1300.0)));
+        assert!(!contains_physical_point(monitor, 2.0, (2600.0, 100.0)));
+    }
+
+    #[test]
+    fn test_hide_cancels_pending_show() {
+        let debounce = ShowDebounce::new();
+        let ticket = debounce.request_show();
+        assert!(debounce.is_current(ticket));
+
+        debounce.request_hide();
+        assert!(!debounce.is_current(ticket));
+        assert!(!debounce.show_requested());
+    }
+
+    #[test]
+    fn test_newer_show_supersedes_older() {
+        let debounce = ShowDebounce::new();
+        let first = debounce.request_show();
+        debounce.request_hide();
+        let second = debounce.request_show();
+
+        assert!(!debounce.is_current(first));
+        assert!(debounce.is_current(second));
+        assert!(debounce.show_requested());
+    }
+}
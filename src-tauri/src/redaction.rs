@@ -0,0 +1,203 @@
+//! Masking of sensitive numbers in stored and logged transcriptions.
+//!
+//! When `config.privacy.redaction.enabled` is set, the pipeline runs the text
+//! through a [`Redactor`] as its final step, so history, the tray and the
+//! logs only ever see the masked form. The pasted text keeps the real values
+//! unless `redact_pasted_text` is also set.
+//!
+//! Built-in patterns cover credit-card numbers (13-19 digits, optionally
+//! grouped by spaces or hyphens, and passing the Luhn check so ordinary long
+//! numbers are left alone) and US Social Security numbers written as
+//! `123-45-6789` or `123 45 6789`. Users can add their own regular
+//! expressions; every match is replaced with [`MASK`].
+
+use regex::Regex;
+use std::sync::OnceLock;
+
+use crate::config::RedactionConfig;
+
+/// Replacement for every redacted match
+pub const MASK: &str = "[REDACTED]";
+
+fn card_regex() -> &'static Regex {
+    static CARD: OnceLock<Regex> = OnceLock::new();
+    CARD.get_or_init(|| Regex::new(r"\b\d(?:[ -]?\d){12,18}\b").expect("valid card regex"))
+}
+
+fn ssn_regex() -> &'static Regex {
+    static SSN: OnceLock<Regex> = OnceLock::new();
+    SSN.get_or_init(|| Regex::new(r"\b\d{3}([- ])\d{2}[- ]\d{4}\b").expect("valid SSN regex"))
+}
+
+/// Luhn checksum over the digits of `candidate`, ignoring separators.
+fn passes_luhn(candidate: &str) -> bool {
+    let digits: Vec<u32> = candidate.chars().filter_map(|c| c.to_digit(10)).collect();
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+/// Check that user-supplied patterns compile, naming the first that does not.
+pub fn validate_patterns(patterns: &[String]) -> Result<(), String> {
+    for pattern in patterns.iter().filter(|p| !p.trim().is_empty()) {
+        Regex::new(pattern)
+            .map_err(|e| format!("Invalid redaction pattern '{}': {}", pattern, e))?;
+    }
+    Ok(())
+}
+
+/// Applies the built-in and custom redaction patterns
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    custom: Vec<Regex>,
+    redact_pasted_text: bool,
+}
+
+impl Redactor {
+    /// Build a redactor from config, or `None` when redaction is off.
+    ///
+    /// Custom patterns are validated when the config is saved; one that
+    /// still fails to compile (e.g. a hand-edited config file) is skipped
+    /// with a warning rather than disabling redaction altogether.
+    pub fn new(config: &RedactionConfig) -> Option<Self> {
+        if !config.enabled {
+            return None;
+        }
+        let custom = config
+            .custom_patterns
+            .iter()
+            .filter(|p| !p.trim().is_empty())
+            .filter_map(|pattern| match Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(e) => {
+                    tracing::warn!("Skipping invalid redaction pattern: {}", e);
+                    None
+                }
+            })
+            .collect();
+        Some(Self {
+            custom,
+            redact_pasted_text: config.redact_pasted_text,
+        })
+    }
+
+    /// Redactor for the current configuration, or `None` when redaction is off
+    pub fn from_config() -> Option<Self> {
+        crate::config::get_config()
+            .ok()
+            .and_then(|c| Self::new(&c.privacy.redaction))
+    }
+
+    /// Whether the pasted/copied text should be masked too
+    pub fn redacts_pasted_text(&self) -> bool {
+        self.redact_pasted_text
+    }
+
+    /// Return `text` with every sensitive match replaced by [`MASK`].
+    pub fn redact(&self, text: &str) -> String {
+        let mut out = card_regex()
+            .replace_all(text, |caps: &regex::Captures| {
+                let candidate = &caps[0];
+                if passes_luhn(candidate) {
+                    MASK.to_string()
+                } else {
+                    candidate.to_string()
+                }
+            })
+            .into_owned();
+        out = ssn_regex()
+            .replace_all(&out, |caps: &regex::Captures| {
+                // "123-45 6789" mixes separators and is more likely two numbers.
+                let candidate = &caps[0];
+                let separator = &caps[1];
+                if candidate[6..7] == *separator {
+                    MASK.to_string()
+                } else {
+                    candidate.to_string()
+                }
+            })
+            .into_owned();
+        for re in &self.custom {
+            out = re.replace_all(&out, MASK).into_owned();
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn redactor(custom: &[&str]) -> Redactor {
+        Redactor::new(&RedactionConfig {
+            enabled: true,
+            redact_pasted_text: false,
+            custom_patterns: custom.iter().map(|s| s.to_string()).collect(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_disabled_config_builds_no_redactor() {
+        assert!(Redactor::new(&RedactionConfig::default()).is_none());
+    }
+
+    #[test]
+    fn test_redacts_card_numbers() {
+        let r = redactor(&[]);
+        assert_eq!(
+            r.redact("my card is 4111 1111 1111 1111 thanks"),
+            "my card is [REDACTED] thanks"
+        );
+        assert_eq!(r.redact("4111-1111-1111-1111"), "[REDACTED]");
+        assert_eq!(r.redact("4111111111111111."), "[REDACTED].");
+    }
+
+    #[test]
+    fn test_keeps_numbers_failing_luhn() {
+        let r = redactor(&[]);
+        assert_eq!(r.redact("order 4111111111111112"), "order 4111111111111112");
+        assert_eq!(r.redact("call 0412 345 678"), "call 0412 345 678");
+    }
+
+    #[test]
+    fn test_redacts_ssn() {
+        let r = redactor(&[]);
+        assert_eq!(r.redact("SSN 123-45-6789."), "SSN [REDACTED].");
+        assert_eq!(r.redact("SSN 123 45 6789"), "SSN [REDACTED]");
+        assert_eq!(r.redact("123-45 6789"), "123-45 6789");
+    }
+
+    #[test]
+    fn test_custom_patterns() {
+        let r = redactor(&[r"ACME-\d+", ""]);
+        assert_eq!(
+            r.redact("ticket ACME-4521 closed"),
+            "ticket [REDACTED] closed"
+        );
+    }
+
+    #[test]
+    fn test_invalid_custom_pattern_is_skipped() {
+        let r = redactor(&["(unclosed", r"\bsecret\b"]);
+        assert_eq!(r.redact("a secret word"), "a [REDACTED] word");
+    }
+
+    #[test]
+    fn test_validate_patterns() {
+        assert!(validate_patterns(&[r"\d+".to_string(), String::new()]).is_ok());
+        let err = validate_patterns(&["(unclosed".to_string()]).unwrap_err();
+        assert!(err.contains("(unclosed"));
+    }
+}
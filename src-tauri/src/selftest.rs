@@ -0,0 +1,204 @@
+//! End-to-end pipeline self-test.
+//!
+//! Runs a short bundled recording through the real pipeline (decode →
+//! transcribe → filter) and reports what happened, for onboarding and bug
+//! reports. Nothing is recorded, pasted, copied or saved to history, and AI
+//! enhancement is skipped so the result depends only on this machine.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::time::Instant;
+use tauri::{AppHandle, Manager, path::BaseDirectory};
+
+use crate::enhancement;
+use crate::error::Error;
+use crate::pipeline;
+use crate::transcription;
+
+/// The bundled sample, relative to the app's resource directory.
+///
+/// Generated by `scripts/generate-selftest-sample.sh`; once committed it
+/// has to be listed under `bundle.resources` in tauri.conf.json.
+const SAMPLE_RESOURCE: &str = "resources/selftest.wav";
+
+/// What the sample says, for comparing against the transcription
+pub const SAMPLE_TEXT: &str = "The quick brown fox jumps over the lazy dog.";
+
+/// Diagnostic result of [`run_pipeline_selftest`]
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTestResult {
+    /// Whether the sample was decoded and transcribed to some text
+    pub success: bool,
+    /// Final text after filtering
+    pub text: Option<String>,
+    /// What the sample says
+    pub expected_text: String,
+    /// Share of the expected words found in the text (0.0–1.0)
+    pub word_match: Option<f64>,
+    /// Error message if a stage failed
+    pub error: Option<String>,
+    /// Transcription backend that ran, e.g. "whisper"
+    pub backend: Option<String>,
+    /// Name of the transcription model used
+    pub model: Option<String>,
+    /// Time spent decoding the sample to 16 kHz mono WAV
+    pub decode_duration_seconds: Option<f64>,
+    /// Time spent waiting for the model to finish loading
+    pub model_wait_seconds: Option<f64>,
+    /// Time spent in the transcription model
+    pub transcription_duration_seconds: Option<f64>,
+    /// Time spent in filters and the dictionary
+    pub filter_duration_seconds: Option<f64>,
+    /// Wall-clock time for the whole test
+    pub total_duration_seconds: f64,
+}
+
+/// Share of `expected`'s words that appear in `actual`, ignoring case and
+/// punctuation.
+fn word_match(expected: &str, actual: &str) -> f64 {
+    let words = |text: &str| -> Vec<String> {
+        text.split_whitespace()
+            .map(|w| {
+                w.chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .flat_map(char::to_lowercase)
+                    .collect::<String>()
+            })
+            .filter(|w| !w.is_empty())
+            .collect()
+    };
+    let expected = words(expected);
+    if expected.is_empty() {
+        return 1.0;
+    }
+    let actual = words(actual);
+    let found = expected.iter().filter(|w| actual.contains(w)).count();
+    found as f64 / expected.len() as f64
+}
+
+/// Decode and transcribe `sample`, filling in `result`.
+async fn run(sample: &Path, result: &mut SelfTestResult) -> Result<(), String> {
+    pipeline::ensure_model_loading_for_import()?;
+
+    let wav_path = std::env::temp_dir().join(format!(
+        "thoth_selftest_{}.wav",
+        chrono::Utc::now().format("%Y%m%d_%H%M%S_%3f")
+    ));
+
+    let decode_start = Instant::now();
+    let input = sample.to_path_buf();
+    let output = wav_path.clone();
+    tokio::task::spawn_blocking(move || {
+        crate::audio::decode::decode_audio_to_wav(&input, &output, &AtomicBool::new(false))
+    })
+    .await
+    .map_err(|e| format!("Decode task failed: {}", e))??;
+    result.decode_duration_seconds = Some(decode_start.elapsed().as_secs_f64());
+
+    let mut config = pipeline::effective_pipeline_config().map_err(|e| e.to_string())?;
+    config.enhancement_enabled = false;
+    config.auto_copy = false;
+    config.auto_paste = false;
+    config.save_to_history = false;
+
+    let output = pipeline::run_transcription_pipeline(
+        &(),
+        &mut transcription::LoadedTranscriber::default(),
+        &enhancement::ActiveEnhancer,
+        &wav_path.to_string_lossy(),
+        &config,
+        &pipeline::RunContext::default(),
+    )
+    .await;
+    if let Err(e) = std::fs::remove_file(&wav_path) {
+        tracing::debug!("Self-test: Failed to remove {}: {}", wav_path.display(), e);
+    }
+    let output = output?;
+
+    result.model = output.transcription_model_name;
+    result.model_wait_seconds = Some(output.model_wait_seconds);
+    result.transcription_duration_seconds = Some(output.transcription_duration_seconds);
+    result.filter_duration_seconds = output.filter_duration_seconds;
+    result.word_match = Some(word_match(SAMPLE_TEXT, &output.text));
+    result.text = Some(output.text);
+    Ok(())
+}
+
+// =============================================================================
+// Tauri Commands
+// =============================================================================
+
+/// Run the bundled sample through decode, transcription and filtering.
+///
+/// Always returns a result; a failed stage is reported in `error` along with
+/// whatever was measured before it.
+#[tauri::command]
+pub async fn run_pipeline_selftest(app: AppHandle) -> Result<SelfTestResult, Error> {
+    tracing::info!("Self-test: Starting");
+    let start = Instant::now();
+    let mut result = SelfTestResult {
+        expected_text: SAMPLE_TEXT.to_string(),
+        ..SelfTestResult::default()
+    };
+
+    let sample: Option<PathBuf> = app
+        .path()
+        .resolve(SAMPLE_RESOURCE, BaseDirectory::Resource)
+        .ok()
+        .filter(|p| p.is_file());
+    let outcome = match sample {
+        Some(sample) => run(&sample, &mut result).await,
+        None => Err("The self-test sample is missing from this installation".to_string()),
+    };
+
+    result.backend = transcription::get_transcription_backend();
+    result.total_duration_seconds = start.elapsed().as_secs_f64();
+    match outcome {
+        Ok(()) => {
+            result.success = true;
+            tracing::info!(
+                "Self-test: Passed in {:.2}s (word match {:.0}%)",
+                result.total_duration_seconds,
+                result.word_match.unwrap_or(0.0) * 100.0
+            );
+        }
+        Err(e) => {
+            tracing::warn!("Self-test: Failed: {}", e);
+            result.error = Some(e);
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_match_ignores_case_and_punctuation() {
+        assert_eq!(word_match(SAMPLE_TEXT, SAMPLE_TEXT), 1.0);
+        assert_eq!(
+            word_match(SAMPLE_TEXT, "the QUICK brown fox, jumps over the lazy dog"),
+            1.0
+        );
+        assert_eq!(word_match("one two three four", "one two"), 0.5);
+        assert_eq!(word_match("one two", ""), 0.0);
+    }
+
+    #[test]
+    fn test_selftest_result_serialisation() {
+        let result = SelfTestResult {
+            success: true,
+            expected_text: SAMPLE_TEXT.to_string(),
+            total_duration_seconds: 1.5,
+            ..SelfTestResult::default()
+        };
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["success"], true);
+        assert_eq!(json["expectedText"], SAMPLE_TEXT);
+        assert_eq!(json["totalDurationSeconds"], 1.5);
+        assert!(json["wordMatch"].is_null());
+    }
+}
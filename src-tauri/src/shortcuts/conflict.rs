@@ -26,6 +26,10 @@ pub enum RegistrationResult {
     Success {
         shortcut: String,
         shortcut_id: String,
+        /// Soft warning when the shortcut matches a well-known system binding
+        /// that may swallow the key press before it reaches Thoth
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        warning: Option<String>,
     },
     /// Shortcut registration failed due to conflict
     Conflict(ShortcutConflict),
@@ -47,6 +51,114 @@ const SINGLE_KEYS: &[&str] = &["F13", "F14", "F15", "F16", "F17", "F18", "F19",
 /// Common base keys for modifier combinations
 const BASE_KEYS: &[&str] = &["Space", "R", "T", "M", "J", "K", "L", "Semicolon"];
 
+/// Well-known macOS system shortcuts, as (modifiers, key, owner).
+///
+/// Modifiers use the canonical names produced by `normalise_accelerator`.
+const MACOS_SYSTEM_SHORTCUTS: &[(&[&str], &str, &str)] = &[
+    (&["Cmd"], "Space", "Spotlight"),
+    (&["Alt", "Cmd"], "Space", "Finder search"),
+    (&["Ctrl"], "Space", "input source switching"),
+    (&["Cmd"], "Tab", "the app switcher"),
+    (&["Cmd"], "Q", "Quit"),
+    (&["Cmd"], "W", "Close Window"),
+    (&["Cmd"], "H", "Hide"),
+    (&["Cmd"], "M", "Minimise"),
+    (&["Cmd"], "C", "Copy"),
+    (&["Cmd"], "V", "Paste"),
+    (&["Cmd"], "X", "Cut"),
+    (&["Cmd"], "Z", "Undo"),
+    (&["Cmd"], "A", "Select All"),
+    (&["Cmd", "Shift"], "3", "screenshots"),
+    (&["Cmd", "Shift"], "4", "screenshots"),
+    (&["Cmd", "Shift"], "5", "screenshots"),
+    (&["Cmd", "Shift"], "Q", "Log Out"),
+    (&["Cmd", "Ctrl"], "Q", "Lock Screen"),
+    (&["Alt", "Cmd"], "Escape", "Force Quit"),
+    (&["Ctrl"], "Up", "Mission Control"),
+    (&["Ctrl"], "Down", "App Exposé"),
+];
+
+/// Well-known Linux desktop shortcuts (GNOME/KDE defaults), as (modifiers, key, owner).
+const LINUX_SYSTEM_SHORTCUTS: &[(&[&str], &str, &str)] = &[
+    (&["Alt"], "Tab", "the window switcher"),
+    (&["Alt"], "F4", "Close Window"),
+    (&["Alt"], "F2", "the run dialog"),
+    (&["Alt", "Ctrl"], "T", "the terminal launcher"),
+    (&["Alt", "Ctrl"], "Delete", "the log out dialog"),
+    (&["Alt", "Ctrl"], "Left", "workspace switching"),
+    (&["Alt", "Ctrl"], "Right", "workspace switching"),
+    (&["Super"], "L", "Lock Screen"),
+    (&["Super"], "Space", "input source switching"),
+    (&["Super"], "Tab", "the app switcher"),
+    (&[], "Print", "screenshots"),
+    (&["Ctrl"], "C", "Copy"),
+    (&["Ctrl"], "V", "Paste"),
+    (&["Ctrl"], "X", "Cut"),
+    (&["Ctrl"], "Z", "Undo"),
+    (&["Ctrl"], "A", "Select All"),
+];
+
+/// Split an accelerator into sorted canonical modifier names and the
+/// upper-cased main key.
+///
+/// `CommandOrControl` resolves to `Cmd` on macOS and `Ctrl` elsewhere.
+fn normalise_accelerator(shortcut: &str, macos: bool) -> (Vec<&'static str>, String) {
+    let mut modifiers: Vec<&'static str> = Vec::new();
+    let mut key = String::new();
+    for part in shortcut.split('+') {
+        let modifier = match part.to_ascii_lowercase().as_str() {
+            "commandorcontrol" | "cmdorctrl" => Some(if macos { "Cmd" } else { "Ctrl" }),
+            "command" | "cmd" => Some("Cmd"),
+            "super" | "meta" => Some(if macos { "Cmd" } else { "Super" }),
+            "control" | "ctrl" => Some("Ctrl"),
+            "alt" | "option" => Some("Alt"),
+            "shift" => Some("Shift"),
+            _ => None,
+        };
+        match modifier {
+            Some(m) if !modifiers.contains(&m) => modifiers.push(m),
+            Some(_) => {}
+            None => key = part.to_ascii_uppercase(),
+        }
+    }
+    modifiers.sort_unstable();
+    (modifiers, key)
+}
+
+/// Look up a shortcut in a system-shortcut table and describe the collision.
+fn find_system_conflict(
+    shortcut: &str,
+    macos: bool,
+    table: &[(&[&str], &str, &str)],
+) -> Option<String> {
+    let (modifiers, key) = normalise_accelerator(shortcut, macos);
+    table
+        .iter()
+        .find(|(m, k, _)| {
+            let mut m = m.to_vec();
+            m.sort_unstable();
+            m == modifiers && k.eq_ignore_ascii_case(&key)
+        })
+        .map(|(_, _, owner)| {
+            format!(
+                "{shortcut} is normally used by {owner}. It may register but never reach Thoth."
+            )
+        })
+}
+
+/// Return a soft warning when `shortcut` matches a well-known system binding
+/// on the current platform.
+///
+/// Such shortcuts often register successfully but the OS consumes the key
+/// press first, so this is advisory only and never blocks registration.
+pub fn system_conflict_warning(shortcut: &str) -> Option<String> {
+    if cfg!(target_os = "macos") {
+        find_system_conflict(shortcut, true, MACOS_SYSTEM_SHORTCUTS)
+    } else {
+        find_system_conflict(shortcut, false, LINUX_SYSTEM_SHORTCUTS)
+    }
+}
+
 /// Generate alternative shortcut suggestions for a failed registration
 ///
 /// Generates a list of alternative shortcuts that the user might try
@@ -270,6 +382,33 @@ mod tests {
         assert!(validate_shortcut_format("Space+").is_err());
     }
 
+    #[test]
+    fn test_system_conflict_macos_spotlight() {
+        let warning = find_system_conflict("CommandOrControl+Space", true, MACOS_SYSTEM_SHORTCUTS);
+        assert!(warning.unwrap().contains("Spotlight"));
+        // Modifier order and case don't matter
+        assert!(find_system_conflict("shift+cmd+4", true, MACOS_SYSTEM_SHORTCUTS).is_some());
+    }
+
+    #[test]
+    fn test_system_conflict_linux() {
+        assert!(find_system_conflict("Alt+F4", false, LINUX_SYSTEM_SHORTCUTS).is_some());
+        // CommandOrControl is Ctrl on Linux, so Ctrl+Alt+T matches
+        assert!(
+            find_system_conflict("CommandOrControl+Alt+T", false, LINUX_SYSTEM_SHORTCUTS).is_some()
+        );
+    }
+
+    #[test]
+    fn test_system_conflict_none_for_free_shortcuts() {
+        assert!(find_system_conflict("F13", true, MACOS_SYSTEM_SHORTCUTS).is_none());
+        assert!(
+            find_system_conflict("CommandOrControl+Shift+Space", true, MACOS_SYSTEM_SHORTCUTS)
+                .is_none()
+        );
+        assert!(find_system_conflict("F13", false, LINUX_SYSTEM_SHORTCUTS).is_none());
+    }
+
     #[test]
     fn test_create_conflict() {
         let conflict = create_conflict("F13", "toggle_recording", "Shortcut already registered");
@@ -83,6 +83,18 @@ fn bind_shortcuts() {
     if let Some(copy) = cfg.shortcuts.copy_last.clone() {
         binds.push((shortcut_ids::COPY_LAST_TRANSCRIPTION, copy));
     }
+    if let Some(unsaved) = cfg.shortcuts.dictate_without_saving.clone() {
+        binds.push((shortcut_ids::DICTATE_WITHOUT_SAVING, unsaved));
+    }
+    if let Some(typing) = cfg.shortcuts.dictate_by_typing.clone() {
+        binds.push((shortcut_ids::DICTATE_BY_TYPING, typing));
+    }
+    if let Some(pasting) = cfg.shortcuts.dictate_by_pasting.clone() {
+        binds.push((shortcut_ids::DICTATE_BY_PASTING, pasting));
+    }
+    if let Some(verbatim) = cfg.shortcuts.dictate_verbatim.clone() {
+        binds.push((shortcut_ids::DICTATE_VERBATIM, verbatim));
+    }
 
     for (id, accel) in binds {
         if accel.is_empty() {
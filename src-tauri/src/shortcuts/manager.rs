@@ -31,6 +31,10 @@ pub mod shortcut_ids {
     pub const TOGGLE_RECORDING_ALT: &str = "toggle_recording_alt";
     pub const COPY_LAST_TRANSCRIPTION: &str = "copy_last_transcription";
     pub const TOGGLE_ENHANCEMENT: &str = "toggle_enhancement";
+    pub const DICTATE_WITHOUT_SAVING: &str = "dictate_without_saving";
+    pub const DICTATE_BY_TYPING: &str = "dictate_by_typing";
+    pub const DICTATE_BY_PASTING: &str = "dictate_by_pasting";
+    pub const DICTATE_VERBATIM: &str = "dictate_verbatim";
 }
 
 /// Global shortcut manager instance
@@ -119,17 +123,21 @@ pub(crate) fn dispatch_shortcut_action<R: Runtime>(app: &AppHandle<R>, shortcut_
     // For recording shortcuts, show indicator and play the start cue IMMEDIATELY
     // in Rust before emitting to the frontend. This eliminates JS round-trip delay.
     let is_toggle_recording = shortcut_id == shortcut_ids::TOGGLE_RECORDING
-        || shortcut_id == shortcut_ids::TOGGLE_RECORDING_ALT;
+        || shortcut_id == shortcut_ids::TOGGLE_RECORDING_ALT
+        || shortcut_id == shortcut_ids::DICTATE_WITHOUT_SAVING
+        || shortcut_id == shortcut_ids::DICTATE_BY_TYPING
+        || shortcut_id == shortcut_ids::DICTATE_BY_PASTING
+        || shortcut_id == shortcut_ids::DICTATE_VERBATIM;
     if is_toggle_recording {
         recording_indicator::maybe_play_start_indicator(app);
     }
 
     // Handle copy-last-transcription directly in Rust (no frontend round-trip).
     if shortcut_id == shortcut_ids::COPY_LAST_TRANSCRIPTION {
-        match crate::database::transcription::list_transcriptions(Some(1), Some(0)) {
-            Ok(transcriptions) => {
-                if let Some(t) = transcriptions.into_iter().next() {
-                    match arboard::Clipboard::new().and_then(|mut cb| cb.set_text(t.text)) {
+        match crate::clipboard::last_transcription_text() {
+            Ok(text) => {
+                if let Some(text) = text {
+                    match arboard::Clipboard::new().and_then(|mut cb| cb.set_text(text)) {
                         Ok(()) => {
                             tracing::info!("Copied last transcription to clipboard via shortcut")
                         }
@@ -139,7 +147,7 @@ pub(crate) fn dispatch_shortcut_action<R: Runtime>(app: &AppHandle<R>, shortcut_
                     tracing::info!("No transcriptions to copy");
                 }
             }
-            Err(e) => tracing::error!("Failed to get last transcription: {}", e),
+            Err(e) => tracing::error!("{}", e),
         }
         return;
     }
@@ -183,6 +191,30 @@ pub fn get_defaults() -> Vec<ShortcutInfo> {
             description: "Toggle AI enhancement".to_string(),
             is_enabled: false,
         },
+        ShortcutInfo {
+            id: shortcut_ids::DICTATE_WITHOUT_SAVING.to_string(),
+            accelerator: String::new(),
+            description: "Dictate without saving to history".to_string(),
+            is_enabled: false,
+        },
+        ShortcutInfo {
+            id: shortcut_ids::DICTATE_BY_TYPING.to_string(),
+            accelerator: String::new(),
+            description: "Dictate and type the result".to_string(),
+            is_enabled: false,
+        },
+        ShortcutInfo {
+            id: shortcut_ids::DICTATE_BY_PASTING.to_string(),
+            accelerator: String::new(),
+            description: "Dictate and paste the result".to_string(),
+            is_enabled: false,
+        },
+        ShortcutInfo {
+            id: shortcut_ids::DICTATE_VERBATIM.to_string(),
+            accelerator: String::new(),
+            description: "Verbatim dictation".to_string(),
+            is_enabled: false,
+        },
     ]
 }
 
@@ -337,7 +369,7 @@ mod tests {
     fn test_get_defaults_returns_expected_shortcuts() {
         let defaults = get_defaults();
 
-        assert_eq!(defaults.len(), 4);
+        assert_eq!(defaults.len(), 8);
 
         let toggle = defaults
             .iter()
@@ -362,6 +394,20 @@ mod tests {
             .find(|s| s.id == shortcut_ids::TOGGLE_ENHANCEMENT);
         assert!(enh.is_some());
         assert_eq!(enh.unwrap().accelerator, "");
+
+        let unsaved = defaults
+            .iter()
+            .find(|s| s.id == shortcut_ids::DICTATE_WITHOUT_SAVING);
+        assert_eq!(unsaved.unwrap().accelerator, "");
+
+        for id in [
+            shortcut_ids::DICTATE_BY_TYPING,
+            shortcut_ids::DICTATE_BY_PASTING,
+            shortcut_ids::DICTATE_VERBATIM,
+        ] {
+            let forced = defaults.iter().find(|s| s.id == id);
+            assert_eq!(forced.unwrap().accelerator, "");
+        }
     }
 
     #[test]
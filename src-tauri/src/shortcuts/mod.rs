@@ -28,6 +28,7 @@ pub use manager::{ShortcutInfo, shortcut_ids};
 
 use crate::error::Error;
 use crate::keyboard_service;
+use serde::Serialize;
 use tauri::AppHandle;
 
 /// Whether the current session uses the Wayland display server.
@@ -46,6 +47,107 @@ pub fn is_wayland() -> bool {
     }
 }
 
+/// Mechanism that delivers global shortcuts in the current session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutBackend {
+    /// Tauri's GlobalShortcut plugin (macOS, Linux X11)
+    GlobalShortcutPlugin,
+    /// XDG Desktop Portal `GlobalShortcuts` (Linux Wayland)
+    WaylandPortal,
+    /// Native `hyprctl` binds (Hyprland)
+    Hyprland,
+}
+
+/// Snapshot of which shortcut mechanisms work in the current session
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutBackendInfo {
+    /// Display server: "macOS", "X11", "Wayland" or "Unknown"
+    pub display_server: String,
+    /// Backend used for accelerator shortcuts such as F13
+    pub backend: ShortcutBackend,
+    /// Whether standalone modifier shortcuts (e.g. ShiftRight) can fire
+    pub modifier_shortcuts_supported: bool,
+    /// Whether the Wayland portal bound shortcuts. `None` when the portal is
+    /// not in use or has not finished binding yet.
+    pub portal_available: Option<bool>,
+    /// User-facing warning when some shortcuts will not work, if any
+    pub warning: Option<String>,
+}
+
+/// Build the backend info from the detected session facts.
+fn backend_info(
+    display_server: &str,
+    backend: ShortcutBackend,
+    modifier_shortcuts_supported: bool,
+    portal_available: Option<bool>,
+) -> ShortcutBackendInfo {
+    let warning = if backend == ShortcutBackend::WaylandPortal && portal_available == Some(false) {
+        Some(
+            "Global shortcuts aren't available on this Wayland compositor. \
+             Switch to an X11 session to use keyboard shortcuts."
+                .to_string(),
+        )
+    } else if !modifier_shortcuts_supported {
+        Some(if display_server == "Wayland" {
+            "Modifier-key shortcuts aren't supported on Wayland; use F-keys or the portal."
+                .to_string()
+        } else {
+            "Modifier-key shortcuts need Input Monitoring permission.".to_string()
+        })
+    } else {
+        None
+    };
+
+    ShortcutBackendInfo {
+        display_server: display_server.to_string(),
+        backend,
+        modifier_shortcuts_supported,
+        portal_available,
+        warning,
+    }
+}
+
+/// Report which shortcut backend is active and what it supports
+///
+/// Consolidates the platform and display-server checks so Settings can warn
+/// before the user binds a shortcut that will never fire in this session.
+#[tauri::command]
+pub fn get_shortcut_backend_info() -> ShortcutBackendInfo {
+    #[cfg(target_os = "linux")]
+    {
+        let display_server = get_display_server();
+        if display_server == DisplayServer::Wayland {
+            if hyprland::is_hyprland() {
+                return backend_info("Wayland", ShortcutBackend::Hyprland, false, None);
+            }
+            let portal_available = wayland_portal::last_status().map(|s| s.available);
+            return backend_info(
+                "Wayland",
+                ShortcutBackend::WaylandPortal,
+                false,
+                portal_available,
+            );
+        }
+        backend_info(
+            &display_server.to_string(),
+            ShortcutBackend::GlobalShortcutPlugin,
+            true,
+            None,
+        )
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        backend_info(
+            "macOS",
+            ShortcutBackend::GlobalShortcutPlugin,
+            keyboard_service::check_input_monitoring(),
+            None,
+        )
+    }
+}
+
 /// Register a global shortcut
 ///
 /// # Arguments
@@ -255,6 +357,7 @@ pub fn try_register_shortcut(
             return RegistrationResult::Success {
                 shortcut: accelerator,
                 shortcut_id: id,
+                warning: None,
             };
         } else {
             return RegistrationResult::Conflict(conflict::ShortcutConflict {
@@ -284,6 +387,7 @@ pub fn try_register_shortcut(
 
     match result {
         Ok(()) => RegistrationResult::Success {
+            warning: conflict::system_conflict_warning(&accelerator),
             shortcut: accelerator,
             shortcut_id: id,
         },
@@ -291,17 +395,18 @@ pub fn try_register_shortcut(
     }
 }
 
-/// Check if a shortcut can be registered without actually registering it
+/// Check if a shortcut can be registered, leaving it unregistered
 ///
 /// Performs validation and checks if the shortcut is already registered
-/// by this application, but does not register with the system.
+/// by this application, then briefly registers it with the system so a key
+/// held by the OS or another application is reported as a conflict.
 ///
 /// # Arguments
 /// * `accelerator` - Keyboard accelerator string to check
 ///
 /// # Returns
-/// * `Ok(true)` if the shortcut appears to be available
-/// * `Ok(false)` if the shortcut is already registered by this app
+/// * `Ok(true)` if the shortcut can be registered
+/// * `Ok(false)` if this app or another holds the shortcut
 /// * `Err(String)` if the format is invalid
 #[tauri::command]
 pub fn check_shortcut_available(app: AppHandle, accelerator: String) -> Result<bool, Error> {
@@ -343,9 +448,26 @@ pub fn check_shortcut_available(app: AppHandle, accelerator: String) -> Result<b
     }
 
     use tauri_plugin_global_shortcut::GlobalShortcutExt;
-    let is_registered = app.global_shortcut().is_registered(accelerator.as_str());
+    let global_shortcut = app.global_shortcut();
+    if global_shortcut.is_registered(accelerator.as_str()) {
+        return Ok(false);
+    }
+
+    // Only the system knows whether another application holds the key, so
+    // try to take it and give it straight back.
+    if let Err(e) = global_shortcut.register(accelerator.as_str()) {
+        tracing::info!("Shortcut '{}' is not available: {}", accelerator, e);
+        return Ok(false);
+    }
+    if let Err(e) = global_shortcut.unregister(accelerator.as_str()) {
+        tracing::warn!(
+            "Failed to release shortcut '{}' after checking it: {}",
+            accelerator,
+            e
+        );
+    }
 
-    Ok(!is_registered)
+    Ok(true)
 }
 
 /// Get alternative shortcut suggestions
@@ -372,9 +494,43 @@ pub fn get_shortcut_suggestions(shortcut: String) -> Vec<String> {
 /// * `shortcut` - The shortcut string to validate
 ///
 /// # Returns
-/// * `Ok(())` if the format is valid
+/// * `Ok(None)` if the format is valid
+/// * `Ok(Some(warning))` if the format is valid but the shortcut matches a
+///   well-known system binding that may swallow it
 /// * `Err(String)` describing the format issue
 #[tauri::command]
-pub fn validate_shortcut(shortcut: String) -> Result<(), Error> {
-    conflict::validate_shortcut_format(&shortcut).map_err(Into::into)
+pub fn validate_shortcut(shortcut: String) -> Result<Option<String>, Error> {
+    conflict::validate_shortcut_format(&shortcut)?;
+    Ok(conflict::system_conflict_warning(&shortcut))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_info_x11_has_no_warning() {
+        let info = backend_info("X11", ShortcutBackend::GlobalShortcutPlugin, true, None);
+        assert!(info.warning.is_none());
+    }
+
+    #[test]
+    fn test_backend_info_wayland_warns_about_modifiers() {
+        let info = backend_info("Wayland", ShortcutBackend::WaylandPortal, false, Some(true));
+        assert!(info.warning.unwrap().contains("Modifier-key shortcuts"));
+    }
+
+    #[test]
+    fn test_backend_info_portal_unavailable_warning() {
+        let info = backend_info(
+            "Wayland",
+            ShortcutBackend::WaylandPortal,
+            false,
+            Some(false),
+        );
+        assert!(info.warning.unwrap().contains("aren't available"));
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["backend"], "wayland_portal");
+        assert_eq!(json["portalAvailable"], false);
+    }
 }
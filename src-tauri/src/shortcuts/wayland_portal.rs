@@ -50,25 +50,94 @@ use super::manager::shortcut_ids;
 /// re-run a no-op.
 static PORTAL_STARTED: OnceLock<()> = OnceLock::new();
 
+/// The most recent status reported to the frontend, kept so it can also be
+/// queried on demand (see `get_shortcut_backend_info`). `None` until the
+/// portal task has finished binding.
+static LAST_STATUS: parking_lot::Mutex<Option<PortalStatus>> = parking_lot::Mutex::new(None);
+
+/// The most recent portal status, or `None` if binding has not completed yet
+/// (or the portal was never set up in this session).
+pub fn last_status() -> Option<PortalStatus> {
+    LAST_STATUS.lock().clone()
+}
+
 /// The shortcuts Thoth requests from the portal, with the trigger we *prefer*
 /// (the compositor may assign something else, or let the user pick).
 ///
+/// The preferred trigger is the user's configured accelerator, translated to
+/// the portal's trigger syntax; when it cannot be expressed (a bare modifier
+/// such as `ShiftRight`), no preference is sent and the compositor asks.
+///
 /// Modifier-only and toggle-enhancement shortcuts are intentionally omitted: a
 /// bare modifier cannot be expressed as a portal trigger, and the portal dialog
 /// is heavyweight enough that we only register the recording toggles users
 /// actually press. The frontend can request more later through the same path.
 fn requested_shortcuts() -> Vec<NewShortcut> {
+    let shortcuts = crate::config::get_config()
+        .map(|c| c.shortcuts)
+        .unwrap_or_default();
+    let toggle = to_portal_trigger(&shortcuts.toggle_recording);
+    let copy_last = shortcuts.copy_last.as_deref().and_then(to_portal_trigger);
+
     vec![
         NewShortcut::new(shortcut_ids::TOGGLE_RECORDING, "Toggle recording")
-            .preferred_trigger(Some("F13")),
+            .preferred_trigger(toggle.as_deref()),
         NewShortcut::new(
             shortcut_ids::COPY_LAST_TRANSCRIPTION,
             "Copy last transcription",
         )
-        .preferred_trigger(Some("F14")),
+        .preferred_trigger(copy_last.as_deref()),
     ]
 }
 
+/// Translate a Tauri accelerator ("CommandOrControl+Shift+Space") into the
+/// XDG shortcut trigger syntax ("CTRL+SHIFT+space").
+///
+/// Returns `None` for accelerators the portal cannot express: empty strings,
+/// bare modifiers, and combinations with no main key.
+fn to_portal_trigger(accelerator: &str) -> Option<String> {
+    if crate::keyboard_service::is_modifier_shortcut(accelerator) {
+        return None;
+    }
+
+    let mut modifiers: Vec<&str> = Vec::new();
+    let mut key: Option<String> = None;
+    for part in accelerator.split('+').map(str::trim) {
+        let modifier = match part {
+            "CommandOrControl" | "CmdOrCtrl" | "Control" | "Ctrl" => Some("CTRL"),
+            "Alt" | "Option" => Some("ALT"),
+            "Shift" => Some("SHIFT"),
+            "Super" | "Meta" | "Command" | "Cmd" => Some("LOGO"),
+            _ => None,
+        };
+        match modifier {
+            Some(m) if !modifiers.contains(&m) => modifiers.push(m),
+            Some(_) => {}
+            None if part.is_empty() || key.is_some() => return None,
+            None => key = Some(portal_key_name(part)),
+        }
+    }
+
+    let key = key?;
+    modifiers.push(&key);
+    Some(modifiers.join("+"))
+}
+
+/// Map an accelerator key name to its XKB keysym name as used by the portal.
+fn portal_key_name(key: &str) -> String {
+    match key {
+        "Space" => "space".to_string(),
+        "Enter" | "Return" => "Return".to_string(),
+        "Backspace" => "BackSpace".to_string(),
+        "PageUp" => "Page_Up".to_string(),
+        "PageDown" => "Page_Down".to_string(),
+        "Plus" => "plus".to_string(),
+        "Minus" => "minus".to_string(),
+        k if k.chars().count() == 1 => k.to_lowercase(),
+        k => k.to_string(),
+    }
+}
+
 /// Reported to the frontend (event `wayland-shortcuts-status`) so the UI can
 /// tell the user whether global shortcuts are working on their compositor and,
 /// if so, which keys were actually assigned.
@@ -219,7 +288,38 @@ async fn bind() -> Result<
 }
 
 fn emit_status(app: &AppHandle, status: PortalStatus) {
+    *LAST_STATUS.lock() = Some(status.clone());
     if let Err(e) = app.emit("wayland-shortcuts-status", &status) {
         tracing::error!("Failed to emit wayland-shortcuts-status event: {e}");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_portal_trigger_function_key() {
+        assert_eq!(to_portal_trigger("F13").as_deref(), Some("F13"));
+    }
+
+    #[test]
+    fn test_portal_trigger_combination() {
+        assert_eq!(
+            to_portal_trigger("CommandOrControl+Shift+Space").as_deref(),
+            Some("CTRL+SHIFT+space")
+        );
+        assert_eq!(
+            to_portal_trigger("Super+Alt+R").as_deref(),
+            Some("LOGO+ALT+r")
+        );
+    }
+
+    #[test]
+    fn test_portal_trigger_unexpressible() {
+        assert_eq!(to_portal_trigger("ShiftRight"), None);
+        assert_eq!(to_portal_trigger(""), None);
+        assert_eq!(to_portal_trigger("Control+Shift"), None);
+        assert_eq!(to_portal_trigger("A+B"), None);
+    }
+}
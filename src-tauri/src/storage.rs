@@ -6,8 +6,13 @@
 
 use crate::error::Error;
 use serde::Serialize;
+use std::ffi::OsStr;
 use std::fs;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::AppHandle;
 
 /// Disk usage breakdown by category
 #[derive(Debug, Clone, Serialize)]
@@ -15,7 +20,7 @@ use std::path::{Path, PathBuf};
 pub struct StorageUsage {
     /// Speech recognition models (~/.thoth/models/)
     pub models_bytes: u64,
-    /// Audio recordings (~/.thoth/Recordings/)
+    /// Audio recordings (the configured recordings directory)
     pub recordings_bytes: u64,
     /// Debug logs (~/.thoth/logs/)
     pub logs_bytes: u64,
@@ -33,11 +38,141 @@ pub struct StorageUsage {
     pub log_count: u64,
 }
 
-/// Get the Thoth data directory (~/.thoth)
-fn thoth_dir() -> PathBuf {
-    dirs::home_dir()
-        .expect("Could not determine home directory")
-        .join(".thoth")
+/// Where new recordings are stored
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingsLocation {
+    /// Resolved recordings directory
+    pub path: String,
+    /// Whether this is a user-chosen directory rather than the default
+    pub is_custom: bool,
+}
+
+/// Where Thoth's data directory is
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataLocation {
+    /// Resolved data directory
+    pub path: String,
+    /// Whether the data has been moved away from `~/.thoth`
+    pub is_custom: bool,
+    /// Whether `THOTH_DATA_DIR` decides the location (it cannot be moved then)
+    pub from_env: bool,
+}
+
+/// Progress of a data directory move started by [`start_pending_move`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataDirMoveProgress {
+    /// Bytes copied so far (entries renamed in place count for nothing)
+    pub copied_bytes: u64,
+    /// Bytes that have to be copied in all
+    pub total_bytes: u64,
+    /// Whether the move has finished; the app restarts shortly after
+    pub done: bool,
+    /// Why the move failed, when it did
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Event emitted while the data directory moves (payload: [`DataDirMoveProgress`])
+pub const DATA_DIR_MOVE_PROGRESS_EVENT: &str = "data-dir-move-progress";
+
+/// Environment variable that overrides the data directory
+pub const DATA_DIR_ENV: &str = "THOTH_DATA_DIR";
+
+/// File in `~/.thoth` naming the data directory after [`migrate_data_dir`]
+const DATA_DIR_POINTER: &str = "data_dir";
+
+/// File in `~/.thoth` naming where [`migrate_data_dir`] asked the data to go.
+/// The move itself happens at the next start, before anything is open.
+const DATA_DIR_MOVE: &str = "data_dir_move";
+
+/// Size of the chunks files are copied in, so progress can be reported
+const COPY_CHUNK_BYTES: usize = 1024 * 1024;
+
+/// Data directory resolved once at startup by [`init_data_dir`]
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Set while [`start_pending_move`] is moving the data, so that nothing
+/// opens the database or writes the config in the meantime
+static MOVING_DATA_DIR: AtomicBool = AtomicBool::new(false);
+
+/// Whether `name` is one of the files recording where the data lives, which
+/// stay in `~/.thoth` rather than moving with the data
+fn is_location_file(name: &OsStr) -> bool {
+    name == DATA_DIR_POINTER || name == DATA_DIR_MOVE
+}
+
+/// The default data directory (`~/.thoth`), which also holds the pointer
+fn default_data_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".thoth"))
+}
+
+/// The non-empty `THOTH_DATA_DIR`, if set
+fn env_data_dir() -> Option<PathBuf> {
+    std::env::var_os(DATA_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// The directory named by the pointer file in `default`, if any
+fn read_data_dir_pointer(default: &Path) -> Option<PathBuf> {
+    let raw = fs::read_to_string(default.join(DATA_DIR_POINTER)).ok()?;
+    let trimmed = raw.trim();
+    (!trimmed.is_empty()).then(|| PathBuf::from(trimmed))
+}
+
+/// `THOTH_DATA_DIR`, else the directory the pointer names, else `~/.thoth`
+fn resolve_data_dir() -> Option<PathBuf> {
+    resolve_data_dir_from(env_data_dir(), default_data_dir())
+}
+
+/// [`resolve_data_dir`] from an explicit override and default directory
+///
+/// The override wins outright, so a home directory is not needed when
+/// `THOTH_DATA_DIR` is set.
+fn resolve_data_dir_from(env: Option<PathBuf>, default: Option<PathBuf>) -> Option<PathBuf> {
+    if let Some(dir) = env {
+        return Some(dir);
+    }
+    let default = default?;
+    Some(read_data_dir_pointer(&default).unwrap_or(default))
+}
+
+/// Resolve the data directory for the rest of the run
+///
+/// Called once at startup, before anything touches the disk. A pending move
+/// is left to [`start_pending_move`], so the location resolved here is the
+/// one the data is in until the app restarts.
+pub fn init_data_dir() -> Option<PathBuf> {
+    let dir = resolve_data_dir()?;
+    Some(DATA_DIR.get_or_init(|| dir).clone())
+}
+
+/// Whether the data directory is being moved (the app restarts afterwards)
+pub fn is_moving_data_dir() -> bool {
+    MOVING_DATA_DIR.load(Ordering::SeqCst)
+}
+
+/// The Thoth data directory (`~/.thoth` unless relocated)
+///
+/// Holds the database, config, models, logs and default recordings
+/// directory. `None` when neither `THOTH_DATA_DIR` nor a home directory is
+/// available.
+pub fn data_dir() -> Option<PathBuf> {
+    match DATA_DIR.get() {
+        Some(dir) => Some(dir.clone()),
+        None => resolve_data_dir(),
+    }
+}
+
+/// [`data_dir`], falling back to the temp directory if it is unavailable
+pub fn data_dir_or_fallback() -> PathBuf {
+    data_dir().unwrap_or_else(|| {
+        tracing::error!("Could not determine the data directory, using the temp directory");
+        std::env::temp_dir().join(".thoth")
+    })
 }
 
 /// Get the FluidAudio model cache directory, if applicable on this platform.
@@ -101,6 +236,51 @@ fn file_count(path: &Path) -> u64 {
         .unwrap_or(0)
 }
 
+/// Size and number of recordings in `dir`. With `only_thoth_files`, files
+/// Thoth did not name are ignored (a user-chosen directory may hold others).
+fn recordings_usage(dir: &Path, only_thoth_files: bool) -> (u64, u64) {
+    if !only_thoth_files {
+        return (dir_size(dir), file_count(dir));
+    }
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_file() && crate::audio::is_thoth_recording(p))
+                .fold((0, 0), |(bytes, count), p| {
+                    (
+                        bytes + p.metadata().map(|m| m.len()).unwrap_or(0),
+                        count + 1,
+                    )
+                })
+        })
+        .unwrap_or((0, 0))
+}
+
+/// Delete recordings in `dir`, limited to Thoth-named files when
+/// `only_thoth_files` is set. Returns how many were deleted.
+fn delete_recordings_in(dir: &Path, only_thoth_files: bool) -> Result<u64, String> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+
+    let mut deleted = 0u64;
+    let entries = fs::read_dir(dir).map_err(|e| e.to_string())?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_file() || (only_thoth_files && !crate::audio::is_thoth_recording(&path)) {
+            continue;
+        }
+        if let Err(e) = fs::remove_file(&path) {
+            tracing::warn!("Failed to delete recording {:?}: {}", path, e);
+        } else {
+            deleted += 1;
+        }
+    }
+    Ok(deleted)
+}
+
 /// Calculate the size of known config files
 fn config_file_sizes(base: &Path) -> u64 {
     let files = ["config.json", "dictionary.json", "prompts.json"];
@@ -114,10 +294,13 @@ fn config_file_sizes(base: &Path) -> u64 {
 /// Get storage usage breakdown
 #[tauri::command]
 pub fn get_storage_usage() -> Result<StorageUsage, Error> {
-    let base = thoth_dir();
+    let base = data_dir_or_fallback();
 
     let models_bytes = dir_size(&base.join("models"));
-    let recordings_bytes = dir_size(&base.join("Recordings"));
+    let (recordings_bytes, recording_count) = recordings_usage(
+        &crate::audio::recordings_dir(),
+        crate::audio::uses_custom_recordings_dir(),
+    );
     let logs_bytes = dir_size(&base.join("logs"));
     let database_bytes = fs::metadata(base.join("thoth.db"))
         .map(|m| m.len())
@@ -125,7 +308,6 @@ pub fn get_storage_usage() -> Result<StorageUsage, Error> {
     let config_bytes = config_file_sizes(&base);
     let fluidaudio_bytes = fluidaudio_models_dir().map(|d| dir_size(&d)).unwrap_or(0);
 
-    let recording_count = file_count(&base.join("Recordings"));
     let log_count = file_count(&base.join("logs"));
 
     let total_bytes = models_bytes
@@ -149,34 +331,414 @@ pub fn get_storage_usage() -> Result<StorageUsage, Error> {
 }
 
 /// Delete all audio recordings
+///
+/// Operates on the configured recordings directory; in a user-chosen one only
+/// Thoth's own files are removed.
 #[tauri::command]
 pub fn delete_all_recordings() -> Result<u64, Error> {
-    let recordings_dir = thoth_dir().join("Recordings");
-    if !recordings_dir.exists() {
-        return Ok(0);
+    let deleted = delete_recordings_in(
+        &crate::audio::recordings_dir(),
+        crate::audio::uses_custom_recordings_dir(),
+    )?;
+    tracing::info!("Deleted {} recording files", deleted);
+    Ok(deleted)
+}
+
+/// Get the directory new recordings are written to
+#[tauri::command]
+pub fn get_recordings_location() -> RecordingsLocation {
+    RecordingsLocation {
+        path: crate::audio::recordings_dir()
+            .to_string_lossy()
+            .into_owned(),
+        is_custom: crate::audio::uses_custom_recordings_dir(),
     }
+}
 
-    let mut deleted = 0u64;
-    let entries = fs::read_dir(&recordings_dir).map_err(|e| e.to_string())?;
-    for entry in entries.flatten() {
-        let path = entry.path();
-        if path.is_file() {
-            if let Err(e) = fs::remove_file(&path) {
-                tracing::warn!("Failed to delete recording {:?}: {}", path, e);
-            } else {
-                deleted += 1;
+/// Change where new recordings are written (`None` restores the default)
+///
+/// The directory is created if needed and must be writable. Existing
+/// recordings are not moved.
+#[tauri::command]
+pub fn set_recordings_dir(path: Option<String>) -> Result<RecordingsLocation, Error> {
+    crate::config::set_recordings_dir_config(path)?;
+    Ok(get_recordings_location())
+}
+
+/// Get where Thoth keeps its data
+#[tauri::command]
+pub fn get_data_location() -> DataLocation {
+    let path = data_dir_or_fallback();
+    DataLocation {
+        is_custom: default_data_dir().is_none_or(|default| default != path),
+        path: path.to_string_lossy().into_owned(),
+        from_env: env_data_dir().is_some(),
+    }
+}
+
+/// Check that the data directory can move from `from` to `to`
+///
+/// `to` must be absolute, outside `from`, and either missing or empty (the
+/// location files aside, so data can move back to `~/.thoth`).
+fn validate_data_dir_target(from: &Path, to: &Path) -> Result<(), String> {
+    if !to.is_absolute() {
+        return Err(format!("{} is not an absolute path", to.display()));
+    }
+    if to == from {
+        return Err(format!("Thoth's data is already in {}", to.display()));
+    }
+    if to.starts_with(from) {
+        return Err(format!(
+            "{} is inside the current data directory",
+            to.display()
+        ));
+    }
+    if to.exists() {
+        if !to.is_dir() {
+            return Err(format!("{} is not a directory", to.display()));
+        }
+        let occupied = fs::read_dir(to)
+            .map_err(|e| format!("Failed to read {}: {}", to.display(), e))?
+            .flatten()
+            .any(|e| !is_location_file(&e.file_name()));
+        if occupied {
+            return Err(format!("{} is not empty", to.display()));
+        }
+    }
+    Ok(())
+}
+
+/// Size of a file, or of everything in a directory tree
+fn entry_size(path: &Path) -> u64 {
+    if path.is_dir() {
+        dir_size(path)
+    } else {
+        path.metadata().map(|m| m.len()).unwrap_or(0)
+    }
+}
+
+/// Copy a file or directory tree from `src` to `dst`, passing the size of
+/// each chunk written to `on_copied`
+fn copy_recursively(src: &Path, dst: &Path, on_copied: &mut dyn FnMut(u64)) -> std::io::Result<()> {
+    if src.is_dir() {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            copy_recursively(&entry.path(), &dst.join(entry.file_name()), on_copied)?;
+        }
+    } else {
+        copy_file(src, dst, on_copied)?;
+    }
+    Ok(())
+}
+
+/// Copy one file in [`COPY_CHUNK_BYTES`] chunks, keeping its permissions
+fn copy_file(src: &Path, dst: &Path, on_copied: &mut dyn FnMut(u64)) -> std::io::Result<()> {
+    let mut reader = fs::File::open(src)?;
+    let mut writer = fs::File::create(dst)?;
+    let mut buf = vec![0u8; COPY_CHUNK_BYTES];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        writer.write_all(&buf[..n])?;
+        on_copied(n as u64);
+    }
+    writer.sync_all()?;
+    fs::set_permissions(dst, reader.metadata()?.permissions())
+}
+
+/// Remove a file or directory tree
+fn remove_path(path: &Path) -> std::io::Result<()> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Move everything in `from` except the location files into `to`
+///
+/// Entries are renamed where possible and copied when `to` is on another
+/// drive. Originals of copies are only removed once every copy succeeded;
+/// if one fails, the copies are removed and renamed entries put back, so the
+/// data stays in one place. `on_progress` gets the bytes copied so far and
+/// the total to copy. Returns how many top-level entries moved.
+fn move_data_entries(
+    from: &Path,
+    to: &Path,
+    on_progress: &mut dyn FnMut(u64, u64),
+) -> Result<u64, String> {
+    fs::create_dir_all(to).map_err(|e| format!("Failed to create {}: {}", to.display(), e))?;
+
+    let names: Vec<_> = fs::read_dir(from)
+        .map_err(|e| format!("Failed to read {}: {}", from.display(), e))?
+        .flatten()
+        .map(|entry| entry.file_name())
+        .filter(|name| !is_location_file(name))
+        .collect();
+
+    let (renamed, to_copy): (Vec<_>, Vec<_>) = names
+        .into_iter()
+        .partition(|name| fs::rename(from.join(name), to.join(name)).is_ok());
+
+    let total: u64 = to_copy
+        .iter()
+        .map(|name| entry_size(&from.join(name)))
+        .sum();
+    let mut copied = 0u64;
+    on_progress(copied, total);
+    for (i, name) in to_copy.iter().enumerate() {
+        let mut on_copied = |bytes: u64| {
+            copied += bytes;
+            on_progress(copied, total);
+        };
+        if let Err(e) = copy_recursively(&from.join(name), &to.join(name), &mut on_copied) {
+            for name in &to_copy[..=i] {
+                let _ = remove_path(&to.join(name));
+            }
+            for name in &renamed {
+                let _ = fs::rename(to.join(name), from.join(name));
             }
+            return Err(format!(
+                "Failed to copy {}: {}",
+                from.join(name).display(),
+                e
+            ));
         }
     }
+    for name in &to_copy {
+        let src = from.join(name);
+        if let Err(e) = remove_path(&src) {
+            tracing::warn!("Copied {:?} but failed to remove the original: {}", src, e);
+        }
+    }
+    Ok((renamed.len() + to_copy.len()) as u64)
+}
+
+/// Point stored recording paths under `from` at the same files under `to`
+fn rewrite_audio_paths(db_path: &Path, from: &Path, to: &Path) -> Result<(), String> {
+    if !db_path.exists() {
+        return Ok(());
+    }
+    let old_prefix = format!("{}{}", from.display(), std::path::MAIN_SEPARATOR);
+    let new_prefix = format!("{}{}", to.display(), std::path::MAIN_SEPARATOR);
 
-    tracing::info!("Deleted {} recording files", deleted);
-    Ok(deleted)
+    let conn = rusqlite::Connection::open(db_path)
+        .map_err(|e| format!("Failed to open the moved database: {}", e))?;
+    for (table, column) in [
+        ("transcriptions", "audio_path"),
+        ("trash", "audio_path"),
+        ("trash", "original_path"),
+    ] {
+        let sql = format!(
+            "UPDATE {table} SET {column} = ?2 || substr({column}, length(?1) + 1) \
+             WHERE substr({column}, 1, length(?1)) = ?1"
+        );
+        conn.execute(&sql, rusqlite::params![old_prefix, new_prefix])
+            .map_err(|e| format!("Failed to update {}.{}: {}", table, column, e))?;
+    }
+    Ok(())
+}
+
+/// Move the data from `from` to `to` and point `~/.thoth` (`default`) at it
+///
+/// Moves the database, config, models, logs and default recordings
+/// directory, rewrites the pointer and updates the recording paths stored in
+/// the database. The pointer is written first: once the data has moved it
+/// must be found, so a failed path update is only logged (the recordings
+/// are still there, history just cannot play them). Returns how many
+/// top-level entries moved.
+fn move_data_dir(
+    default: &Path,
+    from: &Path,
+    to: &Path,
+    on_progress: &mut dyn FnMut(u64, u64),
+) -> Result<u64, String> {
+    validate_data_dir_target(from, to)?;
+    let moved = if from.exists() {
+        move_data_entries(from, to, on_progress)?
+    } else {
+        0
+    };
+
+    let pointer = default.join(DATA_DIR_POINTER);
+    if to == default {
+        if pointer.exists() {
+            fs::remove_file(&pointer)
+                .map_err(|e| format!("Failed to remove {}: {}", pointer.display(), e))?;
+        }
+    } else {
+        fs::create_dir_all(default)
+            .map_err(|e| format!("Failed to create {}: {}", default.display(), e))?;
+        fs::write(&pointer, to.to_string_lossy().as_bytes())
+            .map_err(|e| format!("Failed to write {}: {}", pointer.display(), e))?;
+    }
+
+    if let Err(e) = rewrite_audio_paths(&to.join("thoth.db"), from, to) {
+        tracing::warn!("Data moved, but recording paths were not updated: {}", e);
+    }
+
+    if from != default && from.exists() {
+        // A relocated directory is left empty once its data has moved.
+        let _ = fs::remove_dir(from);
+    }
+    Ok(moved)
+}
+
+/// Carry out a move [`migrate_data_dir`] recorded in `default`, if any
+///
+/// The request is dropped whatever the outcome, so a failed move leaves the
+/// data where it was instead of retrying on every start.
+fn finish_pending_move(
+    default: &Path,
+    on_progress: &mut dyn FnMut(u64, u64),
+) -> Option<Result<String, String>> {
+    let request = default.join(DATA_DIR_MOVE);
+    let raw = fs::read_to_string(&request).ok()?;
+    let _ = fs::remove_file(&request);
+    let to = PathBuf::from(raw.trim());
+    let from = read_data_dir_pointer(default).unwrap_or_else(|| default.to_path_buf());
+
+    Some(match move_data_dir(default, &from, &to, on_progress) {
+        Ok(moved) => Ok(format!(
+            "Moved {} data entries from {} to {}",
+            moved,
+            from.display(),
+            to.display()
+        )),
+        Err(e) => Err(format!(
+            "Failed to move the data from {} to {}: {}",
+            from.display(),
+            to.display(),
+            e
+        )),
+    })
+}
+
+/// Start a move [`migrate_data_dir`] recorded, if there is one
+///
+/// Called from setup, once the single-instance check has passed and before
+/// the database is opened, so a second launch or a CLI run never moves the
+/// data. The move runs on its own thread, reporting through
+/// [`DATA_DIR_MOVE_PROGRESS_EVENT`], and the app restarts when it is done to
+/// pick up the new location. Returns whether a move started, in which case
+/// the rest of setup is skipped.
+pub fn start_pending_move(app: &AppHandle) -> bool {
+    if env_data_dir().is_some() {
+        return false;
+    }
+    let Some(default) = default_data_dir() else {
+        return false;
+    };
+    if !default.join(DATA_DIR_MOVE).exists() {
+        return false;
+    }
+    MOVING_DATA_DIR.store(true, Ordering::SeqCst);
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let mut last_percent = None;
+        let mut bytes = (0, 0);
+        let mut on_progress = |copied: u64, total: u64| {
+            bytes = (copied, total);
+            let percent = (copied * 100).checked_div(total).unwrap_or(100);
+            if last_percent != Some(percent) {
+                last_percent = Some(percent);
+                crate::app_handle::emit(
+                    DATA_DIR_MOVE_PROGRESS_EVENT,
+                    DataDirMoveProgress {
+                        copied_bytes: copied,
+                        total_bytes: total,
+                        done: false,
+                        error: None,
+                    },
+                );
+            }
+        };
+        let outcome = finish_pending_move(&default, &mut on_progress);
+        let error = match outcome {
+            Some(Ok(message)) => {
+                tracing::info!("{}", message);
+                None
+            }
+            Some(Err(message)) => {
+                tracing::error!("{}", message);
+                Some(message)
+            }
+            None => None,
+        };
+        crate::app_handle::emit(
+            DATA_DIR_MOVE_PROGRESS_EVENT,
+            DataDirMoveProgress {
+                copied_bytes: bytes.0,
+                total_bytes: bytes.1,
+                done: true,
+                error,
+            },
+        );
+        // Long enough for the window to show how it went
+        std::thread::sleep(std::time::Duration::from_secs(2));
+        app.restart();
+    });
+    true
+}
+
+/// Whether the data directory is being moved, for the main window to show
+/// the move instead of initialising
+#[tauri::command]
+pub fn is_data_dir_moving() -> bool {
+    is_moving_data_dir()
+}
+
+/// Move all of Thoth's data to `new_path`
+///
+/// Checks the target and records the request in `~/.thoth`; the data moves
+/// at the next start, before the database is opened, so nothing written in
+/// between is lost. The caller relaunches the app.
+/// Unavailable while `THOTH_DATA_DIR` is set, since it would override the
+/// pointer.
+#[tauri::command]
+pub fn migrate_data_dir(new_path: String) -> Result<DataLocation, Error> {
+    if env_data_dir().is_some() {
+        return Err(format!(
+            "The data directory is set by {}; unset it to move the data",
+            DATA_DIR_ENV
+        )
+        .into());
+    }
+    let default = default_data_dir().ok_or("Could not find home directory")?;
+    let from = data_dir_or_fallback();
+    let to = PathBuf::from(new_path.trim());
+    validate_data_dir_target(&from, &to)?;
+
+    // The config must be on disk before it moves.
+    crate::config::flush_pending_save()?;
+    fs::create_dir_all(&default)
+        .map_err(|e| format!("Failed to create {}: {}", default.display(), e))?;
+    let request = default.join(DATA_DIR_MOVE);
+    fs::write(&request, to.to_string_lossy().as_bytes())
+        .map_err(|e| format!("Failed to write {}: {}", request.display(), e))?;
+
+    tracing::info!(
+        "Data will move from {} to {} on restart",
+        from.display(),
+        to.display()
+    );
+    Ok(DataLocation {
+        is_custom: to != default,
+        path: to.to_string_lossy().into_owned(),
+        from_env: false,
+    })
 }
 
 /// Delete all log files
 #[tauri::command]
 pub fn delete_all_logs() -> Result<u64, Error> {
-    let logs_dir = thoth_dir().join("logs");
+    let logs_dir = data_dir_or_fallback().join("logs");
     if !logs_dir.exists() {
         return Ok(0);
     }
@@ -217,7 +779,7 @@ pub fn delete_fluidaudio_cache() -> Result<(), Error> {
     })?;
 
     // Also remove the ready marker so Model Manager reflects the change
-    let marker_dir = thoth_dir()
+    let marker_dir = data_dir_or_fallback()
         .join("models")
         .join("fluidaudio-parakeet-tdt-coreml");
     let marker_path = marker_dir.join(".fluidaudio_ready");
@@ -231,16 +793,32 @@ pub fn delete_fluidaudio_cache() -> Result<(), Error> {
 
 /// Delete ALL Thoth data (full reset / uninstall cleanup)
 ///
-/// Removes ~/.thoth/ and ~/Library/Application Support/FluidAudio/Models/
+/// Removes ~/.thoth/ and ~/Library/Application Support/FluidAudio/Models/,
+/// plus Thoth's recordings in a custom recordings directory.
 #[tauri::command]
 pub fn delete_all_data() -> Result<(), Error> {
-    let base = thoth_dir();
+    if crate::audio::uses_custom_recordings_dir() {
+        let dir = crate::audio::recordings_dir();
+        match delete_recordings_in(&dir, true) {
+            Ok(n) => tracing::info!("Deleted {} recordings from {}", n, dir.display()),
+            Err(e) => tracing::warn!("Failed to clear recordings in {}: {}", dir.display(), e),
+        }
+    }
+
+    let base = data_dir_or_fallback();
     if base.exists() {
         fs::remove_dir_all(&base)
             .map_err(|e| format!("Failed to delete Thoth data at {}: {}", base.display(), e))?;
         tracing::info!("Deleted Thoth data directory: {}", base.display());
     }
 
+    // A relocated data directory leaves its pointer behind in ~/.thoth.
+    if let Some(default) = default_data_dir().filter(|d| *d != base) {
+        let _ = fs::remove_file(default.join(DATA_DIR_POINTER));
+        let _ = fs::remove_file(default.join(DATA_DIR_MOVE));
+        let _ = fs::remove_dir(&default);
+    }
+
     if let Some(fluid_dir) = fluidaudio_models_dir() {
         if fluid_dir.exists() {
             fs::remove_dir_all(&fluid_dir).map_err(|e| {
@@ -264,12 +842,170 @@ pub fn delete_all_data() -> Result<(), Error> {
 mod tests {
     use super::*;
 
+    #[serial_test::serial]
     #[test]
     fn test_thoth_dir_path() {
-        let dir = thoth_dir();
+        let dir = data_dir_or_fallback();
         assert!(dir.to_string_lossy().contains(".thoth"));
     }
 
+    #[test]
+    fn test_data_dir_without_home() {
+        assert_eq!(resolve_data_dir_from(None, None), None);
+        assert_eq!(
+            resolve_data_dir_from(Some(PathBuf::from("/srv/thoth")), None),
+            Some(PathBuf::from("/srv/thoth"))
+        );
+    }
+
+    #[test]
+    fn test_data_dir_target_must_be_empty_and_outside() {
+        let root = tempfile::tempdir().unwrap();
+        let from = root.path().join("from");
+        fs::create_dir_all(&from).unwrap();
+
+        assert!(validate_data_dir_target(&from, &from).is_err());
+        assert!(validate_data_dir_target(&from, &from.join("nested")).is_err());
+        assert!(validate_data_dir_target(&from, Path::new("relative")).is_err());
+        assert!(validate_data_dir_target(&from, &root.path().join("new")).is_ok());
+
+        let occupied = root.path().join("occupied");
+        fs::create_dir_all(&occupied).unwrap();
+        fs::write(occupied.join(DATA_DIR_POINTER), "/elsewhere").unwrap();
+        assert!(validate_data_dir_target(&from, &occupied).is_ok());
+        fs::write(occupied.join("config.json"), "{}").unwrap();
+        assert!(validate_data_dir_target(&from, &occupied).is_err());
+    }
+
+    #[test]
+    fn test_move_data_entries_leaves_pointer() {
+        let root = tempfile::tempdir().unwrap();
+        let from = root.path().join("from");
+        let to = root.path().join("to");
+        fs::create_dir_all(from.join("Recordings")).unwrap();
+        fs::write(from.join("Recordings").join("a.wav"), b"RIFF").unwrap();
+        fs::write(from.join("config.json"), b"{}").unwrap();
+        fs::write(from.join(DATA_DIR_POINTER), "/elsewhere").unwrap();
+
+        assert_eq!(move_data_entries(&from, &to, &mut |_, _| {}).unwrap(), 2);
+        assert!(to.join("Recordings").join("a.wav").exists());
+        assert!(to.join("config.json").exists());
+        assert!(from.join(DATA_DIR_POINTER).exists());
+        assert!(!to.join(DATA_DIR_POINTER).exists());
+    }
+
+    #[test]
+    fn test_move_data_entries_failure_leaves_data() {
+        let root = tempfile::tempdir().unwrap();
+        let from = root.path().join("from");
+        fs::create_dir_all(&from).unwrap();
+        fs::write(from.join("config.json"), b"{}").unwrap();
+        // A file where the target directory should be makes every move fail
+        let to = root.path().join("to");
+        fs::write(&to, b"").unwrap();
+
+        assert!(move_data_entries(&from, &to, &mut |_, _| {}).is_err());
+        assert!(from.join("config.json").exists());
+    }
+
+    #[test]
+    fn test_pending_move_runs_once() {
+        let root = tempfile::tempdir().unwrap();
+        let default = root.path().join(".thoth");
+        let to = root.path().join("elsewhere");
+        fs::create_dir_all(&default).unwrap();
+        fs::write(default.join("config.json"), b"{}").unwrap();
+        fs::write(default.join(DATA_DIR_MOVE), to.to_string_lossy().as_bytes()).unwrap();
+
+        assert!(
+            finish_pending_move(&default, &mut |_, _| {})
+                .unwrap()
+                .is_ok()
+        );
+        assert!(to.join("config.json").exists());
+        assert!(!default.join("config.json").exists());
+        assert!(!default.join(DATA_DIR_MOVE).exists());
+        assert_eq!(read_data_dir_pointer(&default), Some(to.clone()));
+        assert!(finish_pending_move(&default, &mut |_, _| {}).is_none());
+
+        // And back again, dropping the pointer
+        fs::write(
+            default.join(DATA_DIR_MOVE),
+            default.to_string_lossy().as_bytes(),
+        )
+        .unwrap();
+        assert!(
+            finish_pending_move(&default, &mut |_, _| {})
+                .unwrap()
+                .is_ok()
+        );
+        assert!(default.join("config.json").exists());
+        assert_eq!(read_data_dir_pointer(&default), None);
+        assert!(!to.exists());
+    }
+
+    #[test]
+    fn test_copy_reports_copied_bytes() {
+        let root = tempfile::tempdir().unwrap();
+        let from = root.path().join("from");
+        fs::create_dir_all(from.join("models")).unwrap();
+        fs::write(from.join("models").join("model.bin"), vec![0u8; 3000]).unwrap();
+        let to = root.path().join("to");
+
+        let mut copied = 0;
+        let mut on_copied = |bytes: u64| copied += bytes;
+        copy_recursively(&from.join("models"), &to.join("models"), &mut on_copied).unwrap();
+        assert_eq!(copied, 3000);
+        assert_eq!(
+            fs::read(to.join("models").join("model.bin")).unwrap().len(),
+            3000
+        );
+    }
+
+    #[test]
+    fn test_pointer_written_when_path_update_fails() {
+        let root = tempfile::tempdir().unwrap();
+        let default = root.path().join(".thoth");
+        let to = root.path().join("elsewhere");
+        fs::create_dir_all(&default).unwrap();
+        fs::write(default.join("thoth.db"), b"not a database").unwrap();
+
+        assert!(move_data_dir(&default, &default, &to, &mut |_, _| {}).is_ok());
+        assert!(to.join("thoth.db").exists());
+        assert_eq!(read_data_dir_pointer(&default), Some(to));
+    }
+
+    #[test]
+    fn test_rewrite_audio_paths() {
+        let root = tempfile::tempdir().unwrap();
+        let db = root.path().join("thoth.db");
+        let conn = rusqlite::Connection::open(&db).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE transcriptions (id TEXT, audio_path TEXT);
+             CREATE TABLE trash (id TEXT, audio_path TEXT, original_path TEXT);
+             INSERT INTO transcriptions VALUES ('a', '/old/Recordings/a.wav');
+             INSERT INTO transcriptions VALUES ('b', '/elsewhere/b.wav');
+             INSERT INTO transcriptions VALUES ('c', '/old2/c.wav');",
+        )
+        .unwrap();
+        drop(conn);
+
+        rewrite_audio_paths(&db, Path::new("/old"), Path::new("/new")).unwrap();
+
+        let conn = rusqlite::Connection::open(&db).unwrap();
+        let paths: Vec<String> = conn
+            .prepare("SELECT audio_path FROM transcriptions ORDER BY id")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            paths,
+            vec!["/new/Recordings/a.wav", "/elsewhere/b.wav", "/old2/c.wav"]
+        );
+    }
+
     #[test]
     fn test_fluidaudio_dir_path() {
         let dir = fluidaudio_models_dir();
@@ -289,6 +1025,22 @@ mod tests {
         assert_eq!(dir_size(&path), 0);
     }
 
+    #[test]
+    fn test_custom_dir_cleanup_only_touches_thoth_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let ours = dir.path().join("thoth_import_20250101_120000_000.wav");
+        let theirs = dir.path().join("podcast.wav");
+        fs::write(&ours, b"RIFF").unwrap();
+        fs::write(&theirs, b"RIFFRIFF").unwrap();
+
+        assert_eq!(recordings_usage(dir.path(), true), (4, 1));
+        assert_eq!(recordings_usage(dir.path(), false), (12, 2));
+
+        assert_eq!(delete_recordings_in(dir.path(), true).unwrap(), 1);
+        assert!(!ours.exists());
+        assert!(theirs.exists());
+    }
+
     #[test]
     fn test_file_count_nonexistent() {
         let path = PathBuf::from("/nonexistent/path/that/doesnt/exist");
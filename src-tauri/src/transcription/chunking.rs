@@ -0,0 +1,292 @@
+//! Transcription of long files in overlapping windows
+//!
+//! Running a lecture-length recording through the model in one go holds the
+//! whole decoded buffer and a correspondingly large model state in memory.
+//! Instead the WAV is read a window at a time, each window is transcribed on
+//! its own, and the pieces are joined. Windows overlap by
+//! [`CHUNK_OVERLAP_SECS`] so a word cut at a boundary is heard whole in one of
+//! them; the words both windows heard are dropped from the second. Timed
+//! segments, when kept, are shifted by their window's start so they read as
+//! times in the whole file.
+
+use super::{TranscriptSegment, TranscriptionService};
+use crate::audio::decode::CANCELLED_ERROR;
+use anyhow::{Result, anyhow};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Seconds each window shares with the one before it
+pub const CHUNK_OVERLAP_SECS: u32 = 2;
+
+/// Fewest repeated words taken as overlap; a single shared word at a seam
+/// is as likely to be a genuine repeat ("that that") as a duplicate
+const MIN_OVERLAP_WORDS: usize = 2;
+
+/// Most words searched for at a seam; two seconds of speech is well under this
+const MAX_OVERLAP_WORDS: usize = 20;
+
+/// Start and end sample of each window covering `total` samples
+fn plan_chunks(total: usize, chunk: usize, overlap: usize) -> Vec<(usize, usize)> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    loop {
+        let end = (start + chunk).min(total);
+        chunks.push((start, end));
+        if end == total {
+            return chunks;
+        }
+        start = end - overlap;
+    }
+}
+
+/// A word as compared at a seam: lowercase, without punctuation
+fn normalise_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// `next` without the words it repeats from the end of `previous`
+fn drop_overlap(previous: &str, next: &str) -> String {
+    let previous: Vec<&str> = previous.split_whitespace().collect();
+    let next: Vec<&str> = next.split_whitespace().collect();
+    let tail: Vec<String> = previous[previous.len().saturating_sub(MAX_OVERLAP_WORDS)..]
+        .iter()
+        .map(|word| normalise_word(word))
+        .collect();
+    let head: Vec<String> = next
+        .iter()
+        .take(MAX_OVERLAP_WORDS)
+        .map(|word| normalise_word(word))
+        .collect();
+
+    let longest = tail.len().min(head.len());
+    let repeated = (MIN_OVERLAP_WORDS..=longest)
+        .rev()
+        .find(|&count| tail[tail.len() - count..] == head[..count])
+        .unwrap_or(0);
+    next[repeated..].join(" ")
+}
+
+/// Append a window's transcript to `text`, dropping the words it repeats
+/// from the end of `text`. Returns how many words were dropped.
+fn stitch_onto(text: &mut String, part: &str) -> usize {
+    let kept = drop_overlap(text, part);
+    let dropped = part.split_whitespace().count() - kept.split_whitespace().count();
+    if !kept.is_empty() {
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&kept);
+    }
+    dropped
+}
+
+/// Join the windows' transcripts, dropping the words repeated at each seam
+#[cfg(test)]
+fn stitch(parts: &[String]) -> String {
+    let mut text = String::new();
+    for part in parts {
+        stitch_onto(&mut text, part);
+    }
+    text
+}
+
+/// A window's segments without their first `words` words, with times moved
+/// on by `offset_secs`
+fn trim_segments(
+    segments: Vec<TranscriptSegment>,
+    mut words: usize,
+    offset_secs: f32,
+) -> Vec<TranscriptSegment> {
+    let mut kept = Vec::with_capacity(segments.len());
+    for mut segment in segments {
+        let count = segment.text.split_whitespace().count();
+        if words >= count {
+            words -= count;
+            continue;
+        }
+        if words > 0 {
+            segment.text = segment
+                .text
+                .split_whitespace()
+                .skip(words)
+                .collect::<Vec<_>>()
+                .join(" ");
+            words = 0;
+        }
+        segment.start_secs = segment.start_secs.map(|s| s + offset_secs);
+        segment.end_secs = segment.end_secs.map(|s| s + offset_secs);
+        kept.push(segment);
+    }
+    kept
+}
+
+/// A long file's transcript, and its timed segments when they were asked for
+pub(crate) struct ChunkedTranscript {
+    pub text: String,
+    pub segments: Option<Vec<TranscriptSegment>>,
+}
+
+/// Transcribe a 16 kHz mono 16-bit WAV in windows of `chunk_secs`
+///
+/// Returns `None` when the file fits in one window (or is in a format read
+/// whole elsewhere), leaving the caller to transcribe it as usual. With
+/// `keep_segments`, each window's segments are kept, timed from the start of
+/// the file. Fails with "Import cancelled" at the next window once
+/// `cancelled` is raised.
+pub(crate) fn transcribe_chunked(
+    service: &mut TranscriptionService,
+    wav_path: &Path,
+    chunk_secs: u32,
+    keep_segments: bool,
+    cancelled: Option<&AtomicBool>,
+) -> Result<Option<ChunkedTranscript>> {
+    let mut reader = hound::WavReader::open(wav_path)
+        .map_err(|e| anyhow!("Failed to open {}: {}", wav_path.display(), e))?;
+    let spec = reader.spec();
+    if spec.channels != 1
+        || spec.sample_rate != 16_000
+        || spec.sample_format != hound::SampleFormat::Int
+        || spec.bits_per_sample != 16
+    {
+        return Ok(None);
+    }
+
+    let rate = spec.sample_rate as usize;
+    let chunk = chunk_secs as usize * rate;
+    let overlap = CHUNK_OVERLAP_SECS as usize * rate;
+    let total = reader.duration() as usize;
+    if chunk <= overlap || total <= chunk + overlap {
+        return Ok(None);
+    }
+
+    let chunks = plan_chunks(total, chunk, overlap);
+    tracing::info!(
+        "Transcribing {:.0}s of audio in {} windows of {}s",
+        total as f64 / rate as f64,
+        chunks.len(),
+        chunk_secs
+    );
+
+    let mut text = String::new();
+    let mut all_segments = keep_segments.then(Vec::new);
+    for (i, &(start, end)) in chunks.iter().enumerate() {
+        if cancelled.is_some_and(|c| c.load(Ordering::SeqCst)) {
+            tracing::info!("Cancelled after {} of {} windows", i, chunks.len());
+            return Err(anyhow!(CANCELLED_ERROR));
+        }
+        reader.seek(start as u32)?;
+        let samples = reader
+            .samples::<i16>()
+            .take(end - start)
+            .map(|s| s.map(|v| v as f32 / 32768.0))
+            .collect::<std::result::Result<Vec<f32>, _>>()?;
+        let (part, segments) = if keep_segments {
+            let segments = service.transcribe_pcm_segments(samples)?;
+            let part = segments
+                .iter()
+                .map(|s| s.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            (part, Some(segments))
+        } else {
+            (service.transcribe_pcm(samples)?, None)
+        };
+        tracing::debug!(
+            "Window {}/{} ({:.0}s-{:.0}s): {} chars",
+            i + 1,
+            chunks.len(),
+            start as f64 / rate as f64,
+            end as f64 / rate as f64,
+            part.len()
+        );
+        let dropped = stitch_onto(&mut text, &part);
+        if let (Some(all), Some(segments)) = (all_segments.as_mut(), segments) {
+            all.extend(trim_segments(segments, dropped, start as f32 / rate as f32));
+        }
+    }
+
+    Ok(Some(ChunkedTranscript {
+        text,
+        segments: all_segments,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_chunks_overlap() {
+        assert_eq!(
+            plan_chunks(250, 100, 10),
+            vec![(0, 100), (90, 190), (180, 250)]
+        );
+        assert_eq!(plan_chunks(100, 100, 10), vec![(0, 100)]);
+    }
+
+    #[test]
+    fn test_drop_overlap_removes_repeated_words() {
+        assert_eq!(
+            drop_overlap(
+                "the lecture starts with the history of Rome.",
+                "History of Rome. Then we move on"
+            ),
+            "Then we move on"
+        );
+    }
+
+    #[test]
+    fn test_drop_overlap_keeps_unrelated_text() {
+        assert_eq!(drop_overlap("first part", "second part"), "second part");
+        // A single shared word is not enough to count as overlap
+        assert_eq!(drop_overlap("said that", "that is"), "that is");
+        assert_eq!(drop_overlap("", "start here"), "start here");
+    }
+
+    fn segment(text: &str, start: f32, end: f32) -> TranscriptSegment {
+        TranscriptSegment {
+            text: text.to_string(),
+            confidence: None,
+            start_secs: Some(start),
+            end_secs: Some(end),
+        }
+    }
+
+    #[test]
+    fn test_trim_segments_drops_overlap_and_offsets() {
+        let segments = vec![
+            segment("basics of", 0.0, 1.5),
+            segment("thermodynamics and", 1.5, 3.0),
+            segment("entropy.", 6.0, 7.0),
+        ];
+        let trimmed = trim_segments(segments, 3, 28.0);
+        assert_eq!(
+            trimmed,
+            vec![segment("and", 29.5, 31.0), segment("entropy.", 34.0, 35.0)]
+        );
+    }
+
+    #[test]
+    fn test_stitch_onto_reports_dropped_words() {
+        let mut text = "We begin with the basics of".to_string();
+        assert_eq!(stitch_onto(&mut text, "basics of thermodynamics"), 2);
+        assert_eq!(text, "We begin with the basics of thermodynamics");
+    }
+
+    #[test]
+    fn test_stitch_joins_windows() {
+        let parts = vec![
+            "We begin with the basics of".to_string(),
+            "basics of thermodynamics and".to_string(),
+            "".to_string(),
+            "thermodynamics and entropy.".to_string(),
+        ];
+        assert_eq!(
+            stitch(&parts),
+            "We begin with the basics of thermodynamics and entropy."
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Context carried from one dictation into the next.
+//!
+//! With `config.transcription.context_carryover` on, the tail of each
+//! dictation is kept in memory and given to Whisper as the `initial_prompt`
+//! of the next one, so names and style stay consistent when a document is
+//! dictated in several chunks. The context is dropped once
+//! `context_carryover_window_secs` pass without a dictation, or when the
+//! frontmost app changes (where the platform can report it). Other backends
+//! ignore the prompt.
+
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+
+/// Longest prompt carried over, in characters. Whisper only reads the last
+/// 224 tokens of a prompt; this keeps well inside that.
+const MAX_PROMPT_CHARS: usize = 400;
+
+/// The previous dictation, as kept for the next one
+#[derive(Debug)]
+struct Entry {
+    tail: String,
+    app: Option<String>,
+    at: Instant,
+}
+
+/// Holds the last dictation's tail until it goes stale
+#[derive(Debug, Default)]
+struct Carryover {
+    last: Option<Entry>,
+}
+
+impl Carryover {
+    /// Prompt for a dictation into `app`, dropping the context when the
+    /// window has elapsed or the app changed.
+    fn prompt(&mut self, app: Option<&str>, window: Duration, now: Instant) -> Option<String> {
+        let fresh = self.last.as_ref().is_some_and(|entry| {
+            now.saturating_duration_since(entry.at) <= window && entry.app.as_deref() == app
+        });
+        if !fresh {
+            self.last = None;
+        }
+        self.last.as_ref().map(|entry| entry.tail.clone())
+    }
+
+    /// Keep the tail of `text`, dictated into `app`, for the next dictation.
+    fn remember(&mut self, text: &str, app: Option<String>, now: Instant) {
+        let tail = tail(text, MAX_PROMPT_CHARS);
+        if tail.is_empty() {
+            return;
+        }
+        self.last = Some(Entry { tail, app, at: now });
+    }
+}
+
+static CARRYOVER: Mutex<Carryover> = Mutex::new(Carryover { last: None });
+
+/// The carryover window, or `None` when carryover is turned off.
+fn window() -> Option<Duration> {
+    let config = crate::config::get_config().ok()?.transcription;
+    config
+        .context_carryover
+        .then(|| Duration::from_secs(u64::from(config.context_carryover_window_secs)))
+}
+
+/// Whether context carryover is turned on
+pub fn enabled() -> bool {
+    window().is_some()
+}
+
+/// Prompt for the next dictation into `app`, when carryover is on and the
+/// previous dictation is recent enough.
+pub fn prompt_for(app: Option<&str>) -> Option<String> {
+    let Some(window) = window() else {
+        clear();
+        return None;
+    };
+    CARRYOVER.lock().prompt(app, window, Instant::now())
+}
+
+/// Keep the tail of a finished dictation for the next one.
+pub fn remember(text: &str, app: Option<String>) {
+    if window().is_some() {
+        CARRYOVER.lock().remember(text, app, Instant::now());
+    }
+}
+
+/// Forget the carried context.
+pub fn clear() {
+    CARRYOVER.lock().last = None;
+}
+
+/// The last whole words of `text` that fit in `max_chars`.
+fn tail(text: &str, max_chars: usize) -> String {
+    let mut words = Vec::new();
+    let mut len = 0;
+    for word in text.split_whitespace().rev() {
+        let added = word.chars().count() + usize::from(!words.is_empty());
+        if len + added > max_chars {
+            break;
+        }
+        len += added;
+        words.push(word);
+    }
+    words.reverse();
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_prompt_carries_previous_tail() {
+        let start = Instant::now();
+        let mut carryover = Carryover::default();
+        assert_eq!(carryover.prompt(Some("Pages"), WINDOW, start), None);
+
+        carryover.remember(
+            "Chapter three begins with Aoife.",
+            Some("Pages".into()),
+            start,
+        );
+        assert_eq!(
+            carryover
+                .prompt(Some("Pages"), WINDOW, start + Duration::from_secs(30))
+                .as_deref(),
+            Some("Chapter three begins with Aoife.")
+        );
+    }
+
+    #[test]
+    fn test_prompt_cleared_after_window() {
+        let start = Instant::now();
+        let mut carryover = Carryover::default();
+        carryover.remember("Some earlier text", None, start);
+        assert_eq!(
+            carryover.prompt(None, WINDOW, start + Duration::from_secs(61)),
+            None
+        );
+        // Stays cleared even if asked again within the old window.
+        assert_eq!(carryover.prompt(None, WINDOW, start), None);
+    }
+
+    #[test]
+    fn test_prompt_cleared_when_app_changes() {
+        let start = Instant::now();
+        let mut carryover = Carryover::default();
+        carryover.remember("Dear Siobhan,", Some("Mail".into()), start);
+        assert_eq!(carryover.prompt(Some("Slack"), WINDOW, start), None);
+        assert_eq!(carryover.prompt(Some("Mail"), WINDOW, start), None);
+    }
+
+    #[test]
+    fn test_empty_text_keeps_previous_context() {
+        let start = Instant::now();
+        let mut carryover = Carryover::default();
+        carryover.remember("First part.", None, start);
+        carryover.remember("   ", None, start);
+        assert_eq!(
+            carryover.prompt(None, WINDOW, start).as_deref(),
+            Some("First part.")
+        );
+    }
+
+    #[test]
+    fn test_tail_keeps_whole_trailing_words() {
+        assert_eq!(tail("one two three four", 10), "three four");
+        assert_eq!(tail("one two", 100), "one two");
+        assert_eq!(tail("  spaced   out  ", 100), "spaced out");
+        assert_eq!(tail("unbreakableword", 5), "");
+    }
+}
@@ -4,16 +4,19 @@
 //! - Direct file downloads (whisper.cpp ggml models)
 //! - Archive downloads with extraction (sherpa-onnx models)
 
-use super::manifest::{RemoteModelInfo, get_fallback_manifest, get_model_directory};
+use super::manifest::{
+    RemoteModelInfo, get_fallback_manifest, get_model_directory, get_models_root_directory,
+};
 use crate::error::Error;
 use anyhow::{Result, anyhow};
 use parking_lot::Mutex;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use tauri::{AppHandle, Emitter};
 use tokio::io::AsyncWriteExt;
+use tokio::sync::Notify;
 
 /// Download progress information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +33,40 @@ pub struct DownloadProgress {
     pub status: String,
 }
 
+/// Event emitted when a model being downloaded is likely too large for the
+/// GPU or cannot transcribe the configured language (payload: [`ModelFitWarning`])
+pub const MODEL_FIT_WARNING_EVENT: &str = "model-fit-warning";
+
+/// Payload of [`MODEL_FIT_WARNING_EVENT`]
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelFitWarning {
+    /// The model being downloaded
+    pub model_id: String,
+    /// Human-readable warning, recommending a different model
+    pub message: String,
+}
+
+/// A model directory found on disk
+#[derive(Debug, Clone, Serialize)]
+pub struct DownloadedModel {
+    /// Model ID (the directory name for models not in the manifest)
+    pub id: String,
+    /// Size on disk in bytes, including any partial files
+    pub size_bytes: u64,
+    /// Path to the model directory
+    pub path: String,
+    /// Backend type from the manifest (e.g. "whisper_ggml"), or "unknown"
+    pub model_type: String,
+    /// Whether this is the selected transcription model
+    pub active: bool,
+    /// False when required files are missing or an unfinished download
+    /// (a `.part` file or an unextracted archive) is still present
+    pub complete: bool,
+}
+
+/// Archive kept in the model directory until extraction finishes
+const ARCHIVE_FILE_NAME: &str = "model-archive.tar.bz2";
+
 /// Download state tracking
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DownloadState {
@@ -52,6 +89,17 @@ fn get_download_state() -> &'static Mutex<DownloadState> {
     DOWNLOAD_STATE.get_or_init(|| Mutex::new(DownloadState::Idle))
 }
 
+/// The HTTP download in flight, so [`cancel_download`] can stop it
+struct ActiveDownload {
+    model_id: String,
+    cancel: Arc<Notify>,
+}
+
+static ACTIVE_DOWNLOAD: Mutex<Option<ActiveDownload>> = Mutex::new(None);
+
+/// Extraction scratch directory inside a model directory
+const EXTRACT_DIR_NAME: &str = "_extract_temp";
+
 /// Check if the model files are downloaded and valid
 #[tauri::command]
 pub fn check_model_downloaded(model_id: Option<String>) -> bool {
@@ -177,6 +225,41 @@ pub async fn download_model(app: AppHandle, model_id: Option<String>) -> Result<
         .cloned()
         .ok_or_else(|| format!("Model not found: {}", model_id))?;
 
+    // Too large for the GPU is a warning, not a block: the estimate is rough,
+    // and the user may still want the model.
+    if let Some(warning) =
+        super::manifest::vram_warning(model.vram_mb, super::manifest::detected_vram_mb())
+    {
+        tracing::warn!("Model {} may not fit in GPU memory: {}", model_id, warning);
+        let payload = ModelFitWarning {
+            model_id: model_id.clone(),
+            message: warning,
+        };
+        if let Err(e) = app.emit(MODEL_FIT_WARNING_EVENT, payload) {
+            tracing::warn!("Failed to emit {}: {}", MODEL_FIT_WARNING_EVENT, e);
+        }
+    }
+
+    // Likewise an English-only model when another language is configured.
+    let language = crate::config::get_config()
+        .map(|c| c.transcription.language)
+        .unwrap_or_default();
+    if let Some(warning) = super::manifest::language_warning(&model, &language) {
+        tracing::warn!(
+            "Model {} may not suit language {:?}: {}",
+            model_id,
+            language,
+            warning
+        );
+        let payload = ModelFitWarning {
+            model_id: model_id.clone(),
+            message: warning,
+        };
+        if let Err(e) = app.emit(MODEL_FIT_WARNING_EVENT, payload) {
+            tracing::warn!("Failed to emit {}: {}", MODEL_FIT_WARNING_EVENT, e);
+        }
+    }
+
     // FluidAudio models: init_asr() handles download + CoreML compilation
     if model.model_type == "fluidaudio_coreml" {
         {
@@ -207,7 +290,7 @@ pub async fn download_model(app: AppHandle, model_id: Option<String>) -> Result<
             },
         );
 
-        let result = tokio::task::spawn_blocking(super::init_fluidaudio_transcription)
+        let result = tokio::task::spawn_blocking(super::load_fluidaudio)
             .await
             .map_err(|e| format!("FluidAudio init task panicked: {}", e))?;
 
@@ -240,8 +323,26 @@ pub async fn download_model(app: AppHandle, model_id: Option<String>) -> Result<
         *state = DownloadState::Downloading;
     }
 
-    // Run the download in the background
-    let result = download_and_extract_model(&app, &model).await;
+    let cancel = Arc::new(Notify::new());
+    *ACTIVE_DOWNLOAD.lock() = Some(ActiveDownload {
+        model_id: model_id.clone(),
+        cancel: Arc::clone(&cancel),
+    });
+
+    // Dropping the download future on cancel aborts the request and closes
+    // the file, so the leftovers can be removed straight after
+    let result = tokio::select! {
+        result = download_and_extract_model(&app, &model) => Some(result),
+        _ = cancel.notified() => None,
+    };
+    *ACTIVE_DOWNLOAD.lock() = None;
+
+    let Some(result) = result else {
+        remove_partial_download(&get_model_directory(&model_id), direct_file_name(&model));
+        *get_download_state().lock() = DownloadState::Idle;
+        tracing::info!("Download of {} cancelled", model_id);
+        return Ok(());
+    };
 
     match result {
         Ok(()) => {
@@ -281,6 +382,15 @@ fn is_direct_download(model: &RemoteModelInfo) -> bool {
     false
 }
 
+/// File a direct download writes in place (and leaves half-written if stopped)
+fn direct_file_name(model: &RemoteModelInfo) -> Option<&str> {
+    if is_direct_download(model) {
+        model.required_files.first().map(String::as_str)
+    } else {
+        None
+    }
+}
+
 /// Internal function to download and extract the model
 /// Trusted domains for model downloads
 const TRUSTED_DOWNLOAD_DOMAINS: &[&str] = &[
@@ -377,7 +487,7 @@ async fn download_and_extract_model(app: &AppHandle, model: &RemoteModelInfo) ->
         tracing::info!("Direct download complete: {}", dest_path.display());
     } else {
         // For archives, download and extract
-        let archive_path = model_dir.join("model-archive.tar.bz2");
+        let archive_path = model_dir.join(ARCHIVE_FILE_NAME);
         download_file_with_progress(app, &model.download_url, &archive_path, &model.name).await?;
 
         // Verify SHA256 if provided
@@ -681,7 +791,7 @@ fn extract_tar_bz2(
 
     // The archive contains a directory with the model files
     // We need to extract the files to the correct location
-    let temp_extract_dir = dest_dir.join("_extract_temp");
+    let temp_extract_dir = dest_dir.join(EXTRACT_DIR_NAME);
     std::fs::create_dir_all(&temp_extract_dir)?;
 
     let canonical_dest = temp_extract_dir.canonicalize()?;
@@ -765,17 +875,133 @@ fn emit_progress(app: &AppHandle, progress: DownloadProgress) {
 #[tauri::command]
 pub fn get_model_info() -> Vec<super::manifest::ModelInfo> {
     let manifest = get_fallback_manifest();
-    let selected_id = crate::config::get_config()
-        .ok()
+    let config = crate::config::get_config().ok();
+    let selected_id = config
+        .as_ref()
         .and_then(|c| c.transcription.model_id.clone());
+    let language = config.map(|c| c.transcription.language).unwrap_or_default();
 
+    let available_vram_mb = super::manifest::detected_vram_mb();
     manifest
         .models
         .iter()
-        .map(|m| super::manifest::to_model_info(m, selected_id.as_deref()))
+        .map(|m| {
+            super::manifest::to_model_info(m, selected_id.as_deref(), &language, available_vram_mb)
+        })
         .collect()
 }
 
+/// Whether a model directory holds leftovers of an unfinished download
+fn has_partial_download(model_dir: &Path) -> bool {
+    std::fs::read_dir(model_dir)
+        .map(|entries| {
+            entries.flatten().any(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.ends_with(".part") || name == ARCHIVE_FILE_NAME
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Remove what an unfinished download left in `model_dir`: `.part` files,
+/// the archive, the extraction scratch directory and, for a direct download,
+/// its half-written `direct_file`. The directory goes too if that empties it.
+fn remove_partial_download(model_dir: &Path, direct_file: Option<&str>) {
+    let Ok(entries) = std::fs::read_dir(model_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let path = entry.path();
+        let result = if name == EXTRACT_DIR_NAME {
+            std::fs::remove_dir_all(&path)
+        } else if name.ends_with(".part")
+            || name == ARCHIVE_FILE_NAME
+            || direct_file == Some(&*name)
+        {
+            std::fs::remove_file(&path)
+        } else {
+            continue;
+        };
+        match result {
+            Ok(()) => tracing::info!("Removed partial download: {}", path.display()),
+            Err(e) => tracing::warn!("Failed to remove {}: {}", path.display(), e),
+        }
+    }
+
+    let is_empty = std::fs::read_dir(model_dir)
+        .map(|mut entries| entries.next().is_none())
+        .unwrap_or(false);
+    if is_empty {
+        let _ = std::fs::remove_dir(model_dir);
+    }
+}
+
+/// List every model on disk with its size, path, backend and status
+///
+/// Walks the models directory rather than the manifest, so models dropped
+/// from the manifest still show up (as "unknown") and can be cleaned up.
+/// Empty directories are skipped.
+#[tauri::command]
+pub fn list_downloaded_models() -> Vec<DownloadedModel> {
+    let manifest = get_fallback_manifest();
+    let selected_id = crate::config::get_config()
+        .ok()
+        .and_then(|c| c.transcription.model_id.clone());
+
+    let Ok(entries) = std::fs::read_dir(get_models_root_directory()) else {
+        return Vec::new();
+    };
+
+    let mut models: Vec<DownloadedModel> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|dir| dir.is_dir())
+        .filter_map(|dir| {
+            let known = manifest
+                .models
+                .iter()
+                .find(|m| get_model_directory(&m.id) == dir);
+
+            // FluidAudio's CoreML files live in its own cache, not here
+            let size_bytes = match known {
+                Some(m) if m.model_type == "fluidaudio_coreml" => {
+                    super::manifest::get_model_disk_size(m).unwrap_or(0)
+                }
+                _ => super::manifest::dir_size_recursive(&dir),
+            };
+            if size_bytes == 0 {
+                return None;
+            }
+
+            let id = known.map(|m| m.id.clone()).unwrap_or_else(|| {
+                dir.file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned()
+            });
+            let complete = !has_partial_download(&dir)
+                && known.is_none_or(super::manifest::is_model_downloaded);
+
+            Some(DownloadedModel {
+                active: selected_id.as_deref() == Some(id.as_str()),
+                id,
+                size_bytes,
+                path: dir.to_string_lossy().into_owned(),
+                model_type: known
+                    .map(|m| m.model_type.clone())
+                    .unwrap_or_else(|| "unknown".to_string()),
+                complete,
+            })
+        })
+        .collect();
+
+    models.sort_by(|a, b| a.id.cmp(&b.id));
+    models
+}
+
 /// Delete the downloaded model files
 #[tauri::command]
 pub fn delete_model(model_id: Option<String>) -> Result<(), Error> {
@@ -859,6 +1085,50 @@ pub fn delete_model(model_id: Option<String>) -> Result<(), Error> {
     Ok(())
 }
 
+/// Stop a model download and remove what it left behind
+///
+/// Aborts the request if `model_id` is downloading, deletes the partial
+/// file (or archive) so the next attempt starts from scratch rather than
+/// resuming a bad one, and resets the download state. With no download in
+/// flight it still cleans up, so a download left broken can be recovered.
+#[tauri::command]
+pub fn cancel_download(model_id: String) -> Result<(), Error> {
+    let manifest = get_fallback_manifest();
+    let model = manifest
+        .models
+        .iter()
+        .find(|m| m.id == model_id)
+        .ok_or_else(|| format!("Model not found: {}", model_id))?;
+
+    // FluidAudio downloads and compiles inside a single blocking call
+    if model.model_type == "fluidaudio_coreml" {
+        return Err("Preparing a Neural Engine model can't be cancelled"
+            .to_string()
+            .into());
+    }
+
+    let another_active = {
+        let active = ACTIVE_DOWNLOAD.lock();
+        if let Some(active) = active.as_ref().filter(|a| a.model_id == model_id) {
+            // `download_model` cleans up once the request is dropped
+            active.cancel.notify_one();
+            tracing::info!("Cancelling download of {}", model_id);
+            return Ok(());
+        }
+        active.is_some()
+    };
+
+    // Nothing in flight for this model, e.g. the download finished just
+    // before the click. Only clear scratch files: a direct download's file
+    // may be the finished model, which the manifest's size can't tell apart.
+    remove_partial_download(&get_model_directory(&model_id), None);
+    // The global state belongs to whichever download is running
+    if !another_active {
+        *get_download_state().lock() = DownloadState::Idle;
+    }
+    Ok(())
+}
+
 /// Reset the download state to idle
 #[tauri::command]
 pub fn reset_download_state() {
@@ -878,6 +1148,49 @@ mod tests {
         let _result = check_model_downloaded(None);
     }
 
+    #[test]
+    fn test_has_partial_download() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("ggml-small.en.bin"), b"model").unwrap();
+        assert!(!has_partial_download(dir.path()));
+
+        std::fs::write(dir.path().join("ggml-small.en.bin.part"), b"mod").unwrap();
+        assert!(has_partial_download(dir.path()));
+
+        let archive_dir = tempfile::tempdir().unwrap();
+        std::fs::write(archive_dir.path().join(ARCHIVE_FILE_NAME), b"bz").unwrap();
+        assert!(has_partial_download(archive_dir.path()));
+    }
+
+    #[test]
+    fn test_remove_partial_download() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("ggml-small.en");
+        std::fs::create_dir_all(dir.join(EXTRACT_DIR_NAME)).unwrap();
+        std::fs::write(dir.join("ggml-small.en.bin"), b"half").unwrap();
+        std::fs::write(dir.join("encoder.onnx.part"), b"half").unwrap();
+        std::fs::write(dir.join(ARCHIVE_FILE_NAME), b"half").unwrap();
+
+        remove_partial_download(&dir, Some("ggml-small.en.bin"));
+        assert!(!dir.exists(), "an emptied model directory is removed");
+    }
+
+    #[test]
+    fn test_remove_partial_download_keeps_other_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("tokens.txt"), b"done").unwrap();
+        std::fs::write(dir.path().join(ARCHIVE_FILE_NAME), b"half").unwrap();
+
+        remove_partial_download(dir.path(), None);
+        assert!(dir.path().join("tokens.txt").exists());
+        assert!(!dir.path().join(ARCHIVE_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn test_has_partial_download_missing_dir() {
+        assert!(!has_partial_download(Path::new("/nonexistent/thoth/model")));
+    }
+
     #[test]
     fn test_download_state_initial() {
         let state = get_download_progress();
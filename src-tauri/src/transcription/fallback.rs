@@ -0,0 +1,394 @@
+//! Ordered fallback across transcription backends.
+//!
+//! A backend can fail to load on some machines (a Metal shader that will not
+//! compile, a CoreML model the Neural Engine rejects, a damaged download).
+//! Rather than leave the app unable to transcribe, [`load_with_fallback`]
+//! tries the configured model first, then the best loadable model of each
+//! backend in the order FluidAudio → Whisper → Parakeet, and emits
+//! [`BACKEND_FALLBACK_EVENT`] when it settles on something else.
+
+use serde::Serialize;
+
+use super::{TranscriptionBackend, manifest};
+
+/// Event emitted when a model other than the configured one was loaded
+/// (payload: [`BackendFallback`])
+pub const BACKEND_FALLBACK_EVENT: &str = "backend-fallback";
+
+/// Backends tried after the configured model, best first
+const FALLBACK_ORDER: [TranscriptionBackend; 3] = [
+    TranscriptionBackend::FluidAudio,
+    TranscriptionBackend::Whisper,
+    TranscriptionBackend::Parakeet,
+];
+
+/// A model the chain can try to load
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    /// Manifest model ID
+    pub model_id: String,
+    pub backend: TranscriptionBackend,
+}
+
+/// What the chain knows about one manifest model
+#[derive(Debug, Clone)]
+struct ModelOption {
+    model_id: String,
+    backend: TranscriptionBackend,
+    /// Downloaded, or for FluidAudio, compiled and cached
+    downloaded: bool,
+    /// Its backend is in this build and can run on this machine
+    usable: bool,
+}
+
+impl ModelOption {
+    fn loadable(&self) -> bool {
+        self.downloaded && self.usable
+    }
+
+    fn candidate(&self) -> Candidate {
+        Candidate {
+            model_id: self.model_id.clone(),
+            backend: self.backend,
+        }
+    }
+}
+
+/// Payload of [`BACKEND_FALLBACK_EVENT`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackendFallback {
+    /// The configured model, if one is selected
+    pub requested: Option<String>,
+    /// The model loaded instead
+    pub model_id: String,
+    /// Its backend, e.g. "whisper"
+    pub backend: String,
+    /// Why the models tried before it were not used
+    pub reason: String,
+}
+
+/// What running the chain did
+#[derive(Debug, Default)]
+struct ChainOutcome {
+    /// The model that loaded, if any did
+    loaded: Option<Candidate>,
+    /// Models that failed to load and why, in the order tried
+    failures: Vec<(Candidate, String)>,
+}
+
+fn backend_for(model_type: &str) -> Option<TranscriptionBackend> {
+    match model_type {
+        "whisper_ggml" => Some(TranscriptionBackend::Whisper),
+        "nemo_transducer" => Some(TranscriptionBackend::Parakeet),
+        "fluidaudio_coreml" => Some(TranscriptionBackend::FluidAudio),
+        _ => None,
+    }
+}
+
+/// The manifest's models, in manifest (quality) order.
+fn model_options(manifest: &manifest::ModelManifest) -> Vec<ModelOption> {
+    manifest
+        .models
+        .iter()
+        .filter_map(|m| {
+            let backend = backend_for(&m.model_type)?;
+            let downloaded = match backend {
+                TranscriptionBackend::FluidAudio => super::is_fluidaudio_cached(),
+                _ => manifest::is_model_downloaded(m),
+            };
+            Some(ModelOption {
+                model_id: m.id.clone(),
+                backend,
+                downloaded,
+                usable: manifest::is_backend_available(&m.model_type),
+            })
+        })
+        .collect()
+}
+
+/// The models to try, in order: the selected one if it can be loaded, then
+/// the best loadable model of each backend in [`FALLBACK_ORDER`].
+fn plan(selected: Option<&str>, models: &[ModelOption]) -> Vec<Candidate> {
+    let mut plan: Vec<Candidate> = selected
+        .and_then(|id| models.iter().find(|m| m.model_id == id))
+        .filter(|m| m.loadable())
+        .map(ModelOption::candidate)
+        .into_iter()
+        .collect();
+
+    for backend in FALLBACK_ORDER {
+        let next = models
+            .iter()
+            .filter(|m| m.backend == backend && m.loadable())
+            .map(ModelOption::candidate)
+            .find(|c| !plan.contains(c));
+        if let Some(candidate) = next {
+            plan.push(candidate);
+        }
+    }
+    plan
+}
+
+/// Try each candidate in turn until one loads.
+fn run_chain(
+    plan: Vec<Candidate>,
+    mut load: impl FnMut(&Candidate) -> Result<(), String>,
+) -> ChainOutcome {
+    let mut outcome = ChainOutcome::default();
+    for candidate in plan {
+        match load(&candidate) {
+            Ok(()) => {
+                outcome.loaded = Some(candidate);
+                break;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load {} ({}): {}",
+                    candidate.model_id,
+                    candidate.backend.as_str(),
+                    e
+                );
+                outcome.failures.push((candidate, e));
+            }
+        }
+    }
+    outcome
+}
+
+/// The event to emit for `outcome`, when what loaded is not what was wanted:
+/// the selected model, or with none selected, the first model tried.
+fn fallback_notice(selected: Option<&str>, outcome: &ChainOutcome) -> Option<BackendFallback> {
+    let loaded = outcome.loaded.as_ref()?;
+    let is_selected = selected == Some(loaded.model_id.as_str());
+    if is_selected || (selected.is_none() && outcome.failures.is_empty()) {
+        return None;
+    }
+
+    let reason = if outcome.failures.is_empty() {
+        format!(
+            "{} is not downloaded or cannot run on this machine",
+            selected.unwrap_or_default()
+        )
+    } else {
+        outcome
+            .failures
+            .iter()
+            .map(|(c, e)| format!("{}: {}", c.model_id, e))
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+    Some(BackendFallback {
+        requested: selected.map(str::to_string),
+        model_id: loaded.model_id.clone(),
+        backend: loaded.backend.as_str().to_string(),
+        reason,
+    })
+}
+
+/// Load one candidate into the process-wide service.
+fn load(candidate: &Candidate) -> Result<(), String> {
+    let result = match candidate.backend {
+        TranscriptionBackend::FluidAudio => super::load_fluidaudio(),
+        TranscriptionBackend::Whisper | TranscriptionBackend::Parakeet => {
+            let dir = manifest::get_model_directory(&candidate.model_id);
+            super::load_model(dir.to_string_lossy().to_string())
+        }
+    };
+    result.map_err(|e| e.to_string())
+}
+
+/// Load the selected transcription model, falling back to the best
+/// available alternative if it cannot be loaded.
+///
+/// The selected model is the configured language's entry in
+/// `language_model_map` when that is downloaded (see
+/// [`super::effective_model_id`]).
+///
+/// Returns the model that loaded, if any. Does nothing while another load is
+/// in progress.
+pub fn load_with_fallback() -> Option<Candidate> {
+    if super::TRANSCRIPTION_SERVICE.is_loading() {
+        tracing::info!("A transcription model is already loading, skipping");
+        return None;
+    }
+
+    let transcription = crate::config::get_config()
+        .map(|c| c.transcription)
+        .unwrap_or_default();
+    if let Some(mapped) = transcription.language_model_id() {
+        if !super::is_model_id_downloaded(mapped) {
+            tracing::warn!(
+                "Model {} mapped to language {:?} is not downloaded; loading the selected model",
+                mapped,
+                transcription.language
+            );
+        }
+    }
+    let selected = super::effective_model_id(&transcription);
+    let models = model_options(&manifest::get_fallback_manifest());
+    let plan = plan(selected.as_deref(), &models);
+    if plan.is_empty() {
+        tracing::info!("No downloaded transcription model to load");
+        return None;
+    }
+
+    let outcome = run_chain(plan, load);
+    match &outcome.loaded {
+        Some(loaded) => tracing::info!(
+            "Transcription model {} loaded ({})",
+            loaded.model_id,
+            loaded.backend.as_str()
+        ),
+        None => tracing::warn!("No transcription model could be loaded"),
+    }
+    if let Some(notice) = fallback_notice(selected.as_deref(), &outcome) {
+        tracing::warn!(
+            "Fell back to {} ({}): {}",
+            notice.model_id,
+            notice.backend,
+            notice.reason
+        );
+        // Called from warmup threads with no handle to hand
+        crate::app_handle::emit(BACKEND_FALLBACK_EVENT, notice);
+    }
+    outcome.loaded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option(model_id: &str, backend: TranscriptionBackend) -> ModelOption {
+        ModelOption {
+            model_id: model_id.to_string(),
+            backend,
+            downloaded: true,
+            usable: true,
+        }
+    }
+
+    /// FluidAudio, two Whisper models and a Parakeet model, all loadable
+    fn models() -> Vec<ModelOption> {
+        vec![
+            option("fluidaudio", TranscriptionBackend::FluidAudio),
+            option("whisper-large", TranscriptionBackend::Whisper),
+            option("whisper-base", TranscriptionBackend::Whisper),
+            option("parakeet", TranscriptionBackend::Parakeet),
+        ]
+    }
+
+    fn ids(plan: &[Candidate]) -> Vec<&str> {
+        plan.iter().map(|c| c.model_id.as_str()).collect()
+    }
+
+    /// Runs the chain with every model in `failing` refusing to load
+    fn run(selected: Option<&str>, failing: &[&str]) -> (ChainOutcome, Option<BackendFallback>) {
+        let outcome = run_chain(plan(selected, &models()), |c| {
+            if failing.contains(&c.model_id.as_str()) {
+                Err("shader compile failed".to_string())
+            } else {
+                Ok(())
+            }
+        });
+        let notice = fallback_notice(selected, &outcome);
+        (outcome, notice)
+    }
+
+    #[test]
+    fn test_plan_tries_selected_then_each_backend() {
+        let plan = plan(Some("whisper-base"), &models());
+        assert_eq!(
+            ids(&plan),
+            ["whisper-base", "fluidaudio", "whisper-large", "parakeet"]
+        );
+        let plan = super::plan(None, &models());
+        assert_eq!(ids(&plan), ["fluidaudio", "whisper-large", "parakeet"]);
+    }
+
+    #[test]
+    fn test_plan_skips_models_that_cannot_load() {
+        let mut models = models();
+        models[0].usable = false; // no Neural Engine
+        models[1].downloaded = false;
+        models[3].usable = false; // built without Parakeet
+        assert_eq!(ids(&plan(Some("whisper-large"), &models)), ["whisper-base"]);
+
+        for m in &mut models {
+            m.downloaded = false;
+        }
+        assert!(plan(Some("whisper-large"), &models).is_empty());
+    }
+
+    #[test]
+    fn test_selected_model_loads_without_fallback() {
+        let (outcome, notice) = run(Some("whisper-large"), &[]);
+        assert_eq!(outcome.loaded.unwrap().model_id, "whisper-large");
+        assert!(outcome.failures.is_empty());
+        assert_eq!(notice, None);
+    }
+
+    #[test]
+    fn test_selected_failure_falls_back_to_fluidaudio() {
+        let (outcome, notice) = run(Some("whisper-large"), &["whisper-large"]);
+        assert_eq!(outcome.loaded.unwrap().model_id, "fluidaudio");
+        let notice = notice.unwrap();
+        assert_eq!(notice.requested.as_deref(), Some("whisper-large"));
+        assert_eq!(notice.backend, "fluidaudio");
+        assert_eq!(notice.reason, "whisper-large: shader compile failed");
+    }
+
+    #[test]
+    fn test_fluidaudio_failure_falls_back_to_whisper() {
+        let (outcome, notice) = run(None, &["fluidaudio"]);
+        assert_eq!(outcome.loaded.unwrap().model_id, "whisper-large");
+        let notice = notice.unwrap();
+        assert_eq!(notice.requested, None);
+        assert_eq!(notice.backend, "whisper");
+    }
+
+    #[test]
+    fn test_whisper_failure_falls_back_to_parakeet() {
+        let (outcome, notice) = run(
+            Some("whisper-base"),
+            &["whisper-base", "fluidaudio", "whisper-large"],
+        );
+        assert_eq!(outcome.loaded.unwrap().model_id, "parakeet");
+        assert_eq!(outcome.failures.len(), 3);
+        assert_eq!(notice.unwrap().backend, "parakeet");
+    }
+
+    #[test]
+    fn test_all_backends_failing_loads_nothing() {
+        let (outcome, notice) = run(
+            None,
+            &["fluidaudio", "whisper-large", "whisper-base", "parakeet"],
+        );
+        assert!(outcome.loaded.is_none());
+        assert_eq!(outcome.failures.len(), 3);
+        assert_eq!(notice, None);
+    }
+
+    #[test]
+    fn test_missing_selected_model_is_reported() {
+        let mut models = models();
+        models[1].downloaded = false;
+        let outcome = run_chain(plan(Some("whisper-large"), &models), |_| Ok(()));
+        let notice = fallback_notice(Some("whisper-large"), &outcome).unwrap();
+        assert_eq!(notice.model_id, "fluidaudio");
+        assert!(notice.reason.contains("not downloaded"));
+    }
+
+    #[test]
+    fn test_backend_fallback_serialisation() {
+        let notice = BackendFallback {
+            requested: Some("whisper-large".to_string()),
+            model_id: "parakeet".to_string(),
+            backend: "parakeet".to_string(),
+            reason: "whisper-large: failed".to_string(),
+        };
+        let json = serde_json::to_value(&notice).unwrap();
+        assert_eq!(json["modelId"], "parakeet");
+        assert_eq!(json["requested"], "whisper-large");
+    }
+}
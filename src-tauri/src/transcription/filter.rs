@@ -4,6 +4,7 @@
 //! Removes filler words, normalises whitespace, cleans up punctuation,
 //! and applies dictionary word replacements.
 
+use super::TranscriptSegment;
 use crate::dictionary;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -35,6 +36,15 @@ pub struct FilterOptions {
     /// convention used by macOS Dictation, Dragon and Talon.
     #[serde(default = "default_voice_formatting_commands")]
     pub voice_formatting_commands: bool,
+    /// Collapse Whisper's repetition loops ("you you you you...", the same
+    /// sentence dozens of times) to a single occurrence
+    #[serde(default = "default_collapse_repetitions")]
+    pub collapse_repetitions: bool,
+    /// Start a new line at long pauses between segments, as a rough marker
+    /// of speaker turns (see [`split_at_pauses`]). Only applies when the
+    /// transcript comes with segment timing.
+    #[serde(default)]
+    pub mark_pauses: bool,
 }
 
 fn default_apply_dictionary() -> bool {
@@ -45,6 +55,10 @@ fn default_voice_formatting_commands() -> bool {
     true
 }
 
+fn default_collapse_repetitions() -> bool {
+    true
+}
+
 impl Default for FilterOptions {
     fn default() -> Self {
         Self {
@@ -56,6 +70,8 @@ impl Default for FilterOptions {
             australian_spelling: false,
             spoken_numbers_to_digits: false,
             voice_formatting_commands: true,
+            collapse_repetitions: true,
+            mark_pauses: false,
         }
     }
 }
@@ -80,6 +96,14 @@ static LEADING_FILLER_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"(?i)(\A|[.!?]\s+)(?:u+[hm]+|e+r+|a+h+)\b[ \t]*,?[ \t]*([A-Za-z])").unwrap()
 });
 
+/// Back-to-back occurrences of a word or phrase at which it counts as a
+/// repetition loop. Speech repeats itself ("no no no", "very very") but
+/// rarely four times running; Whisper's loops run to dozens.
+const MIN_LOOP_REPEATS: usize = 4;
+
+/// Longest phrase, in words, checked for repetition (a looped sentence)
+const MAX_LOOP_PHRASE_WORDS: usize = 16;
+
 /// Multiple whitespace pattern
 static MULTI_SPACE_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r" {2,}").unwrap());
 
@@ -149,9 +173,19 @@ impl OutputFilter {
     pub fn filter(&self, text: &str) -> String {
         let mut result = text.to_string();
 
-        // Apply dictionary replacements first (before other processing)
+        // Loops are a model artefact, so they go before anything reads words
+        if self.options.collapse_repetitions {
+            result = collapse_repetitions(&result);
+        }
+
+        // Apply dictionary replacements first (before other processing).
+        // No app is known here, so app-scoped entries are left to the
+        // pipeline, which applies the dictionary itself with the run's app.
         if self.options.apply_dictionary {
-            result = dictionary::apply_dictionary(&result);
+            result = dictionary::apply_dictionary(
+                &result,
+                &dictionary::DictionaryContext::for_app(None),
+            );
         }
 
         if self.options.remove_fillers {
@@ -188,6 +222,29 @@ impl OutputFilter {
 
         result
     }
+
+    /// Filter a transcript given as timed segments
+    ///
+    /// With `mark_pauses` each turn (see [`split_at_pauses`]) is filtered on
+    /// its own and the turns go on separate lines; otherwise the segments are
+    /// joined and filtered as one text, exactly as [`filter`](Self::filter).
+    pub fn filter_segments(&self, segments: &[TranscriptSegment]) -> String {
+        if !self.options.mark_pauses {
+            let text = segments
+                .iter()
+                .map(|s| s.text.trim())
+                .filter(|t| !t.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            return self.filter(&text);
+        }
+        split_at_pauses(segments)
+            .iter()
+            .map(|turn| self.filter(turn))
+            .filter(|turn| !turn.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 }
 
 /// Remove common filler words and sounds from text.
@@ -263,6 +320,95 @@ pub fn apply_voice_commands(text: &str) -> String {
     replaced.trim().to_string()
 }
 
+/// A word as compared for repetition: lowercase, without punctuation
+fn loop_key(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// How many times the `len` words at `start` occur back to back
+fn repeat_count(keys: &[String], start: usize, len: usize) -> usize {
+    let phrase = &keys[start..start + len];
+    keys[start..]
+        .chunks_exact(len)
+        .take_while(|chunk| *chunk == phrase)
+        .count()
+}
+
+/// Collapse a word or phrase repeated [`MIN_LOOP_REPEATS`] or more times in a
+/// row to its first occurrence.
+///
+/// Words are compared without case or punctuation, so "Thank you. Thank you.
+/// Thank you. Thank you." is one loop. The shortest repeating phrase wins, so
+/// "you you you you" collapses to "you" rather than "you you". Text without a
+/// loop is returned unchanged; otherwise words are rejoined with single spaces.
+pub fn collapse_repetitions(text: &str) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let keys: Vec<String> = words.iter().map(|word| loop_key(word)).collect();
+
+    let mut kept: Vec<&str> = Vec::with_capacity(words.len());
+    let mut collapsed = false;
+    let mut i = 0;
+    while i < words.len() {
+        let longest = MAX_LOOP_PHRASE_WORDS.min((words.len() - i) / MIN_LOOP_REPEATS);
+        let repeat = (1..=longest)
+            .map(|len| (len, repeat_count(&keys, i, len)))
+            .find(|&(_, count)| count >= MIN_LOOP_REPEATS);
+        match repeat {
+            Some((len, count)) => {
+                kept.extend_from_slice(&words[i..i + len]);
+                i += len * count;
+                collapsed = true;
+            }
+            None => {
+                kept.push(words[i]);
+                i += 1;
+            }
+        }
+    }
+
+    if collapsed {
+        kept.join(" ")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Silence between segments, in seconds, long enough to mark as a turn
+const PAUSE_MARK_SECS: f32 = 2.0;
+
+/// Group timed segments into turns, starting a new one after each pause of
+/// at least [`PAUSE_MARK_SECS`].
+///
+/// A lightweight stand-in for speaker diarisation: it cannot tell who spoke,
+/// but in an interview or meeting a long silence usually means someone else
+/// is about to talk. Segments without timing never start a turn.
+pub fn split_at_pauses(segments: &[TranscriptSegment]) -> Vec<String> {
+    let mut turns: Vec<String> = Vec::new();
+    let mut previous_end: Option<f32> = None;
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let paused = match (previous_end, segment.start_secs) {
+            (Some(end), Some(start)) => start - end >= PAUSE_MARK_SECS,
+            _ => false,
+        };
+        match turns.last_mut() {
+            Some(turn) if !paused => {
+                turn.push(' ');
+                turn.push_str(text);
+            }
+            _ => turns.push(text.to_string()),
+        }
+        previous_end = segment.end_secs;
+    }
+    turns
+}
+
 // ── Australian/British spelling normalisation ─────────────────────────────
 //
 // US → AU spelling is a whole-word lookup against a map generated from VARCON
@@ -1017,9 +1163,9 @@ mod tests {
             cleanup_punctuation: true,
             sentence_case: true,
             apply_dictionary: false, // Disable for test isolation
-            australian_spelling: false,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
 
         let input = "um, I was like  thinking...what do you think ??";
@@ -1039,9 +1185,9 @@ mod tests {
             cleanup_punctuation: false,
             sentence_case: false,
             apply_dictionary: false,
-            australian_spelling: false,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
 
         let input = "um  hello...";
@@ -1062,6 +1208,7 @@ mod tests {
         assert!(!options.sentence_case);
         assert!(options.apply_dictionary);
         assert!(options.voice_formatting_commands);
+        assert!(options.collapse_repetitions);
     }
 
     #[test]
@@ -1072,9 +1219,9 @@ mod tests {
             cleanup_punctuation: false,
             sentence_case: false,
             apply_dictionary: false,
-            australian_spelling: false,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
 
         let input = "I um think so";
@@ -1089,9 +1236,9 @@ mod tests {
             cleanup_punctuation: false,
             sentence_case: false,
             apply_dictionary: false,
-            australian_spelling: false,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
 
         let input = "  hello   world  ";
@@ -1106,9 +1253,9 @@ mod tests {
             cleanup_punctuation: true,
             sentence_case: false,
             apply_dictionary: false,
-            australian_spelling: false,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
         assert_eq!(filter.filter(""), "");
     }
@@ -1193,9 +1340,9 @@ mod tests {
             cleanup_punctuation: true,
             sentence_case: false,
             apply_dictionary: false,
-            australian_spelling: false,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: true,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
         assert_eq!(
             filter.filter("First thought. New paragraph. Second thought."),
@@ -1211,9 +1358,9 @@ mod tests {
             cleanup_punctuation: true,
             sentence_case: false,
             apply_dictionary: false,
-            australian_spelling: false,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
         assert_eq!(
             filter.filter("First thought. New paragraph. Second thought."),
@@ -1229,9 +1376,9 @@ mod tests {
             cleanup_punctuation: true,
             sentence_case: true,
             apply_dictionary: false, // Disable for test isolation
-            australian_spelling: false,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
 
         let input = "um so like I was thinking you know about the project...and uh I think we should like move forward with it what do you think ??";
@@ -1256,9 +1403,9 @@ mod tests {
             cleanup_punctuation: true,
             sentence_case: false,
             apply_dictionary: false,
-            australian_spelling: false,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: true,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
 
         assert_eq!(
@@ -1605,8 +1752,9 @@ mod tests {
             sentence_case: false,
             apply_dictionary: false,
             australian_spelling: true,
-            spoken_numbers_to_digits: false,
             voice_formatting_commands: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
         assert_eq!(
             filter.filter("I love the color and flavor"),
@@ -1622,9 +1770,10 @@ mod tests {
             cleanup_punctuation: false,
             sentence_case: false,
             apply_dictionary: false,
-            australian_spelling: false,
             spoken_numbers_to_digits: true,
             voice_formatting_commands: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
         });
         assert_eq!(
             filter.filter("I have twenty three items"),
@@ -1638,4 +1787,126 @@ mod tests {
         assert!(!options.australian_spelling);
         assert!(!options.spoken_numbers_to_digits);
     }
+
+    // ── Repetition loops ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_collapse_repeated_word_loop() {
+        assert_eq!(
+            collapse_repetitions("Thanks for watching you you you you you you you you"),
+            "Thanks for watching you"
+        );
+    }
+
+    #[test]
+    fn test_collapse_repeated_sentence_loop() {
+        let looped = format!("We'll pick this up tomorrow.{}", " Thank you.".repeat(30));
+        assert_eq!(
+            collapse_repetitions(&looped),
+            "We'll pick this up tomorrow. Thank you."
+        );
+        // Case and punctuation differences between copies don't hide a loop
+        assert_eq!(
+            collapse_repetitions("okay, Okay. okay okay! and then"),
+            "okay, and then"
+        );
+    }
+
+    #[test]
+    fn test_collapse_keeps_natural_repetition() {
+        for text in [
+            "no no no, that's wrong",
+            "it was very very good",
+            "that that is the question",
+            "Thank you. Thank you. Thank you.",
+        ] {
+            assert_eq!(collapse_repetitions(text), text);
+        }
+        // Untouched text keeps its own spacing and line breaks
+        assert_eq!(
+            collapse_repetitions("one\n\ntwo  three"),
+            "one\n\ntwo  three"
+        );
+    }
+
+    #[test]
+    fn test_filter_collapses_repetition_loop_by_default() {
+        let filter = OutputFilter::new(FilterOptions {
+            apply_dictionary: false,
+            ..FilterOptions::default()
+        });
+        assert_eq!(
+            filter.filter("Send it over. you you you you you you"),
+            "Send it over. you"
+        );
+
+        let filter = OutputFilter::new(FilterOptions {
+            apply_dictionary: false,
+            collapse_repetitions: false,
+            ..FilterOptions::default()
+        });
+        assert_eq!(filter.filter("you you you you"), "you you you you");
+    }
+
+    fn timed(text: &str, start: f32, end: f32) -> TranscriptSegment {
+        TranscriptSegment {
+            text: text.to_string(),
+            confidence: None,
+            start_secs: Some(start),
+            end_secs: Some(end),
+        }
+    }
+
+    #[test]
+    fn test_split_at_pauses() {
+        let segments = vec![
+            timed("So how did the project start?", 0.0, 2.4),
+            timed("Take your time.", 2.6, 3.5),
+            timed("It began as a weekend experiment.", 6.1, 8.9),
+            timed("Then it grew.", 9.2, 10.0),
+        ];
+        assert_eq!(
+            split_at_pauses(&segments),
+            vec![
+                "So how did the project start? Take your time.",
+                "It began as a weekend experiment. Then it grew.",
+            ]
+        );
+
+        // Without timing there is nothing to split on
+        let untimed = vec![TranscriptSegment {
+            text: "hello there".to_string(),
+            confidence: None,
+            start_secs: None,
+            end_secs: None,
+        }];
+        assert_eq!(split_at_pauses(&untimed), vec!["hello there"]);
+    }
+
+    #[test]
+    fn test_filter_segments_marks_pauses() {
+        let segments = vec![
+            timed("what brought you here", 0.0, 2.0),
+            timed("the job mostly", 4.5, 5.5),
+        ];
+        let options = FilterOptions {
+            apply_dictionary: false,
+            sentence_case: true,
+            mark_pauses: true,
+            ..FilterOptions::default()
+        };
+        assert_eq!(
+            OutputFilter::new(options.clone()).filter_segments(&segments),
+            "What brought you here\nThe job mostly"
+        );
+
+        let unmarked = FilterOptions {
+            mark_pauses: false,
+            ..options
+        };
+        assert_eq!(
+            OutputFilter::new(unmarked).filter_segments(&segments),
+            "What brought you here the job mostly"
+        );
+    }
 }
@@ -141,7 +141,33 @@ impl TranscriptionService {
             }
         };
 
-        let (segments, seam_gaps) = plan_segments(&samples, spec.sample_rate);
+        self.transcribe_segments(&samples, &spec, audio_path)
+    }
+
+    /// Transcribe an in-memory 16kHz mono buffer.
+    ///
+    /// FluidAudio only reads files, so each segment still goes through a
+    /// temporary WAV; the caller is spared writing the whole recording.
+    pub fn transcribe_pcm(&self, samples: Vec<f32>, sample_rate: u32) -> Result<String> {
+        let dir = tempfile::Builder::new().prefix("thoth_pcm_").tempdir()?;
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        self.transcribe_segments(&samples, &spec, &dir.path().join("pcm.wav"))
+    }
+
+    /// Split `samples` at pauses and transcribe each segment via a temporary
+    /// WAV written next to `base_path`.
+    fn transcribe_segments(
+        &self,
+        samples: &[f32],
+        spec: &hound::WavSpec,
+        base_path: &Path,
+    ) -> Result<String> {
+        let (segments, seam_gaps) = plan_segments(samples, spec.sample_rate);
         let total_secs = samples.len() as f32 / spec.sample_rate as f32;
         tracing::info!(
             "FluidAudio: {:.1}s recording → {} segment(s) (single-shot limit {:.1}s)",
@@ -154,8 +180,8 @@ impl TranscriptionService {
         let mut parts: Vec<String> = Vec::with_capacity(segments.len());
         let mut kept: Vec<bool> = Vec::with_capacity(segments.len());
         for (i, &(begin, end)) in segments.iter().enumerate() {
-            let tmp = audio_path.with_extension(format!("seg{i}.wav"));
-            write_padded_wav(&samples[begin..end], &spec, &tmp)?;
+            let tmp = base_path.with_extension(format!("seg{i}.wav"));
+            write_padded_wav(&samples[begin..end], spec, &tmp)?;
             let text = self.transcribe_one(&tmp);
             let _ = std::fs::remove_file(&tmp);
 
@@ -7,6 +7,7 @@ use crate::error::Error;
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use tauri::{AppHandle, Emitter};
 
 /// URL for the model manifest (can be changed to your own hosting)
 const MANIFEST_URL: &str =
@@ -15,6 +16,10 @@ const MANIFEST_URL: &str =
 /// Cache duration for the manifest (24 hours)
 const MANIFEST_CACHE_HOURS: u64 = 24;
 
+/// Event emitted when [`refresh_manifest`] finds models added, updated or
+/// removed (payload: [`ManifestDiff`])
+pub const MANIFEST_UPDATED_EVENT: &str = "manifest-updated";
+
 /// Model manifest containing all available models
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelManifest {
@@ -58,6 +63,26 @@ pub struct RemoteModelInfo {
     pub recommended: bool,
     /// Minimum app version required (semver)
     pub min_app_version: Option<String>,
+    /// Approximate GPU memory the model needs when run on the GPU, in MB.
+    /// `None` for models that do not run on the GPU or whose need is unknown.
+    #[serde(default)]
+    pub vram_mb: Option<u32>,
+    /// Whether the model transcribes languages other than English. Manifests
+    /// older than this field leave it unset; see [`RemoteModelInfo::multilingual`].
+    #[serde(default)]
+    pub is_multilingual: Option<bool>,
+}
+
+impl RemoteModelInfo {
+    /// Whether the model transcribes languages other than English
+    ///
+    /// Falls back to the language list when the manifest does not say: a
+    /// model listing only English is English-only.
+    pub fn multilingual(&self) -> bool {
+        self.is_multilingual.unwrap_or_else(|| {
+            self.languages.is_empty() || self.languages.iter().any(|l| l != "en")
+        })
+    }
 }
 
 /// Combined model info for the frontend (remote + local status)
@@ -91,6 +116,14 @@ pub struct ModelInfo {
     pub model_type: String,
     /// Whether this model's backend is available in the current build
     pub backend_available: bool,
+    /// Approximate GPU memory the model needs, in MB
+    pub vram_mb: Option<u32>,
+    /// Set when the model likely will not fit in the detected GPU memory
+    pub vram_warning: Option<String>,
+    /// Whether the model transcribes languages other than English
+    pub is_multilingual: bool,
+    /// Set when the model cannot transcribe the configured language
+    pub language_warning: Option<String>,
 }
 
 /// Cached manifest with timestamp
@@ -104,28 +137,18 @@ struct CachedManifest {
 
 /// Get the manifest cache file path
 fn get_cache_path() -> PathBuf {
-    home_dir_or_fallback()
-        .join(".thoth")
-        .join("models")
-        .join("manifest_cache.json")
+    get_models_root_directory().join("manifest_cache.json")
 }
 
-fn home_dir_or_fallback() -> PathBuf {
-    dirs::home_dir().unwrap_or_else(|| {
-        tracing::error!("Could not determine home directory, using /tmp");
-        PathBuf::from("/tmp")
-    })
+/// Read the cached manifest, however old
+fn read_cache() -> Option<CachedManifest> {
+    let data = std::fs::read_to_string(get_cache_path()).ok()?;
+    serde_json::from_str(&data).ok()
 }
 
 /// Load cached manifest if it exists and is not expired
 fn load_cached_manifest() -> Option<ModelManifest> {
-    let cache_path = get_cache_path();
-    if !cache_path.exists() {
-        return None;
-    }
-
-    let data = std::fs::read_to_string(&cache_path).ok()?;
-    let cached: CachedManifest = serde_json::from_str(&data).ok()?;
+    let cached = read_cache()?;
 
     // Check if cache is expired
     let now = std::time::SystemTime::now()
@@ -250,6 +273,8 @@ pub fn get_fallback_manifest() -> ModelManifest {
                     model_type: "fluidaudio_coreml".to_string(),
                     recommended: true,
                     min_app_version: None,
+                    vram_mb: None,
+                    is_multilingual: Some(true),
                 }],
             }
         }
@@ -279,14 +304,16 @@ pub fn is_model_downloaded(model: &RemoteModelInfo) -> bool {
     true
 }
 
+/// Get the directory holding every model's directory
+pub fn get_models_root_directory() -> PathBuf {
+    crate::storage::data_dir_or_fallback().join("models")
+}
+
 /// Get the directory for a specific model
 pub fn get_model_directory(model_id: &str) -> PathBuf {
     // Use model ID as directory name (sanitized)
     let safe_id = model_id.replace(['/', '\\', ':', '*', '?', '"', '<', '>', '|'], "_");
-    home_dir_or_fallback()
-        .join(".thoth")
-        .join("models")
-        .join(safe_id)
+    get_models_root_directory().join(safe_id)
 }
 
 /// Get disk size for a downloaded model
@@ -320,8 +347,7 @@ pub fn get_model_disk_size(model: &RemoteModelInfo) -> Option<u64> {
 }
 
 /// Recursively calculate directory size in bytes
-#[cfg(all(target_os = "macos", feature = "fluidaudio"))]
-fn dir_size_recursive(path: &std::path::Path) -> u64 {
+pub(super) fn dir_size_recursive(path: &std::path::Path) -> u64 {
     if !path.exists() {
         return 0;
     }
@@ -340,13 +366,17 @@ fn dir_size_recursive(path: &std::path::Path) -> u64 {
 }
 
 /// Check if the backend for a given model type is available in this build
-// Arms return different cfg!() expressions, not uniform true — matches! would be wrong.
+/// and, for FluidAudio, usable on this machine (Intel Macs have no Neural Engine)
+// Arms return different availability checks, not uniform true — matches! would be wrong.
 #[allow(clippy::match_like_matches_macro)]
 pub fn is_backend_available(model_type: &str) -> bool {
     match model_type {
         "whisper_ggml" => true,
         "nemo_transducer" => cfg!(feature = "parakeet"),
-        "fluidaudio_coreml" => cfg!(all(target_os = "macos", feature = "fluidaudio")),
+        "fluidaudio_coreml" => {
+            let acceleration = crate::platform::macos_acceleration_info();
+            acceleration.fluidaudio_compiled && acceleration.neural_engine_available
+        }
         _ => false,
     }
 }
@@ -369,8 +399,106 @@ fn is_update_available(downloaded: bool, installed: Option<&str>, manifest_versi
     downloaded && installed.map(|v| v != manifest_version).unwrap_or(false)
 }
 
+/// GPU memory of the GPU transcription would run on, in MB, if known.
+///
+/// Only reported on Linux today; elsewhere this is `None` and no fit
+/// warnings are produced. Detected once per run, since it shells out to the
+/// GPU tools and the model list asks on every refresh.
+pub fn detected_vram_mb() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        static VRAM_MB: std::sync::OnceLock<Option<u64>> = std::sync::OnceLock::new();
+        *VRAM_MB.get_or_init(|| {
+            crate::platform::get_gpu_info()
+                .ok()
+                .filter(|info| info.gpu_available)
+                .and_then(|info| info.vram_mb)
+        })
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Warning for a model that needs more GPU memory than is available.
+///
+/// Advisory only: the estimate is approximate, and loading may still work
+/// when the driver can spill to system memory.
+pub fn vram_warning(required_mb: Option<u32>, available_mb: Option<u64>) -> Option<String> {
+    let (required, available) = (required_mb?, available_mb?);
+    (u64::from(required) > available).then(|| {
+        format!(
+            "Needs about {:.1} GB of GPU memory but this GPU has {:.1} GB. It may crash \
+             while loading; a smaller model is recommended.",
+            f64::from(required) / 1024.0,
+            available as f64 / 1024.0
+        )
+    })
+}
+
+/// Primary subtag of a language code, lowercased ("en-AU" -> "en")
+pub(crate) fn primary_language(language: &str) -> String {
+    language
+        .trim()
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// Warning for a model that cannot transcribe `language`.
+///
+/// "auto" (or no language) never warns. Models listing "multilingual" or no
+/// languages at all are taken to cover every language.
+pub fn language_warning(model: &RemoteModelInfo, language: &str) -> Option<String> {
+    let code = primary_language(language);
+    if code.is_empty() || code == "auto" {
+        return None;
+    }
+    let supported = model.languages.is_empty()
+        || model
+            .languages
+            .iter()
+            .any(|l| l == "multilingual" || primary_language(l) == code);
+    if supported {
+        return None;
+    }
+    Some(if model.multilingual() {
+        format!(
+            "Does not list the configured language ({}). Transcripts may come out in \
+             another language.",
+            code
+        )
+    } else {
+        format!(
+            "English only, so it cannot transcribe the configured language ({}). Choose a \
+             multilingual model.",
+            code
+        )
+    })
+}
+
+/// [`language_warning`] for a model in the bundled manifest, looked up by id
+pub fn model_language_warning(model_id: &str, language: &str) -> Option<String> {
+    get_fallback_manifest()
+        .models
+        .iter()
+        .find(|m| m.id == model_id)
+        .and_then(|m| language_warning(m, language))
+}
+
 /// Convert remote model info to frontend model info
-pub fn to_model_info(remote: &RemoteModelInfo, selected_id: Option<&str>) -> ModelInfo {
+///
+/// `language` is the configured transcription language, used to flag models
+/// that cannot transcribe it. `available_vram_mb` is the detected GPU memory
+/// (see [`detected_vram_mb`]), used to flag models that will not fit.
+pub fn to_model_info(
+    remote: &RemoteModelInfo,
+    selected_id: Option<&str>,
+    language: &str,
+    available_vram_mb: Option<u64>,
+) -> ModelInfo {
     let downloaded = is_model_downloaded(remote);
     let disk_size = if downloaded {
         get_model_disk_size(remote)
@@ -421,27 +549,20 @@ pub fn to_model_info(remote: &RemoteModelInfo, selected_id: Option<&str>) -> Mod
         selected,
         model_type: remote.model_type.clone(),
         backend_available: is_backend_available(&remote.model_type),
+        vram_mb: remote.vram_mb,
+        vram_warning: vram_warning(remote.vram_mb, available_vram_mb),
+        is_multilingual: remote.multilingual(),
+        language_warning: language_warning(remote, language),
     }
 }
 
-/// Tauri command: Fetch model manifest
-///
-/// Uses the higher-versioned manifest between remote and bundled, so that
-/// new models added in app updates are visible even before the remote
-/// manifest on GitHub is updated.
-#[tauri::command]
-pub async fn fetch_model_manifest(force_refresh: bool) -> Result<Vec<ModelInfo>, Error> {
-    let remote_manifest = match fetch_manifest(force_refresh).await {
-        Ok(m) => Some(m),
-        Err(e) => {
-            tracing::warn!("Failed to fetch remote manifest: {}", e);
-            None
-        }
-    };
-
+/// The higher-versioned manifest between `remote` and bundled, so that new
+/// models added in app updates are visible even before the remote manifest
+/// on GitHub is updated
+fn newest_manifest(remote_manifest: Option<ModelManifest>) -> ModelManifest {
     let bundled = get_fallback_manifest();
 
-    let manifest = match remote_manifest {
+    match remote_manifest {
         Some(remote) if remote.version >= bundled.version => remote,
         Some(remote) => {
             tracing::info!(
@@ -455,31 +576,140 @@ pub async fn fetch_model_manifest(force_refresh: bool) -> Result<Vec<ModelInfo>,
             tracing::info!("Using bundled manifest v{}", bundled.version);
             bundled
         }
-    };
+    }
+}
 
-    let selected_id = crate::config::get_config()
-        .ok()
+/// The manifest's models with their local status, for the frontend
+fn model_infos(manifest: &ModelManifest) -> Vec<ModelInfo> {
+    let config = crate::config::get_config().ok();
+    let selected_id = config
+        .as_ref()
         .and_then(|c| c.transcription.model_id.clone());
+    let language = config.map(|c| c.transcription.language).unwrap_or_default();
 
-    let models: Vec<ModelInfo> = manifest
+    let available_vram_mb = detected_vram_mb();
+    manifest
         .models
         .iter()
-        .map(|m| to_model_info(m, selected_id.as_deref()))
+        .map(|m| to_model_info(m, selected_id.as_deref(), &language, available_vram_mb))
+        .collect()
+}
+
+/// Tauri command: Fetch model manifest
+///
+/// Uses the higher-versioned manifest between remote and bundled (see
+/// [`newest_manifest`]).
+#[tauri::command]
+pub async fn fetch_model_manifest(force_refresh: bool) -> Result<Vec<ModelInfo>, Error> {
+    let remote_manifest = match fetch_manifest(force_refresh).await {
+        Ok(m) => Some(m),
+        Err(e) => {
+            tracing::warn!("Failed to fetch remote manifest: {}", e);
+            None
+        }
+    };
+
+    Ok(model_infos(&newest_manifest(remote_manifest)))
+}
+
+/// Models that changed between two manifests, by ID
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ManifestDiff {
+    /// Models not in the previous manifest
+    pub added: Vec<String>,
+    /// Models whose version or download changed
+    pub updated: Vec<String>,
+    /// Models no longer listed
+    pub removed: Vec<String>,
+}
+
+impl ManifestDiff {
+    /// Whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Compare the models of `previous` and `current`
+pub fn diff_manifests(previous: &ModelManifest, current: &ModelManifest) -> ManifestDiff {
+    let mut diff = ManifestDiff::default();
+    for model in &current.models {
+        match previous.models.iter().find(|m| m.id == model.id) {
+            None => diff.added.push(model.id.clone()),
+            Some(old)
+                if old.version != model.version
+                    || old.download_url != model.download_url
+                    || old.sha256 != model.sha256 =>
+            {
+                diff.updated.push(model.id.clone())
+            }
+            Some(_) => {}
+        }
+    }
+    diff.removed = previous
+        .models
+        .iter()
+        .filter(|m| !current.models.iter().any(|c| c.id == m.id))
+        .map(|m| m.id.clone())
         .collect();
+    diff
+}
 
-    Ok(models)
+/// Result of [`refresh_manifest`]
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestRefresh {
+    /// The models now listed, with their local status
+    pub models: Vec<ModelInfo>,
+    /// What changed since the cached manifest
+    pub diff: ManifestDiff,
+    /// Set when the remote manifest could not be fetched, so `models` come
+    /// from the cached (or bundled) copy and may be out of date
+    pub stale: bool,
+    /// When the cached copy was last fetched (ISO 8601)
+    pub updated_at: Option<String>,
 }
 
-/// Tauri command: Get manifest last update time
+/// Tauri command: Re-fetch the manifest and report what changed
+///
+/// Compares against the cached manifest, whatever its age, and replaces it.
+/// Emits [`MANIFEST_UPDATED_EVENT`] when anything changed. Offline, returns
+/// the cached models marked stale.
 #[tauri::command]
-pub fn get_manifest_update_time() -> Option<String> {
-    let cache_path = get_cache_path();
-    if !cache_path.exists() {
-        return None;
+pub async fn refresh_manifest(app: AppHandle) -> Result<ManifestRefresh, Error> {
+    let previous = newest_manifest(read_cache().map(|c| c.manifest));
+    let (manifest, stale) = match fetch_manifest(true).await {
+        Ok(remote) => (newest_manifest(Some(remote)), false),
+        Err(e) => {
+            tracing::warn!("Failed to refresh manifest, keeping cached copy: {}", e);
+            (previous.clone(), true)
+        }
+    };
+
+    let diff = diff_manifests(&previous, &manifest);
+    if !diff.is_empty() {
+        tracing::info!(
+            "Manifest changed: {} added, {} updated, {} removed",
+            diff.added.len(),
+            diff.updated.len(),
+            diff.removed.len()
+        );
+        if let Err(e) = app.emit(MANIFEST_UPDATED_EVENT, &diff) {
+            tracing::warn!("Failed to emit {}: {}", MANIFEST_UPDATED_EVENT, e);
+        }
     }
 
-    let data = std::fs::read_to_string(&cache_path).ok()?;
-    let cached: CachedManifest = serde_json::from_str(&data).ok()?;
+    Ok(ManifestRefresh {
+        models: model_infos(&manifest),
+        diff,
+        stale,
+        updated_at: get_manifest_update_time(),
+    })
+}
+
+/// Tauri command: Get manifest last update time
+#[tauri::command]
+pub fn get_manifest_update_time() -> Option<String> {
+    let cached = read_cache()?;
 
     // Convert timestamp to ISO 8601
     let datetime = chrono::DateTime::from_timestamp(cached.fetched_at as i64, 0)?;
@@ -493,7 +723,7 @@ mod tests {
     #[test]
     fn test_fallback_manifest() {
         let manifest = get_fallback_manifest();
-        assert_eq!(manifest.version, 10);
+        assert_eq!(manifest.version, 12);
         assert_eq!(manifest.models.len(), 6);
 
         // FluidAudio is the recommended model
@@ -546,6 +776,7 @@ mod tests {
         assert!(!is_backend_available("unknown_type"));
     }
 
+    #[serial_test::serial]
     #[test]
     fn test_model_directory() {
         let dir = get_model_directory("parakeet-tdt-0.6b-v3-int8");
@@ -604,17 +835,110 @@ mod tests {
             model_type: "test".to_string(),
             recommended: false,
             min_app_version: None,
+            vram_mb: Some(4000),
+            is_multilingual: None,
         };
 
-        let info = to_model_info(&remote, None);
+        let info = to_model_info(&remote, None, "en", None);
         assert_eq!(info.id, "test-model");
         assert_eq!(info.size_mb, 100);
         assert!(!info.downloaded);
         assert!(!info.selected);
         assert_eq!(info.model_type, "test");
         assert!(!info.backend_available);
+        assert!(!info.is_multilingual);
+        assert!(info.language_warning.is_none());
 
-        let info_selected = to_model_info(&remote, Some("test-model"));
+        let info_selected = to_model_info(&remote, Some("test-model"), "en", None);
         assert!(info_selected.selected);
+        assert_eq!(info_selected.vram_mb, Some(4000));
+        assert!(info_selected.vram_warning.is_none());
+
+        let info_small_gpu = to_model_info(&remote, None, "en", Some(2048));
+        assert!(info_small_gpu.vram_warning.is_some());
+
+        let info_german = to_model_info(&remote, None, "de", None);
+        assert!(info_german.language_warning.is_some());
+    }
+
+    #[test]
+    fn test_vram_warning() {
+        assert!(vram_warning(Some(3000), Some(8192)).is_none());
+        assert!(vram_warning(None, Some(2048)).is_none());
+        assert!(vram_warning(Some(3000), None).is_none());
+        let warning = vram_warning(Some(3900), Some(2048)).unwrap();
+        assert!(warning.contains("3.8 GB"), "{warning}");
+        assert!(warning.contains("2.0 GB"), "{warning}");
+    }
+
+    #[test]
+    fn test_manifest_without_vram_field_parses() {
+        let json = r#"{
+            "id": "m", "name": "M", "description": "", "version": "1",
+            "download_url": "", "download_size": 1, "extracted_size": 1,
+            "sha256": null, "required_files": [], "archive_directory": null,
+            "languages": [], "model_type": "whisper_ggml", "min_app_version": null
+        }"#;
+        let model: RemoteModelInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(model.vram_mb, None);
+        assert_eq!(model.is_multilingual, None);
+        assert!(model.multilingual());
+    }
+
+    #[test]
+    fn test_bundled_manifest_marks_multilingual_models() {
+        let manifest = get_fallback_manifest();
+        let multilingual = |id: &str| {
+            let model = manifest.models.iter().find(|m| m.id == id).unwrap();
+            assert!(
+                model.is_multilingual.is_some(),
+                "{id} should say explicitly"
+            );
+            model.multilingual()
+        };
+        assert!(!multilingual("ggml-medium.en"));
+        assert!(!multilingual("ggml-small.en"));
+        assert!(!multilingual("parakeet-tdt-0.6b-v2-int8"));
+        assert!(multilingual("ggml-large-v3-turbo"));
+        assert!(multilingual("parakeet-tdt-0.6b-v3-int8"));
+    }
+
+    #[test]
+    fn test_language_warning() {
+        let manifest = get_fallback_manifest();
+        let model = |id: &str| manifest.models.iter().find(|m| m.id == id).unwrap();
+        let english_only = model("ggml-small.en");
+        assert!(language_warning(english_only, "en").is_none());
+        assert!(language_warning(english_only, "en-AU").is_none());
+        assert!(language_warning(english_only, "auto").is_none());
+        assert!(language_warning(english_only, "").is_none());
+        let warning = language_warning(english_only, "de").unwrap();
+        assert!(warning.contains("English only"), "{warning}");
+
+        assert!(language_warning(model("ggml-large-v3-turbo"), "de").is_none());
+        let parakeet_v3 = model("parakeet-tdt-0.6b-v3-int8");
+        assert!(language_warning(parakeet_v3, "DE").is_none());
+        assert!(language_warning(parakeet_v3, "ja").is_some());
+
+        assert!(model_language_warning("ggml-medium.en", "fr").is_some());
+        assert!(model_language_warning("no-such-model", "fr").is_none());
+    }
+
+    #[test]
+    fn test_diff_manifests() {
+        let previous = get_fallback_manifest();
+        assert!(diff_manifests(&previous, &previous).is_empty());
+
+        let mut current = previous.clone();
+        let removed = current.models.remove(0);
+        current.models[0].version = "9.9.9".to_string();
+        let mut added = current.models[1].clone();
+        added.id = "new-model".to_string();
+        current.models.push(added);
+
+        let diff = diff_manifests(&previous, &current);
+        assert_eq!(diff.added, vec!["new-model"]);
+        assert_eq!(diff.updated, vec![current.models[0].id.clone()]);
+        assert_eq!(diff.removed, vec![removed.id]);
     }
 }
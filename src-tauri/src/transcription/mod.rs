@@ -4,22 +4,26 @@
 //! Fallback: Sherpa-ONNX with Parakeet models (cross-platform)
 
 mod au_spelling_map;
+mod chunking;
+pub mod context;
 pub mod download;
+pub mod fallback;
 pub mod filter;
 #[cfg(all(target_os = "macos", feature = "fluidaudio"))]
 pub mod fluidaudio;
 pub mod manifest;
 #[cfg(feature = "parakeet")]
 pub mod parakeet;
+mod slot;
 pub mod whisper;
 
 pub use filter::{FilterOptions, OutputFilter};
 pub use manifest::{ModelInfo, fetch_model_manifest, get_manifest_update_time};
 
 use crate::error::Error;
-use parking_lot::Mutex;
+use serde::Serialize;
 use std::path::PathBuf;
-use std::sync::OnceLock;
+use std::sync::atomic::AtomicBool;
 
 /// Transcription backend type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -33,6 +37,118 @@ pub enum TranscriptionBackend {
     FluidAudio,
 }
 
+impl TranscriptionBackend {
+    /// Name reported to the frontend and recorded in history
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Whisper => "whisper",
+            Self::Parakeet => "parakeet",
+            Self::FluidAudio => "fluidaudio",
+        }
+    }
+
+    /// Language of the backend's transcripts (ISO 639-1), if known.
+    ///
+    /// Whisper is always told the speech is English. Parakeet and FluidAudio
+    /// take no language hint, so an English-only `model` yields English and a
+    /// multilingual one the `configured` language when it lists it ("auto"
+    /// leaves the language unknown).
+    pub fn language(
+        self,
+        model: Option<&manifest::RemoteModelInfo>,
+        configured: &str,
+    ) -> Option<String> {
+        match self {
+            Self::Whisper => Some(whisper::LANGUAGE.to_string()),
+            Self::Parakeet | Self::FluidAudio => {
+                let Some(model) = model.filter(|m| m.multilingual()) else {
+                    return Some("en".to_string());
+                };
+                let code = manifest::primary_language(configured);
+                let known = !code.is_empty()
+                    && code != "auto"
+                    && manifest::language_warning(model, configured).is_none();
+                known.then_some(code)
+            }
+        }
+    }
+}
+
+/// [`TranscriptionBackend::language`] for the model and language in the config
+fn configured_language(backend: TranscriptionBackend) -> Option<String> {
+    let config = crate::config::get_config().ok();
+    let configured = config
+        .as_ref()
+        .map_or("", |c| c.transcription.language.as_str());
+    let fallback = manifest::get_fallback_manifest();
+    let model_id = config
+        .as_ref()
+        .and_then(|c| effective_model_id(&c.transcription));
+    let model = match model_id {
+        Some(id) => fallback.models.iter().find(|m| m.id == id),
+        None => fallback.models.iter().find(|m| m.recommended),
+    };
+    backend.language(model, configured)
+}
+
+/// A stretch of transcript as the backend produced it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptSegment {
+    pub text: String,
+    /// How sure the model was of the words, from 0.0 to 1.0 (`None` when
+    /// the backend doesn't report it)
+    pub confidence: Option<f32>,
+    /// Where the segment starts in the audio, in seconds (`None` when the
+    /// backend doesn't report timing)
+    pub start_secs: Option<f32>,
+    /// Where the segment ends in the audio, in seconds
+    pub end_secs: Option<f32>,
+}
+
+/// A speech-to-text engine the pipeline can run audio through.
+///
+/// [`TranscriptionService`] implements this directly, and [`LoadedTranscriber`]
+/// wraps the process-wide service the app loads at startup. Tests and library
+/// callers can supply their own implementation (e.g. one returning canned text).
+pub trait Transcriber: Send {
+    /// Whether the engine can transcribe now. The pipeline waits (bounded) for
+    /// this to become true, covering an eager model load still in progress.
+    fn is_ready(&self) -> bool {
+        true
+    }
+
+    /// Transcribe an audio file. Blocking; may run inference on the calling thread.
+    fn transcribe(&mut self, audio_path: &std::path::Path) -> anyhow::Result<String>;
+
+    /// Model name recorded alongside the transcription, if known
+    fn model_name(&self) -> Option<String> {
+        None
+    }
+
+    /// Language of the speech (ISO 639-1), detected or configured, if known
+    fn language(&self) -> Option<String> {
+        None
+    }
+
+    /// Input levels measured during the last `transcribe` call, if any
+    fn audio_levels(&self) -> Option<AudioLevels> {
+        None
+    }
+
+    /// Timed segments behind the last `transcribe` call's text, when they
+    /// were kept (see [`LoadedTranscriber::keeping_segments`])
+    fn segments(&self) -> Option<Vec<TranscriptSegment>> {
+        None
+    }
+
+    /// Time the last `transcribe` call spent converting its input to 16 kHz
+    /// WAV, if it had to; included in the call's total duration
+    fn decode_duration_seconds(&self) -> Option<f64> {
+        None
+    }
+}
+
 /// Unified transcription service that can use either backend
 pub enum TranscriptionService {
     Whisper(whisper::WhisperTranscriptionService),
@@ -64,9 +180,15 @@ impl TranscriptionService {
     }
 
     /// Transcribe audio from a WAV file
-    pub fn transcribe(&mut self, audio_path: &std::path::Path) -> anyhow::Result<String> {
+    ///
+    /// `initial_prompt` is only used by Whisper; other backends ignore it.
+    pub fn transcribe(
+        &mut self,
+        audio_path: &std::path::Path,
+        initial_prompt: Option<&str>,
+    ) -> anyhow::Result<String> {
         match self {
-            Self::Whisper(service) => service.transcribe(audio_path),
+            Self::Whisper(service) => service.transcribe(audio_path, initial_prompt),
             #[cfg(feature = "parakeet")]
             Self::Parakeet(service) => service.transcribe(audio_path),
             #[cfg(all(target_os = "macos", feature = "fluidaudio"))]
@@ -74,6 +196,58 @@ impl TranscriptionService {
         }
     }
 
+    /// Transcribe audio from a WAV file into segments
+    ///
+    /// Only Whisper reports segments and their confidence; other backends
+    /// return their whole transcript as one segment with no confidence.
+    pub fn transcribe_segments(
+        &mut self,
+        audio_path: &std::path::Path,
+        initial_prompt: Option<&str>,
+    ) -> anyhow::Result<Vec<TranscriptSegment>> {
+        match self {
+            Self::Whisper(service) => service.transcribe_segments(audio_path, initial_prompt),
+            #[cfg(feature = "parakeet")]
+            Self::Parakeet(service) => service.transcribe(audio_path).map(whole_transcript_segment),
+            #[cfg(all(target_os = "macos", feature = "fluidaudio"))]
+            Self::FluidAudio(service) => {
+                service.transcribe(audio_path).map(whole_transcript_segment)
+            }
+        }
+    }
+
+    /// Transcribe 16kHz mono samples without going through a file
+    pub fn transcribe_pcm(&mut self, samples: Vec<f32>) -> anyhow::Result<String> {
+        const SAMPLE_RATE: u32 = 16_000;
+        match self {
+            Self::Whisper(service) => service.transcribe_pcm(samples, SAMPLE_RATE, None),
+            #[cfg(feature = "parakeet")]
+            Self::Parakeet(service) => service.transcribe_pcm(samples, SAMPLE_RATE),
+            #[cfg(all(target_os = "macos", feature = "fluidaudio"))]
+            Self::FluidAudio(service) => service.transcribe_pcm(samples, SAMPLE_RATE),
+        }
+    }
+
+    /// Transcribe 16kHz mono samples into segments, as
+    /// [`transcribe_segments`](Self::transcribe_segments) does for a file
+    pub fn transcribe_pcm_segments(
+        &mut self,
+        samples: Vec<f32>,
+    ) -> anyhow::Result<Vec<TranscriptSegment>> {
+        const SAMPLE_RATE: u32 = 16_000;
+        match self {
+            Self::Whisper(service) => service.transcribe_pcm_segments(samples, SAMPLE_RATE, None),
+            #[cfg(feature = "parakeet")]
+            Self::Parakeet(service) => service
+                .transcribe_pcm(samples, SAMPLE_RATE)
+                .map(whole_transcript_segment),
+            #[cfg(all(target_os = "macos", feature = "fluidaudio"))]
+            Self::FluidAudio(service) => service
+                .transcribe_pcm(samples, SAMPLE_RATE)
+                .map(whole_transcript_segment),
+        }
+    }
+
     /// Get the backend type
     pub fn backend(&self) -> TranscriptionBackend {
         match self {
@@ -86,21 +260,227 @@ impl TranscriptionService {
     }
 }
 
-/// Global transcription service instance
-static TRANSCRIPTION_SERVICE: OnceLock<Mutex<Option<TranscriptionService>>> = OnceLock::new();
+impl Transcriber for TranscriptionService {
+    fn transcribe(&mut self, audio_path: &std::path::Path) -> anyhow::Result<String> {
+        TranscriptionService::transcribe(self, audio_path, None)
+    }
+
+    fn model_name(&self) -> Option<String> {
+        Some(self.backend().as_str().to_string())
+    }
 
-fn get_service() -> &'static Mutex<Option<TranscriptionService>> {
-    TRANSCRIPTION_SERVICE.get_or_init(|| Mutex::new(None))
+    fn language(&self) -> Option<String> {
+        configured_language(self.backend())
+    }
+}
+
+/// The process-wide [`TranscriptionService`] loaded by [`load_model`].
+///
+/// Goes through [`transcribe_file`], so non-WAV input is transcoded and silent
+/// audio short-circuits to an empty string exactly as for the frontend command.
+#[derive(Debug, Default, Clone)]
+pub struct LoadedTranscriber {
+    /// Text the speech is taken to follow (see [`context`])
+    initial_prompt: Option<String>,
+    /// Levels measured by the silence check of the last transcription
+    levels: Option<AudioLevels>,
+    /// Transcode time of the last transcription, when its input needed one
+    decode_seconds: Option<f64>,
+    /// Window length long files are transcribed in (see [`chunking`]);
+    /// `None` runs the whole file at once
+    chunk_secs: Option<u32>,
+    /// Whether to keep the timed segments of each transcription
+    keep_segments: bool,
+    /// Segments of the last transcription, when kept
+    segments: Option<Vec<TranscriptSegment>>,
+    /// Raised to stop a windowed transcription before its next window
+    cancelled: Option<&'static AtomicBool>,
+}
+
+impl LoadedTranscriber {
+    /// Transcriber that gives `initial_prompt` to the model
+    pub fn with_initial_prompt(initial_prompt: Option<String>) -> Self {
+        Self {
+            initial_prompt,
+            ..Self::default()
+        }
+    }
+
+    /// Transcribe files longer than `chunk_secs` in overlapping windows
+    /// (0 keeps whole-file transcription)
+    pub fn chunked(mut self, chunk_secs: u32) -> Self {
+        self.chunk_secs = (chunk_secs > 0).then_some(chunk_secs);
+        self
+    }
+
+    /// Keep each transcription's timed segments for [`Transcriber::segments`]
+    /// (used to mark pauses). Only Whisper reports their timing.
+    pub fn keeping_segments(mut self, keep: bool) -> Self {
+        self.keep_segments = keep;
+        self
+    }
+
+    /// Stop a [`chunked`](Self::chunked) transcription before its next
+    /// window once `cancelled` is raised
+    pub fn cancelled_by(mut self, cancelled: &'static AtomicBool) -> Self {
+        self.cancelled = Some(cancelled);
+        self
+    }
+}
+
+impl Transcriber for LoadedTranscriber {
+    fn is_ready(&self) -> bool {
+        is_transcription_ready()
+    }
+
+    fn transcribe(&mut self, audio_path: &std::path::Path) -> anyhow::Result<String> {
+        let file = transcribe_file_detailed(
+            &audio_path.to_string_lossy(),
+            self.initial_prompt.as_deref(),
+            self.chunk_secs,
+            self.keep_segments,
+            self.cancelled,
+        )
+        .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        self.levels = file.levels;
+        self.decode_seconds = file.decode_seconds;
+        self.segments = file.segments;
+        Ok(file.text)
+    }
+
+    fn audio_levels(&self) -> Option<AudioLevels> {
+        self.levels
+    }
+
+    fn segments(&self) -> Option<Vec<TranscriptSegment>> {
+        self.segments.clone()
+    }
+
+    fn decode_duration_seconds(&self) -> Option<f64> {
+        self.decode_seconds
+    }
+
+    /// The model in use for the configured language, falling back to the
+    /// backend name.
+    fn model_name(&self) -> Option<String> {
+        crate::config::get_config()
+            .ok()
+            .and_then(|c| effective_model_id(&c.transcription))
+            .or_else(get_transcription_backend)
+    }
+
+    fn language(&self) -> Option<String> {
+        TRANSCRIPTION_SERVICE
+            .backend()
+            .and_then(configured_language)
+    }
+}
+
+/// Global transcription service instance (see [`slot`] for the locking)
+static TRANSCRIPTION_SERVICE: slot::ServiceSlot<TranscriptionService> = slot::ServiceSlot::new();
+
+/// Event emitted when a model starts or finishes loading (payload: [`ModelLoading`])
+pub const MODEL_LOADING_EVENT: &str = "model-loading";
+
+/// Payload of [`MODEL_LOADING_EVENT`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelLoading {
+    /// True when the load starts, false when it finishes (either way)
+    pub loading: bool,
+    /// Backend being loaded
+    pub backend: String,
+}
+
+/// A model load in progress.
+///
+/// Holds the slot's load guard and tells the frontend when loading starts
+/// and, on drop, when it ends, so a model on a slow volume shows as loading
+/// rather than missing.
+struct ModelLoad {
+    guard: Option<slot::LoadGuard<'static, TranscriptionService>>,
+    backend: TranscriptionBackend,
+}
+
+impl ModelLoad {
+    fn begin(backend: TranscriptionBackend) -> Result<Self, Error> {
+        let guard = TRANSCRIPTION_SERVICE.begin_load()?;
+        emit_model_loading(true, backend);
+        Ok(Self {
+            guard: Some(guard),
+            backend,
+        })
+    }
+}
+
+impl Drop for ModelLoad {
+    fn drop(&mut self) {
+        // Release the slot first so `is_model_loading` is already false when
+        // the frontend reacts to the event
+        drop(self.guard.take());
+        emit_model_loading(false, self.backend);
+    }
+}
+
+fn emit_model_loading(loading: bool, backend: TranscriptionBackend) {
+    crate::app_handle::emit(
+        MODEL_LOADING_EVENT,
+        ModelLoading {
+            loading,
+            backend: backend.as_str().to_string(),
+        },
+    );
+}
+
+/// Install a freshly loaded service, replacing any previous one
+fn install_service(service: TranscriptionService) {
+    let backend = service.backend();
+    TRANSCRIPTION_SERVICE.install(service, backend);
+}
+
+/// Run a blocking model load on the blocking thread pool.
+///
+/// Reading a model from a network volume can take many seconds; a sync
+/// command would hold the main thread (and the UI) for all of it.
+async fn load_off_main_thread(
+    load: impl FnOnce() -> Result<(), Error> + Send + 'static,
+) -> Result<(), Error> {
+    tokio::task::spawn_blocking(load)
+        .await
+        .map_err(|e| format!("Model load task failed: {}", e))?
 }
 
 /// Initialise the transcription service with whisper backend (primary)
 #[tauri::command]
-pub fn init_whisper_transcription(model_path: String) -> Result<(), Error> {
+pub async fn init_whisper_transcription(model_path: String) -> Result<(), Error> {
+    load_off_main_thread(move || load_whisper(model_path)).await
+}
+
+/// Initialise the transcription service with parakeet backend (fallback)
+#[tauri::command]
+pub async fn init_parakeet_transcription(model_dir: String) -> Result<(), Error> {
+    load_off_main_thread(move || load_parakeet(model_dir)).await
+}
+
+/// Initialise the transcription service with FluidAudio backend (Apple Neural Engine)
+#[tauri::command]
+pub async fn init_fluidaudio_transcription() -> Result<(), Error> {
+    load_off_main_thread(load_fluidaudio).await
+}
+
+/// Initialise the transcription service (auto-detect best backend)
+#[tauri::command]
+pub async fn init_transcription(model_path: String) -> Result<(), Error> {
+    load_off_main_thread(move || load_model(model_path)).await
+}
+
+/// Load a whisper model, blocking until it is ready
+pub fn load_whisper(model_path: String) -> Result<(), Error> {
+    let _loading = ModelLoad::begin(TranscriptionBackend::Whisper)?;
     let service =
         TranscriptionService::new_whisper(&PathBuf::from(model_path)).map_err(|e| e.to_string())?;
 
-    let mut guard = get_service().lock();
-    *guard = Some(service);
+    install_service(service);
 
     tracing::info!(
         "Whisper transcription service initialised ({} backend)",
@@ -109,16 +489,15 @@ pub fn init_whisper_transcription(model_path: String) -> Result<(), Error> {
     Ok(())
 }
 
-/// Initialise the transcription service with parakeet backend (fallback)
-#[tauri::command]
-pub fn init_parakeet_transcription(_model_dir: String) -> Result<(), Error> {
+/// Load a parakeet model, blocking until it is ready
+pub fn load_parakeet(_model_dir: String) -> Result<(), Error> {
     #[cfg(feature = "parakeet")]
     {
+        let _loading = ModelLoad::begin(TranscriptionBackend::Parakeet)?;
         let service = TranscriptionService::new_parakeet(&PathBuf::from(_model_dir))
             .map_err(|e| e.to_string())?;
 
-        let mut guard = get_service().lock();
-        *guard = Some(service);
+        install_service(service);
 
         tracing::info!("Parakeet transcription service initialised");
         Ok(())
@@ -130,15 +509,14 @@ pub fn init_parakeet_transcription(_model_dir: String) -> Result<(), Error> {
         .into())
 }
 
-/// Initialise the transcription service with FluidAudio backend (Apple Neural Engine)
-#[tauri::command]
-pub fn init_fluidaudio_transcription() -> Result<(), Error> {
+/// Load the FluidAudio model, blocking until it is ready
+pub fn load_fluidaudio() -> Result<(), Error> {
     #[cfg(all(target_os = "macos", feature = "fluidaudio"))]
     {
+        let _loading = ModelLoad::begin(TranscriptionBackend::FluidAudio)?;
         let service = TranscriptionService::new_fluidaudio().map_err(|e| e.to_string())?;
 
-        let mut guard = get_service().lock();
-        *guard = Some(service);
+        install_service(service);
 
         // Write sentinel marker so check_model_downloaded() returns true
         if let Err(e) = fluidaudio::write_ready_marker() {
@@ -170,16 +548,15 @@ pub fn init_fluidaudio_transcription() -> Result<(), Error> {
         .into())
 }
 
-/// Initialise the transcription service (auto-detect best backend)
+/// Load the model at `model_path`, picking the backend from what is there
 ///
 /// Tries whisper first, falls back to parakeet if whisper model not found.
-#[tauri::command]
-pub fn init_transcription(model_path: String) -> Result<(), Error> {
+pub fn load_model(model_path: String) -> Result<(), Error> {
     let path = PathBuf::from(&model_path);
 
     // If it's a direct .bin file path, use whisper
     if path.extension().map(|e| e == "bin").unwrap_or(false) {
-        return init_whisper_transcription(model_path);
+        return load_whisper(model_path);
     }
 
     // If it's a directory, check what's inside
@@ -194,7 +571,7 @@ pub fn init_transcription(model_path: String) -> Result<(), Error> {
                     .unwrap_or(false)
                 {
                     tracing::info!("Found whisper model in directory, using Metal GPU backend");
-                    return init_whisper_transcription(entry_path.to_string_lossy().to_string());
+                    return load_whisper(entry_path.to_string_lossy().to_string());
                 }
             }
         }
@@ -205,7 +582,7 @@ pub fn init_transcription(model_path: String) -> Result<(), Error> {
             let encoder = path.join("encoder.int8.onnx");
             if encoder.exists() {
                 tracing::info!("Found ONNX model in directory, using Parakeet backend");
-                return init_parakeet_transcription(model_path);
+                return load_parakeet(model_path);
             }
         }
         #[cfg(not(feature = "parakeet"))]
@@ -239,6 +616,32 @@ pub fn init_transcription(model_path: String) -> Result<(), Error> {
 /// while still filtering out true digital silence.
 const MIN_SPEECH_RMS: f32 = 0.002;
 
+/// Sample magnitude treated as full scale when counting clipped samples.
+/// Just under 1.0 so values a hair below the i16 limit still count.
+const CLIP_LEVEL: f32 = 0.99;
+
+/// Fraction of full-scale samples above which a recording is reported as
+/// clipped. Isolated full-scale peaks are harmless; sustained flat-topping at
+/// this density is audible and degrades recognition.
+pub const CLIP_RATIO_THRESHOLD: f32 = 0.001;
+
+/// Levels measured by the silence check before transcription
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioLevels {
+    /// Whether any 500 ms window reached the speech RMS threshold
+    pub has_speech: bool,
+    /// Fraction of samples at or near full scale (0.0-1.0)
+    pub clip_ratio: f32,
+}
+
+impl AudioLevels {
+    /// Whether enough samples hit full scale to suggest the input gain is too high
+    pub fn clipped(&self) -> bool {
+        self.clip_ratio > CLIP_RATIO_THRESHOLD
+    }
+}
+
 /// Transcribe audio from a file path
 ///
 /// Accepts WAV, MP3, M4A, OGG (Vorbis), and FLAC. Non-WAV files and WAV files
@@ -250,13 +653,117 @@ const MIN_SPEECH_RMS: f32 = 0.002;
 /// which prevents Whisper from hallucinating phrases like "Thank you" on silent input.
 #[tauri::command]
 pub fn transcribe_file(audio_path: String) -> Result<String, Error> {
-    let input = PathBuf::from(&audio_path);
+    transcribe_file_detailed(&audio_path, None, None, false, None).map(|file| file.text)
+}
+
+/// Transcribe audio from a file path into segments, each with the model's
+/// confidence where the backend reports it
+///
+/// Takes the same input and skips silence the same way as [`transcribe_file`];
+/// silent audio gives no segments.
+#[tauri::command]
+pub fn transcribe_file_segments(audio_path: String) -> Result<Vec<TranscriptSegment>, Error> {
+    transcribe_prepared_file(&audio_path, Vec::new(), |service, wav_path| {
+        service.transcribe_segments(wav_path, None)
+    })
+    .map(|(segments, _, _)| segments)
+}
+
+/// A transcript as a single segment of unknown confidence, or none if empty
+#[cfg_attr(
+    not(any(feature = "parakeet", all(target_os = "macos", feature = "fluidaudio"))),
+    allow(dead_code)
+)]
+fn whole_transcript_segment(text: String) -> Vec<TranscriptSegment> {
+    if text.trim().is_empty() {
+        return Vec::new();
+    }
+    vec![TranscriptSegment {
+        text,
+        confidence: None,
+        start_secs: None,
+        end_secs: None,
+    }]
+}
+
+/// Result of [`transcribe_file_detailed`]
+pub(crate) struct FileTranscription {
+    pub text: String,
+    /// Levels the silence check measured (`None` for audio the check cannot
+    /// read, such as non-16-bit WAV)
+    pub levels: Option<AudioLevels>,
+    /// Time spent transcoding the input, when it was not already 16 kHz WAV
+    pub decode_seconds: Option<f64>,
+    /// Timed segments of the text, when asked for
+    pub segments: Option<Vec<TranscriptSegment>>,
+}
+
+/// [`transcribe_file`] with an optional `initial_prompt`, also returning what
+/// the silence check measured and how long any transcode took.
+///
+/// With `chunk_secs`, audio longer than that is transcribed in windows (see
+/// [`chunking`]); the prompt then goes unused. With `keep_segments`, the
+/// backend's timed segments are returned alongside the text, timed from the
+/// start of the file either way. Raising `cancelled` stops a windowed
+/// transcription before its next window.
+pub(crate) fn transcribe_file_detailed(
+    audio_path: &str,
+    initial_prompt: Option<&str>,
+    chunk_secs: Option<u32>,
+    keep_segments: bool,
+    cancelled: Option<&AtomicBool>,
+) -> Result<FileTranscription, Error> {
+    transcribe_prepared_file(audio_path, (String::new(), None), |service, wav_path| {
+        if let Some(chunk_secs) = chunk_secs {
+            if let Some(chunked) = chunking::transcribe_chunked(
+                service,
+                wav_path,
+                chunk_secs,
+                keep_segments,
+                cancelled,
+            )? {
+                return Ok((chunked.text, chunked.segments));
+            }
+        }
+        if keep_segments {
+            let segments = service.transcribe_segments(wav_path, initial_prompt)?;
+            let text = segments
+                .iter()
+                .map(|s| s.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            return Ok((text, Some(segments)));
+        }
+        service
+            .transcribe(wav_path, initial_prompt)
+            .map(|text| (text, None))
+    })
+    .map(
+        |((text, segments), levels, decode_seconds)| FileTranscription {
+            text,
+            levels,
+            decode_seconds,
+            segments,
+        },
+    )
+}
+
+/// Transcode `audio_path` if needed, skip it if silent (giving `silent`),
+/// and otherwise hand the WAV to `run` with the loaded service. Also returns
+/// the levels measured and the transcode time.
+fn transcribe_prepared_file<T>(
+    audio_path: &str,
+    silent: T,
+    run: impl FnOnce(&mut TranscriptionService, &std::path::Path) -> anyhow::Result<T>,
+) -> Result<(T, Option<AudioLevels>, Option<f64>), Error> {
+    let input = PathBuf::from(audio_path);
 
     // Decide whether a transcode is needed.
     // is_target_format_wav returns true only for 16kHz mono i16 WAV files.
     // For any other input we run decode_audio_to_wav first.
     let needs_transcode = !crate::audio::decode::is_target_format_wav(&input);
 
+    let decode_start = std::time::Instant::now();
     let (wav_path, _temp): (PathBuf, Option<tempfile::NamedTempFile>) = if needs_transcode {
         let temp = tempfile::Builder::new()
             .prefix("thoth_transcode_")
@@ -271,33 +778,85 @@ pub fn transcribe_file(audio_path: String) -> Result<String, Error> {
     } else {
         (input.clone(), None)
     };
+    let decode_seconds = needs_transcode.then(|| decode_start.elapsed().as_secs_f64());
 
     // Check if audio contains speech before transcribing
-    if !audio_has_speech(&wav_path)? {
+    let levels = analyse_audio(&wav_path)?;
+    if levels.is_some_and(|l| !l.has_speech) {
         tracing::info!(
             "Audio file appears to be silent, skipping transcription: {}",
             audio_path
         );
-        return Ok(String::new());
+        return Ok((silent, levels, decode_seconds));
+    }
+    if let Some(l) = levels.filter(AudioLevels::clipped) {
+        tracing::warn!(
+            "Audio is clipping: {:.2}% of samples at full scale",
+            l.clip_ratio * 100.0
+        );
     }
 
-    let mut guard = get_service().lock();
-    let service = guard
-        .as_mut()
-        .ok_or_else(|| "Transcription service not initialised".to_string())?;
+    // The model's own lock is held only for inference; the slot is not.
+    let service = TRANSCRIPTION_SERVICE.get()?;
+    let mut service = service.lock();
+    run(&mut *service, &wav_path)
+        .map(|result| (result, levels, decode_seconds))
+        .map_err(|e| e.to_string())
+        .map_err(Into::into)
+    // _temp drops here, deleting the temp file (if any) on both Ok and Err paths.
+}
+
+/// Transcribe interleaved PCM samples already in memory
+///
+/// Skips the WAV round-trip of [`transcribe_file`]: multichannel samples are
+/// reduced to mono with the configured channel mode, resampled to 16 kHz if
+/// needed and handed straight to the backend. The same silence check
+/// applies, so a silent buffer returns an empty string.
+#[tauri::command]
+pub fn transcribe_pcm(samples: Vec<f32>, sample_rate: u32, channels: u16) -> Result<String, Error> {
+    if sample_rate == 0 {
+        return Err("Sample rate must be greater than zero".into());
+    }
+    if channels == 0 {
+        return Err("Channel count must be greater than zero".into());
+    }
+
+    let samples = if channels == 1 {
+        samples
+    } else {
+        let channel_mode = crate::config::get_config()
+            .map(|c| c.audio.channel_mode)
+            .unwrap_or_default();
+        crate::audio::format::downmix(&samples, usize::from(channels), channel_mode)
+    };
+    let samples = if sample_rate == 16_000 {
+        samples
+    } else {
+        crate::audio::format::resample_mono(&samples, sample_rate, 16_000)?
+    };
+
+    if !analyse_levels(&samples).has_speech {
+        tracing::info!(
+            "PCM buffer appears to be silent, skipping transcription ({} samples)",
+            samples.len()
+        );
+        return Ok(String::new());
+    }
 
+    let service = TRANSCRIPTION_SERVICE.get()?;
+    let mut service = service.lock();
     service
-        .transcribe(&wav_path)
+        .transcribe_pcm(samples)
         .map_err(|e| e.to_string())
         .map_err(Into::into)
-    // _temp drops here, deleting the temp file (if any) on both Ok and Err paths.
 }
 
-/// Check if a WAV file contains speech (has sufficient audio energy)
+/// Measure a WAV file's levels: whether it contains speech (sufficient audio
+/// energy) and how much of it is clipped.
 ///
-/// Reads the audio samples and calculates RMS. If the RMS is below
-/// the silence threshold, returns false (no speech detected).
-fn audio_has_speech(path: &std::path::Path) -> Result<bool, String> {
+/// Returns `None` for formats the check cannot read, which are assumed to
+/// contain speech.
+fn analyse_audio(path: &std::path::Path) -> Result<Option<AudioLevels>, String> {
     use std::io::Read;
 
     let file =
@@ -325,7 +884,7 @@ fn audio_has_speech(path: &std::path::Path) -> Result<bool, String> {
             "Non-16-bit audio ({}), assuming speech present",
             bits_per_sample
         );
-        return Ok(true);
+        return Ok(None);
     }
 
     // Read audio data and calculate RMS
@@ -353,36 +912,61 @@ fn audio_has_speech(path: &std::path::Path) -> Result<bool, String> {
         samples
     };
 
+    Ok(Some(analyse_levels(&mono_samples)))
+}
+
+/// Measure 16 kHz mono samples for speech and clipping in a single pass
+fn analyse_levels(mono_samples: &[f32]) -> AudioLevels {
     // Check for speech using windowed RMS rather than overall RMS.
     // Short recordings often contain startup silence from the audio stream
     // initialising, which dilutes the overall RMS below the threshold even
     // when speech is clearly present in part of the recording.
-    let overall_rms = crate::audio::metering::calculate_rms(&mono_samples);
-
-    // Also find the peak RMS in 500ms windows
+    // Clipped samples are counted in the same loop.
     let window_size = 8000; // 500 ms at 16 kHz
-    let peak_window_rms = mono_samples
-        .chunks(window_size)
-        .map(crate::audio::metering::calculate_rms)
-        .fold(0.0f32, f32::max);
+    let mut total_sum_sq = 0.0f32;
+    let mut clipped_samples = 0usize;
+    let mut peak_window_rms = 0.0f32;
+    for window in mono_samples.chunks(window_size) {
+        let mut sum_sq = 0.0f32;
+        for &s in window {
+            sum_sq += s * s;
+            if s.abs() >= CLIP_LEVEL {
+                clipped_samples += 1;
+            }
+        }
+        total_sum_sq += sum_sq;
+        peak_window_rms = peak_window_rms.max((sum_sq / window.len() as f32).sqrt());
+    }
+    let (overall_rms, clip_ratio) = if mono_samples.is_empty() {
+        (0.0, 0.0)
+    } else {
+        let n = mono_samples.len() as f32;
+        ((total_sum_sq / n).sqrt(), clipped_samples as f32 / n)
+    };
 
     tracing::debug!(
-        "Audio RMS: overall={:.6}, peak_window={:.6} (threshold: {}), samples: {}",
+        "Audio RMS: overall={:.6}, peak_window={:.6} (threshold: {}), clipped={:.4}, samples: {}",
         overall_rms,
         peak_window_rms,
         MIN_SPEECH_RMS,
+        clip_ratio,
         mono_samples.len()
     );
 
-    Ok(peak_window_rms >= MIN_SPEECH_RMS)
+    AudioLevels {
+        has_speech: peak_window_rms >= MIN_SPEECH_RMS,
+        clip_ratio,
+    }
 }
 
 /// Eagerly initialise the transcription model in the background.
 /// Triggers Metal shader compilation so the first recording is instant.
+/// Falls back to another backend if the selected model fails to load (see
+/// [`fallback`]).
 pub fn warmup_transcription() {
     let selected_id = crate::config::get_config()
         .ok()
-        .and_then(|c| c.transcription.model_id.clone());
+        .and_then(|c| effective_model_id(&c.transcription));
 
     let manifest = manifest::get_fallback_manifest();
 
@@ -395,104 +979,74 @@ pub fn warmup_transcription() {
             .map(|m| m.model_type.as_str())
     });
 
-    // ── FluidAudio path ────────────────────────────────────────────────
-    // Try FluidAudio when explicitly selected OR when nothing is selected
-    // (it's the recommended default on Apple Silicon).
-    let should_try_fluidaudio =
-        selected_model_type == Some("fluidaudio_coreml") || selected_id.is_none();
+    let acceleration = crate::platform::macos_acceleration_info();
 
-    if should_try_fluidaudio && try_warmup_fluidaudio() {
-        return;
+    // FluidAudio is the recommended default on Apple Silicon; say why it
+    // is passed over here
+    let wants_fluidaudio =
+        selected_model_type == Some("fluidaudio_coreml") || selected_id.is_none();
+    if wants_fluidaudio && cfg!(target_os = "macos") && !acceleration.neural_engine_available {
+        tracing::info!(
+            "FluidAudio skipped: no Apple Neural Engine on this Mac (Intel); using Whisper"
+        );
     }
-    // FluidAudio unavailable/not cached — fall through to Whisper
 
-    // ── Whisper/Parakeet path ──────────────────────────────────────────
-    if selected_id.is_some() && selected_model_type != Some("fluidaudio_coreml") {
-        // A specific non-FluidAudio model is selected — try to init it
-        let model_dir = get_model_directory();
-        if !download::check_model_downloaded(None) {
-            tracing::info!("Selected model not downloaded yet, skipping warmup");
-            return;
-        }
-        match init_transcription(model_dir) {
-            Ok(()) => {
-                tracing::info!("Transcription model warmed up");
-                return;
-            }
-            Err(e) => {
-                tracing::warn!("Transcription warmup failed: {}", e);
-                // Backend might be unavailable, fall through to Whisper fallback
-            }
-        }
+    if cfg!(target_os = "macos")
+        && acceleration.preferred_backend == crate::platform::AccelerationBackend::WhisperCpu
+    {
+        tracing::info!(
+            "Neither Metal nor the Neural Engine is usable on this Mac; using Whisper on the CPU"
+        );
     }
 
-    // ── Whisper fallback ───────────────────────────────────────────────
-    warmup_whisper_fallback(&manifest);
+    fallback::load_with_fallback();
 }
 
-/// Attempt to warm up FluidAudio. Returns `true` if successful.
-fn try_warmup_fluidaudio() -> bool {
-    #[cfg(all(target_os = "macos", feature = "fluidaudio"))]
-    {
-        if fluidaudio::is_cached() {
-            match init_fluidaudio_transcription() {
-                Ok(()) => {
-                    tracing::info!("FluidAudio transcription model warmed up (Neural Engine)");
-                    return true;
-                }
-                Err(e) => {
-                    tracing::warn!("FluidAudio warmup failed: {}, falling back", e);
-                }
-            }
-        } else {
-            tracing::info!("FluidAudio models not yet cached, falling back to Whisper");
-        }
-    }
-
-    #[cfg(not(all(target_os = "macos", feature = "fluidaudio")))]
-    {
-        tracing::debug!("FluidAudio backend not available in this build");
+/// The model to load for `config`'s language
+///
+/// The model `language_model_map` gives the configured language, when it is
+/// downloaded; otherwise the selected model. The language is the configured
+/// one, as no backend detects it.
+pub fn effective_model_id(config: &crate::config::TranscriptionConfig) -> Option<String> {
+    match config.language_model_id() {
+        Some(mapped) if is_model_id_downloaded(mapped) => Some(mapped.to_string()),
+        _ => config.model_id.clone(),
     }
-
-    false
 }
 
-/// Fall back to the best available downloaded Whisper model during warmup.
-fn warmup_whisper_fallback(manifest: &manifest::ModelManifest) {
-    // Try the largest/best downloaded Whisper model (manifest order = quality order)
-    if let Some(whisper_model) = manifest
+/// Whether the manifest model `model_id` is ready to load
+pub fn is_model_id_downloaded(model_id: &str) -> bool {
+    manifest::get_fallback_manifest()
         .models
         .iter()
-        .find(|m| m.model_type == "whisper_ggml" && manifest::is_model_downloaded(m))
-    {
-        let whisper_dir = manifest::get_model_directory(&whisper_model.id);
-        match init_transcription(whisper_dir.to_string_lossy().to_string()) {
-            Ok(()) => {
-                tracing::info!("Fell back to Whisper model '{}'", whisper_model.id);
-            }
-            Err(e) => {
-                tracing::warn!("Whisper fallback also failed: {}", e);
-            }
-        }
-    } else {
-        tracing::info!("No downloaded Whisper model available for fallback");
-    }
+        .find(|m| m.id == model_id)
+        .is_some_and(|m| match m.model_type.as_str() {
+            "fluidaudio_coreml" => is_fluidaudio_cached(),
+            _ => manifest::is_model_downloaded(m),
+        })
 }
 
 /// Check if transcription service is ready
 #[tauri::command]
 pub fn is_transcription_ready() -> bool {
-    get_service().lock().is_some()
+    TRANSCRIPTION_SERVICE.is_loaded()
+}
+
+/// Check if a model is being loaded right now
+///
+/// Distinguishes "still loading" from "no model" while
+/// [`is_transcription_ready`] is false (see [`MODEL_LOADING_EVENT`]).
+#[tauri::command]
+pub fn is_model_loading() -> bool {
+    TRANSCRIPTION_SERVICE.is_loading()
 }
 
 /// Get the current transcription backend
 #[tauri::command]
 pub fn get_transcription_backend() -> Option<String> {
-    get_service().lock().as_ref().map(|s| match s.backend() {
-        TranscriptionBackend::Whisper => "whisper".to_string(),
-        TranscriptionBackend::Parakeet => "parakeet".to_string(),
-        TranscriptionBackend::FluidAudio => "fluidaudio".to_string(),
-    })
+    TRANSCRIPTION_SERVICE
+        .backend()
+        .map(|b| b.as_str().to_string())
 }
 
 /// Get the default model directory path for the currently selected/recommended model
@@ -583,3 +1137,89 @@ pub fn is_fluidaudio_cached() -> bool {
     #[cfg(not(all(target_os = "macos", feature = "fluidaudio")))]
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_language_follows_multilingual_models() {
+        let models = manifest::get_fallback_manifest().models;
+        let model = |id: &str| models.iter().find(|m| m.id == id);
+        let v2 = model("parakeet-tdt-0.6b-v2-int8");
+        let v3 = model("parakeet-tdt-0.6b-v3-int8");
+        let fluid = model("fluidaudio-parakeet-tdt-coreml");
+
+        let parakeet = TranscriptionBackend::Parakeet;
+        assert_eq!(parakeet.language(v3, "fr"), Some("fr".to_string()));
+        assert_eq!(parakeet.language(v3, "fr-CA"), Some("fr".to_string()));
+        assert_eq!(parakeet.language(v3, "ja"), None);
+        assert_eq!(parakeet.language(v3, "auto"), None);
+        assert_eq!(parakeet.language(v2, "fr"), Some("en".to_string()));
+        assert_eq!(
+            TranscriptionBackend::FluidAudio.language(fluid, "de"),
+            Some("de".to_string())
+        );
+        assert_eq!(
+            TranscriptionBackend::Whisper.language(None, "fr"),
+            Some(whisper::LANGUAGE.to_string())
+        );
+    }
+
+    #[test]
+    fn test_whole_transcript_segment() {
+        assert!(whole_transcript_segment(String::new()).is_empty());
+        assert!(whole_transcript_segment("  ".to_string()).is_empty());
+        assert_eq!(
+            whole_transcript_segment("hello world".to_string()),
+            vec![TranscriptSegment {
+                text: "hello world".to_string(),
+                confidence: None,
+                start_secs: None,
+                end_secs: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_silent_samples_have_no_speech() {
+        let levels = analyse_levels(&vec![0.0; 16_000]);
+        assert!(!levels.has_speech);
+        assert!(!levels.clipped());
+    }
+
+    #[test]
+    fn test_loud_window_counts_as_speech() {
+        // One second of silence followed by a half-second tone
+        let mut samples = vec![0.0; 16_000];
+        samples.extend((0..8_000).map(|i| (i as f32 * 0.1).sin() * 0.1));
+        let levels = analyse_levels(&samples);
+        assert!(levels.has_speech);
+        assert!(!levels.clipped());
+    }
+
+    #[test]
+    fn test_flat_topped_signal_is_clipped() {
+        // A sine driven 3x past full scale and hard-limited, as an ADC would
+        let samples: Vec<f32> = (0..16_000)
+            .map(|i| ((i as f32 * 0.05).sin() * 3.0).clamp(-1.0, 1.0))
+            .collect();
+        let levels = analyse_levels(&samples);
+        assert!(levels.clipped());
+        assert!(levels.clip_ratio > 0.5, "ratio {}", levels.clip_ratio);
+    }
+
+    #[test]
+    fn test_isolated_peak_is_not_clipped() {
+        let mut samples: Vec<f32> = (0..16_000).map(|i| (i as f32 * 0.05).sin() * 0.3).collect();
+        samples[100] = 1.0;
+        assert!(!analyse_levels(&samples).clipped());
+    }
+
+    #[test]
+    fn test_empty_samples() {
+        let levels = analyse_levels(&[]);
+        assert!(!levels.has_speech);
+        assert_eq!(levels.clip_ratio, 0.0);
+    }
+}
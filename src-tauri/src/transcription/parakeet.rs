@@ -130,6 +130,14 @@ impl TranscriptionService {
             audio_path.display()
         );
 
+        self.transcribe_pcm(samples, sample_rate)
+    }
+
+    /// Transcribe an in-memory mono buffer
+    ///
+    /// Applies the same level check, silence trim and padding as
+    /// [`transcribe`](Self::transcribe). Samples should be 16kHz.
+    pub fn transcribe_pcm(&mut self, samples: Vec<f32>, sample_rate: u32) -> Result<String> {
         // Check audio levels for debugging
         let max_level = samples.iter().map(|s| s.abs()).fold(0.0f32, f32::max);
         let rms = (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt();
@@ -147,8 +155,10 @@ impl TranscriptionService {
             );
         }
 
-        // Trim leading silence for long recordings (the tail is always kept).
-        let (trim_start, trim_end) = crate::audio::vad::trim_silence(&samples, sample_rate);
+        // Trim leading silence for long recordings (the tail is kept unless
+        // config.audio.trim_silence asks for both edges).
+        let (trim_start, trim_end) =
+            crate::audio::vad::trim_for_transcription(&samples, sample_rate);
         let samples = samples[trim_start..trim_end].to_vec();
 
         if sample_rate != 16000 {
@@ -185,7 +195,8 @@ impl TranscriptionService {
             rtf
         );
 
-        tracing::info!("Transcription result: '{}' ({} chars)", text, text.len());
+        // No text here: the pipeline logs a preview, redacted if configured.
+        tracing::info!("Transcription result: {} chars", text.len());
 
         Ok(text)
     }
@@ -241,8 +252,7 @@ fn load_wav_samples(path: &Path) -> Result<(Vec<f32>, u32)> {
 
 /// Get the default model directory path
 pub fn get_model_directory() -> PathBuf {
-    let home = dirs::home_dir().expect("Could not find home directory");
-    home.join(".thoth").join("models").join("parakeet")
+    crate::transcription::manifest::get_models_root_directory().join("parakeet")
 }
 
 /// Check if the model is downloaded
@@ -258,6 +268,7 @@ pub fn is_model_downloaded() -> bool {
 mod tests {
     use super::*;
 
+    #[serial_test::serial]
     #[test]
     fn test_get_model_directory() {
         let dir = get_model_directory();
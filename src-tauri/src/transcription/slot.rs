@@ -0,0 +1,219 @@
+//! Holder for the process-wide transcription model.
+//!
+//! A single lock around the model would make every readiness check, and every
+//! model swap, wait for whatever transcription is running. Instead the slot
+//! hands out a shared handle under a brief lock and callers then lock only the
+//! model itself:
+//!
+//! - transcriptions hold the model's own lock for the duration of inference;
+//! - installing a new model swaps the handle in a short critical section and
+//!   never waits — a transcription already running finishes on the old model,
+//!   which is freed when it completes;
+//! - readiness and backend queries only touch the slot, so they never block
+//!   behind inference.
+//!
+//! Loading a model takes seconds, so loads are marked with [`LoadGuard`]: a
+//! second concurrent load is refused, and a transcription requested before
+//! any model is ready gets a clear "still loading" error instead of waiting.
+
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use super::TranscriptionBackend;
+
+/// Handle to a loaded model; lock it for the duration of one transcription
+pub type SharedModel<T> = Arc<Mutex<T>>;
+
+/// The installed model and what it runs on
+struct Loaded<T> {
+    model: SharedModel<T>,
+    backend: TranscriptionBackend,
+}
+
+/// Slot holding the current model; see the module docs
+pub struct ServiceSlot<T> {
+    current: Mutex<Option<Loaded<T>>>,
+    loading: AtomicBool,
+    /// Bumped on every install, so callers can tell a model was replaced
+    generation: AtomicU64,
+}
+
+impl<T> ServiceSlot<T> {
+    pub const fn new() -> Self {
+        Self {
+            current: Mutex::new(None),
+            loading: AtomicBool::new(false),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Whether a model is installed
+    pub fn is_loaded(&self) -> bool {
+        self.current.lock().is_some()
+    }
+
+    /// Whether a model load is in progress
+    pub fn is_loading(&self) -> bool {
+        self.loading.load(Ordering::SeqCst)
+    }
+
+    /// Backend of the installed model, without waiting on a transcription
+    pub fn backend(&self) -> Option<TranscriptionBackend> {
+        self.current.lock().as_ref().map(|l| l.backend)
+    }
+
+    /// Number of models installed so far
+    #[cfg(test)]
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// The installed model, or an error saying why there is none.
+    pub fn get(&self) -> Result<SharedModel<T>, String> {
+        if let Some(loaded) = self.current.lock().as_ref() {
+            return Ok(Arc::clone(&loaded.model));
+        }
+        if self.loading.load(Ordering::SeqCst) {
+            Err("Transcription model is still loading, try again shortly".to_string())
+        } else {
+            Err("Transcription service not initialised".to_string())
+        }
+    }
+
+    /// Mark a model load as in progress until the guard drops.
+    ///
+    /// Fails when another load is already running, rather than building two
+    /// models at once.
+    pub fn begin_load(&self) -> Result<LoadGuard<'_, T>, String> {
+        self.loading
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .map(|_| LoadGuard { slot: self })
+            .map_err(|_| "Another transcription model is already loading".to_string())
+    }
+
+    /// Swap in a newly loaded model.
+    ///
+    /// Never waits for a transcription in flight; the previous model is
+    /// dropped outside the slot lock once its last user releases it.
+    pub fn install(&self, model: T, backend: TranscriptionBackend) {
+        let loaded = Loaded {
+            model: Arc::new(Mutex::new(model)),
+            backend,
+        };
+        let previous = self.current.lock().replace(loaded);
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        drop(previous);
+    }
+}
+
+/// Marks a model load in progress; see [`ServiceSlot::begin_load`]
+pub struct LoadGuard<'a, T> {
+    slot: &'a ServiceSlot<T>,
+}
+
+impl<T> Drop for LoadGuard<'_, T> {
+    fn drop(&mut self) {
+        self.slot.loading.store(false, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_get_reports_why_no_model() {
+        let slot: ServiceSlot<u32> = ServiceSlot::new();
+        assert!(slot.get().unwrap_err().contains("not initialised"));
+
+        let _guard = slot.begin_load().unwrap();
+        assert!(slot.get().unwrap_err().contains("still loading"));
+    }
+
+    #[test]
+    fn test_concurrent_load_is_refused() {
+        let slot: ServiceSlot<u32> = ServiceSlot::new();
+        let guard = slot.begin_load().unwrap();
+        assert!(slot.begin_load().is_err());
+        drop(guard);
+        assert!(slot.begin_load().is_ok());
+    }
+
+    #[test]
+    fn test_install_does_not_wait_for_transcription() {
+        let slot: Arc<ServiceSlot<u32>> = Arc::new(ServiceSlot::new());
+        slot.install(1, TranscriptionBackend::Whisper);
+
+        let (held_tx, held_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let worker_slot = Arc::clone(&slot);
+        let worker = std::thread::spawn(move || {
+            let model = worker_slot.get().unwrap();
+            let guard = model.lock();
+            held_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+            *guard
+        });
+
+        // With the old model locked mid-"transcription", swapping and
+        // querying must not block.
+        held_rx.recv().unwrap();
+        slot.install(2, TranscriptionBackend::Parakeet);
+        assert!(slot.is_loaded());
+        assert_eq!(slot.backend(), Some(TranscriptionBackend::Parakeet));
+        assert_eq!(*slot.get().unwrap().lock(), 2);
+
+        release_tx.send(()).unwrap();
+        assert_eq!(worker.join().unwrap(), 1);
+        assert_eq!(slot.generation(), 2);
+    }
+
+    #[test]
+    fn test_concurrent_transcribe_and_reload() {
+        let slot: Arc<ServiceSlot<u64>> = Arc::new(ServiceSlot::new());
+        slot.install(0, TranscriptionBackend::Whisper);
+
+        let transcribers: Vec<_> = (0..4)
+            .map(|_| {
+                let slot = Arc::clone(&slot);
+                std::thread::spawn(move || {
+                    for _ in 0..50 {
+                        let model = slot.get().unwrap();
+                        let mut guard = model.lock();
+                        *guard += 1;
+                        std::thread::sleep(Duration::from_micros(200));
+                    }
+                })
+            })
+            .collect();
+
+        let reloaders: Vec<_> = (0..2)
+            .map(|_| {
+                let slot = Arc::clone(&slot);
+                std::thread::spawn(move || {
+                    let mut installed = 0;
+                    for _ in 0..50 {
+                        if let Ok(_guard) = slot.begin_load() {
+                            std::thread::sleep(Duration::from_micros(300));
+                            slot.install(0, TranscriptionBackend::Whisper);
+                            installed += 1;
+                        }
+                        assert!(slot.is_loaded());
+                    }
+                    installed
+                })
+            })
+            .collect();
+
+        for t in transcribers {
+            t.join().unwrap();
+        }
+        let installed: u64 = reloaders.into_iter().map(|r| r.join().unwrap()).sum();
+
+        assert_eq!(slot.generation(), installed + 1);
+        assert!(slot.begin_load().is_ok(), "load flag must be released");
+    }
+}
@@ -7,6 +7,11 @@ use anyhow::{Result, anyhow};
 use std::path::{Path, PathBuf};
 use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
 
+use super::TranscriptSegment;
+
+/// Language Whisper is told the speech is in (ISO 639-1)
+pub const LANGUAGE: &str = "en";
+
 /// Transcription service using Whisper with GPU acceleration
 pub struct WhisperTranscriptionService {
     ctx: WhisperContext,
@@ -35,6 +40,15 @@ impl WhisperTranscriptionService {
             )
         })?;
 
+        // Skip straight to the CPU when this Mac cannot create a Metal device
+        #[cfg(target_os = "macos")]
+        if !crate::platform::macos_acceleration_info().metal_available {
+            tracing::info!("Metal is not usable on this Mac, loading Whisper on the CPU");
+            return Ok(Self {
+                ctx: Self::load_with_cpu(model_str)?,
+            });
+        }
+
         // Try GPU first, fall back to CPU if it fails
         let ctx = Self::try_load_with_gpu(model_str).or_else(|e| {
             tracing::warn!("GPU initialization failed: {:?}, trying CPU fallback", e);
@@ -113,7 +127,9 @@ impl WhisperTranscriptionService {
     /// Transcribe audio from a WAV file
     ///
     /// The file should be 16kHz mono WAV. Returns the transcribed text.
-    pub fn transcribe(&self, audio_path: &Path) -> Result<String> {
+    /// `initial_prompt` is text the speech is taken to follow (see
+    /// [`super::context`]).
+    pub fn transcribe(&self, audio_path: &Path, initial_prompt: Option<&str>) -> Result<String> {
         let (samples, sample_rate) = load_wav_samples(audio_path)?;
 
         tracing::info!(
@@ -123,54 +139,76 @@ impl WhisperTranscriptionService {
             samples.len() as f32 / sample_rate as f32
         );
 
-        // Check audio levels for debugging
-        let max_level = samples.iter().map(|s| s.abs()).fold(0.0f32, f32::max);
-        let rms = (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt();
-        tracing::info!(
-            "Audio levels - max: {:.4}, RMS: {:.4} (dB: {:.1})",
-            max_level,
-            rms,
-            20.0 * (rms + 1e-10).log10()
-        );
-
-        if max_level < 0.001 {
-            tracing::warn!(
-                "Audio appears to be silent or extremely quiet (max level: {})",
-                max_level
-            );
-        }
+        self.transcribe_pcm(samples, sample_rate, initial_prompt)
+    }
 
-        // Trim leading silence for long recordings (the tail is always kept).
-        let (trim_start, trim_end) = crate::audio::vad::trim_silence(&samples, sample_rate);
-        let samples = samples[trim_start..trim_end].to_vec();
+    /// Transcribe audio from a WAV file, keeping Whisper's segments and how
+    /// confident it was in each
+    pub fn transcribe_segments(
+        &self,
+        audio_path: &Path,
+        initial_prompt: Option<&str>,
+    ) -> Result<Vec<TranscriptSegment>> {
+        let (samples, sample_rate) = load_wav_samples(audio_path)?;
+        let samples = prepare_samples(samples, sample_rate);
+        self.transcribe_samples_segments(&samples, initial_prompt)
+    }
 
-        if sample_rate != 16000 {
-            tracing::warn!(
-                "Audio sample rate is {}Hz, expected 16000Hz. Results may be affected.",
-                sample_rate
-            );
-        }
+    /// Transcribe an in-memory mono buffer
+    ///
+    /// Applies the same level check, silence trim and padding as
+    /// [`transcribe`](Self::transcribe). Samples should be 16kHz.
+    pub fn transcribe_pcm(
+        &self,
+        samples: Vec<f32>,
+        sample_rate: u32,
+        initial_prompt: Option<&str>,
+    ) -> Result<String> {
+        let samples = prepare_samples(samples, sample_rate);
+        self.transcribe_samples(&samples, initial_prompt)
+    }
 
-        // Pad with silence so the model can initialise before the first word
-        // and finalise after the last word. Whisper processes in 30-second
-        // windows, so the extra padding never causes a problem.
-        let samples = {
-            const LEADING_SILENCE: usize = 8_000; // 500 ms at 16 kHz
-            const TRAILING_SILENCE: usize = 24_000; // 1.5 s at 16 kHz
-            let mut padded = Vec::with_capacity(LEADING_SILENCE + samples.len() + TRAILING_SILENCE);
-            padded.extend(std::iter::repeat_n(0.0f32, LEADING_SILENCE));
-            padded.extend(samples);
-            padded.extend(std::iter::repeat_n(0.0f32, TRAILING_SILENCE));
-            padded
-        };
-
-        self.transcribe_samples(&samples)
+    /// Transcribe an in-memory mono buffer into Whisper's segments, prepared
+    /// as for [`transcribe_pcm`](Self::transcribe_pcm)
+    pub fn transcribe_pcm_segments(
+        &self,
+        samples: Vec<f32>,
+        sample_rate: u32,
+        initial_prompt: Option<&str>,
+    ) -> Result<Vec<TranscriptSegment>> {
+        let samples = prepare_samples(samples, sample_rate);
+        self.transcribe_samples_segments(&samples, initial_prompt)
     }
 
     /// Transcribe audio samples directly
     ///
     /// Samples should be 16kHz f32 mono audio.
-    pub fn transcribe_samples(&self, samples: &[f32]) -> Result<String> {
+    pub fn transcribe_samples(
+        &self,
+        samples: &[f32],
+        initial_prompt: Option<&str>,
+    ) -> Result<String> {
+        let segments = self.transcribe_samples_segments(samples, initial_prompt)?;
+        let text = segments
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        // No text here: the pipeline logs a preview, redacted if configured.
+        tracing::info!("Transcription result: {} chars", text.trim().len());
+
+        Ok(text.trim().to_string())
+    }
+
+    /// Transcribe audio samples into Whisper's segments
+    ///
+    /// Samples should be 16kHz f32 mono audio.
+    pub fn transcribe_samples_segments(
+        &self,
+        samples: &[f32],
+        initial_prompt: Option<&str>,
+    ) -> Result<Vec<TranscriptSegment>> {
         let start = std::time::Instant::now();
 
         // Create a state for this transcription
@@ -183,7 +221,7 @@ impl WhisperTranscriptionService {
         let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
 
         // English language for speed (no language detection)
-        params.set_language(Some("en"));
+        params.set_language(Some(LANGUAGE));
 
         // Disable translation, we want transcription
         params.set_translate(false);
@@ -197,20 +235,41 @@ impl WhisperTranscriptionService {
         // Single segment mode for faster processing
         params.set_single_segment(false);
 
+        // Condition on the previous dictation so names and style carry over
+        if let Some(prompt) = initial_prompt {
+            params.set_initial_prompt(prompt);
+        }
+
         // Run transcription
         state
             .full(params, samples)
             .map_err(|e| anyhow!("Transcription failed: {:?}", e))?;
 
-        // Collect results using the iterator API
-        let mut text = String::new();
+        // Collect results using the iterator API. Timestamp and other special
+        // tokens sort at or after end-of-text and say nothing about the words.
+        let first_special = self.ctx.token_eot();
+        let mut segments = Vec::new();
         for segment in state.as_iter() {
-            if let Ok(segment_text) = segment.to_str() {
-                if !text.is_empty() {
-                    text.push(' ');
-                }
-                text.push_str(segment_text);
+            let Ok(segment_text) = segment.to_str() else {
+                continue;
+            };
+            let segment_text = segment_text.trim();
+            if segment_text.is_empty() {
+                continue;
             }
+            let logprobs: Vec<f32> = (0..segment.n_tokens())
+                .filter_map(|i| segment.get_token(i))
+                .map(|token| token.token_data())
+                .filter(|data| data.id < first_special)
+                .map(|data| data.plog)
+                .collect();
+            // Whisper times segments in centiseconds
+            segments.push(TranscriptSegment {
+                text: segment_text.to_string(),
+                confidence: segment_confidence(&logprobs),
+                start_secs: Some(segment.start_timestamp() as f32 / 100.0),
+                end_secs: Some(segment.end_timestamp() as f32 / 100.0),
+            });
         }
 
         let duration = start.elapsed();
@@ -224,14 +283,61 @@ impl WhisperTranscriptionService {
             rtf
         );
 
-        tracing::info!(
-            "Transcription result: '{}' ({} chars)",
-            text.trim(),
-            text.len()
+        Ok(segments)
+    }
+}
+
+/// Confidence in a segment from its text tokens' log probabilities: the
+/// geometric mean token probability, from 0.0 to 1.0.
+fn segment_confidence(token_logprobs: &[f32]) -> Option<f32> {
+    if token_logprobs.is_empty() {
+        return None;
+    }
+    let mean = token_logprobs.iter().sum::<f32>() / token_logprobs.len() as f32;
+    Some(mean.exp().clamp(0.0, 1.0))
+}
+
+/// Apply the level check, silence trim and padding every transcription gets
+fn prepare_samples(samples: Vec<f32>, sample_rate: u32) -> Vec<f32> {
+    // Check audio levels for debugging
+    let max_level = samples.iter().map(|s| s.abs()).fold(0.0f32, f32::max);
+    let rms = (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt();
+    tracing::info!(
+        "Audio levels - max: {:.4}, RMS: {:.4} (dB: {:.1})",
+        max_level,
+        rms,
+        20.0 * (rms + 1e-10).log10()
+    );
+
+    if max_level < 0.001 {
+        tracing::warn!(
+            "Audio appears to be silent or extremely quiet (max level: {})",
+            max_level
         );
+    }
 
-        Ok(text.trim().to_string())
+    // Trim leading silence for long recordings (the tail is kept unless
+    // config.audio.trim_silence asks for both edges).
+    let (trim_start, trim_end) = crate::audio::vad::trim_for_transcription(&samples, sample_rate);
+    let samples = samples[trim_start..trim_end].to_vec();
+
+    if sample_rate != 16000 {
+        tracing::warn!(
+            "Audio sample rate is {}Hz, expected 16000Hz. Results may be affected.",
+            sample_rate
+        );
     }
+
+    // Pad with silence so the model can initialise before the first word
+    // and finalise after the last word. Whisper processes in 30-second
+    // windows, so the extra padding never causes a problem.
+    const LEADING_SILENCE: usize = 8_000; // 500 ms at 16 kHz
+    const TRAILING_SILENCE: usize = 24_000; // 1.5 s at 16 kHz
+    let mut padded = Vec::with_capacity(LEADING_SILENCE + samples.len() + TRAILING_SILENCE);
+    padded.extend(std::iter::repeat_n(0.0f32, LEADING_SILENCE));
+    padded.extend(samples);
+    padded.extend(std::iter::repeat_n(0.0f32, TRAILING_SILENCE));
+    padded
 }
 
 /// Load samples from a WAV file
@@ -274,14 +380,7 @@ fn load_wav_samples(path: &Path) -> Result<(Vec<f32>, u32)> {
 
 /// Get the default whisper model directory path
 pub fn get_whisper_model_directory() -> PathBuf {
-    dirs::home_dir()
-        .unwrap_or_else(|| {
-            tracing::error!("Could not determine home directory, using /tmp");
-            PathBuf::from("/tmp")
-        })
-        .join(".thoth")
-        .join("models")
-        .join("whisper")
+    crate::transcription::manifest::get_models_root_directory().join("whisper")
 }
 
 /// Get the path to a specific whisper model
@@ -298,6 +397,7 @@ pub fn is_whisper_model_downloaded(model_id: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[serial_test::serial]
     #[test]
     fn test_get_whisper_model_directory() {
         let dir = get_whisper_model_directory();
@@ -310,4 +410,13 @@ mod tests {
         let path = get_whisper_model_path("ggml-large-v3-turbo");
         assert!(path.to_string_lossy().contains("ggml-large-v3-turbo.bin"));
     }
+
+    #[test]
+    fn test_segment_confidence() {
+        assert_eq!(segment_confidence(&[]), None);
+        assert_eq!(segment_confidence(&[0.0, 0.0]), Some(1.0));
+        // Geometric mean of 0.9 and 0.1 is 0.3
+        let confidence = segment_confidence(&[0.9f32.ln(), 0.1f32.ln()]).unwrap();
+        assert!((confidence - 0.3).abs() < 1e-5, "confidence {}", confidence);
+    }
 }
@@ -6,9 +6,13 @@
 //! - History window
 //! - Settings window
 //! - Quit
+//!
+//! Items other than Quit can be reordered or hidden with `config.tray.items`.
 
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 use tauri::{
     AppHandle, Emitter, Manager,
     image::Image,
@@ -44,9 +48,65 @@ fn get_tray_state() -> &'static RwLock<TrayState> {
     TRAY_STATE.get_or_init(|| RwLock::new(TrayState::default()))
 }
 
+/// Rebuild requests arriving within this window are coalesced into one
+const REBUILD_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// True while a coalesced rebuild is waiting to run
+static REBUILD_PENDING: AtomicBool = AtomicBool::new(false);
+
+/// How long an enumerated device list is reused by later rebuilds
+const DEVICE_CACHE_TTL: Duration = Duration::from_secs(2);
+
+/// Input devices as last enumerated for the menu
+struct DeviceCache {
+    devices: Vec<audio::device::AudioDevice>,
+    at: Instant,
+}
+
+static DEVICE_CACHE: Mutex<Option<DeviceCache>> = Mutex::new(None);
+
+/// The cached device list if it is still fresh, otherwise `enumerate` again.
+fn cached_devices(
+    cache: &mut Option<DeviceCache>,
+    now: Instant,
+    enumerate: impl FnOnce() -> Vec<audio::device::AudioDevice>,
+) -> Vec<audio::device::AudioDevice> {
+    if let Some(cached) = cache.as_ref() {
+        if now.saturating_duration_since(cached.at) <= DEVICE_CACHE_TTL {
+            return cached.devices.clone();
+        }
+    }
+    let devices = enumerate();
+    *cache = Some(DeviceCache {
+        devices: devices.clone(),
+        at: now,
+    });
+    devices
+}
+
+/// Input devices for the menu, enumerating at most once per
+/// [`DEVICE_CACHE_TTL`] so bursts of rebuilds don't hit CoreAudio each time.
+fn list_input_devices() -> Vec<audio::device::AudioDevice> {
+    cached_devices(
+        &mut DEVICE_CACHE.lock(),
+        Instant::now(),
+        audio::device::list_input_devices,
+    )
+}
+
+/// Drop the cached device list so the next rebuild enumerates again.
+///
+/// Called when the devices are known to have changed or the user asked for
+/// a fresh list, where even a two-second-old list may be wrong.
+pub fn invalidate_device_cache() {
+    *DEVICE_CACHE.lock() = None;
+}
+
 /// Menu item IDs
 mod menu_ids {
     pub const STATUS: &str = "status";
+    /// Fix for whatever the status line reports missing
+    pub const FIX_PREREQUISITE: &str = "fix_prerequisite";
     pub const TOGGLE_RECORDING: &str = "toggle_recording";
     pub const COPY_LAST: &str = "copy_last";
     pub const TRANSCRIBE: &str = "transcribe";
@@ -84,7 +144,7 @@ pub fn setup_tray(app: &tauri::App) -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Enumerate audio devices and read config
-    let devices = audio::device::list_input_devices();
+    let devices = list_input_devices();
     let cfg = config::get_config().ok();
     let selected_device_id = cfg.as_ref().and_then(|c| c.audio.device_id.clone());
     let enhancement_enabled = cfg.as_ref().is_some_and(|c| c.enhancement.enabled);
@@ -94,8 +154,14 @@ pub fn setup_tray(app: &tauri::App) -> Result<(), Box<dyn std::error::Error>> {
         .unwrap_or_else(|| "fix-grammar".to_string());
 
     // Build initial menu
+    let layout = tray_layout(
+        cfg.as_ref()
+            .map(|c| c.tray.items.as_slice())
+            .unwrap_or_default(),
+    );
     let menu = build_tray_menu(
         app,
+        &layout,
         false,
         last_transcription.as_deref(),
         &devices,
@@ -131,12 +197,161 @@ pub fn setup_tray(app: &tauri::App) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Something recording needs that is not set up yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MissingPrerequisite {
+    /// Microphone access has not been granted
+    Microphone,
+    /// Accessibility access (for pasting at the cursor) has not been granted
+    Accessibility,
+    /// Accessibility reports granted but does not work (a stale entry,
+    /// typically after a reinstall)
+    StaleAccessibility,
+    /// No transcription model has been downloaded
+    Model,
+}
+
+impl MissingPrerequisite {
+    /// Status line shown while it is missing
+    fn status_text(self) -> &'static str {
+        match self {
+            Self::Microphone => "🟡 Microphone Permission Required",
+            Self::Accessibility => "🟡 Accessibility Permission Required",
+            Self::StaleAccessibility => "🟡 Accessibility Permission Stale",
+            Self::Model => "🟡 No Model Downloaded",
+        }
+    }
+
+    /// Label of the menu item that fixes it
+    fn fix_label(self) -> &'static str {
+        match self {
+            Self::Microphone => "Grant Microphone Access...",
+            Self::Accessibility => "Grant Accessibility Access...",
+            Self::StaleAccessibility => "Reset Accessibility Access...",
+            Self::Model => "Download a Model...",
+        }
+    }
+}
+
+/// The first unmet prerequisite for recording, in the order they are fixed.
+fn missing_prerequisite() -> Option<MissingPrerequisite> {
+    if platform::check_microphone_permission() != "granted" {
+        Some(MissingPrerequisite::Microphone)
+    } else if !platform::check_accessibility() {
+        Some(MissingPrerequisite::Accessibility)
+    } else if platform::is_accessibility_stale() {
+        Some(MissingPrerequisite::StaleAccessibility)
+    } else if !transcription::download::check_model_downloaded(None) {
+        Some(MissingPrerequisite::Model)
+    } else {
+        None
+    }
+}
+
+/// A configurable top-level item of the tray menu (Quit is fixed)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TrayItem {
+    /// Status line, plus the fix for a missing prerequisite
+    Status,
+    /// "Input Source" submenu
+    InputSource,
+    /// "Model" submenu
+    Model,
+    /// "AI Enhancement" submenu
+    AiEnhancement,
+    /// Start/Stop Recording
+    ToggleRecording,
+    /// Copy Last Transcription
+    CopyLast,
+    /// Transcribe...
+    Transcribe,
+    /// History...
+    History,
+    /// Settings...
+    Settings,
+}
+
+impl TrayItem {
+    /// The default menu, top to bottom
+    const DEFAULT_ORDER: [TrayItem; 9] = [
+        TrayItem::Status,
+        TrayItem::InputSource,
+        TrayItem::Model,
+        TrayItem::AiEnhancement,
+        TrayItem::ToggleRecording,
+        TrayItem::CopyLast,
+        TrayItem::Transcribe,
+        TrayItem::History,
+        TrayItem::Settings,
+    ];
+
+    /// ID used in `config.tray.items`
+    fn id(self) -> &'static str {
+        match self {
+            TrayItem::Status => "status",
+            TrayItem::InputSource => "input_source",
+            TrayItem::Model => "model",
+            TrayItem::AiEnhancement => "ai_enhancement",
+            TrayItem::ToggleRecording => "toggle_recording",
+            TrayItem::CopyLast => "copy_last",
+            TrayItem::Transcribe => "transcribe",
+            TrayItem::History => "history",
+            TrayItem::Settings => "settings",
+        }
+    }
+
+    /// The item with this config ID, if any
+    fn from_id(id: &str) -> Option<Self> {
+        Self::DEFAULT_ORDER.into_iter().find(|item| item.id() == id)
+    }
+
+    /// Items of the same group sit together between separators
+    fn group(self) -> u8 {
+        match self {
+            TrayItem::Status => 0,
+            TrayItem::InputSource | TrayItem::Model | TrayItem::AiEnhancement => 1,
+            TrayItem::ToggleRecording => 2,
+            TrayItem::CopyLast | TrayItem::Transcribe | TrayItem::History | TrayItem::Settings => 3,
+        }
+    }
+}
+
+/// The items to show, in order, for `config.tray.items`.
+///
+/// Listed items come first, in the listed order, and the rest follow in
+/// their default order. Unknown and repeated IDs are ignored. Hidden items
+/// are then dropped.
+fn tray_layout(configured: &[config::TrayItemConfig]) -> Vec<TrayItem> {
+    let mut order: Vec<(TrayItem, bool)> = Vec::new();
+    for entry in configured {
+        match TrayItem::from_id(&entry.id) {
+            Some(item) if !order.iter().any(|(i, _)| *i == item) => {
+                order.push((item, entry.visible));
+            }
+            Some(_) => {}
+            None => tracing::debug!("Ignoring unknown tray item {:?}", entry.id),
+        }
+    }
+
+    for item in TrayItem::DEFAULT_ORDER {
+        if !order.iter().any(|(i, _)| *i == item) {
+            order.push((item, true));
+        }
+    }
+
+    order
+        .into_iter()
+        .filter_map(|(item, visible)| visible.then_some(item))
+        .collect()
+}
+
 /// Build the tray menu with current state
 // All args represent distinct, independent menu state; grouping would add
 // indirection without simplifying callers.
 #[allow(clippy::too_many_arguments)]
 fn build_tray_menu(
     app: &impl Manager<tauri::Wry>,
+    layout: &[TrayItem],
     is_recording: bool,
     last_transcription: Option<&str>,
     devices: &[audio::device::AudioDevice],
@@ -145,108 +360,118 @@ fn build_tray_menu(
     active_prompt_id: &str,
     shortcuts: Option<&config::ShortcutConfig>,
 ) -> Result<Menu<tauri::Wry>, Box<dyn std::error::Error>> {
-    // Status item (non-interactive, coloured dot as visual indicator)
-    let status_text = if is_recording {
-        "🔴 Recording..."
-    } else {
-        // Check for issues that would prevent recording
-        let mic_ok = platform::check_microphone_permission() == "granted";
-        let accessibility_ok = platform::check_accessibility();
-        let model_downloaded = transcription::download::check_model_downloaded(None);
-
-        if !mic_ok {
-            "🟡 Microphone Permission Required"
-        } else if !accessibility_ok {
-            "🟡 Accessibility Permission Required"
-        } else if !model_downloaded {
-            "🟡 No Model Downloaded"
-        } else {
-            "🟢 Ready"
+    let menu = Menu::new(app)?;
+
+    // Items are assembled in the configured order, with a separator wherever
+    // the group changes.
+    let mut previous_group = None;
+    for &item in layout {
+        if previous_group.is_some_and(|group| group != item.group()) {
+            menu.append(&PredefinedMenuItem::separator(app)?)?;
         }
-    };
-    let status = MenuItemBuilder::with_id(menu_ids::STATUS, status_text)
-        .enabled(false)
-        .build(app)?;
-
-    let separator1 = PredefinedMenuItem::separator(app)?;
-
-    // Input Source submenu with checkable device items
-    let input_source_submenu = build_input_source_submenu(app, devices, selected_device_id)?;
-
-    // Model submenu (downloaded models with active tick)
-    let model_submenu = build_model_submenu(app)?;
-
-    // AI Enhancement submenu (always visible)
-    let ai_submenu = build_ai_submenu(app, enhancement_enabled, active_prompt_id)?;
-
-    let separator_input = PredefinedMenuItem::separator(app)?;
-
-    // Toggle recording item
-    let recording_text = if is_recording {
-        "Stop Recording"
-    } else {
-        "Start Recording"
-    };
-    let mut toggle_builder = MenuItemBuilder::with_id(menu_ids::TOGGLE_RECORDING, recording_text);
-    if let Some(sc) = shortcuts {
-        if !sc.toggle_recording.is_empty() {
-            toggle_builder = toggle_builder.accelerator(&sc.toggle_recording);
-        }
-    }
-    let toggle_recording = toggle_builder.build(app)?;
-
-    let separator2 = PredefinedMenuItem::separator(app)?;
-
-    // Copy last transcription
-    let mut copy_builder = MenuItemBuilder::with_id(menu_ids::COPY_LAST, "Copy Last Transcription")
-        .enabled(last_transcription.is_some());
-    if let Some(sc) = shortcuts {
-        if let Some(ref key) = sc.copy_last {
-            if !key.is_empty() {
-                copy_builder = copy_builder.accelerator(key);
+        previous_group = Some(item.group());
+
+        match item {
+            TrayItem::Status => {
+                // Status item (non-interactive, coloured dot as visual
+                // indicator). When something that would prevent recording is
+                // missing, an item beneath it fixes it in one click.
+                let missing = if is_recording {
+                    None
+                } else {
+                    missing_prerequisite()
+                };
+                let status_text = if is_recording {
+                    "🔴 Recording..."
+                } else {
+                    missing.map_or("🟢 Ready", MissingPrerequisite::status_text)
+                };
+                let status = MenuItemBuilder::with_id(menu_ids::STATUS, status_text)
+                    .enabled(false)
+                    .build(app)?;
+                menu.append(&status)?;
+                if let Some(missing) = missing {
+                    let fix =
+                        MenuItemBuilder::with_id(menu_ids::FIX_PREREQUISITE, missing.fix_label())
+                            .build(app)?;
+                    menu.append(&fix)?;
+                }
+            }
+            TrayItem::InputSource => {
+                // Input Source submenu with checkable device items
+                menu.append(&build_input_source_submenu(
+                    app,
+                    devices,
+                    selected_device_id,
+                )?)?;
+            }
+            TrayItem::Model => {
+                // Model submenu (downloaded models with active tick)
+                menu.append(&build_model_submenu(app)?)?;
+            }
+            TrayItem::AiEnhancement => {
+                menu.append(&build_ai_submenu(
+                    app,
+                    enhancement_enabled,
+                    active_prompt_id,
+                )?)?;
+            }
+            TrayItem::ToggleRecording => {
+                let recording_text = if is_recording {
+                    "Stop Recording"
+                } else {
+                    "Start Recording"
+                };
+                let mut toggle_builder =
+                    MenuItemBuilder::with_id(menu_ids::TOGGLE_RECORDING, recording_text);
+                if let Some(sc) = shortcuts {
+                    if !sc.toggle_recording.is_empty() {
+                        toggle_builder = toggle_builder.accelerator(&sc.toggle_recording);
+                    }
+                }
+                menu.append(&toggle_builder.build(app)?)?;
+            }
+            TrayItem::CopyLast => {
+                let mut copy_builder =
+                    MenuItemBuilder::with_id(menu_ids::COPY_LAST, "Copy Last Transcription")
+                        .enabled(last_transcription.is_some());
+                if let Some(sc) = shortcuts {
+                    if let Some(ref key) = sc.copy_last {
+                        if !key.is_empty() {
+                            copy_builder = copy_builder.accelerator(key);
+                        }
+                    }
+                }
+                menu.append(&copy_builder.build(app)?)?;
+            }
+            TrayItem::Transcribe => {
+                menu.append(
+                    &MenuItemBuilder::with_id(menu_ids::TRANSCRIBE, "Transcribe...").build(app)?,
+                )?;
+            }
+            TrayItem::History => {
+                menu.append(
+                    &MenuItemBuilder::with_id(menu_ids::HISTORY, "History...").build(app)?,
+                )?;
+            }
+            TrayItem::Settings => {
+                menu.append(
+                    &MenuItemBuilder::with_id(menu_ids::SETTINGS, "Settings...")
+                        .accelerator("CmdOrCtrl+,")
+                        .build(app)?,
+                )?;
             }
         }
     }
-    let copy_last = copy_builder.build(app)?;
 
-    // Transcribe
-    let transcribe = MenuItemBuilder::with_id(menu_ids::TRANSCRIBE, "Transcribe...").build(app)?;
-
-    // History
-    let history = MenuItemBuilder::with_id(menu_ids::HISTORY, "History...").build(app)?;
-
-    // Settings
-    let settings = MenuItemBuilder::with_id(menu_ids::SETTINGS, "Settings...")
-        .accelerator("CmdOrCtrl+,")
-        .build(app)?;
-
-    let separator3 = PredefinedMenuItem::separator(app)?;
-
-    // Quit
+    // Quit is always present, at the bottom
+    if !layout.is_empty() {
+        menu.append(&PredefinedMenuItem::separator(app)?)?;
+    }
     let quit = MenuItemBuilder::with_id(menu_ids::QUIT, "Quit Thoth")
         .accelerator("CmdOrCtrl+Q")
         .build(app)?;
-
-    // Build menu
-    let menu = Menu::with_items(
-        app,
-        &[
-            &status,
-            &separator1,
-            &input_source_submenu,
-            &model_submenu,
-            &ai_submenu,
-            &separator_input,
-            &toggle_recording,
-            &separator2,
-            &copy_last,
-            &transcribe,
-            &history,
-            &settings,
-            &separator3,
-            &quit,
-        ],
-    )?;
+    menu.append(&quit)?;
 
     Ok(menu)
 }
@@ -595,8 +820,8 @@ pub fn set_recording_state(app: &AppHandle, is_recording: bool) {
         }
     }
 
-    // Update menu
-    rebuild_tray_menu(app);
+    // Update menu straight away; recording state must never lag
+    rebuild_tray_menu_now(app);
 }
 
 /// Update the tray with the latest transcription
@@ -611,11 +836,31 @@ pub fn set_last_transcription(app: &AppHandle, text: Option<String>) {
     rebuild_tray_menu(app);
 }
 
-/// Rebuild the tray menu with current state
+/// Rebuild the tray menu with current state, coalescing bursts of requests.
+///
+/// The first request schedules a rebuild [`REBUILD_DEBOUNCE`] later; any
+/// others before it runs are absorbed, and the rebuild reads the state as it
+/// is by then. Use [`rebuild_tray_menu_now`] when the change must show at
+/// once.
 fn rebuild_tray_menu(app: &AppHandle) {
+    if REBUILD_PENDING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let app = app.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(REBUILD_DEBOUNCE);
+        // Cleared before building so a request arriving mid-build schedules
+        // another rebuild rather than being lost.
+        REBUILD_PENDING.store(false, Ordering::SeqCst);
+        rebuild_tray_menu_now(&app);
+    });
+}
+
+/// Rebuild the tray menu with current state immediately
+fn rebuild_tray_menu_now(app: &AppHandle) {
     // Enumerate devices and read config outside of any lock to avoid
     // blocking tray state updates during CoreAudio enumeration
-    let devices = audio::device::list_input_devices();
+    let devices = list_input_devices();
     let cfg = config::get_config().ok();
     let selected_device_id = cfg.as_ref().and_then(|c| c.audio.device_id.clone());
     let enhancement_enabled = cfg.as_ref().is_some_and(|c| c.enhancement.enabled);
@@ -634,8 +879,14 @@ fn rebuild_tray_menu(app: &AppHandle) {
             .map(|t| t.text)
     });
 
+    let layout = tray_layout(
+        cfg.as_ref()
+            .map(|c| c.tray.items.as_slice())
+            .unwrap_or_default(),
+    );
     match build_tray_menu(
         app,
+        &layout,
         state.is_recording,
         last_text.as_deref(),
         &devices,
@@ -675,6 +926,10 @@ fn handle_menu_event(app: &AppHandle, id: &str) {
                 tracing::error!("Failed to emit shortcut-triggered event from tray: {}", e);
             }
         }
+        menu_ids::FIX_PREREQUISITE => {
+            tracing::info!("Fix prerequisite clicked");
+            handle_fix_prerequisite(app);
+        }
         menu_ids::COPY_LAST => {
             tracing::info!("Copy last transcription clicked");
             handle_copy_last(app);
@@ -741,12 +996,11 @@ fn handle_tray_event(event: TrayIconEvent) {
 /// Copy the last transcription to clipboard
 fn handle_copy_last(app: &AppHandle) {
     // Get last transcription from database
-    match database::transcription::list_transcriptions(Some(1), Some(0)) {
-        Ok(transcriptions) => {
-            if let Some(t) = transcriptions.into_iter().next() {
+    match crate::clipboard::last_transcription_text() {
+        Ok(text) => {
+            if let Some(text) = text {
                 // Use the clipboard module to copy
                 let app_clone = app.clone();
-                let text = t.text.clone();
                 tauri::async_runtime::spawn(async move {
                     match crate::clipboard::copy_transcription(app_clone, text, false).await {
                         Ok(_) => {
@@ -762,7 +1016,7 @@ fn handle_copy_last(app: &AppHandle) {
             }
         }
         Err(e) => {
-            tracing::error!("Failed to get last transcription: {}", e);
+            tracing::error!("{}", e);
         }
     }
 }
@@ -804,6 +1058,51 @@ fn handle_open_settings(app: &AppHandle) {
     }
 }
 
+/// Fix whatever the status line reports missing: request the permission
+/// directly, or open the Models pane to download a model.
+fn handle_fix_prerequisite(app: &AppHandle) {
+    match missing_prerequisite() {
+        Some(MissingPrerequisite::Microphone) => {
+            platform::request_microphone_permission(app.clone());
+        }
+        Some(MissingPrerequisite::Accessibility) => {
+            platform::request_accessibility();
+        }
+        Some(MissingPrerequisite::StaleAccessibility) => {
+            // Clear the stale entry (prompts for an admin password), then ask
+            // for the grant again.
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                match platform::reset_tcc_permissions(vec!["Accessibility".to_string()]).await {
+                    Ok(msg) => {
+                        tracing::info!("Accessibility reset from tray: {}", msg);
+                        platform::request_accessibility();
+                    }
+                    Err(e) => tracing::warn!("Accessibility reset from tray failed: {}", e),
+                }
+                platform::refresh_accessibility_health();
+                rebuild_tray_menu(&app);
+            });
+        }
+        Some(MissingPrerequisite::Model) => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+                if let Err(e) = app.emit("navigate", "models") {
+                    tracing::warn!("Failed to emit navigate event: {}", e);
+                }
+            }
+        }
+        None => {
+            tracing::debug!("Nothing to fix; prerequisites already met");
+        }
+    }
+
+    // Permissions may already have changed (or been met since the menu was
+    // built); refresh so the status line is current.
+    rebuild_tray_menu(app);
+}
+
 /// Handle audio device selection from the tray submenu
 fn handle_select_audio_device(app: &AppHandle, device_id: Option<String>) {
     if let Err(e) = config::set_audio_device_config(device_id.clone()) {
@@ -843,10 +1142,10 @@ fn handle_select_model(app: &AppHandle, model_id: String) {
         let manifest = transcription::manifest::get_fallback_manifest();
         if let Some(model) = manifest.models.iter().find(|m| m.id == model_id) {
             let init_result = if model.model_type == "fluidaudio_coreml" {
-                transcription::init_fluidaudio_transcription()
+                transcription::load_fluidaudio()
             } else {
                 let model_dir = transcription::manifest::get_model_directory(&model_id);
-                transcription::init_transcription(model_dir.to_string_lossy().to_string())
+                transcription::load_model(model_dir.to_string_lossy().to_string())
             };
 
             match init_result {
@@ -990,8 +1289,12 @@ pub fn update_tray_recording_state(app: AppHandle, is_recording: bool) {
 }
 
 /// Refresh the tray menu (e.g. after permissions change)
+///
+/// An explicit refresh re-enumerates the input devices rather than reusing
+/// the cached list.
 #[tauri::command]
 pub fn refresh_tray_menu(app: AppHandle) {
+    invalidate_device_cache();
     rebuild_tray_menu(&app);
 }
 
@@ -1025,6 +1328,78 @@ mod tests {
         );
     }
 
+    fn entry(id: &str, visible: bool) -> config::TrayItemConfig {
+        config::TrayItemConfig {
+            id: id.to_string(),
+            visible,
+        }
+    }
+
+    #[test]
+    fn test_tray_layout_defaults() {
+        assert_eq!(tray_layout(&[]), TrayItem::DEFAULT_ORDER.to_vec());
+    }
+
+    #[test]
+    fn test_tray_layout_reorders_and_hides() {
+        let layout = tray_layout(&[
+            entry("copy_last", true),
+            entry("transcribe", false),
+            entry("nonsense", true),
+            entry("copy_last", false),
+        ]);
+        assert_eq!(
+            layout,
+            vec![
+                TrayItem::CopyLast,
+                TrayItem::Status,
+                TrayItem::InputSource,
+                TrayItem::Model,
+                TrayItem::AiEnhancement,
+                TrayItem::ToggleRecording,
+                TrayItem::History,
+                TrayItem::Settings,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tray_layout_hiding_keeps_default_order() {
+        let mut expected = TrayItem::DEFAULT_ORDER.to_vec();
+        expected.retain(|&item| item != TrayItem::Transcribe);
+        assert_eq!(tray_layout(&[entry("transcribe", false)]), expected);
+    }
+
+    #[test]
+    fn test_device_list_cached_briefly() {
+        let device = || audio::device::AudioDevice {
+            id: "mic".to_string(),
+            name: "Mic".to_string(),
+            is_default: true,
+        };
+        let mut cache = None;
+        let mut calls = 0;
+        let start = Instant::now();
+
+        for offset in [0, 100, 1_000] {
+            let now = start + Duration::from_millis(offset);
+            let devices = cached_devices(&mut cache, now, || {
+                calls += 1;
+                vec![device()]
+            });
+            assert_eq!(devices.len(), 1);
+        }
+        assert_eq!(calls, 1, "rebuilds within the TTL reuse the list");
+
+        let later = start + DEVICE_CACHE_TTL + Duration::from_millis(1);
+        let devices = cached_devices(&mut cache, later, || {
+            calls += 1;
+            Vec::new()
+        });
+        assert!(devices.is_empty());
+        assert_eq!(calls, 2, "a stale list is enumerated again");
+    }
+
     #[test]
     fn test_tray_state_info_serialisation() {
         let info = TrayStateInfo {
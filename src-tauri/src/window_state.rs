@@ -0,0 +1,225 @@
+//! Remembers the main window's position and size across launches.
+//!
+//! The geometry is saved to `config.general.main_window` shortly after the
+//! window is moved or resized, and straight away when it is closed. On
+//! startup it is restored, pulled back onto a connected monitor if the one it
+//! was on has gone (e.g. after unplugging an external display).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::{PhysicalPosition, PhysicalSize, WebviewWindow, WindowEvent};
+
+use crate::config::{self, WindowGeometry};
+
+/// Moves and resizes within this window of each other are saved once
+const SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// How much of the window must be on a monitor to count as visible, in
+/// physical pixels, so a sliver at the edge of a screen is not "visible"
+const MIN_VISIBLE: i64 = 100;
+
+/// True while a debounced save is waiting to run
+static SAVE_PENDING: AtomicBool = AtomicBool::new(false);
+
+/// A monitor's bounds, in physical pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MonitorRect {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+/// Length of overlap between the window and a monitor along one axis.
+fn overlap(start: i32, len: u32, other_start: i32, other_len: u32) -> i64 {
+    let end = i64::from(start) + i64::from(len);
+    let other_end = i64::from(other_start) + i64::from(other_len);
+    (end.min(other_end) - i64::from(start).max(i64::from(other_start))).max(0)
+}
+
+/// Where to put a window saved at `saved` given the connected `monitors`
+/// (the primary first).
+///
+/// Kept as is while enough of it is on some monitor; otherwise shrunk to fit
+/// the first monitor and moved inside it. `None` when no monitor is known.
+fn fit_to_monitors(saved: WindowGeometry, monitors: &[MonitorRect]) -> Option<WindowGeometry> {
+    let visible = monitors.iter().any(|m| {
+        overlap(saved.x, saved.width, m.x, m.width) >= MIN_VISIBLE
+            && overlap(saved.y, saved.height, m.y, m.height) >= MIN_VISIBLE
+    });
+    if visible {
+        return Some(saved);
+    }
+
+    let target = monitors.first()?;
+    let width = saved.width.min(target.width);
+    let height = saved.height.min(target.height);
+    let clamp = |pos: i32, len: u32, start: i32, extent: u32| -> i32 {
+        let max = i64::from(start) + i64::from(extent) - i64::from(len);
+        i64::from(pos).clamp(i64::from(start), max) as i32
+    };
+    Some(WindowGeometry {
+        x: clamp(saved.x, width, target.x, target.width),
+        y: clamp(saved.y, height, target.y, target.height),
+        width,
+        height,
+    })
+}
+
+/// Connected monitors, the primary first.
+fn monitor_rects(window: &WebviewWindow) -> Vec<MonitorRect> {
+    let to_rect = |m: &tauri::Monitor| MonitorRect {
+        x: m.position().x,
+        y: m.position().y,
+        width: m.size().width,
+        height: m.size().height,
+    };
+    let mut rects: Vec<MonitorRect> = window
+        .primary_monitor()
+        .ok()
+        .flatten()
+        .iter()
+        .map(to_rect)
+        .collect();
+    for monitor in window.available_monitors().unwrap_or_default() {
+        let rect = to_rect(&monitor);
+        if !rects.contains(&rect) {
+            rects.push(rect);
+        }
+    }
+    rects
+}
+
+/// The window's current geometry, or `None` while minimised (when the
+/// reported position is meaningless).
+fn current_geometry(window: &WebviewWindow) -> Option<WindowGeometry> {
+    if window.is_minimized().unwrap_or(false) {
+        return None;
+    }
+    let position = window.outer_position().ok()?;
+    // set_size restores the inner size, so save that; the outer size would
+    // grow the window by its decorations on every launch.
+    let size = window.inner_size().ok()?;
+    Some(WindowGeometry {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+    })
+}
+
+/// Save the window's geometry now.
+fn save(window: &WebviewWindow) {
+    let Some(geometry) = current_geometry(window) else {
+        return;
+    };
+    if let Err(e) = config::record_main_window_geometry(geometry) {
+        tracing::warn!("Failed to save main window geometry: {}", e);
+    }
+}
+
+/// Save the window's geometry once moves and resizes settle.
+fn save_debounced(window: &WebviewWindow) {
+    if SAVE_PENDING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let window = window.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(SAVE_DEBOUNCE);
+        SAVE_PENDING.store(false, Ordering::SeqCst);
+        save(&window);
+    });
+}
+
+/// Restore the saved geometry, if any, and keep it up to date from now on.
+pub fn restore_and_track(window: &WebviewWindow) {
+    let saved = config::get_config()
+        .ok()
+        .and_then(|c| c.general.main_window);
+    if let Some(saved) = saved {
+        match fit_to_monitors(saved, &monitor_rects(window)) {
+            Some(geometry) => {
+                if geometry != saved {
+                    tracing::info!(
+                        "Main window was off-screen at {:?}; moved to {:?}",
+                        saved,
+                        geometry
+                    );
+                }
+                let _ = window.set_size(PhysicalSize::new(geometry.width, geometry.height));
+                let _ = window.set_position(PhysicalPosition::new(geometry.x, geometry.y));
+            }
+            None => tracing::debug!("No monitors reported; leaving main window in place"),
+        }
+    }
+
+    let win = window.clone();
+    window.on_window_event(move |event| match event {
+        WindowEvent::Moved(_) | WindowEvent::Resized(_) => save_debounced(&win),
+        WindowEvent::CloseRequested { .. } => save(&win),
+        _ => {}
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIMARY: MonitorRect = MonitorRect {
+        x: 0,
+        y: 0,
+        width: 2560,
+        height: 1440,
+    };
+    const ULTRAWIDE: MonitorRect = MonitorRect {
+        x: 2560,
+        y: 0,
+        width: 5120,
+        height: 1440,
+    };
+
+    fn geometry(x: i32, y: i32, width: u32, height: u32) -> WindowGeometry {
+        WindowGeometry {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_visible_window_kept() {
+        let saved = geometry(4000, 200, 1100, 900);
+        assert_eq!(
+            fit_to_monitors(saved, &[PRIMARY, ULTRAWIDE]),
+            Some(saved),
+            "a window on a secondary monitor stays there"
+        );
+        // Mostly off the edge, but enough still shows.
+        let edge = geometry(-900, 100, 1100, 900);
+        assert_eq!(fit_to_monitors(edge, &[PRIMARY]), Some(edge));
+    }
+
+    #[test]
+    fn test_window_on_missing_monitor_moves_to_primary() {
+        let saved = geometry(4000, 200, 1100, 900);
+        assert_eq!(
+            fit_to_monitors(saved, &[PRIMARY]),
+            Some(geometry(1460, 200, 1100, 900))
+        );
+    }
+
+    #[test]
+    fn test_oversized_window_shrinks_to_fit() {
+        let saved = geometry(9000, -500, 3000, 2000);
+        assert_eq!(
+            fit_to_monitors(saved, &[PRIMARY]),
+            Some(geometry(0, 0, 2560, 1440))
+        );
+    }
+
+    #[test]
+    fn test_no_monitors() {
+        assert_eq!(fit_to_monitors(geometry(0, 0, 800, 600), &[]), None);
+    }
+}
@@ -10,7 +10,10 @@
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use tempfile::NamedTempFile;
-use thoth_lib::audio::decode::{decode_audio_to_wav, is_target_format_wav};
+use thoth_lib::audio::decode::{
+    DecodedAudio, InputFormat, decode_audio_to_wav, is_target_format_wav,
+    is_unsupported_format_error,
+};
 
 fn fixtures_dir() -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -20,7 +23,7 @@ fn fixtures_dir() -> PathBuf {
 
 /// Run decode_audio_to_wav on a fixture and assert the output is a valid
 /// 16 kHz mono i16 WAV with at least one non-zero sample.
-fn assert_decodes_to_16khz_mono(fixture: &str) {
+fn assert_decodes_to_16khz_mono(fixture: &str) -> DecodedAudio {
     let input = fixtures_dir().join(fixture);
     assert!(
         input.exists(),
@@ -31,8 +34,9 @@ fn assert_decodes_to_16khz_mono(fixture: &str) {
     let output = NamedTempFile::with_suffix(".wav").expect("tempfile");
     let cancel = AtomicBool::new(false);
 
-    let duration = decode_audio_to_wav(&input, output.path(), &cancel)
+    let decoded = decode_audio_to_wav(&input, output.path(), &cancel)
         .unwrap_or_else(|e| panic!("decode_audio_to_wav failed for {}: {}", fixture, e));
+    let duration = decoded.duration_seconds;
 
     // Duration must be positive
     assert!(
@@ -80,31 +84,43 @@ fn assert_decodes_to_16khz_mono(fixture: &str) {
         "all samples are zero for {} — decode produced silence",
         fixture
     );
+
+    decoded
 }
 
 #[test]
 fn decode_wav_fixture() {
-    assert_decodes_to_16khz_mono("tone_440hz.wav");
+    let decoded = assert_decodes_to_16khz_mono("tone_440hz.wav");
+    assert_eq!(decoded.format, InputFormat::Wav);
+    assert_eq!(decoded.codec, "pcm");
 }
 
 #[test]
 fn decode_mp3_fixture() {
-    assert_decodes_to_16khz_mono("tone_440hz.mp3");
+    let decoded = assert_decodes_to_16khz_mono("tone_440hz.mp3");
+    assert_eq!(decoded.format, InputFormat::Mp3);
+    assert_eq!(decoded.codec, "mp3");
 }
 
 #[test]
 fn decode_m4a_fixture() {
-    assert_decodes_to_16khz_mono("tone_440hz.m4a");
+    let decoded = assert_decodes_to_16khz_mono("tone_440hz.m4a");
+    assert_eq!(decoded.format, InputFormat::M4a);
+    assert_eq!(decoded.codec, "aac");
 }
 
 #[test]
 fn decode_ogg_fixture() {
-    assert_decodes_to_16khz_mono("tone_440hz.ogg");
+    let decoded = assert_decodes_to_16khz_mono("tone_440hz.ogg");
+    assert_eq!(decoded.format, InputFormat::Ogg);
+    assert_eq!(decoded.codec, "vorbis");
 }
 
 #[test]
 fn decode_flac_fixture() {
-    assert_decodes_to_16khz_mono("tone_440hz.flac");
+    let decoded = assert_decodes_to_16khz_mono("tone_440hz.flac");
+    assert_eq!(decoded.format, InputFormat::Flac);
+    assert_eq!(decoded.codec, "flac");
 }
 
 /// A WAV that is already 16 kHz mono i16 is detected as the target format
@@ -159,4 +175,10 @@ fn corrupt_file_gives_symphonia_error() {
         !err.is_empty(),
         "error message should not be empty for a corrupt file"
     );
+    // And be classified as an unsupported format, so batch import can name the file
+    assert!(
+        is_unsupported_format_error(&err),
+        "corrupt file should be reported as an unsupported format, got: {}",
+        err
+    );
 }
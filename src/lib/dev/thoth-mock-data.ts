@@ -23,9 +23,18 @@ import type { CommandMap } from './tauri-mock';
 
 const MOCK_CONFIG = {
   version: 1,
-  audio: { device_id: null, sample_rate: 16000, play_sounds: true },
+  audio: {
+    device_id: null,
+    sample_rate: 16000,
+    play_sounds: true,
+    channel_mode: 'mix',
+    mix_device_ids: [],
+    trim_silence: false,
+    trim_margin_ms: 500,
+  },
   transcription: {
     language: 'en',
+    language_model_map: {},
     auto_copy: false,
     auto_paste: true,
     add_leading_space: false,
@@ -33,6 +42,11 @@ const MOCK_CONFIG = {
     australian_spelling: true,
     spoken_numbers_to_digits: false,
     voice_formatting_commands: true,
+    collapse_repetitions: true,
+    mark_pauses: false,
+    context_carryover: false,
+    context_carryover_window_secs: 120,
+    import_chunk_secs: 300,
   },
   shortcuts: {
     toggle_recording: 'F13',
@@ -45,6 +59,7 @@ const MOCK_CONFIG = {
     model: 'llama3.2',
     prompt_id: 'fix-grammar',
     ollama_url: 'http://localhost:11434',
+    min_words: 3,
   },
   general: {
     launch_at_login: false,
@@ -53,12 +68,14 @@ const MOCK_CONFIG = {
     check_for_updates: true,
     show_recording_indicator: true,
     indicator_style: 'cursor-dot' as const,
+    close_behavior: 'hide_to_tray' as const,
   },
   recorder: { position: 'top-right' as const, offset_x: -20, offset_y: 20, auto_hide_delay: 3000 },
   integrations: {
     api_enabled: false,
     api_port: 8765,
     mcp_enabled: false,
+    dictation_enabled: false,
   },
   logging: {
     local_retention_days: 7,
@@ -68,6 +85,26 @@ const MOCK_CONFIG = {
     loki_tenant: null,
     loki_labels: [] as [string, string][],
     telemetry_level: 'info',
+    file_logging: true,
+  },
+  privacy: {
+    delete_audio_after_transcription: false,
+    redaction: { enabled: false, redact_pasted_text: false, custom_patterns: [] as string[] },
+    log_transcription_text: false,
+  },
+  output: {
+    realtime_insert: false,
+    trailing_space: 'always' as const,
+    copy_last_plain: false,
+    no_focus_fallback: false,
+  },
+  pipeline: {
+    notify_on_silence: true,
+    save_to_history: true,
+    min_recording_ms: 300,
+  },
+  tray: {
+    items: [] as { id: string; visible: boolean }[],
   },
 };
 
@@ -87,6 +124,10 @@ const MOCK_MODELS = [
     selected: true,
     model_type: 'whisper',
     backend_available: true,
+    vram_mb: null,
+    vram_warning: null,
+    is_multilingual: false,
+    language_warning: null,
   },
   {
     id: 'whisper-small-en',
@@ -103,6 +144,10 @@ const MOCK_MODELS = [
     selected: false,
     model_type: 'whisper',
     backend_available: true,
+    vram_mb: null,
+    vram_warning: null,
+    is_multilingual: false,
+    language_warning: null,
   },
 ];
 
@@ -118,6 +163,7 @@ const MOCK_TRANSCRIPTIONS = [
       "Can you push the latest changes to the staging branch before standup? I'll review the pull request straight after.",
     enhancementPrompt: null,
     transcriptionModelName: 'Parakeet TDT v3',
+    detectedLanguage: 'en',
     transcriptionDurationSeconds: 0.4,
     enhancementModelName: null,
     enhancementDurationSeconds: null,
@@ -132,9 +178,11 @@ const MOCK_TRANSCRIPTIONS = [
     rawText: 'lets grab coffee at 3 and go over the q3 numbers',
     enhancementPrompt: 'Fix grammar and punctuation.',
     transcriptionModelName: 'Parakeet TDT v3',
+    detectedLanguage: 'en',
     transcriptionDurationSeconds: 0.3,
     enhancementModelName: 'llama3.2',
     enhancementDurationSeconds: 0.7,
+    timingBreakdown: '{"wait":0.0,"filter":0.004}',
   },
   {
     id: '3',
@@ -147,6 +195,7 @@ const MOCK_TRANSCRIPTIONS = [
       'Thanks for the quick turnaround on the design mockups. Let us lock in the colour palette on Thursday and ship the landing page by Friday.',
     enhancementPrompt: null,
     transcriptionModelName: 'Parakeet TDT v3',
+    detectedLanguage: 'en',
     transcriptionDurationSeconds: 0.5,
     enhancementModelName: null,
     enhancementDurationSeconds: null,
@@ -162,6 +211,7 @@ const MOCK_TRANSCRIPTIONS = [
       'Note to self: cache the model manifest so the settings window opens instantly next time.',
     enhancementPrompt: null,
     transcriptionModelName: 'Parakeet TDT v3',
+    detectedLanguage: 'en',
     transcriptionDurationSeconds: 0.3,
     enhancementModelName: null,
     enhancementDurationSeconds: null,
@@ -174,10 +224,10 @@ const MOCK_AUDIO_DEVICES = [
 ];
 
 const MOCK_DICTIONARY_ENTRIES = [
-  { from: 'github', to: 'GitHub', caseSensitive: false },
-  { from: 'postgres', to: 'PostgreSQL', caseSensitive: false },
-  { from: 'kubernetes', to: 'Kubernetes', caseSensitive: false },
-  { from: 'im', to: "I'm", caseSensitive: false },
+  { from: 'github', to: 'GitHub', caseSensitive: false, priority: 0, fuzzy: false },
+  { from: 'postgres', to: 'PostgreSQL', caseSensitive: false, priority: 0, fuzzy: false },
+  { from: 'kubernetes', to: 'Kubernetes', caseSensitive: false, priority: 0, fuzzy: false },
+  { from: 'im', to: "I'm", caseSensitive: false, priority: 0, fuzzy: false },
 ];
 
 const MOCK_SHORTCUTS = [
@@ -197,6 +247,12 @@ const MOCK_PROMPTS = [
     template: 'Fix grammar and punctuation in the following text.\n\nText: {text}',
     isBuiltin: true,
   },
+  {
+    id: 'restore-punctuation',
+    name: 'Restore Punctuation',
+    template: 'Add punctuation and capitalisation without changing any words.\n\n{text}',
+    isBuiltin: true,
+  },
   {
     id: 'summarise',
     name: 'Summarise',
@@ -401,6 +457,7 @@ export const thothMockCommands: CommandMap = {
   get_config: () => MOCK_CONFIG,
   init_database: () => undefined,
   is_transcription_ready: () => true,
+  is_model_loading: () => false,
   get_model_directory: () => '/Users/dev/.thoth/models',
   check_model_downloaded: () => true,
   init_transcription: () => undefined,
@@ -420,6 +477,12 @@ export const thothMockCommands: CommandMap = {
   fetch_model_manifest: () => MOCK_MODELS,
   get_download_progress: () => 'Idle',
   get_manifest_update_time: () => new Date(Date.now() - 3600 * 1000).toISOString(),
+  refresh_manifest: () => ({
+    models: MOCK_MODELS,
+    diff: { added: [], updated: [], removed: [] },
+    stale: false,
+    updated_at: new Date().toISOString(),
+  }),
 
   // -- History pane --
   list_all_transcriptions: () => MOCK_TRANSCRIPTIONS,
@@ -435,9 +498,27 @@ export const thothMockCommands: CommandMap = {
   // -- Overview / performance panes --
   get_transcription_stats_cmd: () => MOCK_TRANSCRIPTION_STATS,
   get_gpu_info: () => MOCK_GPU_INFO,
+  get_macos_acceleration_info: () => ({
+    metal_available: true,
+    neural_engine_available: true,
+    fluidaudio_compiled: true,
+    preferred_backend: 'fluidaudio',
+  }),
 
   // -- Storage pane --
   get_storage_usage: () => MOCK_STORAGE_USAGE,
+  list_downloaded_models: () => [
+    {
+      id: 'whisper-base-en',
+      size_bytes: 155000000,
+      path: '/Users/dev/.thoth/models/whisper-base-en',
+      model_type: 'whisper_ggml',
+      active: true,
+      complete: true,
+    },
+  ],
+  get_recordings_location: () => ({ path: '/Users/dev/.thoth/Recordings', isCustom: false }),
+  get_data_location: () => ({ path: '/Users/dev/.thoth', isCustom: false, fromEnv: false }),
 
   // -- Insights pane --
   get_insights: () => MOCK_INSIGHTS_DATA,
@@ -487,12 +568,41 @@ export const thothMockCommands: CommandMap = {
   check_accessibility: () => true,
   check_input_monitoring: () => true,
   verify_accessibility_functional: () => true,
+  get_permission_health: () => ({
+    microphone: 'granted',
+    accessibilityGranted: true,
+    accessibilityFunctional: true,
+    accessibilityStale: false,
+  }),
+  run_pipeline_selftest: () => ({
+    success: true,
+    text: 'The quick brown fox jumps over the lazy dog.',
+    expectedText: 'The quick brown fox jumps over the lazy dog.',
+    wordMatch: 1,
+    error: null,
+    backend: 'whisper',
+    model: 'ggml-large-v3-turbo',
+    decodeDurationSeconds: 0.01,
+    modelWaitSeconds: 0,
+    transcriptionDurationSeconds: 0.42,
+    filterDurationSeconds: 0.001,
+    totalDurationSeconds: 0.45,
+  }),
+  request_all_permissions: () => ({
+    microphone: 'granted',
+    accessibility: 'granted',
+    inputMonitoring: 'granted',
+  }),
   check_microphone_permission: () => 'granted',
 
   // -- Config path (settings pane) --
   get_config_path_cmd: () => '/Users/dev/.thoth/config.json',
   get_show_in_dock: () => false,
+  is_autostart_enabled: () => false,
+  set_autostart: () => undefined,
   reset_config: () => MOCK_CONFIG,
+  reset_filter_config: () => MOCK_CONFIG,
+  reset_enhancement_config: () => MOCK_CONFIG,
 
   // -- Clipboard store --
   get_clipboard_settings: () => MOCK_CLIPBOARD_SETTINGS,
@@ -527,14 +637,17 @@ export const thothMockCommands: CommandMap = {
   add_dictionary_entry: () => undefined,
   update_dictionary_entry: () => undefined,
   remove_dictionary_entry: () => undefined,
-  import_dictionary: () => 0,
+  reset_dictionary: () => undefined,
+  import_dictionary: () => ({ imported: 0, skippedDuplicates: 0, errors: [] }),
   export_dictionary: () => '[]',
   apply_dictionary_to_text: (args) => (args as { text?: string } | undefined)?.text ?? '',
   save_custom_prompt_cmd: () => undefined,
+  preview_prompt: (args) => (args as { sampleText?: string } | undefined)?.sampleText ?? '',
   delete_custom_prompt_cmd: () => undefined,
   download_model: () => undefined,
   delete_model: () => undefined,
   reset_download_state: () => undefined,
+  cancel_download: () => undefined,
   delete_fluidaudio_cache: () => undefined,
   init_fluidaudio_transcription: () => undefined,
   delete_transcription_by_id: () => undefined,
@@ -561,10 +674,23 @@ export const thothMockCommands: CommandMap = {
     rawText: 'Mock file transcription.',
     isEnhanced: false,
     durationSeconds: 2.0,
+    wordsPerMinute: 90,
     audioPath: null,
     error: null,
     transcriptionId: null,
   }),
+  pipeline_dry_run: () => ({
+    rawText: 'um mock file transcription',
+    filteredText: 'Mock file transcription.',
+    enhancedText: null,
+    enhancementPrompt: null,
+    durationSeconds: 2.0,
+    transcriptionModelName: 'ggml-base.en',
+    transcriptionDurationSeconds: 0.4,
+    filterDurationSeconds: 0.001,
+    enhancementModelName: null,
+    enhancementDurationSeconds: null,
+  }),
   show_recording_indicator: () => undefined,
   hide_recording_indicator: () => undefined,
   show_window: () => undefined,
@@ -576,10 +702,20 @@ export const thothMockCommands: CommandMap = {
   relaunch_app: () => undefined,
   delete_all_data: () => undefined,
   delete_all_recordings: () => 0,
+  set_recordings_dir: () => ({ path: '/Users/dev/.thoth/Recordings', isCustom: false }),
+  migrate_data_dir: (args) => ({
+    path: (args as { newPath?: string } | undefined)?.newPath ?? '/Users/dev/.thoth',
+    isCustom: true,
+    fromEnv: false,
+  }),
   delete_all_logs: () => 0,
   export_to_csv: () => 0,
   export_to_json: () => 0,
   export_to_txt: () => 0,
+  list_models_used: () => [
+    { modelName: 'ggml-large-v3-turbo', count: 112 },
+    { modelName: 'ggml-base.en', count: 31 },
+  ],
   filter_transcription: (args) => (args as { text?: string } | undefined)?.text ?? '',
   enter_capture_mode: () => 'F13',
   exit_capture_mode: () => undefined,
@@ -607,11 +743,13 @@ export const thothMockCommands: CommandMap = {
     apiRunning: false,
     apiPort: 8765,
     mcpEnabled: false,
+    dictationEnabled: false,
     hasToken: true,
   }),
   get_api_token: () => 'thoth-dev-0000-1111-2222-3333-444455556666',
   set_api_enabled: () => undefined,
   set_mcp_enabled: () => undefined,
+  set_dictation_enabled: () => undefined,
   set_api_port: () => undefined,
   rotate_api_token: () => 'thoth-dev-rotated-aaaa-bbbb-cccc-ddddeeeeffff',
 };
@@ -4,6 +4,11 @@ export const dictionarySchema = z.object({
   from: z.string().min(1, 'Required'),
   to: z.string().min(1, 'Required'),
   caseSensitive: z.boolean().default(false),
+  scopeApp: z.string().default(''),
+  scopeLanguage: z.string().default(''),
+  priority: z.number().int('Whole numbers only').default(0),
+  fuzzy: z.boolean().default(false),
+  fuzzyThreshold: z.number().min(0, 'Between 0 and 1').max(1, 'Between 0 and 1').default(0.7),
 });
 
 export type DictionaryFormData = z.infer<typeof dictionarySchema>;
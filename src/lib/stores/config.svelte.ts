@@ -15,12 +15,25 @@ export interface AudioConfig {
   sampleRate: number;
   /** Whether to play audio feedback sounds */
   playSounds: boolean;
+  /** How multi-channel input is reduced to mono */
+  channelMode: ChannelMode;
+  /** Input device IDs recorded together and mixed (two or more replace deviceId) */
+  mixDeviceIds: string[];
+  /** Trim leading and trailing silence before transcription */
+  trimSilence: boolean;
+  /** Audio kept before the first word when trimming, in ms (twice this after the last) */
+  trimMarginMs: number;
 }
 
+/** Channel selection when downmixing to mono */
+export type ChannelMode = 'mix' | 'left' | 'right';
+
 /** Transcription engine configuration */
 export interface TranscriptionConfig {
   /** Transcription language code (e.g., "en", "auto") */
   language: string;
+  /** Model to use for each language code; the configured language's entry wins when downloaded */
+  languageModelMap: Record<string, string>;
   /** Whether to automatically copy transcription to clipboard */
   autoCopy: boolean;
   /** Whether to automatically paste transcription at cursor */
@@ -41,6 +54,16 @@ export interface TranscriptionConfig {
   sentenceCase: boolean;
   /** Whether to convert spoken formatting commands ("new paragraph" / "new line") into line breaks */
   voiceFormattingCommands: boolean;
+  /** Whether to collapse repetition loops ("you you you you...") to one occurrence */
+  collapseRepetitions: boolean;
+  /** Whether to start a new line at long pauses, marking likely speaker turns */
+  markPauses: boolean;
+  /** Whether to give the model the end of the previous dictation as context */
+  contextCarryover: boolean;
+  /** Seconds after a dictation during which its context carries over */
+  contextCarryoverWindowSecs: number;
+  /** Seconds per window when transcribing a long imported file (0 disables) */
+  importChunkSecs: number;
 }
 
 /** Recording mode options */
@@ -56,8 +79,18 @@ export interface ShortcutConfig {
   copyLast: string | null;
   /** Toggle AI enhancement on/off shortcut (null = unbound) */
   toggleEnhancement: string | null;
+  /** Toggle a recording that is not saved to history (null = unbound) */
+  dictateWithoutSaving: string | null;
+  /** Toggle a recording that is typed at the cursor (null = unbound) */
+  dictateByTyping: string | null;
+  /** Toggle a recording that is pasted at the cursor (null = unbound) */
+  dictateByPasting: string | null;
+  /** Toggle a recording that is output word for word (null = unbound) */
+  dictateVerbatim: string | null;
   /** Recording mode: toggle or push-to-talk */
   recordingMode: RecordingMode;
+  /** How long a modifier-only shortcut must be held to count as a hold (ms) */
+  holdThresholdMs: number;
 }
 
 /** Integrations configuration */
@@ -68,6 +101,67 @@ export interface IntegrationsConfig {
   apiPort: number;
   /** Whether the MCP server is enabled */
   mcpEnabled: boolean;
+  /** Whether API clients may start a recording and receive its text */
+  dictationEnabled: boolean;
+}
+
+/** Redaction of sensitive numbers in stored and logged text */
+export interface RedactionConfig {
+  /** Mask credit-card and SSN-like numbers plus custom patterns */
+  enabled: boolean;
+  /** Also mask the pasted/copied text */
+  redactPastedText: boolean;
+  /** Additional regular expressions to mask */
+  customPatterns: string[];
+}
+
+/** When to leave a space after inserted text */
+export type TrailingSpace = 'always' | 'never' | 'smart';
+
+/** Output configuration */
+export interface OutputConfig {
+  /** Type text at the cursor while recording, as it is recognised */
+  realtimeInsert: boolean;
+  /** When to leave a space after inserted text */
+  trailingSpace: TrailingSpace;
+  /** "Copy last transcription" copies it on one line, without paragraph breaks */
+  copyLastPlain: boolean;
+  /** Copy instead of pasting when auto-paste finds no text field focused */
+  noFocusFallback: boolean;
+}
+
+/** Saved pipeline settings, the `pipeline` section of the config */
+export interface PipelineSettings {
+  /** Show a "no speech detected" notice for silent recordings and imports */
+  notifyOnSilence: boolean;
+  /** Keep each transcription in history; when off, dictations are only output */
+  saveToHistory: boolean;
+  /** Recordings shorter than this (milliseconds) are discarded; 0 keeps all */
+  minRecordingMs: number;
+}
+
+/** One entry of the tray menu layout */
+export interface TrayItemConfig {
+  /** Item ID, e.g. "copy_last" or "transcribe" */
+  id: string;
+  /** Whether the item is shown */
+  visible: boolean;
+}
+
+/** Tray menu configuration */
+export interface TrayConfig {
+  /** Order and visibility of tray menu items; unlisted items follow in default order */
+  items: TrayItemConfig[];
+}
+
+/** Privacy configuration */
+export interface PrivacyConfig {
+  /** Delete the recording once it has been transcribed */
+  deleteAudioAfterTranscription: boolean;
+  /** Redaction of sensitive numbers */
+  redaction: RedactionConfig;
+  /** Include a short transcription preview in the log */
+  logTranscriptionText: boolean;
 }
 
 /** Logging and telemetry configuration */
@@ -86,6 +180,10 @@ export interface LoggingConfig {
   lokiLabels: [string, string][];
   /** Telemetry level: "error", "warn", "info", "debug" */
   telemetryLevel: string;
+  /** Local log verbosity: "error", "warn", "info", "debug", "trace" */
+  logLevel: string;
+  /** Whether log files are written to disk (applies after restart) */
+  fileLogging: boolean;
 }
 
 /** AI enhancement configuration */
@@ -96,6 +194,8 @@ export interface EnhancementConfig {
   model: string;
   /** Selected prompt template ID */
   promptId: string;
+  /** Prompt template IDs applied in sequence (empty = single promptId) */
+  promptChain: string[];
   /** Ollama server URL */
   ollamaUrl: string;
   /** Active backend: "ollama" (default) or "openai_compat" */
@@ -104,10 +204,17 @@ export interface EnhancementConfig {
   openaiCompatUrl: string;
   /** Optional API key for the OpenAI-compatible endpoint */
   apiKey: string | null;
+  /** Pre-load the Ollama model at startup and after a model change */
+  warmupModel: boolean;
+  /** Transcriptions with fewer words are never enhanced (0 = no minimum) */
+  minWords: number;
 }
 
 /** Recording indicator visual style */
-export type IndicatorStyle = 'cursor-dot' | 'fixed-float' | 'pill';
+export type IndicatorStyle = 'cursor-dot' | 'fixed-float' | 'pill' | 'follow-focus';
+
+/** What closing the main window does */
+export type CloseBehavior = 'hide_to_tray' | 'quit' | 'minimize';
 
 /** General application settings */
 export interface GeneralConfig {
@@ -125,6 +232,8 @@ export interface GeneralConfig {
   indicatorStyle: IndicatorStyle;
   /** Show native window decorations (Linux); custom close button when off */
   windowDecorations: boolean;
+  /** What closing the main window does */
+  closeBehavior: CloseBehavior;
 }
 
 /** Recorder window position options */
@@ -169,6 +278,14 @@ export interface Config {
   integrations: IntegrationsConfig;
   /** Logging and telemetry settings */
   logging: LoggingConfig;
+  /** Privacy settings */
+  privacy: PrivacyConfig;
+  /** Output settings */
+  output: OutputConfig;
+  /** Pipeline settings */
+  pipeline: PipelineSettings;
+  /** Tray menu settings */
+  tray: TrayConfig;
 }
 
 /** Raw config from backend (snake_case fields) */
@@ -178,9 +295,14 @@ interface ConfigRaw {
     device_id: string | null;
     sample_rate: number;
     play_sounds: boolean;
+    channel_mode?: ChannelMode;
+    mix_device_ids?: string[];
+    trim_silence?: boolean;
+    trim_margin_ms?: number;
   };
   transcription: {
     language: string;
+    language_model_map?: Record<string, string>;
     auto_copy: boolean;
     auto_paste: boolean;
     add_leading_space: boolean;
@@ -191,22 +313,35 @@ interface ConfigRaw {
     cleanup_punctuation: boolean;
     sentence_case: boolean;
     voice_formatting_commands: boolean;
+    collapse_repetitions?: boolean;
+    mark_pauses?: boolean;
+    context_carryover?: boolean;
+    context_carryover_window_secs?: number;
+    import_chunk_secs?: number;
   };
   shortcuts: {
     toggle_recording: string;
     toggle_recording_alt: string | null;
     copy_last: string | null;
     toggle_enhancement: string | null;
+    dictate_without_saving?: string | null;
+    dictate_by_typing?: string | null;
+    dictate_by_pasting?: string | null;
+    dictate_verbatim?: string | null;
     recording_mode: RecordingMode;
+    hold_threshold_ms?: number;
   };
   enhancement: {
     enabled: boolean;
     model: string;
     prompt_id: string;
+    prompt_chain?: string[];
     ollama_url: string;
     backend: string;
     openai_compat_url: string;
     api_key: string | null;
+    warmup_model?: boolean;
+    min_words?: number;
   };
   general: {
     launch_at_login: boolean;
@@ -216,6 +351,7 @@ interface ConfigRaw {
     show_recording_indicator: boolean;
     indicator_style: IndicatorStyle;
     window_decorations: boolean;
+    close_behavior?: CloseBehavior;
   };
   recorder: {
     position: RecorderPosition;
@@ -227,6 +363,7 @@ interface ConfigRaw {
     api_enabled: boolean;
     api_port: number;
     mcp_enabled: boolean;
+    dictation_enabled?: boolean;
   };
   logging?: {
     local_retention_days: number;
@@ -236,7 +373,37 @@ interface ConfigRaw {
     loki_tenant: string | null;
     loki_labels: [string, string][];
     telemetry_level: string;
+    log_level?: string;
+    file_logging?: boolean;
+  };
+  privacy?: {
+    delete_audio_after_transcription: boolean;
+    redaction?: {
+      enabled: boolean;
+      redact_pasted_text: boolean;
+      custom_patterns: string[];
+    };
+    log_transcription_text?: boolean;
+  };
+  output?: {
+    realtime_insert: boolean;
+    trailing_space?: TrailingSpace;
+    copy_last_plain?: boolean;
+    no_focus_fallback?: boolean;
+  };
+  pipeline?: {
+    notify_on_silence: boolean;
+    save_to_history?: boolean;
+    min_recording_ms?: number;
   };
+  tray?: {
+    items: TrayItemConfig[];
+  };
+}
+
+/** Hide to the menu bar on macOS; minimise elsewhere, where a tray may not be shown */
+function defaultCloseBehavior(): CloseBehavior {
+  return /Mac/.test(navigator.userAgent) ? 'hide_to_tray' : 'minimize';
 }
 
 /** Convert raw backend config to frontend format (snake_case to camelCase) */
@@ -247,9 +414,14 @@ function parseConfig(raw: ConfigRaw): Config {
       deviceId: raw.audio.device_id,
       sampleRate: raw.audio.sample_rate,
       playSounds: raw.audio.play_sounds,
+      channelMode: raw.audio.channel_mode ?? 'mix',
+      mixDeviceIds: raw.audio.mix_device_ids ?? [],
+      trimSilence: raw.audio.trim_silence ?? false,
+      trimMarginMs: raw.audio.trim_margin_ms ?? 500,
     },
     transcription: {
       language: raw.transcription.language,
+      languageModelMap: raw.transcription.language_model_map ?? {},
       autoCopy: raw.transcription.auto_copy,
       autoPaste: raw.transcription.auto_paste,
       addLeadingSpace: raw.transcription.add_leading_space,
@@ -260,22 +432,35 @@ function parseConfig(raw: ConfigRaw): Config {
       cleanupPunctuation: raw.transcription.cleanup_punctuation ?? true,
       sentenceCase: raw.transcription.sentence_case ?? false,
       voiceFormattingCommands: raw.transcription.voice_formatting_commands ?? true,
+      collapseRepetitions: raw.transcription.collapse_repetitions ?? true,
+      markPauses: raw.transcription.mark_pauses ?? false,
+      contextCarryover: raw.transcription.context_carryover ?? false,
+      contextCarryoverWindowSecs: raw.transcription.context_carryover_window_secs ?? 120,
+      importChunkSecs: raw.transcription.import_chunk_secs ?? 300,
     },
     shortcuts: {
       toggleRecording: raw.shortcuts.toggle_recording,
       toggleRecordingAlt: raw.shortcuts.toggle_recording_alt,
       copyLast: raw.shortcuts.copy_last,
       toggleEnhancement: raw.shortcuts.toggle_enhancement,
+      dictateWithoutSaving: raw.shortcuts.dictate_without_saving ?? null,
+      dictateByTyping: raw.shortcuts.dictate_by_typing ?? null,
+      dictateByPasting: raw.shortcuts.dictate_by_pasting ?? null,
+      dictateVerbatim: raw.shortcuts.dictate_verbatim ?? null,
       recordingMode: raw.shortcuts.recording_mode,
+      holdThresholdMs: raw.shortcuts.hold_threshold_ms ?? 500,
     },
     enhancement: {
       enabled: raw.enhancement.enabled,
       model: raw.enhancement.model,
       promptId: raw.enhancement.prompt_id,
+      promptChain: raw.enhancement.prompt_chain ?? [],
       ollamaUrl: raw.enhancement.ollama_url,
       backend: raw.enhancement.backend,
       openaiCompatUrl: raw.enhancement.openai_compat_url,
       apiKey: raw.enhancement.api_key,
+      warmupModel: raw.enhancement.warmup_model ?? false,
+      minWords: raw.enhancement.min_words ?? 3,
     },
     general: {
       launchAtLogin: raw.general.launch_at_login,
@@ -285,6 +470,7 @@ function parseConfig(raw: ConfigRaw): Config {
       showRecordingIndicator: raw.general.show_recording_indicator,
       indicatorStyle: raw.general.indicator_style,
       windowDecorations: raw.general.window_decorations ?? true,
+      closeBehavior: raw.general.close_behavior ?? defaultCloseBehavior(),
     },
     recorder: {
       position: raw.recorder.position,
@@ -296,6 +482,7 @@ function parseConfig(raw: ConfigRaw): Config {
       apiEnabled: raw.integrations?.api_enabled ?? false,
       apiPort: raw.integrations?.api_port ?? 8765,
       mcpEnabled: raw.integrations?.mcp_enabled ?? false,
+      dictationEnabled: raw.integrations?.dictation_enabled ?? false,
     },
     logging: {
       localRetentionDays: raw.logging?.local_retention_days ?? 7,
@@ -305,6 +492,31 @@ function parseConfig(raw: ConfigRaw): Config {
       lokiTenant: raw.logging?.loki_tenant ?? null,
       lokiLabels: raw.logging?.loki_labels ?? [],
       telemetryLevel: raw.logging?.telemetry_level ?? 'info',
+      logLevel: raw.logging?.log_level ?? 'info',
+      fileLogging: raw.logging?.file_logging ?? true,
+    },
+    privacy: {
+      deleteAudioAfterTranscription: raw.privacy?.delete_audio_after_transcription ?? false,
+      redaction: {
+        enabled: raw.privacy?.redaction?.enabled ?? false,
+        redactPastedText: raw.privacy?.redaction?.redact_pasted_text ?? false,
+        customPatterns: raw.privacy?.redaction?.custom_patterns ?? [],
+      },
+      logTranscriptionText: raw.privacy?.log_transcription_text ?? false,
+    },
+    output: {
+      realtimeInsert: raw.output?.realtime_insert ?? false,
+      trailingSpace: raw.output?.trailing_space ?? 'always',
+      copyLastPlain: raw.output?.copy_last_plain ?? false,
+      noFocusFallback: raw.output?.no_focus_fallback ?? false,
+    },
+    pipeline: {
+      notifyOnSilence: raw.pipeline?.notify_on_silence ?? true,
+      saveToHistory: raw.pipeline?.save_to_history ?? true,
+      minRecordingMs: raw.pipeline?.min_recording_ms ?? 300,
+    },
+    tray: {
+      items: raw.tray?.items ?? [],
     },
   };
 }
@@ -317,9 +529,14 @@ function serialiseConfig(config: Config): ConfigRaw {
       device_id: config.audio.deviceId,
       sample_rate: config.audio.sampleRate,
       play_sounds: config.audio.playSounds,
+      channel_mode: config.audio.channelMode,
+      mix_device_ids: config.audio.mixDeviceIds,
+      trim_silence: config.audio.trimSilence,
+      trim_margin_ms: config.audio.trimMarginMs,
     },
     transcription: {
       language: config.transcription.language,
+      language_model_map: config.transcription.languageModelMap,
       auto_copy: config.transcription.autoCopy,
       auto_paste: config.transcription.autoPaste,
       add_leading_space: config.transcription.addLeadingSpace,
@@ -330,22 +547,35 @@ function serialiseConfig(config: Config): ConfigRaw {
       cleanup_punctuation: config.transcription.cleanupPunctuation,
       sentence_case: config.transcription.sentenceCase,
       voice_formatting_commands: config.transcription.voiceFormattingCommands,
+      collapse_repetitions: config.transcription.collapseRepetitions,
+      mark_pauses: config.transcription.markPauses,
+      context_carryover: config.transcription.contextCarryover,
+      context_carryover_window_secs: config.transcription.contextCarryoverWindowSecs,
+      import_chunk_secs: config.transcription.importChunkSecs,
     },
     shortcuts: {
       toggle_recording: config.shortcuts.toggleRecording,
       toggle_recording_alt: config.shortcuts.toggleRecordingAlt,
       copy_last: config.shortcuts.copyLast,
       toggle_enhancement: config.shortcuts.toggleEnhancement,
+      dictate_without_saving: config.shortcuts.dictateWithoutSaving,
+      dictate_by_typing: config.shortcuts.dictateByTyping,
+      dictate_by_pasting: config.shortcuts.dictateByPasting,
+      dictate_verbatim: config.shortcuts.dictateVerbatim,
       recording_mode: config.shortcuts.recordingMode,
+      hold_threshold_ms: config.shortcuts.holdThresholdMs,
     },
     enhancement: {
       enabled: config.enhancement.enabled,
       model: config.enhancement.model,
       prompt_id: config.enhancement.promptId,
+      prompt_chain: config.enhancement.promptChain,
       ollama_url: config.enhancement.ollamaUrl,
       backend: config.enhancement.backend,
       openai_compat_url: config.enhancement.openaiCompatUrl,
       api_key: config.enhancement.apiKey,
+      warmup_model: config.enhancement.warmupModel,
+      min_words: config.enhancement.minWords,
     },
     general: {
       launch_at_login: config.general.launchAtLogin,
@@ -355,6 +585,7 @@ function serialiseConfig(config: Config): ConfigRaw {
       show_recording_indicator: config.general.showRecordingIndicator,
       indicator_style: config.general.indicatorStyle,
       window_decorations: config.general.windowDecorations,
+      close_behavior: config.general.closeBehavior,
     },
     recorder: {
       position: config.recorder.position,
@@ -366,6 +597,7 @@ function serialiseConfig(config: Config): ConfigRaw {
       api_enabled: config.integrations.apiEnabled,
       api_port: config.integrations.apiPort,
       mcp_enabled: config.integrations.mcpEnabled,
+      dictation_enabled: config.integrations.dictationEnabled,
     },
     logging: {
       local_retention_days: config.logging.localRetentionDays,
@@ -375,6 +607,31 @@ function serialiseConfig(config: Config): ConfigRaw {
       loki_tenant: config.logging.lokiTenant,
       loki_labels: config.logging.lokiLabels,
       telemetry_level: config.logging.telemetryLevel,
+      log_level: config.logging.logLevel,
+      file_logging: config.logging.fileLogging,
+    },
+    privacy: {
+      delete_audio_after_transcription: config.privacy.deleteAudioAfterTranscription,
+      redaction: {
+        enabled: config.privacy.redaction.enabled,
+        redact_pasted_text: config.privacy.redaction.redactPastedText,
+        custom_patterns: config.privacy.redaction.customPatterns,
+      },
+      log_transcription_text: config.privacy.logTranscriptionText,
+    },
+    output: {
+      realtime_insert: config.output.realtimeInsert,
+      trailing_space: config.output.trailingSpace,
+      copy_last_plain: config.output.copyLastPlain,
+      no_focus_fallback: config.output.noFocusFallback,
+    },
+    pipeline: {
+      notify_on_silence: config.pipeline.notifyOnSilence,
+      save_to_history: config.pipeline.saveToHistory,
+      min_recording_ms: config.pipeline.minRecordingMs,
+    },
+    tray: {
+      items: config.tray.items,
     },
   };
 }
@@ -387,9 +644,14 @@ function getDefaultConfig(): Config {
       deviceId: null,
       sampleRate: 16000,
       playSounds: true,
+      channelMode: 'mix',
+      mixDeviceIds: [],
+      trimSilence: false,
+      trimMarginMs: 500,
     },
     transcription: {
       language: 'en',
+      languageModelMap: {},
       autoCopy: false,
       autoPaste: true,
       addLeadingSpace: false,
@@ -400,22 +662,35 @@ function getDefaultConfig(): Config {
       cleanupPunctuation: true,
       sentenceCase: false,
       voiceFormattingCommands: true,
+      collapseRepetitions: true,
+      markPauses: false,
+      contextCarryover: false,
+      contextCarryoverWindowSecs: 120,
+      importChunkSecs: 300,
     },
     shortcuts: {
       toggleRecording: 'F13',
       toggleRecordingAlt: 'CommandOrControl+Shift+Space',
       copyLast: 'F14',
       toggleEnhancement: null,
+      dictateWithoutSaving: null,
+      dictateByTyping: null,
+      dictateByPasting: null,
+      dictateVerbatim: null,
       recordingMode: 'toggle',
+      holdThresholdMs: 500,
     },
     enhancement: {
       enabled: false,
       model: 'llama3.2',
       promptId: 'fix-grammar',
+      promptChain: [],
       ollamaUrl: 'http://localhost:11434',
       backend: 'ollama',
       openaiCompatUrl: 'http://localhost:1234',
       apiKey: null,
+      warmupModel: false,
+      minWords: 3,
     },
     general: {
       launchAtLogin: false,
@@ -425,6 +700,7 @@ function getDefaultConfig(): Config {
       showRecordingIndicator: true,
       indicatorStyle: 'cursor-dot',
       windowDecorations: true,
+      closeBehavior: defaultCloseBehavior(),
     },
     recorder: {
       position: 'top-right',
@@ -436,6 +712,7 @@ function getDefaultConfig(): Config {
       apiEnabled: false,
       apiPort: 8765,
       mcpEnabled: false,
+      dictationEnabled: false,
     },
     logging: {
       localRetentionDays: 7,
@@ -445,6 +722,31 @@ function getDefaultConfig(): Config {
       lokiTenant: null,
       lokiLabels: [],
       telemetryLevel: 'info',
+      logLevel: 'info',
+      fileLogging: true,
+    },
+    privacy: {
+      deleteAudioAfterTranscription: false,
+      redaction: {
+        enabled: false,
+        redactPastedText: false,
+        customPatterns: [],
+      },
+      logTranscriptionText: false,
+    },
+    output: {
+      realtimeInsert: false,
+      trailingSpace: 'always',
+      copyLastPlain: false,
+      noFocusFallback: false,
+    },
+    pipeline: {
+      notifyOnSilence: true,
+      saveToHistory: true,
+      minRecordingMs: 300,
+    },
+    tray: {
+      items: [],
     },
   };
 }
@@ -526,6 +828,39 @@ function createConfigStore() {
     }
   }
 
+  /**
+   * Reset one section of the configuration to defaults, leaving the rest
+   */
+  async function resetSection(
+    command: 'reset_filter_config' | 'reset_enhancement_config'
+  ): Promise<boolean> {
+    error = null;
+
+    try {
+      const rawConfig = await invoke<ConfigRaw>(command);
+      config = parseConfig(rawConfig);
+      return true;
+    } catch (e) {
+      error = e instanceof Error ? e.message : 'Failed to reset settings';
+      console.error(`Failed to ${command}:`, e);
+      return false;
+    }
+  }
+
+  /**
+   * Reset only the output filter settings to defaults
+   */
+  function resetFilters(): Promise<boolean> {
+    return resetSection('reset_filter_config');
+  }
+
+  /**
+   * Reset only the AI enhancement settings to defaults (the API key is kept)
+   */
+  function resetEnhancement(): Promise<boolean> {
+    return resetSection('reset_enhancement_config');
+  }
+
   /**
    * Get the configuration file path
    */
@@ -598,6 +933,34 @@ function createConfigStore() {
     config.logging[key] = value;
   }
 
+  /**
+   * Update a specific output config field
+   */
+  function updateOutput<K extends keyof OutputConfig>(key: K, value: OutputConfig[K]): void {
+    config.output[key] = value;
+  }
+
+  /**
+   * Update a specific pipeline config field
+   */
+  function updatePipeline<K extends keyof PipelineSettings>(key: K, value: PipelineSettings[K]): void {
+    config.pipeline[key] = value;
+  }
+
+  /**
+   * Update a specific tray config field
+   */
+  function updateTray<K extends keyof TrayConfig>(key: K, value: TrayConfig[K]): void {
+    config.tray[key] = value;
+  }
+
+  /**
+   * Update a specific privacy config field
+   */
+  function updatePrivacy<K extends keyof PrivacyConfig>(key: K, value: PrivacyConfig[K]): void {
+    config.privacy[key] = value;
+  }
+
   /**
    * Set or clear the enhancement API key via the dedicated backend command.
    *
@@ -667,11 +1030,25 @@ function createConfigStore() {
     get logging() {
       return config.logging;
     },
+    get privacy() {
+      return config.privacy;
+    },
+    get output() {
+      return config.output;
+    },
+    get pipeline() {
+      return config.pipeline;
+    },
+    get tray() {
+      return config.tray;
+    },
 
     // Actions
     load,
     save,
     reset,
+    resetFilters,
+    resetEnhancement,
     getConfigPath,
     updateAudio,
     updateTranscription,
@@ -681,6 +1058,10 @@ function createConfigStore() {
     updateRecorder,
     updateIntegrations,
     updateLogging,
+    updatePrivacy,
+    updateOutput,
+    updatePipeline,
+    updateTray,
     setEnhancementApiKey,
     clearError,
   };
@@ -15,6 +15,36 @@ export interface DictionaryEntry {
   to: string;
   /** Whether the match should be case-sensitive */
   caseSensitive: boolean;
+  /** Restricts the entry to an application and/or language; absent applies everywhere */
+  scope?: DictionaryScope;
+  /** Higher priorities apply first; equal priorities apply in list order */
+  priority: number;
+  /** Also match phonetically close variants (for names and other distinctive words) */
+  fuzzy: boolean;
+  /** Similarity (0-1) a fuzzy match needs; absent uses the default (0.7) */
+  fuzzyThreshold?: number;
+}
+
+/** Application and/or language a dictionary entry is restricted to */
+export interface DictionaryScope {
+  /** Application name, e.g. "Slack" */
+  app?: string;
+  /** Language code, e.g. "en" */
+  language?: string;
+}
+
+/** A row rejected during import */
+export interface ImportRowError {
+  /** 1-based line in the source file (null for JSON) */
+  line: number | null;
+  message: string;
+}
+
+/** Outcome of a dictionary import */
+export interface DictionaryImportSummary {
+  imported: number;
+  skippedDuplicates: number;
+  errors: ImportRowError[];
 }
 
 /** Dictionary state */
@@ -83,24 +113,39 @@ function createDictionaryStore() {
     }
   }
 
-  /** Import dictionary from JSON content */
-  async function importEntries(jsonContent: string, merge: boolean): Promise<number> {
+  /** Remove every dictionary entry */
+  async function reset(): Promise<void> {
+    state.error = null;
+    try {
+      await invoke('reset_dictionary');
+      await load();
+    } catch (e) {
+      state.error = e instanceof Error ? e.message : String(e);
+      throw new Error(state.error);
+    }
+  }
+
+  /** Import dictionary from JSON, CSV or TSV content */
+  async function importEntries(content: string, merge: boolean): Promise<DictionaryImportSummary> {
     state.error = null;
     try {
-      const count = await invoke<number>('import_dictionary', { jsonContent, merge });
+      const summary = await invoke<DictionaryImportSummary>('import_dictionary', {
+        content,
+        merge,
+      });
       await load();
-      return count;
+      return summary;
     } catch (e) {
       state.error = e instanceof Error ? e.message : String(e);
       throw new Error(state.error);
     }
   }
 
-  /** Export dictionary as JSON */
-  async function exportEntries(): Promise<string> {
+  /** Export dictionary as JSON or CSV */
+  async function exportEntries(format: 'json' | 'csv' = 'json'): Promise<string> {
     state.error = null;
     try {
-      return await invoke<string>('export_dictionary');
+      return await invoke<string>('export_dictionary', { format });
     } catch (e) {
       state.error = e instanceof Error ? e.message : String(e);
       throw new Error(state.error);
@@ -131,6 +176,7 @@ function createDictionaryStore() {
     add,
     update,
     remove,
+    reset,
     importEntries,
     exportEntries,
     applyToText,
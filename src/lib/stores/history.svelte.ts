@@ -20,6 +20,19 @@ export interface TranscriptionRecord {
   transcriptionDurationSeconds?: number;
   enhancementModelName?: string;
   enhancementDurationSeconds?: number;
+  /** Per-stage timings in seconds; stages that did not run are absent */
+  timing?: TimingBreakdown;
+  /** Session the record was dictated in, if any */
+  sessionId?: string;
+  /** Language of the speech (ISO 639-1, e.g. "en"), if known */
+  detectedLanguage?: string;
+}
+
+/** Per-stage pipeline timings stored with a record */
+export interface TimingBreakdown {
+  decode?: number;
+  wait?: number;
+  filter?: number;
 }
 
 /** Serialised form from backend (dates as ISO strings, camelCase field names) */
@@ -36,6 +49,10 @@ interface TranscriptionRecordRaw {
   transcriptionDurationSeconds: number | null;
   enhancementModelName: string | null;
   enhancementDurationSeconds: number | null;
+  /** Compact JSON of the per-stage timings, e.g. `{"decode":0.12,"wait":0.0}` */
+  timingBreakdown?: string | null;
+  sessionId?: string | null;
+  detectedLanguage?: string | null;
 }
 
 /** Pagination state for infinite scroll */
@@ -46,6 +63,16 @@ interface PaginationState {
   isLoading: boolean;
 }
 
+/** Parse the stored timing JSON, ignoring anything malformed */
+function parseTiming(json: string | null | undefined): TimingBreakdown | undefined {
+  if (!json) return undefined;
+  try {
+    return JSON.parse(json) as TimingBreakdown;
+  } catch {
+    return undefined;
+  }
+}
+
 /** Convert raw record from backend to typed record */
 function parseRecord(raw: TranscriptionRecordRaw): TranscriptionRecord {
   return {
@@ -61,6 +88,9 @@ function parseRecord(raw: TranscriptionRecordRaw): TranscriptionRecord {
     transcriptionDurationSeconds: raw.transcriptionDurationSeconds ?? undefined,
     enhancementModelName: raw.enhancementModelName ?? undefined,
     enhancementDurationSeconds: raw.enhancementDurationSeconds ?? undefined,
+    timing: parseTiming(raw.timingBreakdown),
+    sessionId: raw.sessionId ?? undefined,
+    detectedLanguage: raw.detectedLanguage ?? undefined,
   };
 }
 
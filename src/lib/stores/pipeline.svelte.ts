@@ -54,18 +54,32 @@ export interface PipelineConfig {
   sentenceCase: boolean;
   /** Whether to convert spoken formatting commands ("new paragraph" / "new line") into line breaks */
   voiceFormattingCommands: boolean;
+  /** Whether to collapse repetition loops ("you you you you...") to one occurrence */
+  collapseRepetitions: boolean;
+  /** Whether to start a new line at long pauses, marking likely speaker turns */
+  markPauses: boolean;
   /** Whether AI enhancement is enabled */
   enhancementEnabled: boolean;
   /** Ollama model for enhancement */
   enhancementModel: string;
   /** Enhancement prompt template */
   enhancementPrompt: string;
+  /** Prompt templates applied in sequence (empty = single enhancementPrompt) */
+  enhancementChain?: string[];
+  /** Transcriptions with fewer words are not enhanced (0 = no minimum) */
+  enhancementMinWords?: number;
   /** Whether to auto-copy to clipboard */
   autoCopy: boolean;
   /** Whether to auto-paste at cursor */
   autoPaste: boolean;
   /** Insertion method: "typing" or "paste" */
   insertionMethod: string;
+  /** Whether to keep the result in history */
+  saveToHistory?: boolean;
+  /** Insertion method fixed by the shortcut that started the recording */
+  forcedInsertionMethod?: 'typing' | 'paste';
+  /** Output the transcript word for word (set by the shortcut that started it) */
+  verbatim?: boolean;
 }
 
 /** Pipeline execution result */
@@ -80,12 +94,64 @@ export interface PipelineResult {
   isEnhanced: boolean;
   /** Duration of the audio in seconds */
   durationSeconds: number | null;
+  /** Speaking pace over the recording, when it was long enough to measure */
+  wordsPerMinute?: number | null;
   /** Path to the audio file */
   audioPath: string | null;
   /** Error message if the pipeline failed */
   error: string | null;
   /** ID of the saved transcription record */
   transcriptionId: string | null;
+  /** Whether the input clipped enough to suggest lowering the gain */
+  clipped?: boolean;
+  /** Fraction of samples at or near full scale, when measured */
+  clipRatio?: number | null;
+  /** Whether input was lost before capture during the recording */
+  hadDropouts?: boolean;
+  /** Time spent converting the input to 16 kHz WAV, when it needed it */
+  decodeDurationSeconds?: number | null;
+  /** Time spent waiting for the transcription model to load */
+  modelWaitSeconds?: number | null;
+  /** Time spent in filters and the dictionary, when they ran */
+  filterDurationSeconds?: number | null;
+  /** Whether the input held no speech; the text is empty and nothing was saved */
+  wasSilent?: boolean;
+}
+
+/** Why a recording stopped */
+export type StopReason = 'user' | 'cancelled' | 'too_short' | 'error';
+
+/** Payload of the recording-stopped event, sent once per stopped recording */
+export interface RecordingStopped {
+  reason: StopReason;
+  /** The recording's audio file, when the stop produced one */
+  audioPath: string | null;
+  /** What went wrong, for an error stop */
+  error: string | null;
+}
+
+/** Every stage of a dry run: nothing is copied, pasted or saved */
+export interface DryRunResult {
+  /** Raw model output */
+  rawText: string;
+  /** Text after filters and the dictionary */
+  filteredText: string;
+  /** Text after AI enhancement, when it ran and succeeded */
+  enhancedText: string | null;
+  /** The prompt(s) enhancement applied */
+  enhancementPrompt: string | null;
+  /** Duration of the audio in seconds */
+  durationSeconds: number | null;
+  /** Name of the transcription model used */
+  transcriptionModelName: string | null;
+  /** Time spent in the transcription model */
+  transcriptionDurationSeconds: number;
+  /** Time spent in filters and the dictionary, when they ran */
+  filterDurationSeconds: number | null;
+  /** Enhancement model used, when enhancement succeeded */
+  enhancementModelName: string | null;
+  /** Time spent enhancing across all passes */
+  enhancementDurationSeconds: number | null;
 }
 
 /** Progress event from the backend */
@@ -96,8 +162,9 @@ interface PipelineProgress {
 
 /**
  * Sentinel error message emitted by the Rust backend when transcription produces no
- * text (silent recording). Must match pipeline.rs `NO_SPEECH_ERROR` exactly.
- * Used to suppress error UI on both the import path and the retranscribe path.
+ * text. Must match pipeline.rs `NO_SPEECH_ERROR` exactly. Recordings and imports
+ * report silence as a `wasSilent` result instead; only retranscription still
+ * returns this error, and its error UI is suppressed.
  */
 export const NO_SPEECH_SENTINEL = 'Transcription produced no text';
 
@@ -123,11 +190,14 @@ async function resolveEnhancementPrompt(promptId: string): Promise<string> {
 }
 
 /** Create the default pipeline configuration based on app settings */
-async function getDefaultConfig(): Promise<PipelineConfig> {
+export async function getDefaultConfig(): Promise<PipelineConfig> {
   const config = configStore.config;
   const enhancementPrompt = config.enhancement.enabled
     ? await resolveEnhancementPrompt(config.enhancement.promptId)
     : DEFAULT_ENHANCEMENT_PROMPT;
+  const enhancementChain = config.enhancement.enabled
+    ? await Promise.all(config.enhancement.promptChain.map(resolveEnhancementPrompt))
+    : [];
 
   return {
     applyDictionary: true,
@@ -139,15 +209,33 @@ async function getDefaultConfig(): Promise<PipelineConfig> {
     cleanupPunctuation: config.transcription.cleanupPunctuation,
     sentenceCase: config.transcription.sentenceCase,
     voiceFormattingCommands: config.transcription.voiceFormattingCommands,
+    collapseRepetitions: config.transcription.collapseRepetitions,
+    markPauses: config.transcription.markPauses,
     enhancementEnabled: config.enhancement.enabled,
     enhancementModel: config.enhancement.model,
     enhancementPrompt,
+    enhancementChain,
+    enhancementMinWords: config.enhancement.minWords,
     autoCopy: config.transcription.autoCopy,
     autoPaste: config.transcription.autoPaste && settingsStore.autoPaste,
     insertionMethod: 'paste',
+    saveToHistory: config.pipeline.saveToHistory,
   };
 }
 
+/**
+ * Run an audio file through transcription, filters and enhancement without
+ * copying, pasting or saving anything. `config` overrides the saved settings,
+ * e.g. to try a different prompt.
+ */
+export async function dryRun(
+  filePath: string,
+  config?: Partial<PipelineConfig>
+): Promise<DryRunResult> {
+  const fullConfig: PipelineConfig = { ...(await getDefaultConfig()), ...config };
+  return invoke<DryRunResult>('pipeline_dry_run', { filePath, config: fullConfig });
+}
+
 /** Create the pipeline store */
 function createPipelineStore() {
   // Reactive state
@@ -155,6 +243,7 @@ function createPipelineStore() {
   let message = $state<string>('');
   let isRunning = $state<boolean>(false);
   let lastResult = $state<PipelineResult | null>(null);
+  let lastStopReason = $state<StopReason | null>(null);
   let error = $state<string | null>(null);
   let audioPath = $state<string | null>(null);
   let recordingStartTime = $state<number | null>(null);
@@ -226,10 +315,76 @@ function createPipelineStore() {
     // the authoritative state update always follows the result delivery.
     const completeUnlisten = await listen<PipelineResult>('pipeline-complete', (event) => {
       debug(' pipeline-complete result received');
+      // A silent result has no text worth replacing the last transcript with.
+      if (event.payload.wasSilent) {
+        return;
+      }
       lastResult = event.payload;
+      if (event.payload.hadDropouts) {
+        toast.warning(
+          'Some audio was lost during this recording, so the transcript may be incomplete.'
+        );
+      }
     });
     unlisteners.push(completeUnlisten);
 
+    // Silent recordings and imports: the backend only sends this when the
+    // user has asked to be told (config.pipeline.notify_on_silence).
+    const noSpeechUnlisten = await listen('no-speech-detected', () => {
+      toast.info('No speech detected');
+    });
+    unlisteners.push(noSpeechUnlisten);
+
+    // Every stop, with why it happened. A stray tap of the shortcut is
+    // discarded as too short (config.pipeline.min_recording_ms).
+    const stoppedUnlisten = await listen<RecordingStopped>('recording-stopped', (event) => {
+      debug(' recording-stopped:', event.payload.reason);
+      lastStopReason = event.payload.reason;
+      if (event.payload.reason === 'too_short') {
+        toast.info('Recording too short, discarded');
+      }
+    });
+    unlisteners.push(stoppedUnlisten);
+
+    // The OS has the input muted; the recording would come out silent
+    const mutedUnlisten = await listen<{ device: string | null }>('microphone-muted', (event) => {
+      const input = event.payload.device ?? 'The default input';
+      toast.warning(`${input} is muted. Unmute it in your system sound settings`);
+    });
+    unlisteners.push(mutedUnlisten);
+
+    // The model in use does not cover the configured language
+    const languageMismatchUnlisten = await listen<{ modelName: string; warning: string }>(
+      'model-language-mismatch',
+      (event) => {
+        toast.warning(`${event.payload.modelName}: ${event.payload.warning}`);
+      }
+    );
+    unlisteners.push(languageMismatchUnlisten);
+
+    // Auto-paste had nowhere to go (config.output.no_focus_fallback)
+    const noTextFieldUnlisten = await listen('no-text-field-focused', () => {
+      toast.info('No text field focused, copied to clipboard instead');
+    });
+    unlisteners.push(noTextFieldUnlisten);
+
+    // A deferred settings write failed after set_config had already returned
+    const configSaveFailedUnlisten = await listen<string>('config-save-failed', (event) => {
+      toast.error(`Settings could not be saved: ${event.payload}`);
+    });
+    unlisteners.push(configSaveFailedUnlisten);
+
+    // The enhancement model was removed from Ollama; text is kept un-enhanced
+    const modelMissingUnlisten = await listen<{ model: string }>(
+      'enhancement-model-missing',
+      (event) => {
+        toast.warning(
+          `AI enhancement model '${event.payload.model}' is not installed in Ollama. Pull it with 'ollama pull ${event.payload.model}' or choose another model.`
+        );
+      }
+    );
+    unlisteners.push(modelMissingUnlisten);
+
     // Listen for cancellation events
     const cancelUnlisten = await listen('pipeline-cancelled', () => {
       state = 'idle';
@@ -288,6 +443,52 @@ function createPipelineStore() {
     });
     unlisteners.push(deviceFallbackUnlisten);
 
+    // Notify when the selected transcription model failed to load and another
+    // backend was loaded in its place, so transcription still works.
+    const backendFallbackUnlisten = await listen<{
+      requested: string | null;
+      modelId: string;
+      backend: string;
+      reason: string;
+    }>('backend-fallback', (event) => {
+      const { requested, modelId, reason } = event.payload;
+      console.warn('[Pipeline] Transcription backend fallback:', reason);
+      toast.warning(
+        `Couldn't load ${requested ?? 'the transcription model'}; using ${modelId} instead`,
+        { duration: 10000 }
+      );
+    });
+    unlisteners.push(backendFallbackUnlisten);
+
+    // Warn when a recording clipped: the transcript may be garbled, and the
+    // fix (lower input gain) is something only the user can do.
+    const clippedUnlisten = await listen<{ audioPath: string; clipRatio: number }>(
+      'recording-clipped',
+      (event) => {
+        const percent = (event.payload.clipRatio * 100).toFixed(1);
+        toast.warning(
+          `Recording clipped (${percent}% of samples at full scale). Try lowering your microphone input level.`,
+          { duration: 8000 }
+        );
+      }
+    );
+    unlisteners.push(clippedUnlisten);
+
+    // A recording stopped while the model was still loading is transcribed
+    // once it is ready; say so rather than looking stuck.
+    const queuedUnlisten = await listen<{ audioPath: string; position: number }>(
+      'queued-for-transcription',
+      (event) => {
+        const ahead = event.payload.position;
+        toast.info(
+          ahead > 0
+            ? `Recording queued behind ${ahead} other${ahead === 1 ? '' : 's'} until the model has loaded`
+            : 'Recording queued until the transcription model has loaded'
+        );
+      }
+    );
+    unlisteners.push(queuedUnlisten);
+
     // Listen for shortcut events to trigger recording.
     // The start-vs-stop decision is made by the Rust pipeline_toggle_recording
     // command (which reads is_recording() — the single authority). The frontend
@@ -309,6 +510,16 @@ function createPipelineStore() {
         debug(`${timestamp} Calling pipeline_toggle_recording (Rust authority)...`);
         await toggleRecording();
         debug(`${timestamp} toggle completed, new state:`, state);
+      } else if (shortcutId === 'dictate_without_saving') {
+        // Rust remembers at start that this recording is not to be saved
+        await toggleRecording({ saveToHistory: false });
+      } else if (shortcutId === 'dictate_by_typing') {
+        // Rust remembers at start how this recording is to be inserted
+        await toggleRecording({ forcedInsertionMethod: 'typing' });
+      } else if (shortcutId === 'dictate_by_pasting') {
+        await toggleRecording({ forcedInsertionMethod: 'paste' });
+      } else if (shortcutId === 'dictate_verbatim') {
+        await toggleRecording({ verbatim: true });
       }
     });
     debug(' shortcut-triggered listener registered');
@@ -482,6 +693,12 @@ function createPipelineStore() {
         config,
       });
 
+      if (result.wasSilent) {
+        // Silent import: not an error; the backend sends any notice.
+        state = 'idle';
+        return { success: true, result };
+      }
+
       lastResult = result;
 
       if (result.success) {
@@ -494,12 +711,6 @@ function createPipelineStore() {
       }
     } catch (e) {
       const errorMsg = `${e}`;
-      if (errorMsg.includes(NO_SPEECH_SENTINEL)) {
-        // Silent import: not an error from the user's perspective.
-        state = 'idle';
-        toast.info('No speech detected');
-        return { success: false, error: errorMsg };
-      }
       console.error('[Pipeline] Exception in transcribeFile:', errorMsg);
       error = errorMsg;
       state = 'failed';
@@ -581,6 +792,9 @@ function createPipelineStore() {
     get lastResult() {
       return lastResult;
     },
+    get lastStopReason() {
+      return lastStopReason;
+    },
     get error() {
       return error;
     },
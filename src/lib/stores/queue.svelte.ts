@@ -0,0 +1,107 @@
+/**
+ * Transcription queue state for Thoth
+ *
+ * Mirrors the backend queue of audio files waiting to be transcribed
+ * (src-tauri/src/queue.rs). The backend drains it in order on a worker;
+ * this store lists the items and lets pending ones be removed.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import { getDefaultConfig, type PipelineResult } from './pipeline.svelte';
+
+/** A file in the transcription queue */
+export interface QueueItem {
+  /** Unique identifier */
+  id: string;
+  /** The source audio file */
+  filePath: string;
+  /** When the file was added (ISO 8601) */
+  enqueuedAt: string;
+  /** Whether it is waiting or being transcribed */
+  state: 'pending' | 'processing';
+}
+
+/** Payload of the queue-item-finished event */
+export interface QueueItemFinished {
+  item: QueueItem;
+  result: PipelineResult | null;
+  error: string | null;
+}
+
+/** Create the transcription queue store */
+function createQueueStore() {
+  let items = $state<QueueItem[]>([]);
+  let lastFinished = $state<QueueItemFinished | null>(null);
+  let unlisteners: UnlistenFn[] = [];
+
+  /** Reload the queue from the backend */
+  async function refresh(): Promise<void> {
+    try {
+      items = await invoke<QueueItem[]>('get_transcription_queue');
+    } catch (e) {
+      console.error('Failed to load transcription queue:', e);
+    }
+  }
+
+  /** Start following queue events; returns a cleanup function */
+  async function initialise(): Promise<() => void> {
+    cleanup();
+    unlisteners.push(await listen<QueueItem>('queue-item-started', () => refresh()));
+    unlisteners.push(
+      await listen<QueueItemFinished>('queue-item-finished', (event) => {
+        lastFinished = event.payload;
+        refresh();
+      })
+    );
+    await refresh();
+    return cleanup;
+  }
+
+  function cleanup(): void {
+    unlisteners.forEach((unlisten) => unlisten());
+    unlisteners = [];
+  }
+
+  /** Add files to the end of the queue */
+  async function enqueue(filePaths: string[]): Promise<{ success: boolean; error?: string }> {
+    try {
+      const config = { ...(await getDefaultConfig()), autoCopy: false, autoPaste: false };
+      await invoke<QueueItem[]>('enqueue_transcriptions', { filePaths, config });
+      await refresh();
+      return { success: true };
+    } catch (e) {
+      return { success: false, error: `${e}` };
+    }
+  }
+
+  /** Remove a pending item */
+  async function remove(id: string): Promise<{ success: boolean; error?: string }> {
+    try {
+      await invoke<boolean>('remove_from_queue', { id });
+      await refresh();
+      return { success: true };
+    } catch (e) {
+      return { success: false, error: `${e}` };
+    }
+  }
+
+  return {
+    get items() {
+      return items;
+    },
+    get isBusy() {
+      return items.length > 0;
+    },
+    get lastFinished() {
+      return lastFinished;
+    },
+    initialise,
+    refresh,
+    enqueue,
+    remove,
+  };
+}
+
+/** Singleton transcription queue store instance */
+export const queueStore = createQueueStore();
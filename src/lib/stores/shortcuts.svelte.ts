@@ -39,7 +39,7 @@ export interface ShortcutConflict {
 
 /** Result of attempting to register a shortcut with conflict detection */
 export type RegistrationResult =
-  | { type: 'Success'; shortcut: string; shortcut_id: string }
+  | { type: 'Success'; shortcut: string; shortcut_id: string; warning?: string }
   | {
       type: 'Conflict';
       shortcut: string;